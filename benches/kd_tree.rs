@@ -0,0 +1,38 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use jord::spherical::{Cap, KdTree};
+use jord::{Angle, NVector};
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let entries = grid();
+
+    c.bench_function("KdTree::from_entries_64800", |b| {
+        b.iter(|| black_box(KdTree::from_entries(entries.clone())))
+    });
+
+    let tree = KdTree::from_entries(entries);
+    let p = NVector::from_lat_long_degrees(55.605, 13.0038);
+
+    c.bench_function("KdTree::nearest_10_of_64800", |b| {
+        b.iter(|| black_box(tree.nearest(p, 10)))
+    });
+
+    c.bench_function("KdTree::within_cap_64800", |b| {
+        let cap = Cap::from_centre_and_radius(p, Angle::from_degrees(5.0));
+        b.iter(|| black_box(tree.within_cap(cap)))
+    });
+}
+
+/// a position every degree of latitude and longitude.
+fn grid() -> Vec<(NVector, usize)> {
+    let mut entries = Vec::new();
+    for lat in -90..90 {
+        for lon in -180..180 {
+            let p = NVector::from_lat_long_degrees(lat as f64 + 0.5, lon as f64 + 0.5);
+            entries.push((p, entries.len()));
+        }
+    }
+    entries
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
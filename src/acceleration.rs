@@ -0,0 +1,180 @@
+use std::time::Duration;
+
+use crate::{Measurement, Speed};
+
+#[derive(PartialEq, PartialOrd, Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))] // codecov:ignore:this
+/// An acceleration.
+///
+/// It primarely exists to unambigously represent an acceleration as opposed to a bare
+/// [f64] (which could be anything and in any unit).
+/// It allows conversion to or from metres/second squared and knots/second.
+///
+/// # Examples
+///
+/// ```
+/// use jord::Acceleration;
+///
+/// assert_eq!(1.0, Acceleration::from_metres_per_second_squared(1.0).as_metres_per_second_squared());
+/// assert_eq!(0.5144444444444445, Acceleration::from_knots_per_second(1.0).as_metres_per_second_squared());
+/// ```
+///
+/// [Acceleration] implements many traits, including [Add](::std::ops::Add), [Sub](::std::ops::Sub),
+/// [Mul](::std::ops::Mul) and [Div](::std::ops::Div), among others.
+///
+/// # Acceleration from speed and time
+///
+/// ```
+/// use jord::{Acceleration, Speed};
+/// use std::time::Duration;
+///
+/// assert_eq!(
+///     Acceleration::from_metres_per_second_squared(1.0),
+///     Speed::from_metres_per_second(2.0) / Duration::from_secs(2)
+/// );
+/// ```
+///
+/// # Change of speed at acceleration over time
+///
+/// ```
+/// use jord::{Acceleration, Speed};
+/// use std::time::Duration;
+///
+/// assert_eq!(
+///     Speed::from_metres_per_second(2.0),
+///     Acceleration::from_metres_per_second_squared(1.0) * Duration::from_secs(2)
+/// );
+/// ```
+pub struct Acceleration {
+    mps2: f64,
+}
+
+impl Acceleration {
+    const KNOTS_TO_MPS: f64 = 1_852.0 / 3_600.0;
+
+    /// Zero acceleration.
+    pub const ZERO: Acceleration = Acceleration { mps2: 0.0 };
+
+    /// Creates an acceleration from a floating point value in metres per second squared.
+    pub const fn from_metres_per_second_squared(mps2: f64) -> Self {
+        Acceleration { mps2 }
+    }
+
+    /// Creates an acceleration from a floating point value in knots per second.
+    pub fn from_knots_per_second(knots: f64) -> Self {
+        Acceleration::from_metres_per_second_squared(knots * Self::KNOTS_TO_MPS)
+    }
+
+    /// Converts this acceleration to a floating point value in metres per second squared.
+    #[inline]
+    pub const fn as_metres_per_second_squared(&self) -> f64 {
+        self.mps2
+    }
+
+    /// Converts this acceleration to a floating point value in knots per second.
+    pub fn as_knots_per_second(&self) -> f64 {
+        self.mps2 / Self::KNOTS_TO_MPS
+    }
+}
+
+impl Measurement for Acceleration {
+    fn from_default_unit(amount: f64) -> Self {
+        Acceleration::from_metres_per_second_squared(amount)
+    }
+
+    #[inline]
+    fn as_default_unit(&self) -> f64 {
+        self.mps2
+    }
+}
+
+impl_measurement! { Acceleration }
+
+impl ::std::ops::Div<Duration> for Speed {
+    type Output = Acceleration;
+
+    fn div(self, rhs: Duration) -> Acceleration {
+        let mps2 = self.as_metres_per_second() / rhs.as_secs_f64();
+        Acceleration::from_metres_per_second_squared(mps2)
+    }
+}
+
+impl ::std::ops::Mul<Duration> for Acceleration {
+    type Output = Speed;
+
+    fn mul(self, rhs: Duration) -> Speed {
+        let mps = self.as_metres_per_second_squared() * rhs.as_secs_f64();
+        Speed::from_metres_per_second(mps)
+    }
+}
+
+#[cfg(feature = "uom")]
+impl From<uom::si::f64::Acceleration> for Acceleration {
+    fn from(value: uom::si::f64::Acceleration) -> Self {
+        Self::from_metres_per_second_squared(
+            value.get::<uom::si::acceleration::meter_per_second_squared>(),
+        )
+    }
+}
+
+#[cfg(feature = "uom")]
+impl From<Acceleration> for uom::si::f64::Acceleration {
+    fn from(value: Acceleration) -> Self {
+        Self::new::<uom::si::acceleration::meter_per_second_squared>(
+            value.as_metres_per_second_squared(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{Acceleration, Speed};
+    use std::time::Duration;
+
+    #[test]
+    fn conversions() {
+        assert_eq!(
+            1.0,
+            Acceleration::from_knots_per_second(1.0).as_knots_per_second()
+        );
+        assert_eq_e6(
+            1.943844,
+            Acceleration::from_metres_per_second_squared(1.0).as_knots_per_second(),
+        );
+
+        fn assert_eq_e6(expected: f64, actual: f64) {
+            let d = (expected - actual).abs();
+            assert!(d < 1e-6, "expected {} but was {}", expected, actual);
+        }
+    }
+
+    #[test]
+    fn std_ops() {
+        assert_eq!(
+            Acceleration::from_metres_per_second_squared(2.0),
+            2.0 * Acceleration::from_metres_per_second_squared(1.0)
+        );
+        assert_eq!(
+            Acceleration::from_metres_per_second_squared(-1.0),
+            -Acceleration::from_metres_per_second_squared(1.0)
+        );
+        assert_eq!(
+            Acceleration::from_metres_per_second_squared(1.0),
+            Speed::from_metres_per_second(1.0) / Duration::from_secs(1)
+        );
+        assert_eq!(
+            Speed::from_metres_per_second(1.0),
+            Acceleration::from_metres_per_second_squared(1.0) * Duration::from_secs(1)
+        );
+    }
+
+    #[cfg(feature = "uom")]
+    #[test]
+    fn uom() {
+        let acceleration = Acceleration::from_metres_per_second_squared(1.0);
+        let uom = uom::si::f64::Acceleration::from(acceleration);
+        let roundtrip = Acceleration::from(uom);
+        assert_eq!(acceleration, roundtrip);
+    }
+}
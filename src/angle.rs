@@ -1,4 +1,4 @@
-use crate::{impl_measurement, Measurement};
+use crate::Measurement;
 use std::f64::consts::PI;
 
 #[derive(PartialEq, PartialOrd, Clone, Copy, Debug, Default)]
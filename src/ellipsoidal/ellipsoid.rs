@@ -1,8 +1,18 @@
+use std::time::Duration;
+
 use crate::{
-    surface::Surface, Angle, Cartesian3DVector, GeocentricPosition, GeodeticPosition, Length,
-    NVector, Vec3,
+    kinematics::{
+        cpa, intercept, minimise, offset_vehicle, root, Kinematics, SOLVER_PRECISION_SECS,
+    },
+    rhumb::Rhumb,
+    spherical::Sphere,
+    surface::Surface,
+    Angle, Cartesian3DVector, Cpa, GeocentricPosition, GeodeticPosition, Intercept, LatLong,
+    Length, NVector, Speed, Vec3, Vehicle,
 };
 
+use super::geodesic::{Geodesic, Inverse};
+
 /// An ellipsoid.
 #[derive(PartialEq, Clone, Copy, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))] // codecov:ignore:this
@@ -208,6 +218,397 @@ impl Ellipsoid {
         let r = (a * a * b).cbrt();
         Length::from_metres(r)
     }
+
+    /// Computes the destination position from the given position having travelled the given distance on the given
+    /// initial bearing (compass angle) along the geodesic (bearing will normally vary before destination is reached).
+    ///
+    /// This solves the direct geodesic problem using the algorithm of Karney (2013) which is accurate to round-off
+    /// for any distance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, Length, LatLong};
+    /// use jord::ellipsoidal::Ellipsoid;
+    ///
+    /// let p = LatLong::from_degrees(90.0, 0.0).to_nvector();
+    /// let dest = Ellipsoid::WGS84.destination_position(
+    ///     p,
+    ///     Angle::from_degrees(180.0),
+    ///     Length::from_metres(10_001_965.729)
+    /// );
+    ///
+    /// assert_eq!(LatLong::from_degrees(0.0, 0.0), LatLong::from_nvector(dest).round_d7());
+    /// ```
+    pub fn destination_position(&self, p0: NVector, bearing: Angle, distance: Length) -> NVector {
+        if distance == Length::ZERO {
+            p0
+        } else {
+            let ll = LatLong::from_nvector(p0);
+            let d = Geodesic::new(self).direct(
+                ll.latitude().as_degrees(),
+                ll.longitude().as_degrees(),
+                bearing.as_degrees(),
+                distance.as_metres(),
+            );
+            NVector::from_lat_long_degrees(d.lat2, d.lon2)
+        }
+    }
+
+    /// Computes the surface distance on the geodesic (shortest path) between the two given positions.
+    ///
+    /// This solves the inverse geodesic problem using the algorithm of Karney (2013) which converges for all pairs of
+    /// positions including nearly antipodal ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Length, LatLong};
+    /// use jord::ellipsoidal::Ellipsoid;
+    ///
+    /// let d = Ellipsoid::WGS84.distance(
+    ///   LatLong::from_degrees(90.0, 0.0).to_nvector(),
+    ///   LatLong::from_degrees(-90.0, 0.0).to_nvector()
+    /// );
+    /// assert_eq!(
+    ///   Length::from_metres(20_003_931.459),
+    ///   d.round_mm()
+    /// );
+    /// ```
+    pub fn distance(&self, p1: NVector, p2: NVector) -> Length {
+        Length::from_metres(self.inverse(p1, p2).s12)
+    }
+
+    /// Computes the final bearing arriving at `p2` from `p1` along the geodesic in compass angle.
+    /// Compass angles are clockwise angles from true north: 0 = north, 90 = east, 180 = south, 270 = west.
+    /// Returns 0 if both positions are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong};
+    /// use jord::ellipsoidal::Ellipsoid;
+    ///
+    /// assert_eq!(
+    ///   Angle::from_degrees(111.59367),
+    ///   Ellipsoid::WGS84.final_bearing(
+    ///     LatLong::from_degrees(40.6, -73.8).to_nvector(),
+    ///     LatLong::from_degrees(49.01666667, 2.55).to_nvector()
+    ///   ).round_d5()
+    /// );
+    /// ```
+    pub fn final_bearing(&self, p1: NVector, p2: NVector) -> Angle {
+        if p1 == p2 {
+            Angle::ZERO
+        } else {
+            Angle::from_degrees(self.inverse(p1, p2).azi2).normalised()
+        }
+    }
+
+    /// Computes the initial bearing from `p1` to `p2` along the geodesic in compass angle.
+    /// Compass angles are clockwise angles from true north: 0 = north, 90 = east, 180 = south, 270 = west.
+    /// Returns 0 if both positions are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong};
+    /// use jord::ellipsoidal::Ellipsoid;
+    ///
+    /// assert_eq!(
+    ///   Angle::from_degrees(53.47022),
+    ///   Ellipsoid::WGS84.initial_bearing(
+    ///     LatLong::from_degrees(40.6, -73.8).to_nvector(),
+    ///     LatLong::from_degrees(49.01666667, 2.55).to_nvector()
+    ///   ).round_d5()
+    /// );
+    /// ```
+    pub fn initial_bearing(&self, p1: NVector, p2: NVector) -> Angle {
+        if p1 == p2 {
+            Angle::ZERO
+        } else {
+            Angle::from_degrees(self.inverse(p1, p2).azi1).normalised()
+        }
+    }
+
+    /// Computes the constant bearing (compass angle) of the [rhumb line](https://en.wikipedia.org/wiki/Rhumb_line)
+    /// (loxodrome) from `p1` to `p2`: the longitude difference is proportional to the difference of isometric
+    /// latitude. The rhumb line never crosses the antimeridian the long way round.
+    /// Returns 0 if both positions are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong};
+    /// use jord::ellipsoidal::Ellipsoid;
+    ///
+    /// assert_eq!(
+    ///   Angle::from_degrees(77.76839),
+    ///   Ellipsoid::WGS84.rhumb_bearing(
+    ///     LatLong::from_degrees(40.6, -73.8).to_nvector(),
+    ///     LatLong::from_degrees(51.6, -0.5).to_nvector()
+    ///   ).round_d5()
+    /// );
+    /// ```
+    pub fn rhumb_bearing(&self, p1: NVector, p2: NVector) -> Angle {
+        Angle::from_radians(self.rhumb().inverse(p1, p2).bearing).normalised()
+    }
+
+    /// Computes the position reached from the given position having travelled the given distance along the rhumb
+    /// line of given bearing (compass angle). Since rhumb lines spiral towards the poles, the pole is returned if
+    /// the given distance exceeds the distance to the pole.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong, Length};
+    /// use jord::ellipsoidal::Ellipsoid;
+    ///
+    /// let p = Ellipsoid::WGS84.rhumb_destination_position(
+    ///     LatLong::from_degrees(40.6, -73.8).to_nvector(),
+    ///     Angle::from_degrees(77.76838971),
+    ///     Length::from_metres(5_771_083.383)
+    /// );
+    /// assert_eq!(LatLong::from_degrees(51.6, -0.5), LatLong::from_nvector(p).round_d7());
+    /// ```
+    pub fn rhumb_destination_position(
+        &self,
+        p0: NVector,
+        bearing: Angle,
+        distance: Length,
+    ) -> NVector {
+        self.rhumb()
+            .direct(p0, bearing.as_radians(), distance.as_metres())
+    }
+
+    /// Computes the surface distance along the rhumb line between the two given positions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, Length};
+    /// use jord::ellipsoidal::Ellipsoid;
+    ///
+    /// let d = Ellipsoid::WGS84.rhumb_distance(
+    ///     LatLong::from_degrees(40.6, -73.8).to_nvector(),
+    ///     LatLong::from_degrees(51.6, -0.5).to_nvector()
+    /// );
+    /// assert_eq!(Length::from_metres(5_771_083.383), d.round_mm());
+    /// ```
+    pub fn rhumb_distance(&self, p1: NVector, p2: NVector) -> Length {
+        Length::from_metres(self.rhumb().inverse(p1, p2).distance)
+    }
+
+    /// Computes the position at given fraction along the rhumb line between the given positions: e.g. the
+    /// midpoint is obtained with a fraction of 0.5.
+    /// Returns `None` if the given fraction is `< 0` or `> 1`.
+    pub fn rhumb_interpolated_position(&self, p1: NVector, p2: NVector, f: f64) -> Option<NVector> {
+        self.rhumb().interpolated(p1, p2, f)
+    }
+
+    /// Returns the position of the given vehicle after the given number of seconds.
+    fn position_after_secs(&self, vehicle: Vehicle, secs: f64) -> NVector {
+        Kinematics::position_after(self, vehicle, Duration::from_secs_f64(secs))
+    }
+
+    /// Returns the vehicle located at the given offset distance behind the given vehicle along its geodesic.
+    fn offset_vehicle(&self, vehicle: Vehicle, offset: Length) -> Vehicle {
+        offset_vehicle(
+            vehicle,
+            offset,
+            |p, b, d| self.destination_position(p, b, d),
+            |p1, p2| self.initial_bearing(p1, p2),
+        )
+    }
+
+    /// Returns the sphere of radius the mean radius of this ellipsoid.
+    fn sphere(&self) -> Sphere {
+        Sphere::new(self.mean_radius())
+    }
+
+    fn rhumb(&self) -> Rhumb {
+        Rhumb::new(self.equatorial_radius.as_metres(), self.flattening)
+    }
+
+    fn inverse(&self, p1: NVector, p2: NVector) -> Inverse {
+        let ll1 = LatLong::from_nvector(p1);
+        let ll2 = LatLong::from_nvector(p2);
+        Geodesic::new(self).inverse(
+            ll1.latitude().as_degrees(),
+            ll1.longitude().as_degrees(),
+            ll2.latitude().as_degrees(),
+            ll2.longitude().as_degrees(),
+        )
+    }
+}
+
+/// Kinematics along geodesics: the solutions are found numerically to a precision of 1 millisecond, starting from
+/// the solutions on the sphere of radius the [mean radius](crate::ellipsoidal::Ellipsoid::mean_radius) of the
+/// ellipsoid.
+///
+/// # Examples
+///
+/// ```
+/// use jord::{Angle, Kinematics, Length, NVector, Speed, Vehicle};
+/// use jord::ellipsoidal::Ellipsoid;
+///
+/// let ownship = Vehicle::new(
+///     NVector::from_lat_long_degrees(20.0, -60.0),
+///     Angle::from_degrees(10.0),
+///     Speed::from_knots(15.0),
+/// );
+///
+/// let intruder = Vehicle::new(
+///     NVector::from_lat_long_degrees(34.0, -50.0),
+///     Angle::from_degrees(220.0),
+///     Speed::from_knots(300.0),
+/// );
+///
+/// let time_at_cpa = Ellipsoid::WGS84.time_to_cpa(ownship, intruder).unwrap();
+/// assert_eq!(11_375, time_at_cpa.as_secs());
+///
+/// let d_cpa = Ellipsoid::WGS84.distance(
+///     Ellipsoid::WGS84.position_after(ownship, time_at_cpa),
+///     Ellipsoid::WGS84.position_after(intruder, time_at_cpa),
+/// );
+/// assert_eq!(Length::from_metres(119_644.0), d_cpa.round_m());
+/// ```
+impl Kinematics for Ellipsoid {
+    fn distance(&self, p1: NVector, p2: NVector) -> Length {
+        Ellipsoid::distance(self, p1, p2)
+    }
+
+    fn position_after(&self, vehicle: Vehicle, duration: Duration) -> NVector {
+        self.destination_position(
+            vehicle.position(),
+            vehicle.bearing(),
+            vehicle.speed() * duration,
+        )
+    }
+
+    fn state_after(&self, vehicle: Vehicle, duration: Duration) -> Vehicle {
+        let distance = vehicle.speed() * duration;
+        if distance == Length::ZERO {
+            return vehicle;
+        }
+        let ll = LatLong::from_nvector(vehicle.position());
+        let d = Geodesic::new(self).direct(
+            ll.latitude().as_degrees(),
+            ll.longitude().as_degrees(),
+            vehicle.bearing().as_degrees(),
+            distance.as_metres(),
+        );
+        Vehicle::new(
+            NVector::from_lat_long_degrees(d.lat2, d.lon2),
+            Angle::from_degrees(d.azi2).normalised(),
+            vehicle.speed(),
+        )
+    }
+
+    fn time_to_cpa(&self, ownship: Vehicle, intruder: Vehicle) -> Option<Duration> {
+        let d = |t: f64| {
+            self.distance(
+                self.position_after_secs(ownship, t),
+                self.position_after_secs(intruder, t),
+            )
+            .as_metres()
+        };
+        let guess = match self.sphere().time_to_cpa(ownship, intruder) {
+            Some(t) => t.as_secs_f64(),
+            // vehicles may still be converging on the ellipsoid.
+            None if d(SOLVER_PRECISION_SECS) < d(0.0) => 0.0,
+            None => return None,
+        };
+        minimise(d, guess, 0.0).map(Duration::from_secs_f64)
+    }
+
+    fn cpa(&self, ownship: Vehicle, intruder: Vehicle) -> Cpa {
+        cpa(
+            self,
+            ownship,
+            intruder,
+            |p1, p2| self.distance(p1, p2),
+            |p1, p2| self.initial_bearing(p1, p2),
+        )
+    }
+
+    fn max_time_to_intercept(
+        &self,
+        interceptor_pos: NVector,
+        intruder: Vehicle,
+    ) -> Option<Duration> {
+        let guess = self
+            .sphere()
+            .max_time_to_intercept(interceptor_pos, intruder)?
+            .as_secs_f64();
+        if guess == 0.0 {
+            return Some(Duration::ZERO);
+        }
+        // minimum speed required to intercept at time t.
+        let s = |t: f64| {
+            self.distance(interceptor_pos, self.position_after_secs(intruder, t))
+                .as_metres()
+                / t
+        };
+        minimise(s, guess, SOLVER_PRECISION_SECS).map(Duration::from_secs_f64)
+    }
+
+    fn time_to_intercept(
+        &self,
+        interceptor_pos: NVector,
+        interceptor_speed: Speed,
+        intruder: Vehicle,
+    ) -> Option<Duration> {
+        let guess = self
+            .sphere()
+            .time_to_intercept(interceptor_pos, interceptor_speed, intruder)?
+            .as_secs_f64();
+        let speed = interceptor_speed.as_metres_per_second();
+        // distance remaining to the intruder at time t.
+        let r = |t: f64| {
+            self.distance(interceptor_pos, self.position_after_secs(intruder, t))
+                .as_metres()
+                - speed * t
+        };
+        root(r, guess).map(Duration::from_secs_f64)
+    }
+
+    fn intercept(
+        &self,
+        interceptor_pos: NVector,
+        interceptor_speed: Speed,
+        intruder: Vehicle,
+        offset: Length,
+    ) -> Option<Intercept> {
+        let target = self.offset_vehicle(intruder, offset);
+        let time = Kinematics::time_to_intercept(self, interceptor_pos, interceptor_speed, target)?;
+        Some(intercept(
+            self,
+            interceptor_pos,
+            Some(interceptor_speed),
+            target,
+            time,
+            |p1, p2| self.initial_bearing(p1, p2),
+        ))
+    }
+
+    fn min_speed_intercept(
+        &self,
+        interceptor_pos: NVector,
+        intruder: Vehicle,
+        offset: Length,
+    ) -> Option<Intercept> {
+        let target = self.offset_vehicle(intruder, offset);
+        let time = Kinematics::max_time_to_intercept(self, interceptor_pos, target)?;
+        Some(intercept(
+            self,
+            interceptor_pos,
+            None,
+            target,
+            time,
+            |p1, p2| self.initial_bearing(p1, p2),
+        ))
+    }
 }
 
 impl Surface for Ellipsoid {
@@ -259,7 +660,9 @@ impl Surface for Ellipsoid {
 
 #[cfg(test)]
 mod tests {
-    use crate::{spherical::Sphere, Angle, Length};
+    use std::{f64::consts::PI, time::Duration};
+
+    use crate::{spherical::Sphere, Angle, Kinematics, LatLong, Length, NVector, Speed, Vehicle};
 
     use super::Ellipsoid;
 
@@ -372,4 +775,457 @@ mod tests {
         let r = (Ellipsoid::WGS84.volumetric_radius().as_metres() * 10.0).round() / 10.0;
         assert_eq!(Sphere::EARTH.radius().as_metres(), r);
     }
+
+    // destination_position
+
+    #[test]
+    fn destination_position_zero_distance() {
+        let p = NVector::from_lat_long_degrees(53.320556, -1.729722);
+        assert_eq!(
+            p,
+            Ellipsoid::WGS84.destination_position(p, Angle::from_degrees(96.0217), Length::ZERO)
+        );
+    }
+
+    #[test]
+    fn destination_position() {
+        let p = NVector::from_lat_long_degrees(40.63972222, -73.77888889);
+        let dest = Ellipsoid::WGS84.destination_position(
+            p,
+            Angle::from_degrees(45.0),
+            Length::from_kilometres(10_000.0),
+        );
+        assert_eq!(
+            Length::from_kilometres(10_000.0),
+            Ellipsoid::WGS84.distance(p, dest).round_mm()
+        );
+        assert_eq!(
+            Angle::from_degrees(45.0),
+            Ellipsoid::WGS84.initial_bearing(p, dest).round_d7()
+        );
+        assert_eq!(
+            LatLong::from_degrees(32.62125, 49.05345),
+            LatLong::from_nvector(dest).round_d5()
+        );
+    }
+
+    #[test]
+    fn destination_position_across_date_line() {
+        let p = NVector::from_lat_long_degrees(0.0, 179.5);
+        let dest = Ellipsoid::WGS84.destination_position(
+            p,
+            Angle::from_degrees(90.0),
+            Length::from_metres(111_319.491),
+        );
+        assert_eq!(
+            LatLong::from_degrees(0.0, -179.5),
+            LatLong::from_nvector(dest).round_d7()
+        );
+    }
+
+    #[test]
+    fn destination_position_round_trip() {
+        let p1 = NVector::from_lat_long_degrees(-37.95103342, 144.42486789);
+        let p2 = NVector::from_lat_long_degrees(-37.65282114, 143.92649554);
+        let d = Ellipsoid::WGS84.distance(p1, p2);
+        let b = Ellipsoid::WGS84.initial_bearing(p1, p2);
+        assert_eq!(
+            LatLong::from_nvector(p2).round_d7(),
+            LatLong::from_nvector(Ellipsoid::WGS84.destination_position(p1, b, d)).round_d7()
+        );
+    }
+
+    // distance
+
+    #[test]
+    fn distance_same_positions() {
+        let p = NVector::from_lat_long_degrees(50.066389, -5.714722);
+        assert_eq!(Length::ZERO, Ellipsoid::WGS84.distance(p, p));
+    }
+
+    #[test]
+    fn distance() {
+        assert_eq!(
+            Length::from_metres(54_972.271),
+            Ellipsoid::WGS84
+                .distance(
+                    NVector::from_lat_long_degrees(-37.95103342, 144.42486789),
+                    NVector::from_lat_long_degrees(-37.65282114, 143.92649554)
+                )
+                .round_mm()
+        );
+        assert_eq!(
+            Length::from_metres(5_853_226.256),
+            Ellipsoid::WGS84
+                .distance(
+                    NVector::from_lat_long_degrees(40.6, -73.8),
+                    NVector::from_lat_long_degrees(49.01666667, 2.55)
+                )
+                .round_mm()
+        );
+    }
+
+    #[test]
+    fn distance_equator() {
+        assert_eq!(
+            Length::from_metres(19_926_188.852),
+            Ellipsoid::WGS84
+                .distance(
+                    NVector::from_lat_long_degrees(0.0, 0.0),
+                    NVector::from_lat_long_degrees(0.0, 179.0)
+                )
+                .round_mm()
+        );
+    }
+
+    #[test]
+    fn distance_nearly_antipodal() {
+        assert_distance(
+            20_003_898.214,
+            (88.202499451857, 0.0),
+            (-88.202499451857, 179.981_022_032_992_85),
+        );
+        assert_distance(
+            20_003_925.854,
+            (89.262080389218, 0.0),
+            (-89.262080389218, 179.992_207_982_775_37),
+        );
+        assert_distance(
+            19_993_558.287,
+            (56.320923501171, 0.0),
+            (-56.320923501171, 179.664_747_671_772_9),
+        );
+        assert_distance(
+            19_991_596.095,
+            (52.784459512564, 0.0),
+            (-52.784_459_512_563_99, 179.634_407_464_943_8),
+        );
+        assert_distance(
+            19_989_144.774,
+            (48.522876735459, 0.0),
+            (-48.522_876_735_458_986, 179.599_720_456_223_08),
+        );
+    }
+
+    #[test]
+    fn distance_antipodal_equator() {
+        assert_distance(20_003_931.459, (0.0, 0.0), (0.0, 180.0));
+        assert_distance(19_980_862.0, (0.0, 0.0), (0.0, 179.5));
+    }
+
+    #[test]
+    fn distance_meridian() {
+        assert_distance(10_001_965.729, (0.0, 0.0), (90.0, 0.0));
+        assert_distance(20_003_931.459, (90.0, 0.0), (-90.0, 0.0));
+    }
+
+    fn assert_distance(expected_metres: f64, p1: (f64, f64), p2: (f64, f64)) {
+        let d = Ellipsoid::WGS84.distance(
+            NVector::from_lat_long_degrees(p1.0, p1.1),
+            NVector::from_lat_long_degrees(p2.0, p2.1),
+        );
+        let e = (d.as_metres() - expected_metres).abs();
+        assert!(
+            e < 0.5e-3 || (expected_metres.fract() == 0.0 && e < 0.5),
+            "expected {} but was {}",
+            expected_metres,
+            d.as_metres()
+        );
+    }
+
+    // initial and final bearing
+
+    #[test]
+    fn bearings_same_positions() {
+        let p = NVector::from_lat_long_degrees(50.066389, -5.714722);
+        assert_eq!(Angle::ZERO, Ellipsoid::WGS84.initial_bearing(p, p));
+        assert_eq!(Angle::ZERO, Ellipsoid::WGS84.final_bearing(p, p));
+    }
+
+    #[test]
+    fn bearings() {
+        let p1 = NVector::from_lat_long_degrees(40.6, -73.8);
+        let p2 = NVector::from_lat_long_degrees(49.01666667, 2.55);
+        assert_eq!(
+            Angle::from_degrees(53.47022),
+            Ellipsoid::WGS84.initial_bearing(p1, p2).round_d5()
+        );
+        assert_eq!(
+            Angle::from_degrees(111.59367),
+            Ellipsoid::WGS84.final_bearing(p1, p2).round_d5()
+        );
+        assert_eq!(
+            Angle::from_degrees(291.59367),
+            Ellipsoid::WGS84.initial_bearing(p2, p1).round_d5()
+        );
+        assert_eq!(
+            Angle::from_degrees(233.47022),
+            Ellipsoid::WGS84.final_bearing(p2, p1).round_d5()
+        );
+    }
+
+    #[test]
+    fn bearings_nearly_antipodal_equator() {
+        let p1 = NVector::from_lat_long_degrees(0.0, 0.0);
+        let p2 = NVector::from_lat_long_degrees(0.0, 179.5);
+        assert_eq!(
+            Angle::from_degrees(55.9665),
+            Ellipsoid::WGS84.initial_bearing(p1, p2).round_d5()
+        );
+        assert_eq!(
+            Angle::from_degrees(124.0335),
+            Ellipsoid::WGS84.final_bearing(p1, p2).round_d5()
+        );
+    }
+
+    #[test]
+    fn bearings_meridian() {
+        let p1 = NVector::from_lat_long_degrees(0.0, 0.0);
+        let p2 = NVector::from_lat_long_degrees(45.0, 0.0);
+        assert_eq!(Angle::ZERO, Ellipsoid::WGS84.initial_bearing(p1, p2));
+        assert_eq!(Angle::ZERO, Ellipsoid::WGS84.final_bearing(p1, p2));
+        assert_eq!(Angle::HALF_CIRCLE, Ellipsoid::WGS84.initial_bearing(p2, p1));
+    }
+
+    #[test]
+    fn rhumb_bearing_and_distance() {
+        // RhumbSolve -i 40.6 -73.8 51.6 -0.5
+        let p1 = NVector::from_lat_long_degrees(40.6, -73.8);
+        let p2 = NVector::from_lat_long_degrees(51.6, -0.5);
+        assert_eq!(
+            Angle::from_degrees(77.7683897),
+            Ellipsoid::WGS84.rhumb_bearing(p1, p2).round_d7()
+        );
+        assert_eq!(
+            Angle::from_degrees(257.7683897),
+            Ellipsoid::WGS84.rhumb_bearing(p2, p1).round_d7()
+        );
+        assert_eq!(
+            Length::from_metres(5_771_083.383),
+            Ellipsoid::WGS84.rhumb_distance(p1, p2).round_mm()
+        );
+        assert_eq!(
+            Length::from_metres(5_771_083.383),
+            Ellipsoid::WGS84.rhumb_distance(p2, p1).round_mm()
+        );
+    }
+
+    #[test]
+    fn rhumb_bearing_across_date_line() {
+        let p1 = NVector::from_lat_long_degrees(10.0, 179.0);
+        let p2 = NVector::from_lat_long_degrees(10.0, -179.0);
+        assert_eq!(
+            Angle::from_degrees(90.0),
+            Ellipsoid::WGS84.rhumb_bearing(p1, p2).round_d7()
+        );
+    }
+
+    #[test]
+    fn rhumb_distance_same_positions() {
+        let p = NVector::from_lat_long_degrees(50.066389, -5.714722);
+        assert_eq!(Length::ZERO, Ellipsoid::WGS84.rhumb_distance(p, p));
+        assert_eq!(Angle::ZERO, Ellipsoid::WGS84.rhumb_bearing(p, p));
+    }
+
+    #[test]
+    fn rhumb_distance_equator() {
+        let p1 = NVector::from_lat_long_degrees(0.0, 0.0);
+        let p2 = NVector::from_lat_long_degrees(0.0, 90.0);
+        assert_eq!(
+            (Ellipsoid::WGS84.equatorial_radius() * (PI / 2.0)).round_mm(),
+            Ellipsoid::WGS84.rhumb_distance(p1, p2).round_mm()
+        );
+    }
+
+    #[test]
+    fn rhumb_distance_meridian() {
+        // quarter meridian.
+        let p1 = NVector::from_lat_long_degrees(0.0, 0.0);
+        let p2 = NVector::from_lat_long_degrees(90.0, 0.0);
+        assert_eq!(
+            Length::from_metres(10_001_965.729),
+            Ellipsoid::WGS84.rhumb_distance(p1, p2).round_mm()
+        );
+    }
+
+    #[test]
+    fn rhumb_destination_position_beyond_pole() {
+        let p = NVector::from_lat_long_degrees(-80.0, 0.0);
+        let d = Ellipsoid::WGS84.rhumb_destination_position(
+            p,
+            Angle::from_degrees(135.0),
+            Length::from_kilometres(5_000.0),
+        );
+        assert_eq!(
+            LatLong::from_degrees(-90.0, 0.0),
+            LatLong::from_nvector(d).round_d7()
+        );
+    }
+
+    #[test]
+    fn rhumb_destination_position_round_trip() {
+        let p1 = NVector::from_lat_long_degrees(-33.8688, 151.2093);
+        let p2 = NVector::from_lat_long_degrees(37.7749, -122.4194);
+        let b = Ellipsoid::WGS84.rhumb_bearing(p1, p2);
+        let d = Ellipsoid::WGS84.rhumb_distance(p1, p2);
+        assert_eq!(
+            LatLong::from_nvector(p2).round_d7(),
+            LatLong::from_nvector(Ellipsoid::WGS84.rhumb_destination_position(p1, b, d)).round_d7()
+        );
+    }
+
+    #[test]
+    fn rhumb_interpolated_position() {
+        let p1 = NVector::from_lat_long_degrees(40.6, -73.8);
+        let p2 = NVector::from_lat_long_degrees(51.6, -0.5);
+        assert_eq!(
+            Some(p1),
+            Ellipsoid::WGS84.rhumb_interpolated_position(p1, p2, 0.0)
+        );
+        assert_eq!(
+            Some(p2),
+            Ellipsoid::WGS84.rhumb_interpolated_position(p1, p2, 1.0)
+        );
+        assert!(Ellipsoid::WGS84
+            .rhumb_interpolated_position(p1, p2, 1.5)
+            .is_none());
+
+        let m = Ellipsoid::WGS84
+            .rhumb_interpolated_position(p1, p2, 0.5)
+            .unwrap();
+        let d = Ellipsoid::WGS84.rhumb_distance(p1, p2);
+        assert_eq!(
+            (d * 0.5).round_mm(),
+            Ellipsoid::WGS84.rhumb_distance(p1, m).round_mm()
+        );
+        assert_eq!(
+            Ellipsoid::WGS84.rhumb_bearing(p1, p2).round_d7(),
+            Ellipsoid::WGS84.rhumb_bearing(m, p2).round_d7()
+        );
+    }
+
+    // kinematics
+
+    fn ownship() -> Vehicle {
+        Vehicle::new(
+            NVector::from_lat_long_degrees(20.0, -60.0),
+            Angle::from_degrees(10.0),
+            Speed::from_knots(15.0),
+        )
+    }
+
+    fn intruder() -> Vehicle {
+        Vehicle::new(
+            NVector::from_lat_long_degrees(34.0, -50.0),
+            Angle::from_degrees(220.0),
+            Speed::from_knots(300.0),
+        )
+    }
+
+    #[test]
+    fn position_after() {
+        let v = ownship();
+        let d = Duration::from_secs(7200);
+        assert_eq!(
+            Ellipsoid::WGS84.destination_position(v.position(), v.bearing(), v.speed() * d),
+            Ellipsoid::WGS84.position_after(v, d)
+        );
+        assert_eq!(
+            v.position(),
+            Ellipsoid::WGS84.position_after(v, Duration::ZERO)
+        );
+    }
+
+    #[test]
+    fn time_to_cpa_is_minimum() {
+        let e = Ellipsoid::WGS84;
+        let (own, int) = (ownship(), intruder());
+        let t = e.time_to_cpa(own, int).unwrap();
+        let d = |t: Duration| e.distance(e.position_after(own, t), e.position_after(int, t));
+        let one_sec = Duration::from_secs(1);
+        assert!(d(t) < d(t - one_sec));
+        assert!(d(t) < d(t + one_sec));
+        // close to the spherical solution.
+        let s = Sphere::EARTH.time_to_cpa(own, int).unwrap();
+        assert!((t.as_secs_f64() - s.as_secs_f64()).abs() < 60.0);
+    }
+
+    #[test]
+    fn time_to_cpa_diverging() {
+        let own = Vehicle::new(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            Angle::from_degrees(270.0),
+            Speed::from_knots(400.0),
+        );
+        let int = Vehicle::new(
+            NVector::from_lat_long_degrees(0.0, 1.0),
+            Angle::from_degrees(90.0),
+            Speed::from_knots(400.0),
+        );
+        assert!(Ellipsoid::WGS84.time_to_cpa(own, int).is_none());
+    }
+
+    #[test]
+    fn time_to_cpa_head_on_along_meridian() {
+        let own = Vehicle::new(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            Angle::ZERO,
+            Speed::from_knots(400.0),
+        );
+        let int = Vehicle::new(
+            NVector::from_lat_long_degrees(2.0, 0.0),
+            Angle::from_degrees(180.0),
+            Speed::from_knots(400.0),
+        );
+        let e = Ellipsoid::WGS84;
+        let t = e.time_to_cpa(own, int).unwrap();
+        // both vehicles meet half-way.
+        let expected = e.distance(own.position(), int.position()).as_metres()
+            / (2.0 * own.speed().as_metres_per_second());
+        assert!((t.as_secs_f64() - expected).abs() < 0.01);
+    }
+
+    #[test]
+    fn max_time_to_intercept_minimises_speed() {
+        let e = Ellipsoid::WGS84;
+        let p = NVector::from_lat_long_degrees(20.0, -60.0);
+        let int = Vehicle::new(
+            intruder().position(),
+            intruder().bearing(),
+            Speed::from_knots(600.0),
+        );
+        let t = e.max_time_to_intercept(p, int).unwrap();
+        let speed = |t: Duration| e.distance(p, e.position_after(int, t)) / t;
+        let ten_secs = Duration::from_secs(10);
+        assert!(speed(t) < speed(t - ten_secs));
+        assert!(speed(t) < speed(t + ten_secs));
+    }
+
+    #[test]
+    fn max_time_to_intercept_same_pos() {
+        let int = intruder();
+        assert_eq!(
+            Some(Duration::ZERO),
+            Ellipsoid::WGS84.max_time_to_intercept(int.position(), int)
+        );
+    }
+
+    #[test]
+    fn time_to_intercept() {
+        let e = Ellipsoid::WGS84;
+        let p = NVector::from_lat_long_degrees(20.0, -60.0);
+        let int = Vehicle::new(
+            intruder().position(),
+            intruder().bearing(),
+            Speed::from_knots(600.0),
+        );
+        let speed = Speed::from_knots(700.0);
+        let t = e.time_to_intercept(p, speed, int).unwrap();
+        let d = e.distance(p, e.position_after(int, t));
+        // within the distance travelled in 1 millisecond.
+        assert!((d - speed * t).abs() < Length::from_metres(1.0));
+        assert!(e
+            .time_to_intercept(p, Speed::from_knots(50.0), int)
+            .is_none());
+    }
 }
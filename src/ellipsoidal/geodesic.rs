@@ -0,0 +1,1044 @@
+// Geodesics on an ellipsoid of revolution.
+//
+// This is a port of the algorithms described in C. F. F. Karney, "Algorithms for geodesics",
+// J. Geodesy 87, 43-55 (2013) and implemented in GeographicLib (https://geographiclib.sourceforge.io).
+// Series are expanded to 6th order which gives round-off limited accuracy (~15 nm) for the terrestrial
+// ellipsoids. Only the quantities needed by [crate::ellipsoidal::Ellipsoid] are computed: distance and
+// azimuths for the inverse problem, position for the direct problem.
+//
+// Angles are in degrees; distances in metres.
+
+use super::Ellipsoid;
+
+// Number of coefficients of the series (order 6).
+const N_A1: usize = 6;
+const N_C1: usize = 6;
+const N_C1P: usize = 6;
+const N_A2: usize = 6;
+const N_C2: usize = 6;
+const N_A3: usize = 6;
+const N_A3X: usize = N_A3;
+const N_C3: usize = 6;
+const N_C3X: usize = (N_C3 * (N_C3 - 1)) / 2;
+const N_C: usize = 7;
+
+const DEGREE: f64 = std::f64::consts::PI / 180.0;
+const QUARTER: f64 = 90.0;
+const HALF: f64 = 180.0;
+const FULL: f64 = 360.0;
+
+const MAXIT1: u32 = 20;
+const MAXIT2: u32 = MAXIT1 + f64::MANTISSA_DIGITS + 10;
+
+const TOL0: f64 = f64::EPSILON;
+const TOL1: f64 = 200.0 * TOL0;
+const TOLB: f64 = TOL0;
+
+/// Solver for the direct and inverse geodesic problems on a given ellipsoid.
+#[derive(Debug)]
+pub(crate) struct Geodesic {
+    a: f64,
+    b: f64,
+    f: f64,
+    f1: f64,
+    ep2: f64,
+    n: f64,
+    etol2: f64,
+    a3x: [f64; N_A3X],
+    c3x: [f64; N_C3X],
+}
+
+/// Solution of the inverse geodesic problem.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Inverse {
+    /// distance in metres.
+    pub(crate) s12: f64,
+    /// azimuth at the first position in degrees, in [-180, 180].
+    pub(crate) azi1: f64,
+    /// azimuth at the second position in degrees, in [-180, 180].
+    pub(crate) azi2: f64,
+}
+
+/// Solution of the direct geodesic problem.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Direct {
+    /// latitude of the destination in degrees.
+    pub(crate) lat2: f64,
+    /// longitude of the destination in degrees.
+    pub(crate) lon2: f64,
+    /// azimuth at the destination in degrees.
+    pub(crate) azi2: f64,
+}
+
+impl Geodesic {
+    pub(crate) fn new(ellipsoid: &Ellipsoid) -> Self {
+        let a = ellipsoid.equatorial_radius().as_metres();
+        let f = ellipsoid.flattening();
+        let f1 = 1.0 - f;
+        let e2 = f * (2.0 - f);
+        let ep2 = e2 / (f1 * f1);
+        let n = f / (2.0 - f);
+        let b = a * f1;
+        let tol2 = TOL0.sqrt();
+        let etol2 = 0.1 * tol2 / (f.abs().max(0.001) * (1.0 - f / 2.0).min(1.0) / 2.0).sqrt();
+        Geodesic {
+            a,
+            b,
+            f,
+            f1,
+            ep2,
+            n,
+            etol2,
+            a3x: a3_coeff(n),
+            c3x: c3_coeff(n),
+        }
+    }
+
+    /// Solves the inverse geodesic problem between the given positions.
+    pub(crate) fn inverse(&self, lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> Inverse {
+        let (mut lon12, mut lon12s) = ang_diff(lon1, lon2);
+        // make longitude difference positive.
+        let mut lon_sign: f64 = if lon12.is_sign_negative() { -1.0 } else { 1.0 };
+        lon12 *= lon_sign;
+        lon12s *= lon_sign;
+        let lam12 = lon12 * DEGREE;
+        let (slam12, clam12) = sin_cos_de(lon12, lon12s);
+        // the supplementary longitude difference.
+        lon12s = (HALF - lon12) - lon12s;
+
+        // if really close to the equator, treat as on equator.
+        let mut lat1 = ang_round(lat1);
+        let mut lat2 = ang_round(lat2);
+        // swap positions so that position with higher (abs) latitude is position 1.
+        let swapp: f64 = if lat1.abs() < lat2.abs() { -1.0 } else { 1.0 };
+        if swapp < 0.0 {
+            lon_sign *= -1.0;
+            std::mem::swap(&mut lat1, &mut lat2);
+        }
+        // make lat1 <= -0.
+        let lat_sign: f64 = if lat1.is_sign_negative() { 1.0 } else { -1.0 };
+        lat1 *= lat_sign;
+        lat2 *= lat_sign;
+
+        let (mut sbet1, mut cbet1) = sin_cos_d(lat1);
+        sbet1 *= self.f1;
+        // ensure cbet1 = +epsilon at poles.
+        (sbet1, cbet1) = norm2(sbet1, cbet1);
+        cbet1 = cbet1.max(tiny());
+
+        let (mut sbet2, mut cbet2) = sin_cos_d(lat2);
+        sbet2 *= self.f1;
+        // ensure cbet2 = +epsilon at poles.
+        (sbet2, cbet2) = norm2(sbet2, cbet2);
+        cbet2 = cbet2.max(tiny());
+
+        // if cbet1 < -sbet1, then cbet2 - cbet1 is a sensitive measure of |bet1| - |bet2|.
+        // Alternatively (cbet1 >= -sbet1), abs(sbet2) + sbet1 is a better measure: sometimes
+        // these quantities vanish and in that case force bet2 = +/- bet1 exactly.
+        if cbet1 < -sbet1 {
+            if cbet2 == cbet1 {
+                sbet2 = sbet1.copysign(sbet2);
+            }
+        } else if sbet2.abs() == -sbet1 {
+            cbet2 = cbet1;
+        }
+
+        let dn1 = (1.0 + self.ep2 * sbet1 * sbet1).sqrt();
+        let dn2 = (1.0 + self.ep2 * sbet2 * sbet2).sqrt();
+
+        let mut ca = [0.0; N_C];
+
+        let mut s12x = 0.0;
+        let mut salp1 = 0.0;
+        let mut calp1 = 0.0;
+        let mut salp2 = 0.0;
+        let mut calp2 = 0.0;
+
+        let mut meridian = lat1 == -QUARTER || slam12 == 0.0;
+
+        if meridian {
+            // endpoints are on a single full meridian, so the geodesic might lie on a meridian.
+            // head to the target longitude.
+            calp1 = clam12;
+            salp1 = slam12;
+            // at the target we're heading north.
+            calp2 = 1.0;
+            salp2 = 0.0;
+
+            // tan(bet) = tan(sig) * cos(alp)
+            let ssig1 = sbet1;
+            let csig1 = calp1 * cbet1;
+            let ssig2 = sbet2;
+            let csig2 = calp2 * cbet2;
+
+            // sig12 = sig2 - sig1
+            let sig12 = ((csig1 * ssig2 - ssig1 * csig2).max(0.0) + 0.0)
+                .atan2(csig1 * csig2 + ssig1 * ssig2);
+            let (s12b, m12b) =
+                self.lengths(self.n, sig12, ssig1, csig1, dn1, ssig2, csig2, dn2, &mut ca);
+            s12x = s12b;
+            // sig12 > pi/2 for meridional geodesic which is not a shortest path.
+            if sig12 < 1.0 || m12b >= 0.0 {
+                // need at least 2, to handle 90 0 90 180; prevent negative s12 for short lines.
+                if sig12 < 3.0 * tiny() || (sig12 < TOL0 && (s12x < 0.0 || m12b < 0.0)) {
+                    s12x = 0.0;
+                }
+                s12x *= self.b;
+            } else {
+                // m12 < 0, i.e., prolate and too close to anti-podal.
+                meridian = false;
+            }
+        }
+
+        if !meridian && sbet1 == 0.0 && (self.f <= 0.0 || lon12s >= self.f * HALF) {
+            // geodesic runs along equator.
+            calp1 = 0.0;
+            calp2 = 0.0;
+            salp1 = 1.0;
+            salp2 = 1.0;
+            s12x = self.a * lam12;
+        } else if !meridian {
+            // now position 1 and position 2 belong within a hemisphere bounded by a meridian
+            // and geodesic is neither meridional or equatorial: figure a starting point for
+            // Newton's method.
+            let start = self.inverse_start(
+                sbet1, cbet1, dn1, sbet2, cbet2, dn2, lam12, slam12, clam12, &mut ca,
+            );
+            salp1 = start.salp1;
+            calp1 = start.calp1;
+
+            if start.sig12 >= 0.0 {
+                // short lines.
+                salp2 = start.salp2;
+                calp2 = start.calp2;
+                s12x = start.sig12 * self.b * start.dnm;
+            } else {
+                // Newton's method: solve f(alp1) = lambda12(alp1) - lam12 = 0. f(alp) has exactly
+                // one root in the interval (0, pi) and its derivative is positive at the root.
+                // During the course of the iteration, a range (alp1a, alp1b) is maintained which
+                // brackets the root and with each evaluation of f(alp) the range is shrunk, if possible.
+                // Newton's method is restarted whenever the derivative of f is negative or if the
+                // new estimate of alp1 lies outside (0, pi); in this case, the new starting guess is
+                // taken to be (alp1a + alp1b) / 2.
+                let mut numit = 0;
+                let mut salp1a = tiny();
+                let mut calp1a = 1.0;
+                let mut salp1b = tiny();
+                let mut calp1b = -1.0;
+                let mut tripn = false;
+                let mut tripb = false;
+                let mut l;
+                loop {
+                    l = self.lambda12(
+                        sbet1,
+                        cbet1,
+                        dn1,
+                        sbet2,
+                        cbet2,
+                        dn2,
+                        salp1,
+                        calp1,
+                        slam12,
+                        clam12,
+                        numit < MAXIT1,
+                        &mut ca,
+                    );
+                    let v = l.lam12;
+                    if tripb
+                        || v.is_nan()
+                        || v.abs() < (if tripn { 8.0 } else { 1.0 }) * TOL0
+                        || numit == MAXIT2
+                    {
+                        break;
+                    }
+                    // update bracketing values.
+                    if v > 0.0 && (numit > MAXIT1 || calp1 / salp1 > calp1b / salp1b) {
+                        salp1b = salp1;
+                        calp1b = calp1;
+                    } else if v < 0.0 && (numit > MAXIT1 || calp1 / salp1 < calp1a / salp1a) {
+                        salp1a = salp1;
+                        calp1a = calp1;
+                    }
+                    numit += 1;
+                    if numit <= MAXIT1 && l.dlam12 > 0.0 {
+                        let dalp1 = -v / l.dlam12;
+                        if dalp1.abs() < std::f64::consts::PI {
+                            let (sdalp1, cdalp1) = dalp1.sin_cos();
+                            let nsalp1 = salp1 * cdalp1 + calp1 * sdalp1;
+                            if nsalp1 > 0.0 {
+                                calp1 = calp1 * cdalp1 - salp1 * sdalp1;
+                                salp1 = nsalp1;
+                                (salp1, calp1) = norm2(salp1, calp1);
+                                // in some regimes we don't get quadratic convergence because
+                                // slope -> 0. So use convergence conditions based on epsilon
+                                // instead of sqrt(epsilon).
+                                tripn = v.abs() <= 16.0 * TOL0;
+                                continue;
+                            }
+                        }
+                    }
+                    // either dv was not positive or updated value was outside legal range: use the
+                    // midpoint of the bracket as the next estimate.
+                    salp1 = (salp1a + salp1b) / 2.0;
+                    calp1 = (calp1a + calp1b) / 2.0;
+                    (salp1, calp1) = norm2(salp1, calp1);
+                    tripn = false;
+                    tripb = (salp1a - salp1).abs() + (calp1a - calp1) < TOLB
+                        || (salp1 - salp1b).abs() + (calp1 - calp1b) < TOLB;
+                }
+                salp2 = l.salp2;
+                calp2 = l.calp2;
+                let (s12b, _) = self.lengths(
+                    l.eps, l.sig12, l.ssig1, l.csig1, dn1, l.ssig2, l.csig2, dn2, &mut ca,
+                );
+                s12x = s12b * self.b;
+            }
+        }
+
+        // convert -0 to 0.
+        let s12 = 0.0 + s12x;
+
+        // convert calp, salp to azimuth accounting for lon_sign, swapp, lat_sign.
+        if swapp < 0.0 {
+            std::mem::swap(&mut salp1, &mut salp2);
+            std::mem::swap(&mut calp1, &mut calp2);
+        }
+
+        salp1 *= swapp * lon_sign;
+        calp1 *= swapp * lat_sign;
+        salp2 *= swapp * lon_sign;
+        calp2 *= swapp * lat_sign;
+
+        Inverse {
+            s12,
+            azi1: atan2_d(salp1, calp1),
+            azi2: atan2_d(salp2, calp2),
+        }
+    }
+
+    /// Solves the direct geodesic problem from the given position, azimuth and distance.
+    pub(crate) fn direct(&self, lat1: f64, lon1: f64, azi1: f64, s12: f64) -> Direct {
+        let azi1 = ang_normalise(azi1);
+        let (salp1, calp1) = sin_cos_d(ang_round(azi1));
+
+        let (mut sbet1, mut cbet1) = sin_cos_d(ang_round(lat1));
+        sbet1 *= self.f1;
+        // ensure cbet1 = +epsilon at poles.
+        (sbet1, cbet1) = norm2(sbet1, cbet1);
+        cbet1 = cbet1.max(tiny());
+
+        // evaluate alp0 from sin(alp1) * cos(bet1) = sin(alp0).
+        let salp0 = salp1 * cbet1;
+        let calp0 = calp1.hypot(salp1 * sbet1);
+        // evaluate sig with tan(bet1) = tan(sig1) * cos(alp1) and omg1 with
+        // tan(omg1) = sin(alp0) * tan(sig1).
+        let somg1 = salp0 * sbet1;
+        let comg1 = if sbet1 != 0.0 || calp1 != 0.0 {
+            cbet1 * calp1
+        } else {
+            1.0
+        };
+        let (ssig1, csig1) = norm2(sbet1, comg1);
+
+        let k2 = calp0 * calp0 * self.ep2;
+        let eps = k2 / (2.0 * (1.0 + (1.0 + k2).sqrt()) + k2);
+
+        let a1m1 = a1m1f(eps);
+        let mut c1a = [0.0; N_C];
+        c1f(eps, &mut c1a);
+        let b11 = sin_cos_series(ssig1, csig1, &c1a[..=N_C1]);
+        let (s, c) = b11.sin_cos();
+        // tau1 = sig1 + B11
+        let stau1 = ssig1 * c + csig1 * s;
+        let ctau1 = csig1 * c - ssig1 * s;
+
+        let mut c1pa = [0.0; N_C];
+        c1pf(eps, &mut c1pa);
+
+        let mut c3a = [0.0; N_C];
+        self.c3f(eps, &mut c3a);
+        let a3c = -self.f * salp0 * self.a3f(eps);
+        let b31 = sin_cos_series(ssig1, csig1, &c3a[..N_C3]);
+
+        let tau12 = s12 / (self.b * (1.0 + a1m1));
+        let (s, c) = tau12.sin_cos();
+        // tau2 = tau1 + tau12
+        let b12 = -sin_cos_series(
+            stau1 * c + ctau1 * s,
+            ctau1 * c - stau1 * s,
+            &c1pa[..=N_C1P],
+        );
+        let mut sig12 = tau12 - (b12 - b11);
+        let (mut ssig12, mut csig12) = sig12.sin_cos();
+        if self.f.abs() > 0.01 {
+            // reverted distance series is inaccurate for |f| > 1/100, so correct sig12 with 1
+            // Newton iteration.
+            let ssig2 = ssig1 * csig12 + csig1 * ssig12;
+            let csig2 = csig1 * csig12 - ssig1 * ssig12;
+            let b12 = sin_cos_series(ssig2, csig2, &c1a[..=N_C1]);
+            let serr = (1.0 + a1m1) * (sig12 + (b12 - b11)) - s12 / self.b;
+            sig12 -= serr / (1.0 + k2 * ssig2 * ssig2).sqrt();
+            (ssig12, csig12) = sig12.sin_cos();
+        }
+
+        // sig2 = sig1 + sig12
+        let ssig2 = ssig1 * csig12 + csig1 * ssig12;
+        let mut csig2 = csig1 * csig12 - ssig1 * ssig12;
+        // sin(bet2) = cos(alp0) * sin(sig2)
+        let sbet2 = calp0 * ssig2;
+        let mut cbet2 = salp0.hypot(calp0 * csig2);
+        if cbet2 == 0.0 {
+            // i.e., salp0 = 0, csig2 = 0: break the degeneracy in this case.
+            cbet2 = tiny();
+            csig2 = tiny();
+        }
+        // tan(omg2) = sin(alp0) * tan(sig2)
+        let somg2 = salp0 * ssig2;
+        let comg2 = csig2;
+        // omg12 = omg2 - omg1
+        let omg12 = (somg2 * comg1 - comg2 * somg1).atan2(comg2 * comg1 + somg2 * somg1);
+        let lam12 = omg12 + a3c * (sig12 + (sin_cos_series(ssig2, csig2, &c3a[..N_C3]) - b31));
+        let lon12 = lam12 / DEGREE;
+
+        Direct {
+            lat2: atan2_d(sbet2, self.f1 * cbet2),
+            lon2: ang_normalise(ang_normalise(lon1) + ang_normalise(lon12)),
+            // sin(alp2) = sin(alp0), cos(alp2) = cos(alp0) * cos(sig2)
+            azi2: atan2_d(salp0, calp0 * csig2),
+        }
+    }
+
+    /// Returns s12b = distance / b and m12b = reduced length / b.
+    #[allow(clippy::too_many_arguments)]
+    fn lengths(
+        &self,
+        eps: f64,
+        sig12: f64,
+        ssig1: f64,
+        csig1: f64,
+        dn1: f64,
+        ssig2: f64,
+        csig2: f64,
+        dn2: f64,
+        ca: &mut [f64; N_C],
+    ) -> (f64, f64) {
+        let mut cb = [0.0; N_C];
+        let a1 = a1m1f(eps);
+        c1f(eps, ca);
+        let a2 = a2m1f(eps);
+        c2f(eps, &mut cb);
+        let m0 = a1 - a2;
+        let a1 = 1.0 + a1;
+        let a2 = 1.0 + a2;
+
+        let b1 =
+            sin_cos_series(ssig2, csig2, &ca[..=N_C1]) - sin_cos_series(ssig1, csig1, &ca[..=N_C1]);
+        let s12b = a1 * (sig12 + b1);
+        let b2 =
+            sin_cos_series(ssig2, csig2, &cb[..=N_C2]) - sin_cos_series(ssig1, csig1, &cb[..=N_C2]);
+        let j12 = m0 * sig12 + (a1 * b1 - a2 * b2);
+        // add parens around (csig1 * ssig2) and (ssig1 * csig2) to ensure accurate
+        // cancellation in the case of coincident positions.
+        let m12b = dn2 * (csig1 * ssig2) - dn1 * (ssig1 * csig2) - csig1 * csig2 * j12;
+        (s12b, m12b)
+    }
+
+    /// Returns a starting point for Newton's method in salp1 and calp1 (sig12 = -1). If Newton's
+    /// method doesn't need to be used, returns also salp2, calp2 and sig12.
+    #[allow(clippy::too_many_arguments)]
+    fn inverse_start(
+        &self,
+        sbet1: f64,
+        cbet1: f64,
+        dn1: f64,
+        sbet2: f64,
+        cbet2: f64,
+        dn2: f64,
+        lam12: f64,
+        slam12: f64,
+        clam12: f64,
+        ca: &mut [f64; N_C],
+    ) -> Start {
+        let mut sig12 = -1.0;
+        let mut salp2 = 0.0;
+        let mut calp2 = 0.0;
+        let mut dnm = 0.0;
+        // bet12 = bet2 - bet1 in [0, pi); bet12a = bet2 + bet1 in (-pi, 0]
+        let sbet12 = sbet2 * cbet1 - cbet2 * sbet1;
+        let cbet12 = cbet2 * cbet1 + sbet2 * sbet1;
+        let sbet12a = sbet2 * cbet1 + cbet2 * sbet1;
+        let shortline = cbet12 >= 0.0 && sbet12 < 0.5 && cbet2 * lam12 < 0.5;
+        let (mut somg12, mut comg12) = if shortline {
+            let mut sbetm2 = (sbet1 + sbet2) * (sbet1 + sbet2);
+            // sin((bet1+bet2)/2)^2 =  (sbet1 + sbet2)^2 / ((sbet1 + sbet2)^2 + (cbet1 + cbet2)^2)
+            sbetm2 /= sbetm2 + (cbet1 + cbet2) * (cbet1 + cbet2);
+            dnm = (1.0 + self.ep2 * sbetm2).sqrt();
+            let omg12 = lam12 / (self.f1 * dnm);
+            omg12.sin_cos()
+        } else {
+            (slam12, clam12)
+        };
+
+        let mut salp1 = cbet2 * somg12;
+        let mut calp1 = if comg12 >= 0.0 {
+            sbet12 + cbet2 * sbet1 * somg12 * somg12 / (1.0 + comg12)
+        } else {
+            sbet12a - cbet2 * sbet1 * somg12 * somg12 / (1.0 - comg12)
+        };
+
+        let ssig12 = salp1.hypot(calp1);
+        let csig12 = sbet1 * sbet2 + cbet1 * cbet2 * comg12;
+
+        if shortline && ssig12 < self.etol2 {
+            // really short lines.
+            salp2 = cbet1 * somg12;
+            calp2 = sbet12
+                - cbet1
+                    * sbet2
+                    * (if comg12 >= 0.0 {
+                        somg12 * somg12 / (1.0 + comg12)
+                    } else {
+                        1.0 - comg12
+                    });
+            (salp2, calp2) = norm2(salp2, calp2);
+            sig12 = ssig12.atan2(csig12);
+        } else if self.n.abs() > 0.1
+            || csig12 >= 0.0
+            || ssig12 >= 6.0 * self.n.abs() * std::f64::consts::PI * cbet1 * cbet1
+        {
+            // nothing to do, zeroth order spherical approximation is OK.
+        } else {
+            // scale lam12 and bet2 to x, y coordinate system where antipodal position is at origin
+            // and singular position is at y = 0, x = -1.
+            let lam12x = (-slam12).atan2(-clam12); // lam12 - pi
+            let (x, y, lamscale) = if self.f >= 0.0 {
+                // x = dlong, y = dlat
+                let k2 = sbet1 * sbet1 * self.ep2;
+                let eps = k2 / (2.0 * (1.0 + (1.0 + k2).sqrt()) + k2);
+                let lamscale = self.f * cbet1 * self.a3f(eps) * std::f64::consts::PI;
+                let betscale = lamscale * cbet1;
+                (lam12x / lamscale, sbet12a / betscale, lamscale)
+            } else {
+                // x = dlat, y = dlong
+                let cbet12a = cbet2 * cbet1 - sbet2 * sbet1;
+                let bet12a = sbet12a.atan2(cbet12a);
+                let (_, m12b) = self.lengths(
+                    self.n,
+                    std::f64::consts::PI + bet12a,
+                    sbet1,
+                    -cbet1,
+                    dn1,
+                    sbet2,
+                    cbet2,
+                    dn2,
+                    ca,
+                );
+                let m0 = a1m1f(self.n) - a2m1f(self.n);
+                let x = -1.0 + m12b / (cbet1 * cbet2 * m0 * std::f64::consts::PI);
+                let betscale = if x < -0.01 {
+                    sbet12a / x
+                } else {
+                    -self.f * cbet1 * cbet1 * std::f64::consts::PI
+                };
+                let lamscale = betscale / cbet1;
+                (x, lam12x / lamscale, lamscale)
+            };
+
+            if y > -TOL1 && x > -1.0 - xthresh() {
+                // strip near cut.
+                if self.f >= 0.0 {
+                    salp1 = (-x).min(1.0);
+                    calp1 = -(1.0 - salp1 * salp1).sqrt();
+                } else {
+                    calp1 = x.max(if x > -TOL1 { 0.0 } else { -1.0 });
+                    salp1 = (1.0 - calp1 * calp1).sqrt();
+                }
+            } else {
+                // estimate alp1, by solving the astroid problem: because omg12 is near pi,
+                // estimate work with omg12a = pi - omg12.
+                let k = astroid(x, y);
+                let omg12a = lamscale
+                    * (if self.f >= 0.0 {
+                        -x * k / (1.0 + k)
+                    } else {
+                        -y * (1.0 + k) / k
+                    });
+                (somg12, comg12) = omg12a.sin_cos();
+                comg12 = -comg12;
+                // update spherical estimate of alp1 using omg12 instead of lam12.
+                salp1 = cbet2 * somg12;
+                calp1 = sbet12a - cbet2 * sbet1 * somg12 * somg12 / (1.0 - comg12);
+            }
+        }
+        // sanity check on starting guess, allowing NaN through.
+        if salp1 > 0.0 || salp1.is_nan() {
+            (salp1, calp1) = norm2(salp1, calp1);
+        } else {
+            salp1 = 1.0;
+            calp1 = 0.0;
+        }
+
+        Start {
+            sig12,
+            salp1,
+            calp1,
+            salp2,
+            calp2,
+            dnm,
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn lambda12(
+        &self,
+        sbet1: f64,
+        cbet1: f64,
+        dn1: f64,
+        sbet2: f64,
+        cbet2: f64,
+        dn2: f64,
+        salp1: f64,
+        calp1: f64,
+        slam120: f64,
+        clam120: f64,
+        diffp: bool,
+        ca: &mut [f64; N_C],
+    ) -> Lambda {
+        let calp1 = if sbet1 == 0.0 && calp1 == 0.0 {
+            // break degeneracy of equatorial line. This case has already been handled.
+            -tiny()
+        } else {
+            calp1
+        };
+
+        // sin(alp1) * cos(bet1) = sin(alp0)
+        let salp0 = salp1 * cbet1;
+        // calp0 > 0
+        let calp0 = calp1.hypot(salp1 * sbet1);
+
+        // tan(bet1) = tan(sig1) * cos(alp1)
+        // tan(omg1) = sin(alp0) * tan(sig1) = tan(omg1)=tan(alp1)*sin(bet1)
+        let somg1 = salp0 * sbet1;
+        let comg1 = calp1 * cbet1;
+        let (ssig1, csig1) = norm2(sbet1, comg1);
+
+        // enforce symmetries in the case abs(bet2) = -bet1. Need to be careful about this case,
+        // since this can yield singularities in the Newton iteration.
+        // sin(alp2) * cos(bet2) = sin(alp0)
+        let salp2 = if cbet2 != cbet1 { salp0 / cbet2 } else { salp1 };
+        // calp2 = sqrt(1 - sq(salp2)) = sqrt(sq(calp0) - sq(sbet2)) / cbet2
+        // and subst for calp0 and rearrange to give (choose positive sqrt to give alp2 in [0, pi/2]).
+        let calp2 = if cbet2 != cbet1 || sbet2.abs() != -sbet1 {
+            ((calp1 * cbet1) * (calp1 * cbet1)
+                + if cbet1 < -sbet1 {
+                    (cbet2 - cbet1) * (cbet1 + cbet2)
+                } else {
+                    (sbet1 - sbet2) * (sbet1 + sbet2)
+                })
+            .sqrt()
+                / cbet2
+        } else {
+            calp1.abs()
+        };
+        // tan(bet2) = tan(sig2) * cos(alp2)
+        // tan(omg2) = sin(alp0) * tan(sig2).
+        let somg2 = salp0 * sbet2;
+        let comg2 = calp2 * cbet2;
+        let (ssig2, csig2) = norm2(sbet2, comg2);
+
+        // sig12 = sig2 - sig1, limit to [0, pi]
+        let sig12 =
+            ((csig1 * ssig2 - ssig1 * csig2).max(0.0) + 0.0).atan2(csig1 * csig2 + ssig1 * ssig2);
+
+        // omg12 = omg2 - omg1, limit to [0, pi]
+        let somg12 = (comg1 * somg2 - somg1 * comg2).max(0.0) + 0.0;
+        let comg12 = comg1 * comg2 + somg1 * somg2;
+        // eta = omg12 - lam120
+        let eta = (somg12 * clam120 - comg12 * slam120).atan2(comg12 * clam120 + somg12 * slam120);
+        let k2 = calp0 * calp0 * self.ep2;
+        let eps = k2 / (2.0 * (1.0 + (1.0 + k2).sqrt()) + k2);
+        self.c3f(eps, ca);
+        let b312 =
+            sin_cos_series(ssig2, csig2, &ca[..N_C3]) - sin_cos_series(ssig1, csig1, &ca[..N_C3]);
+        let domg12 = -self.f * self.a3f(eps) * salp0 * (sig12 + b312);
+        let lam12 = eta + domg12;
+
+        let dlam12 = if diffp {
+            if calp2 == 0.0 {
+                -2.0 * self.f1 * dn1 / sbet1
+            } else {
+                let (_, m12b) = self.lengths(eps, sig12, ssig1, csig1, dn1, ssig2, csig2, dn2, ca);
+                m12b * self.f1 / (calp2 * cbet2)
+            }
+        } else {
+            0.0
+        };
+
+        Lambda {
+            lam12,
+            salp2,
+            calp2,
+            sig12,
+            ssig1,
+            csig1,
+            ssig2,
+            csig2,
+            eps,
+            dlam12,
+        }
+    }
+
+    fn a3f(&self, eps: f64) -> f64 {
+        polyval(&self.a3x, eps)
+    }
+
+    fn c3f(&self, eps: f64, c: &mut [f64; N_C]) {
+        let mut mult = 1.0;
+        let mut o = 0;
+        for (l, cl) in c.iter_mut().enumerate().take(N_C3).skip(1) {
+            // order of polynomial in eps.
+            let m = N_C3 - l - 1;
+            mult *= eps;
+            *cl = mult * polyval(&self.c3x[o..=o + m], eps);
+            o += m + 1;
+        }
+    }
+}
+
+/// Result of [Geodesic::inverse_start].
+struct Start {
+    sig12: f64,
+    salp1: f64,
+    calp1: f64,
+    salp2: f64,
+    calp2: f64,
+    dnm: f64,
+}
+
+/// Result of [Geodesic::lambda12].
+struct Lambda {
+    lam12: f64,
+    salp2: f64,
+    calp2: f64,
+    sig12: f64,
+    ssig1: f64,
+    csig1: f64,
+    ssig2: f64,
+    csig2: f64,
+    eps: f64,
+    dlam12: f64,
+}
+
+#[inline]
+fn tiny() -> f64 {
+    f64::MIN_POSITIVE.sqrt()
+}
+
+#[inline]
+fn xthresh() -> f64 {
+    1000.0 * TOL0.sqrt()
+}
+
+/// Evaluates the polynomial whose coefficients are given highest degree first.
+fn polyval(p: &[f64], x: f64) -> f64 {
+    p.iter().fold(0.0, |y, c| y * x + c)
+}
+
+/// Evaluates sum(c[i] * sin(2 * i * x), i, 1, n) using Clenshaw summation; c[0] is unused.
+fn sin_cos_series(sinx: f64, cosx: f64, c: &[f64]) -> f64 {
+    let mut n = c.len() - 1;
+    let mut k = c.len();
+    // 2 * cos(2 * x)
+    let ar = 2.0 * (cosx - sinx) * (cosx + sinx);
+    let mut y0 = if n & 1 == 1 {
+        k -= 1;
+        c[k]
+    } else {
+        0.0
+    };
+    let mut y1 = 0.0;
+    n /= 2;
+    while n > 0 {
+        n -= 1;
+        k -= 1;
+        y1 = ar * y0 - y1 + c[k];
+        k -= 1;
+        y0 = ar * y1 - y0 + c[k];
+    }
+    // sin(2 * x) * y0
+    2.0 * sinx * cosx * y0
+}
+
+/// Evaluates series in eps^2 given as groups of (coefficients highest degree first, divisor).
+fn series(coeffs: &[f64], eps: f64, n: usize, c: &mut [f64; N_C]) {
+    let eps2 = eps * eps;
+    let mut d = eps;
+    let mut o = 0;
+    for (l, cl) in c.iter_mut().enumerate().take(n + 1).skip(1) {
+        // order of polynomial in eps^2.
+        let m = (n - l) / 2;
+        *cl = d * polyval(&coeffs[o..=o + m], eps2) / coeffs[o + m + 1];
+        o += m + 2;
+        d *= eps;
+    }
+}
+
+/// The scale factor A1-1 = mean value of (d/dsigma)I1 - 1.
+fn a1m1f(eps: f64) -> f64 {
+    const COEFF: [f64; 5] = [1.0, 4.0, 64.0, 0.0, 256.0];
+    let m = N_A1 / 2;
+    let t = polyval(&COEFF[..=m], eps * eps) / COEFF[m + 1];
+    (t + eps) / (1.0 - eps)
+}
+
+/// The coefficients C1[l] in the Fourier expansion of B1.
+fn c1f(eps: f64, c: &mut [f64; N_C]) {
+    const COEFF: [f64; 18] = [
+        -1.0, 6.0, -16.0, 32.0, //
+        -9.0, 64.0, -128.0, 2048.0, //
+        9.0, -16.0, 768.0, //
+        3.0, -5.0, 512.0, //
+        -7.0, 1280.0, //
+        -7.0, 2048.0,
+    ];
+    series(&COEFF, eps, N_C1, c)
+}
+
+/// The coefficients C1p[l] in the Fourier expansion of B1p.
+fn c1pf(eps: f64, c: &mut [f64; N_C]) {
+    const COEFF: [f64; 18] = [
+        205.0, -432.0, 768.0, 1536.0, //
+        4005.0, -4736.0, 3840.0, 12288.0, //
+        -225.0, 116.0, 384.0, //
+        -7173.0, 2695.0, 7680.0, //
+        3467.0, 7680.0, //
+        38081.0, 61440.0,
+    ];
+    series(&COEFF, eps, N_C1P, c)
+}
+
+/// The scale factor A2-1 = mean value of (d/dsigma)I2 - 1.
+fn a2m1f(eps: f64) -> f64 {
+    const COEFF: [f64; 5] = [-11.0, -28.0, -192.0, 0.0, 256.0];
+    let m = N_A2 / 2;
+    let t = polyval(&COEFF[..=m], eps * eps) / COEFF[m + 1];
+    (t - eps) / (1.0 + eps)
+}
+
+/// The coefficients C2[l] in the Fourier expansion of B2.
+fn c2f(eps: f64, c: &mut [f64; N_C]) {
+    const COEFF: [f64; 18] = [
+        1.0, 2.0, 16.0, 32.0, //
+        35.0, 64.0, 384.0, 2048.0, //
+        15.0, 80.0, 768.0, //
+        7.0, 35.0, 512.0, //
+        63.0, 1280.0, //
+        77.0, 2048.0,
+    ];
+    series(&COEFF, eps, N_C2, c)
+}
+
+/// The coefficients of A3 (polynomial in eps) as polynomials in n.
+fn a3_coeff(n: f64) -> [f64; N_A3X] {
+    const COEFF: [f64; 18] = [
+        -3.0, 128.0, //
+        -2.0, -3.0, 64.0, //
+        -1.0, -3.0, -1.0, 16.0, //
+        3.0, -1.0, -2.0, 8.0, //
+        1.0, -1.0, 2.0, //
+        1.0, 1.0,
+    ];
+    let mut a3x = [0.0; N_A3X];
+    let mut o = 0;
+    for (k, j) in (0..N_A3).rev().enumerate() {
+        let m = (N_A3 - j - 1).min(j);
+        a3x[k] = polyval(&COEFF[o..=o + m], n) / COEFF[o + m + 1];
+        o += m + 2;
+    }
+    a3x
+}
+
+/// The coefficients of C3[l] (polynomials in eps) as polynomials in n.
+fn c3_coeff(n: f64) -> [f64; N_C3X] {
+    const COEFF: [f64; 45] = [
+        3.0, 128.0, //
+        2.0, 5.0, 128.0, //
+        -1.0, 3.0, 3.0, 64.0, //
+        -1.0, 0.0, 1.0, 8.0, //
+        -1.0, 1.0, 4.0, //
+        5.0, 256.0, //
+        1.0, 3.0, 128.0, //
+        -3.0, -2.0, 3.0, 64.0, //
+        1.0, -3.0, 2.0, 32.0, //
+        7.0, 512.0, //
+        -10.0, 9.0, 384.0, //
+        5.0, -9.0, 5.0, 192.0, //
+        7.0, 512.0, //
+        -14.0, 7.0, 512.0, //
+        21.0, 2560.0,
+    ];
+    let mut c3x = [0.0; N_C3X];
+    let mut o = 0;
+    let mut k = 0;
+    for l in 1..N_C3 {
+        for j in (l..N_C3).rev() {
+            let m = (N_C3 - j - 1).min(j);
+            c3x[k] = polyval(&COEFF[o..=o + m], n) / COEFF[o + m + 1];
+            k += 1;
+            o += m + 2;
+        }
+    }
+    c3x
+}
+
+/// Solves k^4+2*k^3-(x^2+y^2-1)*k^2-2*y^2*k-y^2 = 0 for positive root k.
+fn astroid(x: f64, y: f64) -> f64 {
+    let p = x * x;
+    let q = y * y;
+    let r = (p + q - 1.0) / 6.0;
+    if !(q == 0.0 && r <= 0.0) {
+        // avoid possible division by zero when r = 0 by multiplying equations for s and t by
+        // r^3 and r, resp.
+        let s = p * q / 4.0;
+        let r2 = r * r;
+        let r3 = r * r2;
+        // the discriminant of the quadratic equation for T3. This is zero on the evolute curve
+        // p^(1/3)+q^(1/3) = 1.
+        let disc = s * (s + 2.0 * r3);
+        let mut u = r;
+        if disc >= 0.0 {
+            let mut t3 = s + r3;
+            // pick the sign on the sqrt to maximise abs(T3). This minimises loss of precision due
+            // to cancellation.
+            t3 += if t3 < 0.0 { -disc.sqrt() } else { disc.sqrt() };
+            let t = t3.cbrt();
+            // t can be zero; but then r2 / t -> 0.
+            u += t + if t != 0.0 { r2 / t } else { 0.0 };
+        } else {
+            // T is complex, but the way u is defined the result is real.
+            let ang = (-disc).sqrt().atan2(-(s + r3));
+            // there are three possible cube roots. Choose the root which avoids cancellation.
+            // Note that disc < 0 implies that r < 0.
+            u += 2.0 * r * (ang / 3.0).cos();
+        }
+        // guaranteed positive.
+        let v = (u * u + q).sqrt();
+        // avoid loss of accuracy when u < 0: u + v, guaranteed positive.
+        let uv = if u < 0.0 { q / (v - u) } else { u + v };
+        let w = (uv - q) / (2.0 * v);
+        // rearrange expression for k to avoid loss of accuracy due to subtraction. Division by 0
+        // not possible because uv > 0, w >= 0.
+        uv / ((uv + w * w).sqrt() + w)
+    } else {
+        // y = 0 with |x| <= 1.
+        0.0
+    }
+}
+
+/// Normalises the given pair to a unit vector.
+#[inline]
+fn norm2(s: f64, c: f64) -> (f64, f64) {
+    let r = s.hypot(c);
+    (s / r, c / r)
+}
+
+/// The error-free sum of 2 numbers: returns (s, t) such that s = round(u + v) and t = u + v - s.
+fn sum(u: f64, v: f64) -> (f64, f64) {
+    let s = u + v;
+    let up = s - v;
+    let vpp = s - up;
+    let up = up - u;
+    let vpp = vpp - v;
+    let t = if s != 0.0 { 0.0 - (up + vpp) } else { s };
+    (s, t)
+}
+
+/// IEEE remainder of x / y (rounding quotient to nearest).
+fn remainder(x: f64, y: f64) -> f64 {
+    let r = x % y;
+    if r.abs() > y / 2.0 {
+        r - y.copysign(r)
+    } else {
+        r
+    }
+}
+
+/// Normalises the given angle in degrees to [-180, 180].
+fn ang_normalise(x: f64) -> f64 {
+    let y = remainder(x, FULL);
+    if y.abs() == HALF {
+        HALF.copysign(x)
+    } else {
+        y
+    }
+}
+
+/// Returns the exact difference of 2 angles in degrees, reduced to [-180, 180] and the error.
+fn ang_diff(x: f64, y: f64) -> (f64, f64) {
+    let (d, t) = sum(remainder(-x, FULL), remainder(y, FULL));
+    let (d, t) = sum(remainder(d, FULL), t);
+    let d = if d == 0.0 || d.abs() == HALF {
+        d.copysign(if t == 0.0 { y - x } else { -t })
+    } else {
+        d
+    };
+    (d, t)
+}
+
+/// Coarsens a value close to zero so that the result has at most 1/16 degree granularity.
+fn ang_round(x: f64) -> f64 {
+    let z = 1.0 / 16.0;
+    let y = x.abs();
+    let w = z - y;
+    let y = if w > 0.0 { z - w } else { y };
+    y.copysign(x)
+}
+
+/// Returns sin and cos of the given angle in degrees with exact values for multiples of 90.
+fn sin_cos_d(x: f64) -> (f64, f64) {
+    let q = (x / QUARTER).round();
+    let r = (x - q * QUARTER) * DEGREE;
+    let (s, c) = quadrant(r, q, x);
+    (s, c)
+}
+
+/// Returns sin and cos of x + t in degrees, where t is a small correction.
+fn sin_cos_de(x: f64, t: f64) -> (f64, f64) {
+    let q = (x / QUARTER).round();
+    let r = ang_round((x - q * QUARTER) + t) * DEGREE;
+    quadrant(r, q, x)
+}
+
+fn quadrant(r: f64, q: f64, x: f64) -> (f64, f64) {
+    let (s, c) = r.sin_cos();
+    let (sinx, cosx) = match (q as i64).rem_euclid(4) {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    };
+    let sinx = if sinx == 0.0 { sinx.copysign(x) } else { sinx };
+    (sinx, cosx + 0.0)
+}
+
+/// Returns atan2(y, x) in degrees, in [-180, 180], with exact values for multiples of 90.
+fn atan2_d(y: f64, x: f64) -> f64 {
+    let (mut x, mut y) = (x, y);
+    let mut q = 0;
+    if y.abs() > x.abs() {
+        std::mem::swap(&mut x, &mut y);
+        q = 2;
+    }
+    if x.is_sign_negative() {
+        x = -x;
+        q += 1;
+    }
+    // here x >= 0 and x >= abs(y), so angle is in [-pi/4, pi/4]
+    let ang = y.atan2(x) / DEGREE;
+    match q {
+        1 => HALF.copysign(y) - ang,
+        2 => QUARTER - ang,
+        3 => -QUARTER + ang,
+        _ => ang,
+    }
+}
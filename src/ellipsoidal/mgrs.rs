@@ -0,0 +1,620 @@
+use std::{fmt, str::FromStr};
+
+use crate::{Length, NVector};
+
+use super::{utm::BANDS, Ellipsoid, Hemisphere, UpsPosition, UtmPosition};
+
+/// 100 km square column letters of UTM zones, by set (zone - 1) % 3.
+const UTM_COLUMNS: [&[u8; 8]; 3] = [b"ABCDEFGH", b"JKLMNPQR", b"STUVWXYZ"];
+
+/// 100 km square row letters of UTM zones, by set (zone - 1) % 2.
+const UTM_ROWS: [&[u8; 20]; 2] = [b"ABCDEFGHJKLMNPQRSTUV", b"FGHJKLMNPQRSTUVABCDE"];
+
+/// 100 km square column letters of UPS zones: A, B, Y and Z.
+const UPS_COLUMNS: [&[u8]; 4] = [b"JKLPQRSTUXYZ", b"ABCFGHJKLPQR", b"RSTUXYZ", b"ABCFGHJ"];
+
+/// 100 km square row letters of UPS zones: south and north.
+const UPS_ROWS: [&[u8]; 2] = [b"ABCDEFGHJKLMNPQRSTUVWXYZ", b"ABCDEFGHJKLMNP"];
+
+/// first 100 km column (west) and row of the UPS zones: south and north.
+const UPS_MIN: [u32; 2] = [8, 13];
+
+/// first 100 km column of the eastern UPS zones (B and Z).
+const UPS_EAST: u32 = 20;
+
+/// 100 km in metres.
+const SQUARE: u32 = 100_000;
+
+/// Precision of a [MgrsPosition]: the size of the grid cell identified by the reference.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))] // codecov:ignore:this
+pub enum MgrsPrecision {
+    /// 100 km: no digits, grid zone designator and 100 km square identifier only.
+    HundredKilometres,
+    /// 10 km: 1 digit for easting and northing.
+    TenKilometres,
+    /// 1 km: 2 digits for easting and northing.
+    Kilometre,
+    /// 100 m: 3 digits for easting and northing.
+    HundredMetres,
+    /// 10 m: 4 digits for easting and northing.
+    TenMetres,
+    /// 1 m: 5 digits for easting and northing.
+    #[default]
+    Metre,
+}
+
+impl MgrsPrecision {
+    /// number of digits of easting and northing.
+    fn digits(&self) -> usize {
+        match self {
+            MgrsPrecision::HundredKilometres => 0,
+            MgrsPrecision::TenKilometres => 1,
+            MgrsPrecision::Kilometre => 2,
+            MgrsPrecision::HundredMetres => 3,
+            MgrsPrecision::TenMetres => 4,
+            MgrsPrecision::Metre => 5,
+        }
+    }
+
+    fn from_digits(digits: usize) -> Option<Self> {
+        match digits {
+            0 => Some(MgrsPrecision::HundredKilometres),
+            1 => Some(MgrsPrecision::TenKilometres),
+            2 => Some(MgrsPrecision::Kilometre),
+            3 => Some(MgrsPrecision::HundredMetres),
+            4 => Some(MgrsPrecision::TenMetres),
+            5 => Some(MgrsPrecision::Metre),
+            _ => None,
+        }
+    }
+
+    /// size of the grid cell in metres.
+    fn cell_size(&self) -> u32 {
+        10u32.pow(5 - self.digits() as u32)
+    }
+}
+
+/// Error returned when parsing a malformed MGRS reference.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum MgrsError {
+    /// The reference is too short to contain a grid zone designator and a 100 km square identifier.
+    TooShort,
+    /// The UTM zone number is not in the range 1 to 60.
+    InvalidZone(String),
+    /// The latitude band letter is not valid: C to X (excluding I and O) for UTM, A, B, Y or Z for UPS.
+    InvalidBand(char),
+    /// The 100 km square identifier is not valid for the grid zone.
+    InvalidSquare(char, char),
+    /// The numerical location is not made of an even number of at most 10 digits.
+    InvalidDigits(String),
+}
+
+impl fmt::Display for MgrsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MgrsError::TooShort => write!(
+                f,
+                "MGRS reference too short: expected grid zone designator and 100 km square identifier"
+            ),
+            MgrsError::InvalidZone(z) => {
+                write!(f, "invalid MGRS zone '{}': expected 1 to 60", z)
+            }
+            MgrsError::InvalidBand(b) => write!(
+                f,
+                "invalid MGRS latitude band '{}': expected C to X (excluding I and O), A, B, Y or Z",
+                b
+            ),
+            MgrsError::InvalidSquare(c, r) => {
+                write!(f, "invalid MGRS 100 km square identifier '{}{}'", c, r)
+            }
+            MgrsError::InvalidDigits(d) => write!(
+                f,
+                "invalid MGRS numerical location '{}': expected an even number of at most 10 digits",
+                d
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MgrsError {}
+
+/// A position expressed as a [Military Grid Reference System](https://en.wikipedia.org/wiki/Military_Grid_Reference_System)
+/// (MGRS) reference: grid zone designator, 100 km square identifier and numerical location (easting and northing
+/// within the square) at a given [precision](crate::ellipsoidal::MgrsPrecision).
+///
+/// MGRS references are derived from [UTM](crate::ellipsoidal::UtmPosition) coordinates between 80°S and 84°N and
+/// from [UPS](crate::ellipsoidal::UpsPosition) coordinates in the polar regions. The 100 km square identifiers
+/// follow the lettering scheme used with WGS84 (AA scheme).
+///
+/// A reference identifies a grid cell: easting and northing are truncated - not rounded - to the precision,
+/// therefore the reference denotes the south-west corner of the cell containing the position.
+///
+/// # Examples
+///
+/// ```
+/// use jord::LatLong;
+/// use jord::ellipsoidal::{Ellipsoid, MgrsPosition, MgrsPrecision};
+///
+/// let p = LatLong::from_degrees(33.3, 44.4).to_nvector();
+///
+/// let mgrs = MgrsPosition::from_nvector(p, MgrsPrecision::Metre, Ellipsoid::WGS84);
+/// assert_eq!("38SMB4414084706", mgrs.to_string());
+///
+/// let mgrs = MgrsPosition::from_nvector(p, MgrsPrecision::Kilometre, Ellipsoid::WGS84);
+/// assert_eq!("38SMB4484", mgrs.to_string());
+///
+/// let parsed: MgrsPosition = "38SMB4414084706".parse().unwrap();
+/// assert_eq!(
+///     LatLong::from_degrees(33.3, 44.4),
+///     LatLong::from_nvector(parsed.centre(Ellipsoid::WGS84)).round_d5()
+/// );
+///
+/// assert!("38SMB441408470".parse::<MgrsPosition>().is_err());
+/// ```
+#[derive(PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))] // codecov:ignore:this
+pub struct MgrsPosition {
+    /// UTM zone; 0 for UPS.
+    zone: u8,
+    band: char,
+    column: char,
+    row: char,
+    /// easting within the 100 km square in metres, truncated to the precision.
+    easting: u32,
+    /// northing within the 100 km square in metres, truncated to the precision.
+    northing: u32,
+    precision: MgrsPrecision,
+}
+
+impl MgrsPosition {
+    /// Converts the given [NVector] into a [MgrsPosition] at the given precision using the given ellipsoid.
+    pub fn from_nvector(p: NVector, precision: MgrsPrecision, ellipsoid: Ellipsoid) -> Self {
+        match UtmPosition::from_nvector(p, ellipsoid) {
+            Some(utm) => Self::from_utm(utm, precision),
+            None => Self::from_ups(UpsPosition::from_nvector(p, ellipsoid), precision),
+        }
+    }
+
+    /// Returns the precision of this [MgrsPosition].
+    #[inline]
+    pub fn precision(&self) -> MgrsPrecision {
+        self.precision
+    }
+
+    /// Converts this [MgrsPosition] into the [NVector] of the south-west corner of the grid cell using the given
+    /// ellipsoid.
+    pub fn to_nvector(&self, ellipsoid: Ellipsoid) -> NVector {
+        self.position(0.0, ellipsoid)
+    }
+
+    /// Converts this [MgrsPosition] into the [NVector] of the centre of the grid cell using the given ellipsoid.
+    pub fn centre(&self, ellipsoid: Ellipsoid) -> NVector {
+        self.position(self.precision.cell_size() as f64 / 2.0, ellipsoid)
+    }
+
+    fn from_utm(utm: UtmPosition, precision: MgrsPrecision) -> Self {
+        let zone = utm.zone();
+        let (e100k, easting) = split(utm.easting(), precision);
+        let (n100k, northing) = split(utm.northing(), precision);
+        let column = UTM_COLUMNS[(zone as usize - 1) % 3][e100k as usize - 1] as char;
+        let row = UTM_ROWS[(zone as usize - 1) % 2][n100k as usize % 20] as char;
+        Self {
+            zone,
+            band: utm.band(),
+            column,
+            row,
+            easting,
+            northing,
+            precision,
+        }
+    }
+
+    fn from_ups(ups: UpsPosition, precision: MgrsPrecision) -> Self {
+        // the band and the 100 km column are derived from the same easting, rounded to the millimetre as done by
+        // split: an easting of 1_999_999.9999999998 is in band A or Y but in the first column of band B or Z.
+        let ups = ups.round_mm();
+        let band = ups.band();
+        let north = ups.hemisphere() == Hemisphere::North;
+        let (e100k, easting) = split(ups.easting(), precision);
+        let (n100k, northing) = split(ups.northing(), precision);
+        let (i, min_col) = ups_column_set(band);
+        let column = UPS_COLUMNS[i][(e100k - min_col) as usize] as char;
+        let row = UPS_ROWS[north as usize][(n100k - UPS_MIN[north as usize]) as usize] as char;
+        Self {
+            zone: 0,
+            band,
+            column,
+            row,
+            easting,
+            northing,
+            precision,
+        }
+    }
+
+    /// position at the given offset in metres from the south-west corner of the grid cell.
+    fn position(&self, offset: f64, ellipsoid: Ellipsoid) -> NVector {
+        let e = self.easting as f64 + offset;
+        let n = self.northing as f64 + offset;
+        if self.zone == 0 {
+            let north = self.band >= 'Y';
+            let (i, min_col) = ups_column_set(self.band);
+            let col = min_col + position_of(UPS_COLUMNS[i], self.column);
+            let row = UPS_MIN[north as usize] + position_of(UPS_ROWS[north as usize], self.row);
+            let hemisphere = if north {
+                Hemisphere::North
+            } else {
+                Hemisphere::South
+            };
+            UpsPosition::new(
+                hemisphere,
+                Length::from_metres((col * SQUARE) as f64 + e),
+                Length::from_metres((row * SQUARE) as f64 + n),
+            )
+            .to_nvector(ellipsoid)
+        } else {
+            let col = position_of(UTM_COLUMNS[(self.zone as usize - 1) % 3], self.column) + 1;
+            let row = position_of(UTM_ROWS[(self.zone as usize - 1) % 2], self.row);
+            // the row letters repeat every 2,000 km: add the multiple of 2,000 km that places the northing within
+            // the latitude band; the lowest northing of the band is on the central meridian.
+            let band_lat = (position_of(BANDS, self.band) as f64) * 8.0 - 80.0;
+            let band_lon = (self.zone as f64 - 1.0) * 6.0 - 177.0;
+            let band_northing = UtmPosition::from_nvector_in_zone(
+                NVector::from_lat_long_degrees(band_lat, band_lon),
+                self.zone,
+                ellipsoid,
+            )
+            .map_or(0.0, |u| u.northing().as_metres());
+            let band_northing = (band_northing / SQUARE as f64).floor() * SQUARE as f64;
+            let mut northing = (row * SQUARE) as f64 + n;
+            while northing < band_northing {
+                northing += 2_000_000.0;
+            }
+            UtmPosition::new(
+                self.zone,
+                self.band,
+                Length::from_metres((col * SQUARE) as f64 + e),
+                Length::from_metres(northing),
+            )
+            .expect("zone and band validated when the MGRS position was created")
+            .to_nvector(ellipsoid)
+        }
+    }
+}
+
+impl fmt::Display for MgrsPosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.zone != 0 {
+            write!(f, "{:02}", self.zone)?;
+        }
+        write!(f, "{}{}{}", self.band, self.column, self.row)?;
+        let digits = self.precision.digits();
+        if digits > 0 {
+            let cell = self.precision.cell_size();
+            write!(
+                f,
+                "{:0d$}{:0d$}",
+                self.easting / cell,
+                self.northing / cell,
+                d = digits
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for MgrsPosition {
+    type Err = MgrsError;
+
+    /// Parses the given MGRS reference; whitespaces are ignored and letters are case-insensitive, e.g.
+    /// "33UVP0483515842", "33U VP 04835 15842", "4QFJ12345678" or "ZAH0000000000".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s: Vec<char> = s
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        let zone_len = s.iter().take_while(|c| c.is_ascii_digit()).count();
+        if s.len() < zone_len + 3 {
+            return Err(MgrsError::TooShort);
+        }
+        let zone_str: String = s[..zone_len].iter().collect();
+        let band = s[zone_len];
+        let column = s[zone_len + 1];
+        let row = s[zone_len + 2];
+        let digits: String = s[zone_len + 3..].iter().collect();
+
+        let zone: u8 = if zone_len == 0 {
+            0
+        } else if zone_len > 2 {
+            return Err(MgrsError::InvalidZone(zone_str));
+        } else {
+            zone_str
+                .parse()
+                .ok()
+                .filter(|z| (1..=60).contains(z))
+                .ok_or(MgrsError::InvalidZone(zone_str))?
+        };
+
+        let valid_square = if zone == 0 {
+            if !['A', 'B', 'Y', 'Z'].contains(&band) {
+                return Err(MgrsError::InvalidBand(band));
+            }
+            let north = band >= 'Y';
+            let (i, _) = ups_column_set(band);
+            contains(UPS_COLUMNS[i], column) && contains(UPS_ROWS[north as usize], row)
+        } else {
+            if !band.is_ascii() || !BANDS.contains(&(band as u8)) {
+                return Err(MgrsError::InvalidBand(band));
+            }
+            contains(UTM_COLUMNS[(zone as usize - 1) % 3], column)
+                && contains(UTM_ROWS[(zone as usize - 1) % 2], row)
+        };
+        if !valid_square {
+            return Err(MgrsError::InvalidSquare(column, row));
+        }
+
+        if digits.len() % 2 != 0 || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err(MgrsError::InvalidDigits(digits));
+        }
+        let precision = MgrsPrecision::from_digits(digits.len() / 2)
+            .ok_or(MgrsError::InvalidDigits(digits.clone()))?;
+        let (e, n) = digits.split_at(digits.len() / 2);
+        let cell = precision.cell_size();
+        let easting = e.parse::<u32>().unwrap_or(0) * cell;
+        let northing = n.parse::<u32>().unwrap_or(0) * cell;
+
+        Ok(Self {
+            zone,
+            band,
+            column,
+            row,
+            easting,
+            northing,
+            precision,
+        })
+    }
+}
+
+/// splits the given easting or northing into the 100 km index and the remainder truncated to the given precision.
+fn split(l: Length, precision: MgrsPrecision) -> (u32, u32) {
+    // truncate to the millimetre first so that values such as 4_834.9999999 are not truncated to 4_834.
+    let m = (l.round_mm().as_metres()).floor() as u32;
+    let cell = precision.cell_size();
+    (m / SQUARE, (m % SQUARE) / cell * cell)
+}
+
+/// index of the set of column letters and first 100 km column of the given UPS band.
+fn ups_column_set(band: char) -> (usize, u32) {
+    match band {
+        'A' => (0, UPS_MIN[0]),
+        'B' => (1, UPS_EAST),
+        'Y' => (2, UPS_MIN[1]),
+        _ => (3, UPS_EAST),
+    }
+}
+
+fn contains(letters: &[u8], c: char) -> bool {
+    c.is_ascii() && letters.contains(&(c as u8))
+}
+
+fn position_of(letters: &[u8], c: char) -> u32 {
+    letters.iter().position(|l| *l as char == c).unwrap_or(0) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ellipsoidal::Ellipsoid, LatLong, Length, NVector};
+
+    use super::{MgrsError, MgrsPosition, MgrsPrecision};
+
+    #[test]
+    fn precisions() {
+        let p = NVector::from_lat_long_degrees(33.3, 44.4);
+        let expected = [
+            (MgrsPrecision::HundredKilometres, "38SMB"),
+            (MgrsPrecision::TenKilometres, "38SMB48"),
+            (MgrsPrecision::Kilometre, "38SMB4484"),
+            (MgrsPrecision::HundredMetres, "38SMB441847"),
+            (MgrsPrecision::TenMetres, "38SMB44148470"),
+            (MgrsPrecision::Metre, "38SMB4414084706"),
+        ];
+        for (precision, s) in expected {
+            let mgrs = MgrsPosition::from_nvector(p, precision, Ellipsoid::WGS84);
+            assert_eq!(s, mgrs.to_string());
+            assert_eq!(precision, mgrs.precision());
+            assert_eq!(Ok(mgrs), s.parse::<MgrsPosition>());
+        }
+    }
+
+    #[test]
+    fn poles() {
+        assert_eq!("ZAH0000000000", mgrs(90.0, 0.0));
+        assert_eq!("BAN0000000000", mgrs(-90.0, 0.0));
+    }
+
+    #[test]
+    fn ups_bands() {
+        assert!(mgrs(85.0, -10.0).starts_with('Y'));
+        assert!(mgrs(85.0, 10.0).starts_with('Z'));
+        assert!(mgrs(-85.0, -10.0).starts_with('A'));
+        assert!(mgrs(-85.0, 10.0).starts_with('B'));
+    }
+
+    #[test]
+    fn ups_antimeridian() {
+        // easting a fraction of a nanometre west of the false easting.
+        for lat in [-80.25, -80.5, -80.75, -81.0, -81.25, 80.5, 85.0] {
+            for lon in [-180.0, 180.0] {
+                let p = NVector::from_lat_long_degrees(lat, lon);
+                let mgrs = MgrsPosition::from_nvector(p, MgrsPrecision::Metre, Ellipsoid::WGS84);
+                let parsed = mgrs.to_string().parse::<MgrsPosition>().unwrap();
+                assert_eq!(mgrs, parsed);
+                // within 1 metre.
+                let d = (p.as_vec3() - parsed.centre(Ellipsoid::WGS84).as_vec3()).norm();
+                assert!(d < 1.0 / 6_371_000.0, "{} at {}, {}", mgrs, lat, lon);
+            }
+        }
+    }
+
+    #[test]
+    fn utm_zone_padded() {
+        assert!(mgrs(20.0, -157.0).starts_with("04Q"));
+    }
+
+    #[test]
+    fn parse_format() {
+        let r = "33UVP0483515842";
+        assert_eq!(r, r.parse::<MgrsPosition>().unwrap().to_string());
+        assert_eq!(
+            r,
+            "33U VP 04835 15842"
+                .parse::<MgrsPosition>()
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            r,
+            "33uvp0483515842"
+                .parse::<MgrsPosition>()
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            "04QFJ12345678",
+            "4QFJ12345678".parse::<MgrsPosition>().unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(Err(MgrsError::TooShort), "".parse::<MgrsPosition>());
+        assert_eq!(Err(MgrsError::TooShort), "33UV".parse::<MgrsPosition>());
+        assert_eq!(
+            Err(MgrsError::InvalidZone("61".to_string())),
+            "61UVP".parse::<MgrsPosition>()
+        );
+        assert_eq!(
+            Err(MgrsError::InvalidZone("0".to_string())),
+            "0UVP".parse::<MgrsPosition>()
+        );
+        assert_eq!(
+            Err(MgrsError::InvalidZone("123".to_string())),
+            "123UVP".parse::<MgrsPosition>()
+        );
+        assert_eq!(
+            Err(MgrsError::InvalidBand('I')),
+            "33IVP".parse::<MgrsPosition>()
+        );
+        assert_eq!(
+            Err(MgrsError::InvalidBand('C')),
+            "CAH".parse::<MgrsPosition>()
+        );
+        assert_eq!(
+            Err(MgrsError::InvalidSquare('A', 'P')),
+            "33UAP".parse::<MgrsPosition>()
+        );
+        assert_eq!(
+            Err(MgrsError::InvalidSquare('V', 'W')),
+            "33UVW".parse::<MgrsPosition>()
+        );
+        assert_eq!(
+            Err(MgrsError::InvalidSquare('D', 'H')),
+            "ZDH".parse::<MgrsPosition>()
+        );
+        assert_eq!(
+            Err(MgrsError::InvalidDigits("123".to_string())),
+            "33UVP123".parse::<MgrsPosition>()
+        );
+        assert_eq!(
+            Err(MgrsError::InvalidDigits("12A4".to_string())),
+            "33UVP12A4".parse::<MgrsPosition>()
+        );
+        assert_eq!(
+            Err(MgrsError::InvalidDigits("123456789012".to_string())),
+            "33UVP123456789012".parse::<MgrsPosition>()
+        );
+    }
+
+    #[test]
+    fn error_message() {
+        assert_eq!(
+            "invalid MGRS zone '61': expected 1 to 60",
+            MgrsError::InvalidZone("61".to_string()).to_string()
+        );
+    }
+
+    #[test]
+    fn corner_and_centre() {
+        let m: MgrsPosition = "38SMB4484".parse().unwrap();
+        let sw = m.to_nvector(Ellipsoid::WGS84);
+        let c = m.centre(Ellipsoid::WGS84);
+        // diagonal of half a 1 km cell.
+        let d = Ellipsoid::WGS84.distance(sw, c);
+        assert!((d.as_metres() - 707.1).abs() < 1.0);
+        assert_eq!(
+            "38SMB4484",
+            MgrsPosition::from_nvector(c, MgrsPrecision::Kilometre, Ellipsoid::WGS84).to_string()
+        );
+        assert_eq!(
+            "38SMB4484",
+            MgrsPosition::from_nvector(sw, MgrsPrecision::Kilometre, Ellipsoid::WGS84).to_string()
+        );
+    }
+
+    #[test]
+    fn decode_encode() {
+        for r in [
+            "33UVP0483565842",
+            "18TWL8040",
+            "55HFA12",
+            "BAN12343456",
+            "YZN2345",
+            "04QFJ",
+        ] {
+            let m: MgrsPosition = r.parse().unwrap();
+            let sw = m.to_nvector(Ellipsoid::WGS84);
+            assert_eq!(
+                r,
+                MgrsPosition::from_nvector(sw, m.precision(), Ellipsoid::WGS84).to_string()
+            );
+            let c = m.centre(Ellipsoid::WGS84);
+            assert_eq!(
+                r,
+                MgrsPosition::from_nvector(c, m.precision(), Ellipsoid::WGS84).to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut lat = -89.5;
+        while lat < 90.0 {
+            let mut lon = -179.5;
+            while lon < 180.0 {
+                let p = NVector::from_lat_long_degrees(lat, lon);
+                let m = MgrsPosition::from_nvector(p, MgrsPrecision::Metre, Ellipsoid::WGS84);
+                let parsed: MgrsPosition = m.to_string().parse().unwrap();
+                assert_eq!(m, parsed);
+                let c = parsed.centre(Ellipsoid::WGS84);
+                assert!(
+                    Ellipsoid::WGS84.distance(p, c) < Length::from_metres(1.0),
+                    "{:?} {}",
+                    LatLong::from_degrees(lat, lon),
+                    m
+                );
+                lon += 7.3;
+            }
+            lat += 3.7;
+        }
+    }
+
+    fn mgrs(lat: f64, lon: f64) -> String {
+        MgrsPosition::from_nvector(
+            NVector::from_lat_long_degrees(lat, lon),
+            MgrsPrecision::Metre,
+            Ellipsoid::WGS84,
+        )
+        .to_string()
+    }
+}
@@ -2,3 +2,14 @@
 
 mod ellipsoid;
 pub use ellipsoid::Ellipsoid;
+
+mod geodesic;
+
+mod mgrs;
+pub use mgrs::{MgrsError, MgrsPosition, MgrsPrecision};
+
+mod ups;
+pub use ups::UpsPosition;
+
+mod utm;
+pub use utm::{Hemisphere, UtmPosition};
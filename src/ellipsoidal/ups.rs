@@ -0,0 +1,234 @@
+use std::f64::consts::FRAC_PI_2;
+
+use crate::{Angle, LatLong, Length, NVector};
+
+use super::{Ellipsoid, Hemisphere};
+
+/// UPS scale factor at the pole.
+const K0: f64 = 0.994;
+
+/// UPS false easting and northing in metres.
+const FALSE_EASTING_NORTHING: f64 = 2_000_000.0;
+
+/// A position expressed in the [Universal Polar Stereographic](https://en.wikipedia.org/wiki/Universal_polar_stereographic_coordinate_system)
+/// (UPS) coordinate system: hemisphere, easting and northing.
+///
+/// UPS is intended for the polar regions not covered by [UTM](crate::ellipsoidal::UtmPosition): north of 84°N
+/// and south of 80°S, but the projection is defined for any position of the hemisphere.
+///
+/// # Examples
+///
+/// ```
+/// use jord::{LatLong, Length};
+/// use jord::ellipsoidal::{Ellipsoid, Hemisphere, UpsPosition};
+///
+/// let p = LatLong::from_degrees(90.0, 0.0).to_nvector();
+/// let ups = UpsPosition::from_nvector(p, Ellipsoid::WGS84);
+///
+/// assert_eq!(Hemisphere::North, ups.hemisphere());
+/// assert_eq!(Length::from_metres(2_000_000.0), ups.easting().round_mm());
+/// assert_eq!(Length::from_metres(2_000_000.0), ups.northing().round_mm());
+/// ```
+#[derive(PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))] // codecov:ignore:this
+pub struct UpsPosition {
+    hemisphere: Hemisphere,
+    easting: Length,
+    northing: Length,
+}
+
+impl UpsPosition {
+    /// Creates a new [UpsPosition] from the given hemisphere, easting and northing.
+    pub const fn new(hemisphere: Hemisphere, easting: Length, northing: Length) -> Self {
+        Self {
+            hemisphere,
+            easting,
+            northing,
+        }
+    }
+
+    /// Converts the given [NVector] into a [UpsPosition] using the given ellipsoid; the projection is centred on the
+    /// pole of the hemisphere of the given position.
+    pub fn from_nvector(p: NVector, ellipsoid: Ellipsoid) -> Self {
+        let ll = LatLong::from_nvector(p);
+        let lat = ll.latitude().as_radians();
+        let (hemisphere, lat) = if lat >= 0.0 {
+            (Hemisphere::North, lat)
+        } else {
+            (Hemisphere::South, -lat)
+        };
+        let e = ellipsoid.eccentricity();
+        let es = e * lat.sin();
+        let t = (FRAC_PI_2 / 2.0 - lat / 2.0).tan() / ((1.0 - es) / (1.0 + es)).powf(e / 2.0);
+        let rho = t * pole_scale(ellipsoid);
+        let (sin_lon, cos_lon) = ll.longitude().as_radians().sin_cos();
+        let easting = FALSE_EASTING_NORTHING + rho * sin_lon;
+        let northing = match hemisphere {
+            Hemisphere::North => FALSE_EASTING_NORTHING - rho * cos_lon,
+            Hemisphere::South => FALSE_EASTING_NORTHING + rho * cos_lon,
+        };
+        Self::new(
+            hemisphere,
+            Length::from_metres(easting),
+            Length::from_metres(northing),
+        )
+    }
+
+    /// Converts this [UpsPosition] into an [NVector] using the given ellipsoid.
+    pub fn to_nvector(&self, ellipsoid: Ellipsoid) -> NVector {
+        let dx = self.easting.as_metres() - FALSE_EASTING_NORTHING;
+        let dy = self.northing.as_metres() - FALSE_EASTING_NORTHING;
+        let rho = (dx * dx + dy * dy).sqrt();
+        let t = rho / pole_scale(ellipsoid);
+        let e = ellipsoid.eccentricity();
+        let mut lat = FRAC_PI_2 - 2.0 * t.atan();
+        for _ in 0..20 {
+            let es = e * lat.sin();
+            let next = FRAC_PI_2 - 2.0 * (t * ((1.0 - es) / (1.0 + es)).powf(e / 2.0)).atan();
+            let done = (next - lat).abs() < 1e-15;
+            lat = next;
+            if done {
+                break;
+            }
+        }
+        let (lat, lon) = match self.hemisphere {
+            Hemisphere::North => (lat, dx.atan2(-dy)),
+            Hemisphere::South => (-lat, dx.atan2(dy)),
+        };
+        LatLong::new(Angle::from_radians(lat), Angle::from_radians(lon)).to_nvector()
+    }
+
+    /// Returns the hemisphere of this [UpsPosition].
+    #[inline]
+    pub fn hemisphere(&self) -> Hemisphere {
+        self.hemisphere
+    }
+
+    /// Returns the zone letter of this [UpsPosition]: A (south, west), B (south, east), Y (north, west) or Z
+    /// (north, east).
+    pub fn band(&self) -> char {
+        let west = self.easting.as_metres() < FALSE_EASTING_NORTHING;
+        match (self.hemisphere, west) {
+            (Hemisphere::North, true) => 'Y',
+            (Hemisphere::North, false) => 'Z',
+            (Hemisphere::South, true) => 'A',
+            (Hemisphere::South, false) => 'B',
+        }
+    }
+
+    /// Returns the easting of this [UpsPosition].
+    #[inline]
+    pub fn easting(&self) -> Length {
+        self.easting
+    }
+
+    /// Returns the northing of this [UpsPosition].
+    #[inline]
+    pub fn northing(&self) -> Length {
+        self.northing
+    }
+
+    /// Rounds the easting and northing of this [UpsPosition] to the nearest millimetre.
+    pub fn round_mm(&self) -> Self {
+        Self::new(
+            self.hemisphere,
+            self.easting.round_mm(),
+            self.northing.round_mm(),
+        )
+    }
+}
+
+/// 2 * a * k0 / sqrt((1 + e)^(1 + e) * (1 - e)^(1 - e)): distance from the pole = t * pole_scale.
+fn pole_scale(ellipsoid: Ellipsoid) -> f64 {
+    let a = ellipsoid.equatorial_radius().as_metres();
+    let e = ellipsoid.eccentricity();
+    2.0 * a * K0 / ((1.0 + e).powf(1.0 + e) * (1.0 - e).powf(1.0 - e)).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ellipsoidal::{Ellipsoid, Hemisphere},
+        LatLong, Length, NVector,
+    };
+
+    use super::UpsPosition;
+
+    #[test]
+    fn poles() {
+        let c = Length::from_metres(2_000_000.0);
+        assert_eq!(
+            UpsPosition::new(Hemisphere::North, c, c),
+            UpsPosition::from_nvector(NVector::from_lat_long_degrees(90.0, 0.0), Ellipsoid::WGS84)
+                .round_mm()
+        );
+        assert_eq!(
+            UpsPosition::new(Hemisphere::South, c, c),
+            UpsPosition::from_nvector(NVector::from_lat_long_degrees(-90.0, 0.0), Ellipsoid::WGS84)
+                .round_mm()
+        );
+    }
+
+    #[test]
+    fn axes() {
+        // grid north is along the 180° meridian in the northern hemisphere and along the 0° meridian in the
+        // southern hemisphere.
+        let n = UpsPosition::from_nvector(
+            NVector::from_lat_long_degrees(85.0, 180.0),
+            Ellipsoid::WGS84,
+        );
+        assert_eq!(Length::from_metres(2_000_000.0), n.easting().round_mm());
+        assert!(n.northing() > Length::from_metres(2_000_000.0));
+
+        let s =
+            UpsPosition::from_nvector(NVector::from_lat_long_degrees(-85.0, 0.0), Ellipsoid::WGS84);
+        assert_eq!(Length::from_metres(2_000_000.0), s.easting().round_mm());
+        assert!(s.northing() > Length::from_metres(2_000_000.0));
+
+        let e =
+            UpsPosition::from_nvector(NVector::from_lat_long_degrees(85.0, 90.0), Ellipsoid::WGS84);
+        assert_eq!(Length::from_metres(2_000_000.0), e.northing().round_mm());
+        assert!(e.easting() > Length::from_metres(2_000_000.0));
+    }
+
+    #[test]
+    fn limits() {
+        let n =
+            UpsPosition::from_nvector(NVector::from_lat_long_degrees(84.0, 0.0), Ellipsoid::WGS84);
+        assert_eq!(Length::from_metres(1_333_272.296), n.northing().round_mm());
+
+        let s =
+            UpsPosition::from_nvector(NVector::from_lat_long_degrees(-80.0, 0.0), Ellipsoid::WGS84);
+        assert_eq!(Length::from_metres(3_112_951.137), s.northing().round_mm());
+    }
+
+    #[test]
+    fn bands() {
+        let ups = |lat: f64, lon: f64| {
+            UpsPosition::from_nvector(NVector::from_lat_long_degrees(lat, lon), Ellipsoid::WGS84)
+        };
+        assert_eq!('Y', ups(85.0, -10.0).band());
+        assert_eq!('Z', ups(85.0, 10.0).band());
+        assert_eq!('A', ups(-85.0, -10.0).band());
+        assert_eq!('B', ups(-85.0, 10.0).band());
+    }
+
+    #[test]
+    fn round_trip() {
+        for lat in [-90.0, -87.5, -84.0, -80.0, 84.0, 86.3, 89.9, 90.0] {
+            let mut lon = -180.0;
+            while lon < 180.0 {
+                let p = NVector::from_lat_long_degrees(lat, lon);
+                let ups = UpsPosition::from_nvector(p, Ellipsoid::WGS84);
+                let actual = ups.to_nvector(Ellipsoid::WGS84);
+                assert!(
+                    Ellipsoid::WGS84.distance(p, actual) < Length::from_metres(0.001),
+                    "{:?} {:?}",
+                    LatLong::from_degrees(lat, lon),
+                    ups
+                );
+                lon += 7.5;
+            }
+        }
+    }
+}
@@ -0,0 +1,488 @@
+use std::f64::consts::PI;
+
+use crate::{Angle, LatLong, Length, NVector};
+
+use super::Ellipsoid;
+
+/// UTM scale factor on the central meridian.
+const K0: f64 = 0.9996;
+
+/// UTM false easting in metres.
+const FALSE_EASTING: f64 = 500_000.0;
+
+/// UTM false northing in metres (southern hemisphere only).
+const FALSE_NORTHING: f64 = 10_000_000.0;
+
+/// UTM latitude bands, from 80°S to 84°N; each band spans 8° except X which spans 12°.
+pub(crate) const BANDS: &[u8; 20] = b"CDEFGHJKLMNPQRSTUVWX";
+
+/// Hemisphere of a [UtmPosition] or [UpsPosition](crate::ellipsoidal::UpsPosition).
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))] // codecov:ignore:this
+pub enum Hemisphere {
+    /// Northern hemisphere.
+    #[default]
+    North,
+    /// Southern hemisphere.
+    South,
+}
+
+/// A position expressed in the [Universal Transverse Mercator](https://en.wikipedia.org/wiki/Universal_Transverse_Mercator_coordinate_system)
+/// (UTM) coordinate system: zone, latitude band, easting and northing.
+///
+/// UTM covers latitudes from 80°S to 84°N; polar regions are covered by the
+/// [Universal Polar Stereographic](crate::ellipsoidal::UpsPosition) system.
+///
+/// The transverse Mercator projection is computed using the 6th order Krüger series given by Karney (2011), which are
+/// accurate to a few nanometres within the UTM zones.
+///
+/// # Examples
+///
+/// ```
+/// use jord::{LatLong, Length};
+/// use jord::ellipsoidal::{Ellipsoid, UtmPosition};
+///
+/// let p = LatLong::from_degrees(33.3, 44.4).to_nvector();
+/// let utm = UtmPosition::from_nvector(p, Ellipsoid::WGS84).unwrap();
+///
+/// assert_eq!(38, utm.zone());
+/// assert_eq!('S', utm.band());
+/// assert_eq!(Length::from_metres(444_140.54), utm.easting().round_cm());
+/// assert_eq!(Length::from_metres(3_684_706.36), utm.northing().round_cm());
+///
+/// assert_eq!(
+///     LatLong::from_degrees(33.3, 44.4),
+///     LatLong::from_nvector(utm.to_nvector(Ellipsoid::WGS84)).round_d7()
+/// );
+/// ```
+#[derive(PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))] // codecov:ignore:this
+pub struct UtmPosition {
+    zone: u8,
+    band: char,
+    easting: Length,
+    northing: Length,
+}
+
+impl UtmPosition {
+    /// Creates a new [UtmPosition] from the given zone (1 to 60), latitude band (C to X, excluding I and O),
+    /// easting and northing.
+    ///
+    /// Returns `None` if the zone or the band are invalid.
+    pub fn new(zone: u8, band: char, easting: Length, northing: Length) -> Option<Self> {
+        if (1..=60).contains(&zone) && band.is_ascii() && BANDS.contains(&(band as u8)) {
+            Some(Self {
+                zone,
+                band,
+                easting,
+                northing,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Converts the given [NVector] into a [UtmPosition] using the given ellipsoid; the zone is selected from the
+    /// longitude, taking into account the Norway and Svalbard exceptions.
+    ///
+    /// Returns `None` if the latitude of the given position is outside of the UTM limits: [80°S, 84°N].
+    pub fn from_nvector(p: NVector, ellipsoid: Ellipsoid) -> Option<Self> {
+        let ll = LatLong::from_nvector(p);
+        let lat = ll.latitude().as_degrees();
+        let lon = ll.longitude().as_degrees();
+        if !(-80.0..=84.0).contains(&lat) {
+            return None;
+        }
+        let band = band_of(lat);
+        let zone = zone_of(lat, lon);
+        Some(Self::project(ll, zone, band, ellipsoid))
+    }
+
+    /// Converts the given [NVector] into a [UtmPosition] in the given zone using the given ellipsoid; this allows
+    /// to express positions close to a zone boundary in the coordinates of the neighbouring zone.
+    ///
+    /// Returns `None` if the zone is invalid or if the latitude of the given position is outside of the UTM limits:
+    /// [80°S, 84°N].
+    pub fn from_nvector_in_zone(p: NVector, zone: u8, ellipsoid: Ellipsoid) -> Option<Self> {
+        let ll = LatLong::from_nvector(p);
+        let lat = ll.latitude().as_degrees();
+        if !(1..=60).contains(&zone) || !(-80.0..=84.0).contains(&lat) {
+            return None;
+        }
+        Some(Self::project(ll, zone, band_of(lat), ellipsoid))
+    }
+
+    /// Converts this [UtmPosition] into an [NVector] using the given ellipsoid.
+    pub fn to_nvector(&self, ellipsoid: Ellipsoid) -> NVector {
+        let x = self.easting.as_metres() - FALSE_EASTING;
+        let y = if self.hemisphere() == Hemisphere::South {
+            self.northing.as_metres() - FALSE_NORTHING
+        } else {
+            self.northing.as_metres()
+        };
+        let (lat, lon) = TransverseMercator::new(ellipsoid).reverse(x, y);
+        let lon0 = central_meridian(self.zone);
+        LatLong::new(Angle::from_radians(lat), Angle::from_radians(lon + lon0)).to_nvector()
+    }
+
+    /// Returns the zone of this [UtmPosition]: 1 to 60.
+    #[inline]
+    pub fn zone(&self) -> u8 {
+        self.zone
+    }
+
+    /// Returns the latitude band of this [UtmPosition]: C to X (excluding I and O).
+    #[inline]
+    pub fn band(&self) -> char {
+        self.band
+    }
+
+    /// Returns the hemisphere of this [UtmPosition]: northern for bands N to X, southern for bands C to M.
+    pub fn hemisphere(&self) -> Hemisphere {
+        if self.band >= 'N' {
+            Hemisphere::North
+        } else {
+            Hemisphere::South
+        }
+    }
+
+    /// Returns the easting of this [UtmPosition].
+    #[inline]
+    pub fn easting(&self) -> Length {
+        self.easting
+    }
+
+    /// Returns the northing of this [UtmPosition].
+    #[inline]
+    pub fn northing(&self) -> Length {
+        self.northing
+    }
+
+    /// Rounds the easting and northing of this [UtmPosition] to the nearest millimetre.
+    pub fn round_mm(&self) -> Self {
+        Self {
+            zone: self.zone,
+            band: self.band,
+            easting: self.easting.round_mm(),
+            northing: self.northing.round_mm(),
+        }
+    }
+
+    fn project(ll: LatLong, zone: u8, band: char, ellipsoid: Ellipsoid) -> Self {
+        let lon = ll.longitude().as_radians() - central_meridian(zone);
+        // longitude relative to the central meridian in [-PI, PI].
+        let lon = (lon + PI).rem_euclid(2.0 * PI) - PI;
+        let (x, y) = TransverseMercator::new(ellipsoid).forward(ll.latitude().as_radians(), lon);
+        let northing = if band < 'N' { y + FALSE_NORTHING } else { y };
+        Self {
+            zone,
+            band,
+            easting: Length::from_metres(x + FALSE_EASTING),
+            northing: Length::from_metres(northing),
+        }
+    }
+}
+
+/// Transverse Mercator projection scaled by the UTM central scale factor.
+///
+/// See: Karney, C. F. F., "Transverse Mercator with an accuracy of a few nanometers", J. Geodesy 85(8), 475–485
+/// (2011).
+#[derive(Clone, Copy, Debug)]
+struct TransverseMercator {
+    /// first eccentricity.
+    e: f64,
+    /// 2 * PI * k0 * A is the scaled circumference of a meridian.
+    k0a: f64,
+    alpha: [f64; 6],
+    beta: [f64; 6],
+}
+
+impl TransverseMercator {
+    fn new(ellipsoid: Ellipsoid) -> Self {
+        let f = ellipsoid.flattening();
+        let n = f / (2.0 - f);
+        let n2 = n * n;
+        let n3 = n * n2;
+        let n4 = n * n3;
+        let n5 = n * n4;
+        let n6 = n * n5;
+        let a = ellipsoid.equatorial_radius().as_metres() / (1.0 + n)
+            * (1.0 + n2 / 4.0 + n4 / 64.0 + n6 / 256.0);
+        let alpha = [
+            n / 2.0 - 2.0 * n2 / 3.0 + 5.0 * n3 / 16.0 + 41.0 * n4 / 180.0 - 127.0 * n5 / 288.0
+                + 7891.0 * n6 / 37800.0,
+            13.0 * n2 / 48.0 - 3.0 * n3 / 5.0 + 557.0 * n4 / 1440.0 + 281.0 * n5 / 630.0
+                - 1983433.0 * n6 / 1935360.0,
+            61.0 * n3 / 240.0 - 103.0 * n4 / 140.0
+                + 15061.0 * n5 / 26880.0
+                + 167603.0 * n6 / 181440.0,
+            49561.0 * n4 / 161280.0 - 179.0 * n5 / 168.0 + 6601661.0 * n6 / 7257600.0,
+            34729.0 * n5 / 80640.0 - 3418889.0 * n6 / 1995840.0,
+            212378941.0 * n6 / 319334400.0,
+        ];
+        let beta = [
+            n / 2.0 - 2.0 * n2 / 3.0 + 37.0 * n3 / 96.0 - n4 / 360.0 - 81.0 * n5 / 512.0
+                + 96199.0 * n6 / 604800.0,
+            n2 / 48.0 + n3 / 15.0 - 437.0 * n4 / 1440.0 + 46.0 * n5 / 105.0
+                - 1118711.0 * n6 / 3870720.0,
+            17.0 * n3 / 480.0 - 37.0 * n4 / 840.0 - 209.0 * n5 / 4480.0 + 5569.0 * n6 / 90720.0,
+            4397.0 * n4 / 161280.0 - 11.0 * n5 / 504.0 - 830251.0 * n6 / 7257600.0,
+            4583.0 * n5 / 161280.0 - 108847.0 * n6 / 3991680.0,
+            20648693.0 * n6 / 638668800.0,
+        ];
+        Self {
+            e: ellipsoid.eccentricity(),
+            k0a: K0 * a,
+            alpha,
+            beta,
+        }
+    }
+
+    /// Projects the given latitude and longitude relative to the central meridian (radians) to (x, y) in metres.
+    fn forward(&self, lat: f64, lon: f64) -> (f64, f64) {
+        let tau = lat.tan();
+        let tau_p = self.conformal_tan(tau);
+        let (sin_lon, cos_lon) = lon.sin_cos();
+        let xi_p = tau_p.atan2(cos_lon);
+        let eta_p = (sin_lon / (tau_p * tau_p + cos_lon * cos_lon).sqrt()).asinh();
+
+        let mut xi = xi_p;
+        let mut eta = eta_p;
+        for (j, a) in self.alpha.iter().enumerate() {
+            let k = 2.0 * (j + 1) as f64;
+            xi += a * (k * xi_p).sin() * (k * eta_p).cosh();
+            eta += a * (k * xi_p).cos() * (k * eta_p).sinh();
+        }
+        (self.k0a * eta, self.k0a * xi)
+    }
+
+    /// Unprojects the given (x, y) in metres to the latitude and longitude relative to the central meridian (radians).
+    fn reverse(&self, x: f64, y: f64) -> (f64, f64) {
+        let eta = x / self.k0a;
+        let xi = y / self.k0a;
+
+        let mut xi_p = xi;
+        let mut eta_p = eta;
+        for (j, b) in self.beta.iter().enumerate() {
+            let k = 2.0 * (j + 1) as f64;
+            xi_p -= b * (k * xi).sin() * (k * eta).cosh();
+            eta_p -= b * (k * xi).cos() * (k * eta).sinh();
+        }
+
+        let sinh_eta_p = eta_p.sinh();
+        let (sin_xi_p, cos_xi_p) = xi_p.sin_cos();
+        let tau_p = sin_xi_p / (sinh_eta_p * sinh_eta_p + cos_xi_p * cos_xi_p).sqrt();
+        let tau = self.geodetic_tan(tau_p);
+        (tau.atan(), sinh_eta_p.atan2(cos_xi_p))
+    }
+
+    /// tangent of the conformal latitude from the tangent of the geodetic latitude.
+    fn conformal_tan(&self, tau: f64) -> f64 {
+        let e = self.e;
+        let sigma = (e * (e * tau / (1.0 + tau * tau).sqrt()).atanh()).sinh();
+        tau * (1.0 + sigma * sigma).sqrt() - sigma * (1.0 + tau * tau).sqrt()
+    }
+
+    /// tangent of the geodetic latitude from the tangent of the conformal latitude (Newton-Raphson).
+    fn geodetic_tan(&self, tau_p: f64) -> f64 {
+        let e2m = 1.0 - self.e * self.e;
+        let mut tau = tau_p;
+        for _ in 0..10 {
+            let tau_i_p = self.conformal_tan(tau);
+            let d = (tau_p - tau_i_p) / (1.0 + tau_i_p * tau_i_p).sqrt() * (1.0 + e2m * tau * tau)
+                / (e2m * (1.0 + tau * tau).sqrt());
+            tau += d;
+            if d.abs() <= 1e-12 * tau.abs().max(1.0) {
+                break;
+            }
+        }
+        tau
+    }
+}
+
+/// central meridian of the given zone in radians.
+fn central_meridian(zone: u8) -> f64 {
+    ((zone as f64 - 1.0) * 6.0 - 180.0 + 3.0).to_radians()
+}
+
+/// latitude band of the given latitude in degrees, which must be within [-80, 84].
+fn band_of(lat: f64) -> char {
+    let i = ((lat + 80.0) / 8.0).floor().clamp(0.0, 19.0) as usize;
+    BANDS[i] as char
+}
+
+/// zone of the given latitude and longitude in degrees including the Norway and Svalbard exceptions.
+fn zone_of(lat: f64, lon: f64) -> u8 {
+    let lon = (lon + 180.0).rem_euclid(360.0) - 180.0;
+    let zone = ((lon + 180.0) / 6.0).floor() as u8 % 60 + 1;
+    if (56.0..64.0).contains(&lat) && (3.0..12.0).contains(&lon) {
+        // Norway: zone 32 is widened to cover the south-west coast.
+        32
+    } else if lat >= 72.0 && (0.0..42.0).contains(&lon) {
+        // Svalbard: zones 32, 34 and 36 are not used.
+        if lon < 9.0 {
+            31
+        } else if lon < 21.0 {
+            33
+        } else if lon < 33.0 {
+            35
+        } else {
+            37
+        }
+    } else {
+        zone
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ellipsoidal::{Ellipsoid, Hemisphere},
+        LatLong, Length, NVector,
+    };
+
+    use super::UtmPosition;
+
+    #[test]
+    fn new_invalid() {
+        let l = Length::from_metres(500_000.0);
+        assert!(UtmPosition::new(0, 'N', l, l).is_none());
+        assert!(UtmPosition::new(61, 'N', l, l).is_none());
+        assert!(UtmPosition::new(31, 'A', l, l).is_none());
+        assert!(UtmPosition::new(31, 'I', l, l).is_none());
+        assert!(UtmPosition::new(31, 'O', l, l).is_none());
+        assert!(UtmPosition::new(31, 'Y', l, l).is_none());
+        assert!(UtmPosition::new(31, 'n', l, l).is_none());
+        assert!(UtmPosition::new(31, 'N', l, l).is_some());
+    }
+
+    #[test]
+    fn hemisphere() {
+        let l = Length::from_metres(500_000.0);
+        assert_eq!(
+            Hemisphere::South,
+            UtmPosition::new(31, 'M', l, l).unwrap().hemisphere()
+        );
+        assert_eq!(
+            Hemisphere::North,
+            UtmPosition::new(31, 'N', l, l).unwrap().hemisphere()
+        );
+    }
+
+    #[test]
+    fn central_meridian_equator() {
+        let utm = from_lat_long(0.0, 3.0).unwrap();
+        assert_eq!(
+            UtmPosition::new(
+                31,
+                'N',
+                Length::from_metres(500_000.0),
+                Length::from_metres(0.0)
+            ),
+            Some(utm.round_mm())
+        );
+    }
+
+    #[test]
+    fn southern_hemisphere() {
+        let utm = from_lat_long(-33.3, 44.4).unwrap();
+        assert_eq!(38, utm.zone());
+        assert_eq!('H', utm.band());
+        assert_eq!(Hemisphere::South, utm.hemisphere());
+        assert_eq!(Length::from_metres(444_140.54), utm.easting().round_cm());
+        assert_eq!(Length::from_metres(6_315_293.64), utm.northing().round_cm());
+    }
+
+    #[test]
+    fn bands() {
+        assert_eq!('C', from_lat_long(-80.0, 0.0).unwrap().band());
+        assert_eq!('M', from_lat_long(-0.1, 0.0).unwrap().band());
+        assert_eq!('N', from_lat_long(0.0, 0.0).unwrap().band());
+        assert_eq!('W', from_lat_long(71.9, 0.0).unwrap().band());
+        assert_eq!('X', from_lat_long(72.0, 0.0).unwrap().band());
+        assert_eq!('X', from_lat_long(84.0, 0.0).unwrap().band());
+    }
+
+    #[test]
+    fn outside_limits() {
+        assert!(from_lat_long(84.1, 0.0).is_none());
+        assert!(from_lat_long(-80.1, 0.0).is_none());
+        let p = NVector::from_lat_long_degrees(85.0, 0.0);
+        assert!(UtmPosition::from_nvector_in_zone(p, 31, Ellipsoid::WGS84).is_none());
+    }
+
+    #[test]
+    fn zones() {
+        assert_eq!(1, from_lat_long(0.0, -180.0).unwrap().zone());
+        assert_eq!(1, from_lat_long(0.0, 180.0).unwrap().zone());
+        assert_eq!(30, from_lat_long(0.0, -0.1).unwrap().zone());
+        assert_eq!(31, from_lat_long(0.0, 0.0).unwrap().zone());
+        assert_eq!(60, from_lat_long(0.0, 179.9).unwrap().zone());
+    }
+
+    #[test]
+    fn norway() {
+        assert_eq!(31, from_lat_long(60.0, 2.9).unwrap().zone());
+        assert_eq!(32, from_lat_long(60.0, 3.0).unwrap().zone());
+        assert_eq!(32, from_lat_long(60.0, 11.9).unwrap().zone());
+        assert_eq!(31, from_lat_long(55.9, 5.0).unwrap().zone());
+        assert_eq!(31, from_lat_long(64.0, 5.0).unwrap().zone());
+    }
+
+    #[test]
+    fn svalbard() {
+        assert_eq!(31, from_lat_long(78.0, 8.9).unwrap().zone());
+        assert_eq!(33, from_lat_long(78.0, 9.0).unwrap().zone());
+        assert_eq!(33, from_lat_long(78.0, 20.9).unwrap().zone());
+        assert_eq!(35, from_lat_long(78.0, 21.0).unwrap().zone());
+        assert_eq!(35, from_lat_long(78.0, 32.9).unwrap().zone());
+        assert_eq!(37, from_lat_long(78.0, 33.0).unwrap().zone());
+        assert_eq!(37, from_lat_long(78.0, 41.9).unwrap().zone());
+        assert_eq!(38, from_lat_long(78.0, 42.0).unwrap().zone());
+        assert_eq!(31, from_lat_long(71.9, 5.9).unwrap().zone());
+        assert_eq!(32, from_lat_long(71.9, 9.0).unwrap().zone());
+    }
+
+    #[test]
+    fn in_zone() {
+        let p = NVector::from_lat_long_degrees(45.0, 5.9);
+        let utm = UtmPosition::from_nvector_in_zone(p, 32, Ellipsoid::WGS84).unwrap();
+        assert_eq!(32, utm.zone());
+        assert!(utm.easting() < Length::from_metres(500_000.0));
+        assert_round_trip(p, utm);
+        assert!(UtmPosition::from_nvector_in_zone(p, 0, Ellipsoid::WGS84).is_none());
+    }
+
+    #[test]
+    fn round_trip() {
+        let ellipsoids = [Ellipsoid::WGS84, Ellipsoid::GRS80, Ellipsoid::MOLA];
+        for ellipsoid in ellipsoids {
+            let mut lat = -80.0;
+            while lat <= 84.0 {
+                let mut lon = -180.0;
+                while lon < 180.0 {
+                    let p = NVector::from_lat_long_degrees(lat, lon);
+                    let utm = UtmPosition::from_nvector(p, ellipsoid).unwrap();
+                    let actual = utm.to_nvector(ellipsoid);
+                    assert!(
+                        ellipsoid.distance(p, actual) < Length::from_metres(0.001),
+                        "{:?} {:?}",
+                        LatLong::from_degrees(lat, lon),
+                        utm
+                    );
+                    lon += 2.9;
+                }
+                lat += 4.1;
+            }
+        }
+    }
+
+    fn assert_round_trip(p: NVector, utm: UtmPosition) {
+        let actual = utm.to_nvector(Ellipsoid::WGS84);
+        assert!(Ellipsoid::WGS84.distance(p, actual) < Length::from_metres(0.001));
+    }
+
+    fn from_lat_long(lat: f64, lon: f64) -> Option<UtmPosition> {
+        UtmPosition::from_nvector(NVector::from_lat_long_degrees(lat, lon), Ellipsoid::WGS84)
+    }
+}
@@ -0,0 +1,1289 @@
+//! Import and export of positions and [spherical](crate::spherical) shapes as [GeoJSON](https://www.rfc-editor.org/rfc/rfc7946)
+//! geometries and features.
+//!
+//! Exported geometries follow the recommendations of RFC 7946:
+//! - polygon exterior rings are counterclockwise and holes are clockwise,
+//! - geometries crossing the antimeridian are split into `MultiLineString` or `MultiPolygon` whose parts
+//!   lie on either side of the antimeridian,
+//! - polygons enclosing a pole are closed along the antimeridian through that pole.
+//!
+//! Parsed geometries accept rings in either orientation and parts split at the antimeridian are joined back
+//! together.
+//!
+//! This module is only available with the `geojson` feature.
+
+use std::{collections::HashMap, fmt};
+
+use serde_json::{json, Value};
+
+use crate::{
+    spherical::{Cap, Loop, MinorArc, Polygon, Rectangle},
+    LatLong, NVector, Vec3,
+};
+
+/// (longitude, latitude) in degrees.
+type Coord = (f64, f64);
+
+/// Error returned when a GeoJSON value cannot be converted.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum GeoJsonError {
+    /// The GeoJSON object has no type or a type that is not expected.
+    InvalidType(String),
+    /// A position is not an array of at least 2 finite numbers with a latitude in [-90, 90].
+    InvalidPosition(String),
+    /// The geometry is malformed: missing coordinates, too few positions, unclosed ring or disconnected parts.
+    InvalidGeometry(String),
+}
+
+impl fmt::Display for GeoJsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GeoJsonError::InvalidType(s) => write!(f, "invalid GeoJSON type: {}", s),
+            GeoJsonError::InvalidPosition(s) => write!(f, "invalid GeoJSON position: {}", s),
+            GeoJsonError::InvalidGeometry(s) => write!(f, "invalid GeoJSON geometry: {}", s),
+        }
+    }
+}
+
+impl std::error::Error for GeoJsonError {}
+
+/// Conversion into a GeoJSON geometry.
+pub trait ToGeoJson {
+    /// Returns the GeoJSON geometry representing this value.
+    fn to_geojson(&self) -> Value;
+
+    /// Returns a GeoJSON feature whose geometry represents this value and with the given properties (object or
+    /// null).
+    fn to_geojson_feature(&self, properties: Value) -> Value {
+        json!({
+            "type": "Feature",
+            "geometry": self.to_geojson(),
+            "properties": properties
+        })
+    }
+}
+
+impl ToGeoJson for NVector {
+    /// Returns a `Point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::NVector;
+    /// use jord::geojson::ToGeoJson;
+    ///
+    /// let p = NVector::from_lat_long_degrees(55.5, 13.0);
+    /// assert_eq!(
+    ///     r#"{"coordinates":[13.0,55.5],"type":"Point"}"#,
+    ///     p.to_geojson().to_string()
+    /// );
+    /// ```
+    fn to_geojson(&self) -> Value {
+        let (lon, lat) = coord(*self);
+        json!({ "type": "Point", "coordinates": [lon, lat] })
+    }
+}
+
+impl ToGeoJson for MinorArc {
+    /// Returns a `LineString`, or a `MultiLineString` if this minor arc crosses the antimeridian.
+    fn to_geojson(&self) -> Value {
+        line(&[self.start(), self.end()])
+    }
+}
+
+impl ToGeoJson for [MinorArc] {
+    /// Returns a `LineString` joining all the minor arcs of this chain, or a `MultiLineString` if this chain crosses
+    /// the antimeridian; the end of each minor arc is assumed to be the start of the next one.
+    fn to_geojson(&self) -> Value {
+        let mut vs: Vec<NVector> = self.iter().map(|a| a.start()).collect();
+        if let Some(last) = self.last() {
+            vs.push(last.end());
+        }
+        line(&vs)
+    }
+}
+
+impl ToGeoJson for Loop {
+    /// Returns a `Polygon`, or a `MultiPolygon` if this loop crosses the antimeridian.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::NVector;
+    /// use jord::geojson::ToGeoJson;
+    /// use jord::spherical::Loop;
+    ///
+    /// let l = Loop::new(&vec![
+    ///     NVector::from_lat_long_degrees(-10.0, 170.0),
+    ///     NVector::from_lat_long_degrees(-10.0, -170.0),
+    ///     NVector::from_lat_long_degrees(10.0, -170.0),
+    ///     NVector::from_lat_long_degrees(10.0, 170.0),
+    /// ]);
+    /// assert_eq!("MultiPolygon", l.to_geojson()["type"]);
+    /// ```
+    fn to_geojson(&self) -> Value {
+        polygon(exterior(self), Vec::new())
+    }
+}
+
+impl ToGeoJson for Polygon {
+    /// Returns a `Polygon`, or a `MultiPolygon` if this polygon crosses the antimeridian.
+    fn to_geojson(&self) -> Value {
+        polygon(
+            exterior(self.shell()),
+            self.iter_holes().map(hole).collect(),
+        )
+    }
+}
+
+impl ToGeoJson for Rectangle {
+    /// Returns a `Polygon` whose edges follow the parallels and meridians bounding this rectangle, or a
+    /// `MultiPolygon` if this rectangle crosses the antimeridian.
+    fn to_geojson(&self) -> Value {
+        if self.is_empty() {
+            return json!({ "type": "Polygon", "coordinates": [] });
+        }
+        let s = self.south_west().latitude().as_degrees();
+        let n = self.north_east().latitude().as_degrees();
+        let (w, e) = if self.is_longitude_full() {
+            (-180.0, 180.0)
+        } else {
+            (
+                self.south_west().longitude().as_degrees(),
+                self.north_east().longitude().as_degrees(),
+            )
+        };
+        let ring = |w: f64, e: f64| vec![vec![(w, s), (e, s), (e, n), (w, n)]];
+        if w <= e {
+            polygon_value(vec![ring(w, e)])
+        } else {
+            polygon_value(vec![ring(w, 180.0), ring(-180.0, e)])
+        }
+    }
+}
+
+/// Returns the GeoJSON geometry of the [boundary](crate::spherical::Cap::boundary) of the given cap approximated
+/// with the given number of vertices: a `Polygon`, or a `MultiPolygon` if the cap crosses the antimeridian.
+///
+/// # Examples
+///
+/// ```
+/// use jord::{Angle, NVector};
+/// use jord::geojson::cap_to_geojson;
+/// use jord::spherical::Cap;
+///
+/// let cap = Cap::from_centre_and_radius(NVector::from_lat_long_degrees(0.0, 0.0), Angle::from_degrees(1.0));
+/// let g = cap_to_geojson(cap, 16);
+/// assert_eq!("Polygon", g["type"]);
+/// // closed ring.
+/// assert_eq!(17, g["coordinates"][0].as_array().unwrap().len());
+/// ```
+pub fn cap_to_geojson(cap: Cap, nb_vertices: usize) -> Value {
+    Loop::new(&cap.boundary(nb_vertices)).to_geojson()
+}
+
+/// Parses the given GeoJSON `Point` (or `Feature` thereof) into an [NVector].
+///
+/// # Examples
+///
+/// ```
+/// use jord::NVector;
+/// use jord::geojson::parse_point;
+///
+/// let v: serde_json::Value = serde_json::from_str(r#"{"type":"Point","coordinates":[13.0,55.5]}"#).unwrap();
+/// assert_eq!(Ok(NVector::from_lat_long_degrees(55.5, 13.0)), parse_point(&v));
+/// ```
+pub fn parse_point(v: &Value) -> Result<NVector, GeoJsonError> {
+    let coordinates = coordinates_of(v, &["Point"])?.1;
+    position(coordinates).map(to_nvector)
+}
+
+/// Parses the given GeoJSON `LineString` or `MultiLineString` (or `Feature` thereof) into a chain of [MinorArc]s.
+///
+/// The parts of a `MultiLineString` must be connected: each part must start where the previous part ends, either
+/// at the same position or at the same latitude on the other side of the antimeridian.
+///
+/// # Examples
+///
+/// ```
+/// use jord::NVector;
+/// use jord::geojson::parse_line_string;
+///
+/// let v: serde_json::Value = serde_json::from_str(
+///     r#"{"type":"MultiLineString","coordinates":[[[170.0,0.0],[180.0,0.0]],[[-180.0,0.0],[-170.0,0.0]]]}"#
+/// ).unwrap();
+/// let arcs = parse_line_string(&v).unwrap();
+/// assert_eq!(2, arcs.len());
+/// assert_eq!(NVector::from_lat_long_degrees(0.0, 170.0), arcs[0].start());
+/// assert_eq!(NVector::from_lat_long_degrees(0.0, -170.0), arcs[1].end());
+/// ```
+pub fn parse_line_string(v: &Value) -> Result<Vec<MinorArc>, GeoJsonError> {
+    let (t, coordinates) = coordinates_of(v, &["LineString", "MultiLineString"])?;
+    let coords = if t == "LineString" {
+        line_positions(coordinates)?
+    } else {
+        let mut parts = array(coordinates)?.iter().map(line_positions);
+        let mut joined = parts.next().unwrap_or_else(|| Ok(Vec::new()))?;
+        for part in parts {
+            let part = part?;
+            match (joined.last(), part.first()) {
+                (Some(a), Some(b)) if same_position(*a, *b) => joined.extend(&part[1..]),
+                _ => {
+                    return Err(GeoJsonError::InvalidGeometry(
+                        "disconnected MultiLineString parts".to_string(),
+                    ))
+                }
+            }
+        }
+        joined
+    };
+    let mut vs: Vec<NVector> = Vec::with_capacity(coords.len());
+    for p in coords.into_iter().map(to_nvector) {
+        if vs.last() != Some(&p) {
+            vs.push(p);
+        }
+    }
+    Ok(vs.windows(2).map(|w| MinorArc::new(w[0], w[1])).collect())
+}
+
+/// Parses the given GeoJSON `Polygon` or `MultiPolygon` (or `Feature` thereof) into a single [Polygon]: the parts
+/// of a `MultiPolygon` must all be joined back together across the antimeridian.
+///
+/// # Examples
+///
+/// ```
+/// use jord::NVector;
+/// use jord::geojson::{parse_polygon, ToGeoJson};
+/// use jord::spherical::Loop;
+///
+/// let l = Loop::new(&vec![
+///     NVector::from_lat_long_degrees(-10.0, 170.0),
+///     NVector::from_lat_long_degrees(-10.0, -170.0),
+///     NVector::from_lat_long_degrees(10.0, -170.0),
+///     NVector::from_lat_long_degrees(10.0, 170.0),
+/// ]);
+/// let p = parse_polygon(&l.to_geojson()).unwrap();
+/// assert!(p.contains_position(NVector::from_lat_long_degrees(0.0, 180.0)));
+/// assert!(!p.contains_position(NVector::from_lat_long_degrees(0.0, 0.0)));
+/// ```
+pub fn parse_polygon(v: &Value) -> Result<Polygon, GeoJsonError> {
+    let mut ps = parse_polygons(v)?;
+    if ps.len() == 1 {
+        Ok(ps.remove(0))
+    } else {
+        Err(GeoJsonError::InvalidGeometry(format!(
+            "expected 1 polygon, found {}",
+            ps.len()
+        )))
+    }
+}
+
+/// Parses the given GeoJSON `Polygon` or `MultiPolygon` (or `Feature` thereof) into [Polygon]s; the parts of a
+/// `MultiPolygon` that have been split at the antimeridian are joined back together.
+pub fn parse_polygons(v: &Value) -> Result<Vec<Polygon>, GeoJsonError> {
+    let (t, coordinates) = coordinates_of(v, &["Polygon", "MultiPolygon"])?;
+    let pieces = if t == "Polygon" {
+        vec![polygon_rings(coordinates)?]
+    } else {
+        let pieces = array(coordinates)?
+            .iter()
+            .map(polygon_rings)
+            .collect::<Result<Vec<_>, _>>()?;
+        merge_at_antimeridian(pieces)
+    };
+    Ok(pieces
+        .into_iter()
+        .filter(|rings| !rings.is_empty())
+        .map(|rings| {
+            let to_loop = |r: &Vec<Coord>| {
+                let vs: Vec<NVector> = r.iter().map(|c| to_nvector(*c)).collect();
+                Loop::new(&vs)
+            };
+            let mut rings = rings.into_iter();
+            // the exterior ring of a polygon enclosing a pole and having a hole enclosing the same pole is bridged
+            // to the hole along the antimeridian: the shell is the part containing the others.
+            let mut parts: Vec<Loop> = rings
+                .next()
+                .map(split_at_bridges)
+                .unwrap_or_default()
+                .iter()
+                .map(to_loop)
+                .collect();
+            let contains_all = |i: usize| {
+                parts.iter().enumerate().all(|(j, o)| {
+                    j == i || o.iter_vertices().all(|v| parts[i].contains_position(*v))
+                })
+            };
+            let shell = match (0..parts.len()).find(|i| contains_all(*i)) {
+                Some(i) => parts.remove(i),
+                None if parts.is_empty() => Loop::EMPTY,
+                None => parts.remove(0),
+            };
+            parts.extend(rings.map(|r| to_loop(&r)));
+            Polygon::new(shell, parts)
+        })
+        .collect())
+}
+
+/// Splits the given opened ring at each pair of opposite edges along the antimeridian, e.g. bridging the exterior
+/// ring and a hole of a polygon enclosing a pole.
+fn split_at_bridges(r: Vec<Coord>) -> Vec<Vec<Coord>> {
+    let n = r.len();
+    let on_seam = |k: usize| r[k].0.abs() == 180.0 && r[(k + 1) % n].0.abs() == 180.0;
+    let opposite = |k: usize, m: usize| {
+        r[k].1 == r[(m + 1) % n].1 && r[(k + 1) % n].1 == r[m].1 && r[k].1 != r[m].1
+    };
+    let bridge = (0..n).filter(|k| on_seam(*k)).find_map(|k| {
+        ((k + 1)..n)
+            .find(|m| on_seam(*m) && opposite(k, *m))
+            .map(|m| (k, m))
+    });
+    match bridge {
+        None => vec![r],
+        Some((k, m)) => {
+            let inner: Vec<Coord> = r[(k + 1)..=m].to_vec();
+            let outer: Vec<Coord> = (m + 1..n + k + 1).map(|i| r[i % n]).collect();
+            let mut res = split_at_bridges(outer);
+            res.extend(split_at_bridges(inner));
+            res
+        }
+    }
+}
+
+/// (longitude, latitude) in degrees of the given position.
+fn coord(p: NVector) -> Coord {
+    let ll = LatLong::from_nvector(p);
+    (ll.longitude().as_degrees(), ll.latitude().as_degrees())
+}
+
+fn to_nvector(c: Coord) -> NVector {
+    NVector::from_lat_long_degrees(c.1, c.0)
+}
+
+/// counterclockwise exterior ring: loop vertices are stored clockwise.
+fn exterior(l: &Loop) -> Vec<Coord> {
+    let mut r: Vec<Coord> = l.iter_vertices().map(|v| coord(*v)).collect();
+    r.reverse();
+    r
+}
+
+/// clockwise interior ring.
+fn hole(l: &Loop) -> Vec<Coord> {
+    l.iter_vertices().map(|v| coord(*v)).collect()
+}
+
+/// `LineString` or `MultiLineString` of the given positions.
+fn line(vs: &[NVector]) -> Value {
+    let cs: Vec<Coord> = vs.iter().map(|v| coord(*v)).collect();
+    let mut parts: Vec<Vec<Coord>> = Vec::new();
+    let mut current: Vec<Coord> = Vec::new();
+    for (i, c) in cs.iter().enumerate() {
+        if i > 0 && crosses(cs[i - 1], *c) {
+            let lat = crossing_latitude(cs[i - 1], *c);
+            let lon = 180.0f64.copysign(cs[i - 1].0);
+            push_distinct(&mut current, (lon, lat));
+            parts.push(std::mem::take(&mut current));
+            current.push((-lon, lat));
+        }
+        push_distinct(&mut current, *c);
+    }
+    parts.push(current);
+    if parts.len() == 1 {
+        json!({ "type": "LineString", "coordinates": positions(&parts[0]) })
+    } else {
+        let coordinates: Vec<Value> = parts.iter().map(|p| positions(p)).collect();
+        json!({ "type": "MultiLineString", "coordinates": coordinates })
+    }
+}
+
+/// `Polygon` or `MultiPolygon` of the given exterior and interior (opened) rings.
+fn polygon(exterior: Vec<Coord>, holes: Vec<Vec<Coord>>) -> Value {
+    if exterior.is_empty() {
+        return json!({ "type": "Polygon", "coordinates": [] });
+    }
+    let mut rings = vec![exterior];
+    rings.extend(holes);
+    polygon_value(split_at_antimeridian(rings))
+}
+
+/// `Polygon` or `MultiPolygon` of the given pieces made of opened rings.
+fn polygon_value(pieces: Vec<Vec<Vec<Coord>>>) -> Value {
+    let piece = |rings: &Vec<Vec<Coord>>| -> Value {
+        Value::Array(
+            rings
+                .iter()
+                .map(|r| {
+                    let mut closed = r.clone();
+                    closed.push(r[0]);
+                    positions(&closed)
+                })
+                .collect(),
+        )
+    };
+    if pieces.len() == 1 {
+        json!({ "type": "Polygon", "coordinates": piece(&pieces[0]) })
+    } else {
+        let coordinates: Vec<Value> = pieces.iter().map(piece).collect();
+        json!({ "type": "MultiPolygon", "coordinates": coordinates })
+    }
+}
+
+fn positions(cs: &[Coord]) -> Value {
+    Value::Array(cs.iter().map(|c| json!([c.0, c.1])).collect())
+}
+
+fn push_distinct(cs: &mut Vec<Coord>, c: Coord) {
+    if cs.last() != Some(&c) {
+        cs.push(c);
+    }
+}
+
+/// whether the segment between the given coordinates crosses the antimeridian (or the meridian at 180 when
+/// longitudes are in [0, 360)).
+fn crosses(a: Coord, b: Coord) -> bool {
+    (b.0 - a.0).abs() > 180.0
+}
+
+/// latitude at which the minor arc between the given coordinates crosses the antimeridian.
+fn crossing_latitude(a: Coord, b: Coord) -> f64 {
+    let n = to_nvector(a).as_vec3().cross_prod(to_nvector(b).as_vec3());
+    // intersection with the plane of the 0/180 meridian, on the 180 side.
+    let d = Vec3::new(-n.z(), 0.0, n.x());
+    if d == Vec3::ZERO {
+        return a.1;
+    }
+    let d = if d.x() > 0.0 { -d } else { d };
+    d.z().atan2(-d.x()).to_degrees()
+}
+
+/// A part of a ring between 2 crossings of the antimeridian. Both ends are identified by the index of the crossing
+/// and the side of the antimeridian they lie on: true if east (longitude 180), false if west (longitude -180).
+#[derive(Debug)]
+struct Segment {
+    start: (usize, bool),
+    end: (usize, bool),
+    coords: Vec<Coord>,
+}
+
+/// Splits the polygon made of the given opened rings (exterior first, then holes) along the antimeridian into
+/// pieces (exterior first, then holes). A polygon enclosing a pole is closed along the antimeridian through that
+/// pole. The polygon is returned as is if it cannot be consistently split.
+fn split_at_antimeridian(rings: Vec<Vec<Coord>>) -> Vec<Vec<Vec<Coord>>> {
+    let ring_crosses = |r: &Vec<Coord>| (0..r.len()).any(|i| crosses(r[i], r[(i + 1) % r.len()]));
+    if !rings.iter().any(ring_crosses) {
+        return vec![rings];
+    }
+    match split_rings(&rings) {
+        Some(pieces) => pieces,
+        None => vec![rings],
+    }
+}
+
+/// Splits the given rings along the antimeridian, returns None if the rings cannot be consistently stitched back
+/// (e.g. rings with inconsistent orientation).
+fn split_rings(rings: &[Vec<Coord>]) -> Option<Vec<Vec<Vec<Coord>>>> {
+    let is_east = |c: Coord| c.0 >= 0.0;
+    let seam = |east: bool| if east { 180.0 } else { -180.0 };
+    let mut crossings: Vec<f64> = Vec::new();
+    // number of times the rings wind eastward around the axis of the poles.
+    let mut winding = 0;
+    let mut segments: Vec<Segment> = Vec::new();
+    let mut whole: Vec<(usize, &Vec<Coord>)> = Vec::new();
+    for (ri, r) in rings.iter().enumerate() {
+        let n = r.len();
+        let first = (0..n).find(|&i| crosses(r[i], r[(i + 1) % n]));
+        let Some(f) = first else {
+            whole.push((ri, r));
+            continue;
+        };
+        let first_id = crossings.len();
+        crossings.push(crossing_latitude(r[f], r[(f + 1) % n]));
+        let mut start = (first_id, is_east(r[(f + 1) % n]));
+        let mut coords = vec![(seam(start.1), crossings[first_id])];
+        for k in 1..=n {
+            let i = (f + k) % n;
+            let j = (i + 1) % n;
+            push_distinct(&mut coords, r[i]);
+            if crosses(r[i], r[j]) {
+                let id = if i == f {
+                    first_id
+                } else {
+                    crossings.push(crossing_latitude(r[i], r[j]));
+                    crossings.len() - 1
+                };
+                winding += if is_east(r[i]) { 1 } else { -1 };
+                push_distinct(&mut coords, (seam(is_east(r[i])), crossings[id]));
+                let next = (id, is_east(r[j]));
+                segments.push(Segment {
+                    start,
+                    end: (id, is_east(r[i])),
+                    coords: std::mem::replace(&mut coords, vec![(seam(next.1), crossings[id])]),
+                });
+                start = next;
+            }
+        }
+    }
+    // counterclockwise rings winding eastward enclose the north pole, westward the south pole: the pole is an
+    // additional crossing.
+    let pole = match winding {
+        0 => None,
+        1 | -1 => Some(crossings.len()),
+        _ => return None,
+    };
+    if pole.is_some() {
+        crossings.push(90.0f64.copysign(winding as f64));
+    }
+    if crossings.len() % 2 != 0 {
+        return None;
+    }
+    // the interior of the polygon along the antimeridian is between consecutive crossings (by latitude).
+    let mut order: Vec<usize> = (0..crossings.len()).collect();
+    order.sort_by(|a, b| crossings[*a].total_cmp(&crossings[*b]));
+    let mut partner = vec![0; crossings.len()];
+    for pair in order.chunks(2) {
+        partner[pair[0]] = pair[1];
+        partner[pair[1]] = pair[0];
+    }
+
+    let start_of: HashMap<(usize, bool), usize> = segments
+        .iter()
+        .enumerate()
+        .map(|(i, s)| (s.start, i))
+        .collect();
+    let mut pieces: Vec<Vec<Vec<Coord>>> = Vec::new();
+    let mut visited = vec![false; segments.len()];
+    for first in 0..segments.len() {
+        if visited[first] {
+            continue;
+        }
+        let mut ring: Vec<Coord> = Vec::new();
+        let mut current = first;
+        loop {
+            visited[current] = true;
+            for c in &segments[current].coords {
+                push_distinct(&mut ring, *c);
+            }
+            let (id, east) = segments[current].end;
+            let next = if Some(partner[id]) == pole {
+                // along the antimeridian to the pole and back to the same crossing on the other side.
+                let lat = crossings[partner[id]];
+                push_distinct(&mut ring, (seam(east), lat));
+                push_distinct(&mut ring, (seam(!east), lat));
+                (id, !east)
+            } else {
+                (partner[id], east)
+            };
+            match start_of.get(&next) {
+                Some(&n) if n == first => break,
+                Some(&n) if !visited[n] => current = n,
+                _ => return None,
+            }
+        }
+        if ring.len() > 1 && ring.first() == ring.last() {
+            ring.pop();
+        }
+        pieces.push(vec![ring]);
+    }
+    for (ri, r) in whole {
+        if ri == 0 {
+            pieces.push(vec![r.clone()]);
+        } else {
+            let piece = pieces
+                .iter_mut()
+                .find(|rings| planar_contains(&rings[0], r[0]))?;
+            piece.push(r.clone());
+        }
+    }
+    Some(pieces)
+}
+
+/// Joins the pieces of a `MultiPolygon` that share edges along the antimeridian.
+fn merge_at_antimeridian(pieces: Vec<Vec<Vec<Coord>>>) -> Vec<Vec<Vec<Coord>>> {
+    let on_seam = |r: &Vec<Coord>| {
+        (0..r.len()).any(|i| r[i].0.abs() == 180.0 && r[(i + 1) % r.len()].0.abs() == 180.0)
+    };
+    let (seam, mut others): (Vec<_>, Vec<_>) = pieces
+        .into_iter()
+        .partition(|rings| rings.first().map_or(false, on_seam));
+    if seam.len() < 2 {
+        others.extend(seam);
+        return others;
+    }
+    // longitudes in [0, 360): the antimeridian is the meridian at 180.
+    let to_x = |c: &Coord| (if c.0 < 0.0 { c.0 + 360.0 } else { c.0 } + 0.0, c.1 + 0.0);
+    let seam: Vec<Vec<Vec<Coord>>> = seam
+        .into_iter()
+        .map(|rings| rings.iter().map(|r| r.iter().map(to_x).collect()).collect())
+        .collect();
+    let key = |c: Coord| (c.0.to_bits(), c.1.to_bits());
+    let lats: Vec<f64> = seam
+        .iter()
+        .flat_map(|rings| rings[0].iter())
+        .filter(|c| c.0 == 180.0)
+        .map(|c| c.1)
+        .collect();
+
+    // directed edges of all exteriors, edges along the seam are split at every seam vertex.
+    let mut edges: Vec<(Coord, Coord)> = Vec::new();
+    for rings in &seam {
+        let r = &rings[0];
+        for i in 0..r.len() {
+            let (a, b) = (r[i], r[(i + 1) % r.len()]);
+            if a.0 == 180.0 && b.0 == 180.0 {
+                let (lo, hi) = (a.1.min(b.1), a.1.max(b.1));
+                let mut inner: Vec<f64> = lats
+                    .iter()
+                    .copied()
+                    .filter(|l| *l > lo && *l < hi)
+                    .collect();
+                inner.sort_by(|x, y| x.total_cmp(y));
+                inner.dedup();
+                if a.1 > b.1 {
+                    inner.reverse();
+                }
+                let mut prev = a;
+                for l in inner {
+                    edges.push((prev, (180.0, l)));
+                    prev = (180.0, l);
+                }
+                edges.push((prev, b));
+            } else {
+                edges.push((a, b));
+            }
+        }
+    }
+    // edges shared by 2 pieces (in opposite directions) are interior to the merged polygon.
+    let mut removed = vec![false; edges.len()];
+    for i in 0..edges.len() {
+        if removed[i] {
+            continue;
+        }
+        let (a, b) = edges[i];
+        if let Some(j) = (0..edges.len())
+            .find(|&j| !removed[j] && key(edges[j].0) == key(b) && key(edges[j].1) == key(a))
+        {
+            removed[i] = true;
+            removed[j] = true;
+        }
+    }
+    let mut from: HashMap<(u64, u64), Vec<usize>> = HashMap::new();
+    for (i, e) in edges.iter().enumerate() {
+        if !removed[i] {
+            from.entry(key(e.0)).or_default().push(i);
+        }
+    }
+    let mut rings: Vec<Vec<Coord>> = Vec::new();
+    for i in 0..edges.len() {
+        if removed[i] {
+            continue;
+        }
+        let mut ring = Vec::new();
+        let mut current = i;
+        loop {
+            removed[current] = true;
+            ring.push(edges[current].0);
+            let next = from
+                .get(&key(edges[current].1))
+                .and_then(|es| es.iter().copied().find(|e| !removed[*e]));
+            match next {
+                Some(n) => current = n,
+                None => break,
+            }
+        }
+        rings.push(ring);
+    }
+
+    // counterclockwise rings (once closed through the pole they enclose, if any) are exteriors, clockwise rings are
+    // holes.
+    let (exteriors, mut holes): (Vec<_>, Vec<_>) = rings
+        .into_iter()
+        .partition(|r| planar_area(&unwrapped(r)) > 0.0);
+    holes.extend(seam.into_iter().flat_map(|rings| rings.into_iter().skip(1)));
+    let mut merged: Vec<Vec<Vec<Coord>>> = exteriors.into_iter().map(|e| vec![e]).collect();
+    for h in holes {
+        let contains = |e: &Vec<Coord>| {
+            let u = unwrapped(e);
+            [-360.0, 0.0, 360.0]
+                .iter()
+                .any(|shift| planar_contains(&u, (h[0].0 + shift, h[0].1)))
+        };
+        if let Some(p) = merged.iter_mut().find(|p| contains(&p[0])) {
+            p.push(h);
+        }
+    }
+    merged.extend(others);
+    merged
+}
+
+/// signed area of the given ring: positive if counterclockwise.
+fn planar_area(r: &[Coord]) -> f64 {
+    (0..r.len())
+        .map(|i| {
+            let (a, b) = (r[i], r[(i + 1) % r.len()]);
+            a.0 * b.1 - b.0 * a.1
+        })
+        .sum::<f64>()
+        / 2.0
+}
+
+/// the given ring with continuous longitudes (i.e. consecutive longitudes differ by at most 180 degrees); a ring
+/// enclosing a pole is closed through that pole.
+fn unwrapped(r: &[Coord]) -> Vec<Coord> {
+    let delta = |a: f64, b: f64| {
+        let d = b - a;
+        d - 360.0 * (d / 360.0).round()
+    };
+    let mut res: Vec<Coord> = Vec::with_capacity(r.len() + 3);
+    for c in r {
+        let x = res.last().map_or(c.0, |p| p.0 + delta(p.0, c.0));
+        res.push((x, c.1));
+    }
+    if let (Some(first), Some(last)) = (res.first().copied(), res.last().copied()) {
+        let end = last.0 + delta(last.0, first.0);
+        let winding = ((end - first.0) / 360.0).round();
+        if winding != 0.0 {
+            let lat = 90.0f64.copysign(winding);
+            res.push((end, first.1));
+            res.push((end, lat));
+            res.push((first.0, lat));
+        }
+    }
+    res
+}
+
+/// whether the given ring contains the given coordinate (even-odd rule).
+fn planar_contains(r: &[Coord], c: Coord) -> bool {
+    let mut inside = false;
+    for i in 0..r.len() {
+        let (a, b) = (r[i], r[(i + 1) % r.len()]);
+        if (a.1 > c.1) != (b.1 > c.1) && c.0 < (b.0 - a.0) * (c.1 - a.1) / (b.1 - a.1) + a.0 {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+/// whether the given coordinates denote the same position, including at the same latitude on both sides of the
+/// antimeridian.
+fn same_position(a: Coord, b: Coord) -> bool {
+    a == b || (a.1 == b.1 && a.0.abs() == 180.0 && b.0.abs() == 180.0)
+}
+
+/// type and coordinates of the given geometry or feature, the type must be one of the given types.
+fn coordinates_of<'a>(v: &'a Value, types: &[&str]) -> Result<(&'a str, &'a Value), GeoJsonError> {
+    let v = if v["type"] == "Feature" {
+        &v["geometry"]
+    } else {
+        v
+    };
+    let t = v["type"]
+        .as_str()
+        .ok_or_else(|| GeoJsonError::InvalidType(v["type"].to_string()))?;
+    if !types.contains(&t) {
+        return Err(GeoJsonError::InvalidType(format!(
+            "expected {}, found {}",
+            types.join(" or "),
+            t
+        )));
+    }
+    match v.get("coordinates") {
+        Some(c) => Ok((t, c)),
+        None => Err(GeoJsonError::InvalidGeometry(
+            "missing coordinates".to_string(),
+        )),
+    }
+}
+
+fn array(v: &Value) -> Result<&Vec<Value>, GeoJsonError> {
+    v.as_array()
+        .ok_or_else(|| GeoJsonError::InvalidGeometry(format!("expected an array, found {}", v)))
+}
+
+fn position(v: &Value) -> Result<Coord, GeoJsonError> {
+    let invalid = || GeoJsonError::InvalidPosition(v.to_string());
+    let a = v.as_array().filter(|a| a.len() >= 2).ok_or_else(invalid)?;
+    let lon = a[0]
+        .as_f64()
+        .filter(|l| l.is_finite())
+        .ok_or_else(invalid)?;
+    let lat = a[1]
+        .as_f64()
+        .filter(|l| (-90.0..=90.0).contains(l))
+        .ok_or_else(invalid)?;
+    Ok((lon, lat))
+}
+
+fn line_positions(v: &Value) -> Result<Vec<Coord>, GeoJsonError> {
+    let cs = array(v)?
+        .iter()
+        .map(position)
+        .collect::<Result<Vec<_>, _>>()?;
+    if cs.len() < 2 {
+        return Err(GeoJsonError::InvalidGeometry(
+            "a line string requires at least 2 positions".to_string(),
+        ));
+    }
+    Ok(cs)
+}
+
+/// opened rings of the given polygon coordinates.
+fn polygon_rings(v: &Value) -> Result<Vec<Vec<Coord>>, GeoJsonError> {
+    array(v)?
+        .iter()
+        .map(|r| {
+            let mut cs = array(r)?
+                .iter()
+                .map(position)
+                .collect::<Result<Vec<_>, _>>()?;
+            if cs.len() < 4 || cs.first() != cs.last() {
+                return Err(GeoJsonError::InvalidGeometry(
+                    "a linear ring requires at least 4 positions and must be closed".to_string(),
+                ));
+            }
+            cs.pop();
+            Ok(without_polar_closure(cs))
+        })
+        .collect()
+}
+
+/// Removes the consecutive positions at a pole on both sides of the antimeridian from the given opened ring: those
+/// close a ring enclosing that pole along the antimeridian.
+fn without_polar_closure(cs: Vec<Coord>) -> Vec<Coord> {
+    let n = cs.len();
+    let is_closure =
+        |a: Coord, b: Coord| a.1.abs() == 90.0 && a.1 == b.1 && a.0.abs() == 180.0 && b.0 == -a.0;
+    let mut removed = vec![false; n];
+    for i in 0..n {
+        let j = (i + 1) % n;
+        if !removed[i] && !removed[j] && is_closure(cs[i], cs[j]) {
+            removed[i] = true;
+            removed[j] = true;
+        }
+    }
+    cs.into_iter()
+        .zip(removed)
+        .filter(|(_, r)| !r)
+        .map(|(c, _)| c)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{
+        spherical::{Loop, MinorArc, Polygon, Rectangle},
+        Angle, LatLong, NVector,
+    };
+
+    use super::{
+        parse_line_string, parse_point, parse_polygon, parse_polygons, planar_area, GeoJsonError,
+        ToGeoJson,
+    };
+
+    fn ring(v: &serde_json::Value) -> Vec<(f64, f64)> {
+        v.as_array()
+            .unwrap()
+            .iter()
+            .map(|c| (c[0].as_f64().unwrap(), c[1].as_f64().unwrap()))
+            .collect()
+    }
+
+    fn square(lat: f64, lon: f64, size: f64) -> Loop {
+        Loop::new(&[
+            NVector::from_lat_long_degrees(lat, lon),
+            NVector::from_lat_long_degrees(lat, lon + size),
+            NVector::from_lat_long_degrees(lat + size, lon + size),
+            NVector::from_lat_long_degrees(lat + size, lon),
+        ])
+    }
+
+    fn lat_longs<'a>(vs: impl Iterator<Item = &'a NVector>) -> Vec<LatLong> {
+        vs.map(|v| LatLong::from_nvector(*v).round_d7()).collect()
+    }
+
+    fn assert_same_polygon(expected: &Polygon, actual: &Polygon) {
+        assert_eq!(
+            lat_longs(expected.shell().iter_vertices()),
+            lat_longs(actual.shell().iter_vertices())
+        );
+        assert_eq!(expected.num_holes(), actual.num_holes());
+        for (e, a) in expected.iter_holes().zip(actual.iter_holes()) {
+            assert_eq!(lat_longs(e.iter_vertices()), lat_longs(a.iter_vertices()));
+        }
+    }
+
+    fn assert_same_area(expected: Angle, actual: Angle) {
+        assert!(
+            (expected.as_radians() - actual.as_radians()).abs() < 1e-12,
+            "{:?} {:?}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn point() {
+        let p = NVector::from_lat_long_degrees(-33.5, 151.25);
+        assert_eq!(Ok(p), parse_point(&p.to_geojson()));
+        let f = p.to_geojson_feature(json!({ "name": "Sydney" }));
+        assert_eq!("Feature", f["type"]);
+        assert_eq!("Sydney", f["properties"]["name"]);
+        assert_eq!(Ok(p), parse_point(&f));
+    }
+
+    #[test]
+    fn line_string() {
+        let arcs = [
+            MinorArc::new(
+                NVector::from_lat_long_degrees(0.0, 0.0),
+                NVector::from_lat_long_degrees(1.0, 1.0),
+            ),
+            MinorArc::new(
+                NVector::from_lat_long_degrees(1.0, 1.0),
+                NVector::from_lat_long_degrees(2.0, 0.0),
+            ),
+        ];
+        let g = arcs.to_geojson();
+        assert_eq!("LineString", g["type"]);
+        let actual: Vec<(f64, f64)> = ring(&g["coordinates"])
+            .iter()
+            .map(|c| ((c.0 * 1e9).round() / 1e9, (c.1 * 1e9).round() / 1e9))
+            .collect();
+        assert_eq!(vec![(0.0, 0.0), (1.0, 1.0), (0.0, 2.0)], actual);
+        let parsed = parse_line_string(&g).unwrap();
+        assert_eq!(2, parsed.len());
+        for (e, a) in arcs.iter().zip(parsed.iter()) {
+            assert_eq!(
+                lat_longs([e.start(), e.end()].iter()),
+                lat_longs([a.start(), a.end()].iter())
+            );
+        }
+    }
+
+    #[test]
+    fn line_string_antimeridian() {
+        let a = MinorArc::new(
+            NVector::from_lat_long_degrees(10.0, 170.0),
+            NVector::from_lat_long_degrees(20.0, -170.0),
+        );
+        let g = a.to_geojson();
+        assert_eq!("MultiLineString", g["type"]);
+        let first = ring(&g["coordinates"][0]);
+        let second = ring(&g["coordinates"][1]);
+        assert_eq!(180.0, first[1].0);
+        assert_eq!(-180.0, second[0].0);
+        assert_eq!(first[1].1, second[0].1);
+        // crossing on the minor arc, north of the rhumb line.
+        assert!(first[1].1 > 15.0 && first[1].1 < 16.0);
+
+        let arcs = parse_line_string(&g).unwrap();
+        assert_eq!(2, arcs.len());
+        assert_eq!(
+            lat_longs([a.start(), a.end()].iter()),
+            lat_longs([arcs[0].start(), arcs[1].end()].iter())
+        );
+        assert!(a.contains_position(arcs[0].end()));
+    }
+
+    #[test]
+    fn loop_orientation() {
+        let l = square(10.0, 10.0, 5.0);
+        let g = l.to_geojson();
+        assert_eq!("Polygon", g["type"]);
+        let r = ring(&g["coordinates"][0]);
+        assert_eq!(5, r.len());
+        assert_eq!(r[0], r[4]);
+        assert!(planar_area(&r[..4]) > 0.0);
+        assert_same_polygon(&Polygon::new(l, Vec::new()), &parse_polygon(&g).unwrap());
+    }
+
+    #[test]
+    fn polygon_hole_orientation() {
+        let p = Polygon::new(square(0.0, 0.0, 10.0), vec![square(2.0, 2.0, 2.0)]);
+        let g = p.to_geojson();
+        assert!(planar_area(&ring(&g["coordinates"][0])) > 0.0);
+        assert!(planar_area(&ring(&g["coordinates"][1])) < 0.0);
+        assert_same_polygon(&p, &parse_polygon(&g).unwrap());
+    }
+
+    #[test]
+    fn loop_antimeridian() {
+        let l = square(-5.0, 175.0, 10.0);
+        let g = l.to_geojson();
+        assert_eq!("MultiPolygon", g["type"]);
+        let pieces = g["coordinates"].as_array().unwrap();
+        assert_eq!(2, pieces.len());
+        for piece in pieces {
+            let r = ring(&piece[0]);
+            assert!(planar_area(&r) > 0.0);
+            let east = r.iter().all(|c| c.0 >= 0.0);
+            let west = r.iter().all(|c| c.0 <= 0.0);
+            assert!(east != west);
+        }
+
+        let p = parse_polygon(&g).unwrap();
+        assert_same_area(l.spherical_excess(), p.spherical_excess());
+        assert!(p.contains_position(NVector::from_lat_long_degrees(0.0, 180.0)));
+        assert!(p.contains_position(NVector::from_lat_long_degrees(0.0, 176.0)));
+        assert!(p.contains_position(NVector::from_lat_long_degrees(0.0, -176.0)));
+        assert!(!p.contains_position(NVector::from_lat_long_degrees(0.0, 0.0)));
+    }
+
+    #[test]
+    fn concave_loop_antimeridian() {
+        // U shape opened to the north, both arms crossing the antimeridian.
+        let l = Loop::new(&[
+            NVector::from_lat_long_degrees(0.0, 170.0),
+            NVector::from_lat_long_degrees(0.0, -170.0),
+            NVector::from_lat_long_degrees(10.0, -170.0),
+            NVector::from_lat_long_degrees(10.0, -175.0),
+            NVector::from_lat_long_degrees(2.0, -175.0),
+            NVector::from_lat_long_degrees(2.0, 175.0),
+            NVector::from_lat_long_degrees(10.0, 175.0),
+            NVector::from_lat_long_degrees(10.0, 170.0),
+        ]);
+        let g = l.to_geojson();
+        assert_eq!(2, g["coordinates"].as_array().unwrap().len());
+        let p = parse_polygon(&g).unwrap();
+        assert_same_area(l.spherical_excess(), p.spherical_excess());
+        assert!(!p.contains_position(NVector::from_lat_long_degrees(5.0, 180.0)));
+        assert!(p.contains_position(NVector::from_lat_long_degrees(1.0, 180.0)));
+    }
+
+    #[test]
+    fn polygon_hole_antimeridian() {
+        let p = Polygon::new(square(-10.0, 170.0, 20.0), vec![square(-2.0, 178.0, 4.0)]);
+        let g = p.to_geojson();
+        assert_eq!("MultiPolygon", g["type"]);
+        let actual = parse_polygon(&g).unwrap();
+        assert_same_area(p.spherical_excess(), actual.spherical_excess());
+        assert!(!actual.contains_position(NVector::from_lat_long_degrees(0.0, 180.0)));
+        assert!(actual.contains_position(NVector::from_lat_long_degrees(0.0, 175.0)));
+
+        // hole not crossing the antimeridian.
+        let p = Polygon::new(square(-10.0, 170.0, 20.0), vec![square(-2.0, -175.0, 2.0)]);
+        let actual = parse_polygon(&p.to_geojson()).unwrap();
+        assert_eq!(1, actual.num_holes());
+        assert_same_area(p.spherical_excess(), actual.spherical_excess());
+    }
+
+    fn polar_loop(lat: f64) -> Loop {
+        Loop::new(&[
+            NVector::from_lat_long_degrees(lat, 0.0),
+            NVector::from_lat_long_degrees(lat, 120.0),
+            NVector::from_lat_long_degrees(lat, -120.0),
+        ])
+    }
+
+    #[test]
+    fn north_polar_loop() {
+        let l = polar_loop(80.0);
+        let g = l.to_geojson();
+        assert_eq!("Polygon", g["type"]);
+        let r = ring(&g["coordinates"][0]);
+        assert_eq!(r[0], r[r.len() - 1]);
+        assert!(planar_area(&r) > 0.0);
+        let closure = r.iter().position(|c| *c == (180.0, 90.0)).unwrap();
+        assert_eq!((-180.0, 90.0), r[closure + 1]);
+        assert_eq!(180.0, r[closure - 1].0);
+        assert_eq!(-180.0, r[closure + 2].0);
+        assert_eq!(r[closure - 1].1, r[closure + 2].1);
+        assert!(r.iter().all(|c| c.0.abs() <= 180.0));
+
+        let p = parse_polygon(&g).unwrap();
+        assert_same_area(l.spherical_excess(), p.spherical_excess());
+        assert!(p.contains_position(NVector::from_lat_long_degrees(90.0, 0.0)));
+        assert!(!p.contains_position(NVector::from_lat_long_degrees(-90.0, 0.0)));
+    }
+
+    #[test]
+    fn south_polar_loop() {
+        let l = polar_loop(-80.0);
+        let g = l.to_geojson();
+        assert_eq!("Polygon", g["type"]);
+        let r = ring(&g["coordinates"][0]);
+        assert!(planar_area(&r) > 0.0);
+        let closure = r.iter().position(|c| *c == (-180.0, -90.0)).unwrap();
+        assert_eq!((180.0, -90.0), r[closure + 1]);
+
+        let p = parse_polygon(&g).unwrap();
+        assert_same_area(l.spherical_excess(), p.spherical_excess());
+        assert!(p.contains_position(NVector::from_lat_long_degrees(-90.0, 0.0)));
+        assert!(!p.contains_position(NVector::from_lat_long_degrees(90.0, 0.0)));
+    }
+
+    #[test]
+    fn polar_loop_crossing_antimeridian_3_times() {
+        // around the north pole, with a notch crossing the antimeridian twice.
+        let l = Loop::new(&[
+            NVector::from_lat_long_degrees(70.0, 0.0),
+            NVector::from_lat_long_degrees(70.0, 120.0),
+            NVector::from_lat_long_degrees(70.0, 170.0),
+            NVector::from_lat_long_degrees(80.0, 170.0),
+            NVector::from_lat_long_degrees(80.0, -175.0),
+            NVector::from_lat_long_degrees(75.0, -175.0),
+            NVector::from_lat_long_degrees(75.0, 175.0),
+            NVector::from_lat_long_degrees(72.0, 175.0),
+            NVector::from_lat_long_degrees(72.0, -170.0),
+            NVector::from_lat_long_degrees(70.0, -120.0),
+        ]);
+        assert!(l.is_simple());
+        let g = l.to_geojson();
+        assert_eq!("MultiPolygon", g["type"]);
+        for piece in g["coordinates"].as_array().unwrap() {
+            let r = ring(&piece[0]);
+            assert!(planar_area(&r) > 0.0);
+            assert!(r.iter().all(|c| c.0.abs() <= 180.0));
+        }
+
+        let p = parse_polygon(&g).unwrap();
+        assert_same_area(l.spherical_excess(), p.spherical_excess());
+        assert!(p.contains_position(NVector::from_lat_long_degrees(90.0, 0.0)));
+        assert!(!p.contains_position(NVector::from_lat_long_degrees(77.0, 180.0)));
+        assert!(p.contains_position(NVector::from_lat_long_degrees(73.0, 180.0)));
+    }
+
+    #[test]
+    fn polar_polygon_with_holes() {
+        // hole crossing the antimeridian.
+        let p = Polygon::new(polar_loop(60.0), vec![square(78.0, 178.0, 4.0)]);
+        let g = p.to_geojson();
+        let actual = parse_polygon(&g).unwrap();
+        assert_same_area(p.spherical_excess(), actual.spherical_excess());
+        assert!(!actual.contains_position(NVector::from_lat_long_degrees(80.0, 180.0)));
+        assert!(actual.contains_position(NVector::from_lat_long_degrees(90.0, 0.0)));
+
+        // hole enclosing the pole.
+        let p = Polygon::new(polar_loop(70.0), vec![polar_loop(80.0)]);
+        let g = p.to_geojson();
+        assert_eq!("Polygon", g["type"]);
+        assert!(planar_area(&ring(&g["coordinates"][0])) > 0.0);
+        let actual = parse_polygon(&g).unwrap();
+        assert!(actual.contains_position(NVector::from_lat_long_degrees(82.0, 180.0)));
+        assert!(actual.contains_position(NVector::from_lat_long_degrees(75.0, 0.0)));
+        assert_eq!(1, actual.num_holes());
+        assert_same_area(p.spherical_excess(), actual.spherical_excess());
+        assert!(!actual.contains_position(NVector::from_lat_long_degrees(90.0, 0.0)));
+    }
+
+    #[test]
+    fn multi_polygon() {
+        let g = json!({
+            "type": "MultiPolygon",
+            "coordinates": [
+                [[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0], [0.0, 0.0]]],
+                [[[10.0, 0.0], [11.0, 0.0], [11.0, 1.0], [10.0, 1.0], [10.0, 0.0]]]
+            ]
+        });
+        assert_eq!(2, parse_polygons(&g).unwrap().len());
+        assert!(matches!(
+            parse_polygon(&g),
+            Err(GeoJsonError::InvalidGeometry(_))
+        ));
+    }
+
+    #[test]
+    fn rectangle() {
+        let r = Rectangle::from_nesw(
+            Angle::from_degrees(10.0),
+            Angle::from_degrees(20.0),
+            Angle::from_degrees(-10.0),
+            Angle::from_degrees(5.0),
+        );
+        let g = r.to_geojson();
+        assert_eq!("Polygon", g["type"]);
+        assert_eq!(
+            vec![
+                (5.0, -10.0),
+                (20.0, -10.0),
+                (20.0, 10.0),
+                (5.0, 10.0),
+                (5.0, -10.0)
+            ],
+            ring(&g["coordinates"][0])
+        );
+
+        let r = Rectangle::from_nesw(
+            Angle::from_degrees(10.0),
+            Angle::from_degrees(-170.0),
+            Angle::from_degrees(-10.0),
+            Angle::from_degrees(170.0),
+        );
+        let g = r.to_geojson();
+        assert_eq!("MultiPolygon", g["type"]);
+        assert_eq!(
+            vec![
+                (170.0, -10.0),
+                (180.0, -10.0),
+                (180.0, 10.0),
+                (170.0, 10.0),
+                (170.0, -10.0)
+            ],
+            ring(&g["coordinates"][0][0])
+        );
+        assert_eq!(
+            vec![
+                (-180.0, -10.0),
+                (-170.0, -10.0),
+                (-170.0, 10.0),
+                (-180.0, 10.0),
+                (-180.0, -10.0)
+            ],
+            ring(&g["coordinates"][1][0])
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let g = Loop::EMPTY.to_geojson();
+        assert_eq!(json!({ "type": "Polygon", "coordinates": [] }), g);
+        assert_eq!(Ok(Vec::new()), parse_polygons(&g));
+        assert_eq!(
+            json!({ "type": "Polygon", "coordinates": [] }),
+            Rectangle::EMPTY.to_geojson()
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            parse_point(&json!({ "type": "LineString", "coordinates": [] })),
+            Err(GeoJsonError::InvalidType(_))
+        ));
+        assert!(matches!(
+            parse_point(&json!({ "coordinates": [0.0, 0.0] })),
+            Err(GeoJsonError::InvalidType(_))
+        ));
+        assert!(matches!(
+            parse_point(&json!({ "type": "Point" })),
+            Err(GeoJsonError::InvalidGeometry(_))
+        ));
+        assert_eq!(
+            Err(GeoJsonError::InvalidPosition("[0.0,91.0]".to_string())),
+            parse_point(&json!({ "type": "Point", "coordinates": [0.0, 91.0] }))
+        );
+        assert!(matches!(
+            parse_point(&json!({ "type": "Point", "coordinates": ["a", 1.0] })),
+            Err(GeoJsonError::InvalidPosition(_))
+        ));
+        assert!(matches!(
+            parse_line_string(&json!({ "type": "LineString", "coordinates": [[0.0, 0.0]] })),
+            Err(GeoJsonError::InvalidGeometry(_))
+        ));
+        assert!(matches!(
+            parse_line_string(&json!({
+                "type": "MultiLineString",
+                "coordinates": [[[0.0, 0.0], [1.0, 0.0]], [[2.0, 0.0], [3.0, 0.0]]]
+            })),
+            Err(GeoJsonError::InvalidGeometry(_))
+        ));
+        assert!(matches!(
+            parse_polygon(&json!({
+                "type": "Polygon",
+                "coordinates": [[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]]
+            })),
+            Err(GeoJsonError::InvalidGeometry(_))
+        ));
+    }
+}
@@ -0,0 +1,1492 @@
+use std::time::Duration;
+
+use crate::{surface::Surface, Angle, Length, Manoeuvre, NVector, Speed, Turn, Vehicle};
+
+/// Kinematics of vehicles travelling at constant speed along the shortest path on a [Surface]: great circles on a
+/// [Sphere](crate::spherical::Sphere) and geodesics on an [Ellipsoid](crate::ellipsoidal::Ellipsoid).
+///
+/// This allows writing kinematics calculations once for any celestial body and any figure of that body.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use jord::{Angle, Kinematics, LatLong, NVector, Speed, Vehicle};
+/// use jord::ellipsoidal::Ellipsoid;
+/// use jord::spherical::Sphere;
+///
+/// fn north_after_one_hour<K: Kinematics>(k: K) -> LatLong {
+///     let v = Vehicle::new(NVector::from_lat_long_degrees(0.0, 0.0), Angle::ZERO, Speed::from_knots(600.0));
+///     LatLong::from_nvector(k.position_after(v, Duration::from_secs(3600)))
+/// }
+///
+/// let earth = north_after_one_hour(Sphere::EARTH);
+/// let moon = north_after_one_hour(Sphere::MOON);
+/// let wgs84 = north_after_one_hour(Ellipsoid::WGS84);
+///
+/// assert_eq!(Angle::from_degrees(9.99326), earth.latitude().round_d5());
+/// assert_eq!(Angle::from_degrees(36.64503), moon.latitude().round_d5());
+/// assert_eq!(Angle::from_degrees(10.04833), wgs84.latitude().round_d5());
+/// ```
+pub trait Kinematics: Surface {
+    /// Computes the surface distance between the given positions along the shortest path: great circle or geodesic.
+    fn distance(&self, p1: NVector, p2: NVector) -> Length;
+
+    /// Calculates the position that the given vehicle will reach after the given time.
+    fn position_after(&self, vehicle: Vehicle, duration: Duration) -> NVector;
+
+    /// Calculates the state that the given vehicle will reach after the given time: its position and its bearing at
+    /// that position, the speed being unchanged.
+    fn state_after(&self, vehicle: Vehicle, duration: Duration) -> Vehicle;
+
+    /// Computes the time to the closest point of approach (CPA) between the two given vehicles: the time at which the
+    /// 2 vehicles will be the closest assuming they both maintain a constant course and speed. Returns [None] if the 2
+    /// vehicles are not getting closer to one another.
+    fn time_to_cpa(&self, ownship: Vehicle, intruder: Vehicle) -> Option<Duration>;
+
+    /// Computes the closest point of approach (CPA) between the two given vehicles assuming they both maintain a
+    /// constant course and speed: time, positions of both vehicles, distance, bearing and range rate at CPA.
+    ///
+    /// If the 2 vehicles are not getting closer to one another, the returned CPA is
+    /// [diverging](crate::Cpa::is_diverging) and describes the current situation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, Kinematics, Length, NVector, Speed, Vehicle};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let ownship = Vehicle::new(
+    ///     NVector::from_lat_long_degrees(20.0, -60.0),
+    ///     Angle::from_degrees(10.0),
+    ///     Speed::from_knots(15.0),
+    /// );
+    ///
+    /// let intruder = Vehicle::new(
+    ///     NVector::from_lat_long_degrees(34.0, -50.0),
+    ///     Angle::from_degrees(220.0),
+    ///     Speed::from_knots(300.0),
+    /// );
+    ///
+    /// let cpa = Sphere::EARTH.cpa(ownship, intruder);
+    /// assert!(!cpa.is_diverging());
+    /// assert_eq!(11_396_140, cpa.time().as_millis());
+    /// assert_eq!(Length::from_metres(124_232.0), cpa.distance().round_m());
+    /// assert_eq!(Angle::from_degrees(304.1051), cpa.bearing().round_d5());
+    /// assert_eq!(Angle::from_degrees(294.05392), cpa.relative_bearing().round_d5());
+    /// assert_eq!(0.0, cpa.range_rate().as_knots().round());
+    /// ```
+    fn cpa(&self, ownship: Vehicle, intruder: Vehicle) -> Cpa;
+
+    /// Calculates the maximum time required by an interceptor at the given position to intercept the given intruder:
+    /// i.e. the interceptor is travelling at the minimum speed required to achieve intercept.
+    fn max_time_to_intercept(
+        &self,
+        interceptor_pos: NVector,
+        intruder: Vehicle,
+    ) -> Option<Duration>;
+
+    /// Calculates time required by an interceptor at the given position and travelling at the given speed to
+    /// intercept the given intruder. Returns [None] if intercept cannot be achieved.
+    fn time_to_intercept(
+        &self,
+        interceptor_pos: NVector,
+        interceptor_speed: Speed,
+        intruder: Vehicle,
+    ) -> Option<Duration>;
+
+    /// Computes the intercept of the given intruder by an interceptor at the given position and travelling at the
+    /// given speed: time, position of interception, initial bearing and distance to fly.
+    ///
+    /// The interceptor aims at the point located at the given offset distance behind the intruder along its track:
+    /// a positive offset gives a lag intercept, a negative offset a lead intercept and zero a pure intercept of the
+    /// intruder. Returns [None] if intercept cannot be achieved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, Kinematics, Length, NVector, Speed, Vehicle};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let interceptor_pos = NVector::from_lat_long_degrees(20.0, -60.0);
+    /// let intruder = Vehicle::new(
+    ///     NVector::from_lat_long_degrees(34.0, -50.0),
+    ///     Angle::from_degrees(220.0),
+    ///     Speed::from_knots(600.0)
+    /// );
+    ///
+    /// // minimum interceptor speed to achieve intercept is ~ 53 knots
+    /// assert!(Sphere::EARTH.intercept(interceptor_pos, Speed::from_knots(50.0), intruder, Length::ZERO).is_none());
+    ///
+    /// let speed = Speed::from_knots(700.0);
+    /// let i = Sphere::EARTH.intercept(interceptor_pos, speed, intruder, Length::ZERO).unwrap();
+    /// assert_eq!(2_764_688, i.time().as_millis());
+    /// assert_eq!(Angle::from_degrees(25.93541), i.bearing().round_d5());
+    /// assert_eq!(Length::from_metres(995_595.0), i.distance().round_m());
+    /// assert_eq!(Sphere::EARTH.position_after(intruder, i.time()), i.position());
+    ///
+    /// // 10 nautical miles behind the intruder.
+    /// let lag = Sphere::EARTH.intercept(interceptor_pos, speed, intruder, Length::from_metres(18_520.0)).unwrap();
+    /// assert_eq!(2_792_181, lag.time().as_millis());
+    /// ```
+    fn intercept(
+        &self,
+        interceptor_pos: NVector,
+        interceptor_speed: Speed,
+        intruder: Vehicle,
+        offset: Length,
+    ) -> Option<Intercept>;
+
+    /// Computes the intercept of the given intruder by an interceptor at the given position travelling at the minimum
+    /// speed required to achieve intercept - see [intercept](crate::Kinematics::intercept) for the offset distance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, Kinematics, Length, NVector, Speed, Vehicle};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let interceptor_pos = NVector::from_lat_long_degrees(20.0, -60.0);
+    /// let intruder = Vehicle::new(
+    ///     NVector::from_lat_long_degrees(34.0, -50.0),
+    ///     Angle::from_degrees(220.0),
+    ///     Speed::from_knots(600.0)
+    /// );
+    ///
+    /// let i = Sphere::EARTH.min_speed_intercept(interceptor_pos, intruder, Length::ZERO).unwrap();
+    /// assert_eq!(5_993_823, i.time().as_millis());
+    /// assert_eq!(53.0, i.speed().as_knots().round());
+    /// ```
+    fn min_speed_intercept(
+        &self,
+        interceptor_pos: NVector,
+        intruder: Vehicle,
+        offset: Length,
+    ) -> Option<Intercept>;
+
+    /// Detects a loss of separation between the two given vehicles within the given look-ahead time: i.e. whether
+    /// the distance between the 2 vehicles - assuming they both maintain a constant course and speed - falls below the
+    /// given separation minimum before the given look-ahead time.
+    ///
+    /// Returns [None] if the separation is maintained during the look-ahead time, otherwise a [Conflict] giving the
+    /// time at which the separation is lost (possibly now) and regained (if within the look-ahead time).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use jord::{Angle, Kinematics, Length, NVector, Speed, Vehicle};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let ownship = Vehicle::new(
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     Angle::from_degrees(90.0),
+    ///     Speed::from_knots(400.0),
+    /// );
+    ///
+    /// // head-on, 60 nautical miles away.
+    /// let intruder = Vehicle::new(
+    ///     NVector::from_lat_long_degrees(0.0, 1.0),
+    ///     Angle::from_degrees(270.0),
+    ///     Speed::from_knots(400.0),
+    /// );
+    ///
+    /// let five_nm = Length::from_metres(5.0 * 1852.0);
+    /// let ten_minutes = Duration::from_secs(600);
+    ///
+    /// let c = Sphere::EARTH.conflict(ownship, intruder, five_nm, ten_minutes).unwrap();
+    /// // separation is lost when the 2 vehicles are 5 nautical miles apart, about 4 minutes from now...
+    /// assert_eq!(247_682, c.entry().as_millis());
+    /// // ...and regained 45 seconds later.
+    /// assert_eq!(Some(292_682), c.exit().map(|t| t.as_millis()));
+    ///
+    /// // no conflict within the next 3 minutes.
+    /// assert!(Sphere::EARTH.conflict(ownship, intruder, five_nm, Duration::from_secs(180)).is_none());
+    /// ```
+    fn conflict(
+        &self,
+        ownship: Vehicle,
+        intruder: Vehicle,
+        separation: Length,
+        look_ahead: Duration,
+    ) -> Option<Conflict> {
+        let sep = separation.as_metres();
+        let d = |t: f64| {
+            let d = Duration::from_secs_f64(t);
+            self.distance(
+                self.position_after(ownship, d),
+                self.position_after(intruder, d),
+            )
+            .as_metres()
+                - sep
+        };
+        let d0 = d(0.0);
+        let horizon = look_ahead.as_secs_f64();
+        // the distance between the 2 vehicles decreases at most by the sum of their speeds.
+        let closing = ownship.speed().as_metres_per_second().abs()
+            + intruder.speed().as_metres_per_second().abs();
+        if d0 - closing * horizon >= 0.0 {
+            return None;
+        }
+        let cpa = self.cpa(ownship, intruder);
+        if cpa.distance() >= separation {
+            return None;
+        }
+        let tc = cpa.time().as_secs_f64();
+        let entry = if d0 < 0.0 {
+            0.0
+        } else {
+            let end = tc.min(horizon);
+            if d(end) >= 0.0 {
+                return None;
+            }
+            bisection(&d, 0.0, end)
+        };
+        let exit = if tc >= horizon || d(horizon) < 0.0 {
+            None
+        } else {
+            Some(Duration::from_secs_f64(bisection(
+                &d,
+                tc.max(entry),
+                horizon,
+            )))
+        };
+        Some(Conflict {
+            entry: Duration::from_secs_f64(entry),
+            exit,
+            cpa,
+        })
+    }
+
+    /// Detects losses of separation between the given ownship and each of the given intruders within the given
+    /// look-ahead time - see [conflict](crate::Kinematics::conflict).
+    ///
+    /// Intruders that are too far away to be reached within the look-ahead time are discarded before computing their
+    /// CPA. Returns the index of each conflicting intruder in the given slice together with the conflict, ordered by
+    /// increasing time of loss of separation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use jord::{Angle, Kinematics, Length, NVector, Speed, Vehicle};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let ownship = Vehicle::new(
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     Angle::from_degrees(90.0),
+    ///     Speed::from_knots(400.0),
+    /// );
+    ///
+    /// let intruders = vec![
+    ///     // far away.
+    ///     Vehicle::new(NVector::from_lat_long_degrees(40.0, 40.0), Angle::ZERO, Speed::from_knots(400.0)),
+    ///     // head-on, 60 nautical miles away.
+    ///     Vehicle::new(NVector::from_lat_long_degrees(0.0, 1.0), Angle::from_degrees(270.0), Speed::from_knots(400.0)),
+    ///     // already within 5 nautical miles.
+    ///     Vehicle::new(NVector::from_lat_long_degrees(0.05, 0.0), Angle::from_degrees(90.0), Speed::from_knots(380.0)),
+    /// ];
+    ///
+    /// let five_nm = Length::from_metres(5.0 * 1852.0);
+    /// let cs = Sphere::EARTH.conflicts(ownship, &intruders, five_nm, Duration::from_secs(600));
+    /// assert_eq!(vec![2, 1], cs.iter().map(|c| c.0).collect::<Vec<_>>());
+    /// assert_eq!(Duration::ZERO, cs[0].1.entry());
+    /// ```
+    fn conflicts(
+        &self,
+        ownship: Vehicle,
+        intruders: &[Vehicle],
+        separation: Length,
+        look_ahead: Duration,
+    ) -> Vec<(usize, Conflict)> {
+        let mut res: Vec<(usize, Conflict)> = intruders
+            .iter()
+            .enumerate()
+            .filter_map(|(i, int)| {
+                self.conflict(ownship, *int, separation, look_ahead)
+                    .map(|c| (i, c))
+            })
+            .collect();
+        res.sort_by_key(|c| c.1.entry);
+        res
+    }
+
+    /// Calculates the state - position and velocity - that the given vehicle will reach after the given time,
+    /// executing the given [manoeuvres](crate::Manoeuvre) in sequence, then travelling along the shortest path at
+    /// constant speed once all manoeuvres have been executed.
+    ///
+    /// Turns are integrated in steps of at most 1 degree, using at most 3600 steps (i.e. 10 full turns) per
+    /// manoeuvre: longer turns are integrated in larger steps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use jord::{Acceleration, Angle, Kinematics, LatLong, Length, Manoeuvre, NVector, Speed, Turn, Vehicle};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let vehicle = Vehicle::new(
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     Angle::ZERO,
+    ///     Speed::from_metres_per_second(100.0),
+    /// );
+    ///
+    /// // head north for 1 minute accelerating at 1 m/s², then turn right with a radius of 2 km.
+    /// let manoeuvres = [
+    ///     Manoeuvre::accelerating(Acceleration::from_metres_per_second_squared(1.0), Duration::from_secs(60)),
+    ///     Manoeuvre::turning(Turn::Radius(Length::from_metres(2_000.0)), Duration::from_secs(60)),
+    /// ];
+    ///
+    /// let s = Sphere::EARTH.state_after_manoeuvres(vehicle, &manoeuvres, Duration::from_secs(60));
+    /// assert_eq!(160.0, s.speed().as_metres_per_second().round());
+    /// // 100 * 60 + 0.5 * 60 * 60 = 7800 metres north.
+    /// assert_eq!(
+    ///     Length::from_metres(7_800.0),
+    ///     Sphere::EARTH.distance(vehicle.position(), s.position()).round_m()
+    /// );
+    ///
+    /// // 160 m/s on a radius of 2 km: 180 degrees in 12.5 * PI seconds.
+    /// let s = Sphere::EARTH.state_after_manoeuvres(vehicle, &manoeuvres, Duration::from_secs_f64(99.2699082));
+    /// assert_eq!(180.0, s.bearing().as_degrees().round());
+    /// ```
+    fn state_after_manoeuvres(
+        &self,
+        vehicle: Vehicle,
+        manoeuvres: &[Manoeuvre],
+        duration: Duration,
+    ) -> Vehicle {
+        let mut state = vehicle;
+        let mut remaining = duration;
+        for m in manoeuvres {
+            if remaining.is_zero() {
+                return state;
+            }
+            let d = m.duration().min(remaining);
+            state = execute(self, state, *m, d);
+            remaining -= d;
+        }
+        self.state_after(state, remaining)
+    }
+}
+
+/// The closest point of approach (CPA) between 2 vehicles - an ownship and an intruder - maintaining a constant course
+/// and speed: see [Kinematics::cpa].
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))] // codecov:ignore:this
+pub struct Cpa {
+    time: Duration,
+    ownship_position: NVector,
+    intruder_position: NVector,
+    distance: Length,
+    bearing: Angle,
+    relative_bearing: Angle,
+    range_rate: Speed,
+    diverging: bool,
+}
+
+impl Cpa {
+    /// Returns the time to CPA from now: zero if the vehicles are [diverging](crate::Cpa::is_diverging).
+    #[inline]
+    pub fn time(&self) -> Duration {
+        self.time
+    }
+
+    /// Returns the position of the ownship at CPA.
+    #[inline]
+    pub fn ownship_position(&self) -> NVector {
+        self.ownship_position
+    }
+
+    /// Returns the position of the intruder at CPA.
+    #[inline]
+    pub fn intruder_position(&self) -> NVector {
+        self.intruder_position
+    }
+
+    /// Returns the distance between the ownship and the intruder at CPA.
+    #[inline]
+    pub fn distance(&self) -> Length {
+        self.distance
+    }
+
+    /// Returns the bearing (compass angle) from the ownship to the intruder at CPA.
+    #[inline]
+    pub fn bearing(&self) -> Angle {
+        self.bearing
+    }
+
+    /// Returns the bearing from the ownship to the intruder at CPA relative to the heading of the ownship at CPA, in
+    /// the range [0, 360) degrees clockwise.
+    #[inline]
+    pub fn relative_bearing(&self) -> Angle {
+        self.relative_bearing
+    }
+
+    /// Returns the rate of change of the distance between the ownship and the intruder at CPA: nil unless the vehicles
+    /// are [diverging](crate::Cpa::is_diverging), in which case it is positive (or nil if neither vehicle moves).
+    #[inline]
+    pub fn range_rate(&self) -> Speed {
+        self.range_rate
+    }
+
+    /// Determines whether the vehicles are not getting closer to one another: in that case CPA is now.
+    #[inline]
+    pub fn is_diverging(&self) -> bool {
+        self.diverging
+    }
+}
+
+/// The intercept of an intruder by an interceptor: see [Kinematics::intercept].
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))] // codecov:ignore:this
+pub struct Intercept {
+    time: Duration,
+    position: NVector,
+    bearing: Angle,
+    distance: Length,
+    speed: Speed,
+}
+
+impl Intercept {
+    /// Returns the time to interception from now.
+    #[inline]
+    pub fn time(&self) -> Duration {
+        self.time
+    }
+
+    /// Returns the position of interception: the position of the intruder at the time of interception, minus the
+    /// offset distance if any.
+    #[inline]
+    pub fn position(&self) -> NVector {
+        self.position
+    }
+
+    /// Returns the initial bearing the interceptor must fly to reach the position of interception.
+    #[inline]
+    pub fn bearing(&self) -> Angle {
+        self.bearing
+    }
+
+    /// Returns the distance the interceptor travels to reach the position of interception.
+    #[inline]
+    pub fn distance(&self) -> Length {
+        self.distance
+    }
+
+    /// Returns the speed of the interceptor.
+    #[inline]
+    pub fn speed(&self) -> Speed {
+        self.speed
+    }
+}
+
+/// A loss of separation between 2 vehicles - an ownship and an intruder - maintaining a constant course and speed:
+/// see [Kinematics::conflict].
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))] // codecov:ignore:this
+pub struct Conflict {
+    entry: Duration,
+    exit: Option<Duration>,
+    cpa: Cpa,
+}
+
+impl Conflict {
+    /// Returns the time from now at which the separation is lost: zero if the separation is already lost.
+    #[inline]
+    pub fn entry(&self) -> Duration {
+        self.entry
+    }
+
+    /// Returns the time from now at which the separation is regained, or [None] if the separation is still lost at
+    /// the end of the look-ahead time.
+    #[inline]
+    pub fn exit(&self) -> Option<Duration> {
+        self.exit
+    }
+
+    /// Returns the closest point of approach between both vehicles: note that it may be beyond the look-ahead time.
+    #[inline]
+    pub fn cpa(&self) -> Cpa {
+        self.cpa
+    }
+}
+
+/// Computes the CPA between the given vehicles given the kinematics, the distance and initial bearing functions of a
+/// surface.
+pub(crate) fn cpa<K, D, B>(
+    k: &K,
+    ownship: Vehicle,
+    intruder: Vehicle,
+    distance: D,
+    initial_bearing: B,
+) -> Cpa
+where
+    K: Kinematics,
+    D: Fn(NVector, NVector) -> Length,
+    B: Fn(NVector, NVector) -> Angle,
+{
+    let (time, diverging) = match k.time_to_cpa(ownship, intruder) {
+        Some(t) => (t, false),
+        None => (Duration::ZERO, true),
+    };
+    let own = k.position_after(ownship, time);
+    let int = k.position_after(intruder, time);
+    // heading of the ownship at CPA: final bearing from its current position.
+    let heading = if own == ownship.position() {
+        ownship.bearing()
+    } else {
+        initial_bearing(own, ownship.position()) + Angle::HALF_CIRCLE
+    };
+    let bearing = initial_bearing(own, int);
+    // the distance is minimal at CPA unless the vehicles are diverging, in which case CPA is now: the rate of change
+    // of the distance is then the sum of the components of both velocities along the geodesic joining the vehicles.
+    let range_rate = if !diverging || own == int {
+        Speed::ZERO
+    } else {
+        // final bearing of the geodesic from the ownship to the intruder.
+        let away = initial_bearing(int, own) + Angle::HALF_CIRCLE;
+        intruder.speed() * (intruder.bearing() - away).as_radians().cos()
+            - ownship.speed() * (ownship.bearing() - bearing).as_radians().cos()
+    };
+    Cpa {
+        time,
+        ownship_position: own,
+        intruder_position: int,
+        distance: distance(own, int),
+        bearing,
+        relative_bearing: (bearing - heading).normalised(),
+        range_rate,
+        diverging,
+    }
+}
+
+/// Returns the vehicle located at the given offset distance behind the given vehicle along its track (ahead if the
+/// offset is negative) given the destination position and initial bearing functions of a surface.
+pub(crate) fn offset_vehicle<D, B>(
+    vehicle: Vehicle,
+    offset: Length,
+    destination_position: D,
+    initial_bearing: B,
+) -> Vehicle
+where
+    D: Fn(NVector, Angle, Length) -> NVector,
+    B: Fn(NVector, NVector) -> Angle,
+{
+    let p = vehicle.position();
+    if offset == Length::ZERO {
+        vehicle
+    } else if offset > Length::ZERO {
+        let behind = destination_position(p, vehicle.bearing() + Angle::HALF_CIRCLE, offset);
+        Vehicle::new(behind, initial_bearing(behind, p), vehicle.speed())
+    } else {
+        let ahead = destination_position(p, vehicle.bearing(), offset.abs());
+        // final bearing from the current position.
+        let bearing = initial_bearing(ahead, p) + Angle::HALF_CIRCLE;
+        Vehicle::new(ahead, bearing.normalised(), vehicle.speed())
+    }
+}
+
+/// Computes the intercept of the given target at the given time given the kinematics and the initial bearing
+/// function of a surface. The speed of the interceptor is the minimum speed to achieve intercept if none is given.
+pub(crate) fn intercept<K, B>(
+    k: &K,
+    interceptor_pos: NVector,
+    interceptor_speed: Option<Speed>,
+    target: Vehicle,
+    time: Duration,
+    initial_bearing: B,
+) -> Intercept
+where
+    K: Kinematics,
+    B: Fn(NVector, NVector) -> Angle,
+{
+    let position = k.position_after(target, time);
+    let distance = k.distance(interceptor_pos, position);
+    let speed = match interceptor_speed {
+        Some(s) => s,
+        None if time.is_zero() => Speed::ZERO,
+        None => distance / time,
+    };
+    Intercept {
+        time,
+        position,
+        bearing: initial_bearing(interceptor_pos, position),
+        distance,
+        speed,
+    }
+}
+
+/// Maximum angle in radians turned by a vehicle in one integration step: 1 degree.
+const MAX_TURN_PER_STEP: f64 = std::f64::consts::PI / 180.0;
+
+/// Maximum number of integration steps of a manoeuvre: 10 full turns at [MAX_TURN_PER_STEP].
+const MAX_STEPS: f64 = 3600.0;
+
+/// Calculates the state of the given vehicle after executing the given manoeuvre for the given time (at most the
+/// duration of the manoeuvre).
+///
+/// Each integration step turns by half the step angle, travels along the chord of the arc of the step and turns by
+/// the other half, which is exact on the plane at constant speed.
+fn execute<K>(k: &K, vehicle: Vehicle, manoeuvre: Manoeuvre, duration: Duration) -> Vehicle
+where
+    K: Kinematics,
+{
+    let secs = duration.as_secs_f64();
+    if secs == 0.0 {
+        return vehicle;
+    }
+    let v0 = vehicle.speed().as_metres_per_second();
+    let a = manoeuvre.acceleration().as_metres_per_second_squared();
+    // time during which the vehicle moves: it stops if decelerating.
+    let moving = if a < 0.0 { (-v0 / a).min(secs) } else { secs };
+    let travelled = |t: f64| {
+        let t = t.min(moving);
+        v0 * t + 0.5 * a * t * t
+    };
+    // angle in radians turned between the given times.
+    let turned = |t0: f64, t1: f64| match manoeuvre.turn() {
+        Some(Turn::Rate(r)) => r.as_radians() * (t1 - t0),
+        Some(Turn::Radius(r)) if r != Length::ZERO => {
+            (travelled(t1) - travelled(t0)) / r.as_metres()
+        }
+        _ => 0.0,
+    };
+
+    let steps = (turned(0.0, moving).abs() / MAX_TURN_PER_STEP)
+        .ceil()
+        .clamp(1.0, MAX_STEPS);
+    let h = moving / steps;
+    let mut position = vehicle.position();
+    let mut bearing = vehicle.bearing();
+    if h > 0.0 {
+        for i in 0..(steps as usize) {
+            let t0 = i as f64 * h;
+            let t1 = t0 + h;
+            let half = 0.5 * turned(t0, t1);
+            let arc = travelled(t1) - travelled(t0);
+            let chord = if half == 0.0 {
+                arc
+            } else {
+                arc * half.sin() / half
+            };
+            let speed = Speed::from_metres_per_second(chord / h);
+            let step = Vehicle::new(position, bearing + Angle::from_radians(half), speed);
+            let s = k.state_after(step, Duration::from_secs_f64(h));
+            position = s.position();
+            bearing = s.bearing() + Angle::from_radians(half);
+        }
+    }
+    // a stationary vehicle may still turn at a constant rate.
+    bearing = bearing + Angle::from_radians(turned(moving, secs));
+    Vehicle::new(
+        position,
+        bearing.normalised(),
+        Speed::from_metres_per_second(v0 + a * moving),
+    )
+}
+
+/// Precision in seconds of the numerical solvers: 1 millisecond.
+pub(crate) const SOLVER_PRECISION_SECS: f64 = 0.001;
+
+/// Maximum number of iterations when bracketing a minimum or a root.
+const MAX_BRACKETING_ITERATIONS: usize = 64;
+
+/// Finds the time (in seconds, not negative) minimising the given function which is assumed unimodal, starting from
+/// the given guess. Returns [None] if the given function is not decreasing from the lower bound of the search.
+pub(crate) fn minimise<F>(f: F, guess: f64, lower: f64) -> Option<f64>
+where
+    F: Fn(f64) -> f64,
+{
+    let step = (0.05 * guess).max(1.0);
+    let mut lo = (guess - step).max(lower);
+    let mut hi = guess + step;
+    for _ in 0..MAX_BRACKETING_ITERATIONS {
+        let mid = 0.5 * (lo + hi);
+        let fm = f(mid);
+        if lo > lower && f(lo) < fm {
+            lo = (lo - (hi - lo)).max(lower);
+        } else if f(hi) < fm {
+            hi += hi - lo;
+        } else {
+            break;
+        }
+    }
+    let t = golden_section(&f, lo, hi);
+    if t - lower < SOLVER_PRECISION_SECS && f(lower + SOLVER_PRECISION_SECS) >= f(lower) {
+        None
+    } else {
+        Some(t)
+    }
+}
+
+/// Finds a root of the given function - which is assumed positive for times before the root and negative after -
+/// starting from the given guess. Returns [None] if no root is found.
+pub(crate) fn root<F>(f: F, guess: f64) -> Option<f64>
+where
+    F: Fn(f64) -> f64,
+{
+    let mut lo = guess;
+    let mut hi = guess;
+    let mut found = false;
+    for _ in 0..MAX_BRACKETING_ITERATIONS {
+        if f(hi) <= 0.0 {
+            found = true;
+            break;
+        }
+        lo = hi;
+        hi = (2.0 * hi).max(1.0);
+    }
+    if !found {
+        return None;
+    }
+    while lo > 0.0 && f(lo) <= 0.0 {
+        hi = lo;
+        lo = if lo < SOLVER_PRECISION_SECS {
+            0.0
+        } else {
+            0.5 * lo
+        };
+    }
+    while hi - lo > SOLVER_PRECISION_SECS {
+        let mid = 0.5 * (lo + hi);
+        if f(mid) > 0.0 {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    Some(hi)
+}
+
+/// Finds the time at which the given function changes sign within [lo, hi] using the bisection method.
+fn bisection<F>(f: &F, lo: f64, hi: f64) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    let positive = f(lo) > 0.0;
+    let (mut a, mut b) = (lo, hi);
+    while b - a > SOLVER_PRECISION_SECS {
+        let mid = 0.5 * (a + b);
+        if (f(mid) > 0.0) == positive {
+            a = mid;
+        } else {
+            b = mid;
+        }
+    }
+    b
+}
+
+/// Golden-section search of the minimum of the given function within [lo, hi].
+fn golden_section<F>(f: &F, lo: f64, hi: f64) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    // 1 / golden ratio.
+    let inv_phi = (5.0f64.sqrt() - 1.0) / 2.0;
+    let (mut a, mut b) = (lo, hi);
+    let mut c = b - inv_phi * (b - a);
+    let mut d = a + inv_phi * (b - a);
+    let (mut fc, mut fd) = (f(c), f(d));
+    while b - a > SOLVER_PRECISION_SECS {
+        if fc <= fd {
+            b = d;
+            d = c;
+            fd = fc;
+            c = b - inv_phi * (b - a);
+            fc = f(c);
+        } else {
+            a = c;
+            c = d;
+            fc = fd;
+            d = a + inv_phi * (b - a);
+            fd = f(d);
+        }
+    }
+    0.5 * (a + b)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{
+        ellipsoidal::Ellipsoid, spherical::Sphere, Acceleration, Angle, Kinematics, LatLong,
+        Length, Manoeuvre, NVector, Speed, Turn, Vehicle,
+    };
+
+    use super::{bisection, golden_section, minimise, offset_vehicle, root};
+
+    #[test]
+    fn bisection_sign_change() {
+        let t = bisection(&|t: f64| 42.0 - t, 0.0, 100.0);
+        assert!((t - 42.0).abs() < 0.001);
+        let t = bisection(&|t: f64| t - 42.0, 0.0, 100.0);
+        assert!((t - 42.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn golden_section_parabola() {
+        let t = golden_section(&|t: f64| (t - 42.0) * (t - 42.0), 0.0, 100.0);
+        assert!((t - 42.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn minimise_bracket_expansion() {
+        let t = minimise(|t: f64| (t - 5000.0).abs(), 10.0, 0.0).unwrap();
+        assert!((t - 5000.0).abs() < 0.001);
+        let t = minimise(|t: f64| (t - 10.0).abs(), 5000.0, 0.0).unwrap();
+        assert!((t - 10.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn minimise_increasing() {
+        assert!(minimise(|t: f64| t, 0.0, 0.0).is_none());
+        assert!(minimise(|t: f64| t, 100.0, 0.0).is_none());
+    }
+
+    #[test]
+    fn cpa_diverging() {
+        let own = Vehicle::new(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            Angle::from_degrees(270.0),
+            Speed::from_knots(400.0),
+        );
+        let int = Vehicle::new(
+            NVector::from_lat_long_degrees(0.0, 1.0),
+            Angle::from_degrees(90.0),
+            Speed::from_knots(400.0),
+        );
+        for c in [Sphere::EARTH.cpa(own, int), Ellipsoid::WGS84.cpa(own, int)] {
+            assert!(c.is_diverging());
+            assert_eq!(Duration::ZERO, c.time());
+            assert_eq!(own.position(), c.ownship_position());
+            assert_eq!(int.position(), c.intruder_position());
+            assert_eq!(Angle::from_degrees(90.0), c.bearing().round_d7());
+            assert_eq!(Angle::from_degrees(180.0), c.relative_bearing().round_d7());
+            // both vehicles fly apart at 400 knots each.
+            assert_eq!(800.0, c.range_rate().as_knots().round());
+        }
+    }
+
+    #[test]
+    fn cpa_diverging_range_rate() {
+        let own = Vehicle::new(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            Angle::from_degrees(180.0),
+            Speed::from_knots(300.0),
+        );
+        let int = Vehicle::new(
+            NVector::from_lat_long_degrees(0.0, 1.0),
+            Angle::from_degrees(45.0),
+            Speed::from_knots(400.0),
+        );
+        for c in [Sphere::EARTH.cpa(own, int), Ellipsoid::WGS84.cpa(own, int)] {
+            assert!(c.is_diverging());
+            // only the intruder moves away along the equator.
+            assert_eq!(282.842712, (c.range_rate().as_knots() * 1e6).round() / 1e6);
+        }
+    }
+
+    #[test]
+    fn cpa_stationary() {
+        let own = Vehicle::new(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            Angle::ZERO,
+            Speed::from_knots(0.0),
+        );
+        let int = Vehicle::new(
+            NVector::from_lat_long_degrees(0.0, 1.0),
+            Angle::ZERO,
+            Speed::from_knots(0.0),
+        );
+        let c = Sphere::EARTH.cpa(own, int);
+        assert!(c.is_diverging());
+        assert_eq!(
+            Sphere::EARTH.distance(own.position(), int.position()),
+            c.distance()
+        );
+        assert_eq!(Angle::from_degrees(90.0), c.relative_bearing().round_d7());
+        assert_eq!(0.0, c.range_rate().as_metres_per_second());
+    }
+
+    #[test]
+    fn cpa_head_on() {
+        let own = Vehicle::new(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            Angle::from_degrees(90.0),
+            Speed::from_knots(400.0),
+        );
+        let int = Vehicle::new(
+            NVector::from_lat_long_degrees(0.0, 2.0),
+            Angle::from_degrees(270.0),
+            Speed::from_knots(400.0),
+        );
+        for c in [Sphere::EARTH.cpa(own, int), Ellipsoid::WGS84.cpa(own, int)] {
+            assert!(!c.is_diverging());
+            assert!(c.distance() < Length::from_metres(1.0));
+            assert_eq!(
+                LatLong::from_degrees(0.0, 1.0),
+                LatLong::from_nvector(c.ownship_position()).round_d5()
+            );
+            assert!(c.range_rate().as_knots().abs() < 1.0);
+        }
+    }
+
+    #[test]
+    fn cpa_crossing() {
+        // intruder crosses ahead of the ownship from right to left.
+        let own = Vehicle::new(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            Angle::ZERO,
+            Speed::from_knots(100.0),
+        );
+        let int = Vehicle::new(
+            NVector::from_lat_long_degrees(1.0, 1.0),
+            Angle::from_degrees(270.0),
+            Speed::from_knots(400.0),
+        );
+        let s = Sphere::EARTH;
+        let c = s.cpa(own, int);
+        assert!(!c.is_diverging());
+        assert_eq!(Some(c.time()), s.time_to_cpa(own, int));
+        assert_eq!(s.position_after(own, c.time()), c.ownship_position());
+        assert_eq!(s.position_after(int, c.time()), c.intruder_position());
+        assert_eq!(
+            s.distance(c.ownship_position(), c.intruder_position()),
+            c.distance()
+        );
+        // intruder is ahead and left of the ownship at CPA.
+        assert!(c.relative_bearing() > Angle::from_degrees(270.0));
+        assert!(c.range_rate().as_knots().abs() < 0.01);
+    }
+
+    // conflict
+
+    fn nm(n: f64) -> Length {
+        Length::from_metres(n * 1852.0)
+    }
+
+    fn head_on() -> (Vehicle, Vehicle) {
+        // 60 nautical miles apart, closing at 800 knots.
+        let own = Vehicle::new(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            Angle::from_degrees(90.0),
+            Speed::from_knots(400.0),
+        );
+        let int = Vehicle::new(
+            NVector::from_lat_long_degrees(0.0, 1.0),
+            Angle::from_degrees(270.0),
+            Speed::from_knots(400.0),
+        );
+        (own, int)
+    }
+
+    #[test]
+    fn conflict_already_lost() {
+        let own = Vehicle::new(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            Angle::from_degrees(90.0),
+            Speed::from_knots(400.0),
+        );
+        // 3 nautical miles north, same course and speed.
+        let int = Vehicle::new(
+            NVector::from_lat_long_degrees(0.05, 0.0),
+            Angle::from_degrees(90.0),
+            Speed::from_knots(400.0),
+        );
+        let ten_minutes = Duration::from_secs(600);
+        let s = Sphere::EARTH.conflict(own, int, nm(5.0), ten_minutes);
+        let e = Ellipsoid::WGS84.conflict(own, int, nm(5.0), ten_minutes);
+        for c in [s, e] {
+            let c = c.unwrap();
+            assert_eq!(Duration::ZERO, c.entry());
+            assert!(c.exit().is_none());
+        }
+    }
+
+    #[test]
+    fn conflict_beyond_look_ahead() {
+        let (own, int) = head_on();
+        // separation is lost after about 4 minutes.
+        let three_minutes = Duration::from_secs(180);
+        assert!(Sphere::EARTH
+            .conflict(own, int, nm(5.0), three_minutes)
+            .is_none());
+        assert!(Ellipsoid::WGS84
+            .conflict(own, int, nm(5.0), three_minutes)
+            .is_none());
+    }
+
+    #[test]
+    fn conflict_diverging() {
+        let (own, int) = head_on();
+        let own = Vehicle::new(own.position(), Angle::from_degrees(270.0), own.speed());
+        let int = Vehicle::new(int.position(), Angle::from_degrees(90.0), int.speed());
+        assert!(Sphere::EARTH
+            .conflict(own, int, nm(5.0), Duration::from_secs(3600))
+            .is_none());
+    }
+
+    #[test]
+    fn conflict_exit_beyond_look_ahead() {
+        let (own, int) = head_on();
+        // separation is lost after about 4 minutes and regained 45 seconds later.
+        let c = Sphere::EARTH
+            .conflict(own, int, nm(5.0), Duration::from_secs(270))
+            .unwrap();
+        assert_eq!(247_682, c.entry().as_millis());
+        assert!(c.exit().is_none());
+    }
+
+    #[test]
+    fn conflict_head_on() {
+        let (own, int) = head_on();
+        let ten_minutes = Duration::from_secs(600);
+        let s = Sphere::EARTH;
+        let e = Ellipsoid::WGS84;
+        for (c, d) in [
+            (
+                s.conflict(own, int, nm(5.0), ten_minutes).unwrap(),
+                s.distance(own.position(), int.position()),
+            ),
+            (
+                e.conflict(own, int, nm(5.0), ten_minutes).unwrap(),
+                e.distance(own.position(), int.position()),
+            ),
+        ] {
+            // 2 x 5 nautical miles at 800 knots is 45 seconds.
+            let exit = c.exit().unwrap();
+            assert!(((exit - c.entry()).as_secs_f64() - 45.0).abs() < 0.01);
+            assert!(c.entry() < c.cpa().time() && c.cpa().time() < exit);
+            let expected_entry =
+                (d - nm(5.0)).as_metres() / Speed::from_knots(800.0).as_metres_per_second();
+            assert!((c.entry().as_secs_f64() - expected_entry).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn conflict_separation_maintained() {
+        let (own, int) = head_on();
+        // intruder passes 10 nautical miles north.
+        let int = Vehicle::new(
+            NVector::from_lat_long_degrees(10.0 / 60.0, 1.0),
+            int.bearing(),
+            int.speed(),
+        );
+        assert!(Sphere::EARTH
+            .conflict(own, int, nm(5.0), Duration::from_secs(3600))
+            .is_none());
+        assert!(Sphere::EARTH
+            .conflict(own, int, nm(11.0), Duration::from_secs(3600))
+            .is_some());
+    }
+
+    #[test]
+    fn conflicts_ordered_by_entry() {
+        let (own, int) = head_on();
+        let far = Vehicle::new(
+            NVector::from_lat_long_degrees(40.0, 40.0),
+            Angle::ZERO,
+            Speed::from_knots(400.0),
+        );
+        let closer = Vehicle::new(
+            NVector::from_lat_long_degrees(0.0, 0.5),
+            int.bearing(),
+            int.speed(),
+        );
+        let intruders = [far, int, far, closer];
+        let ten_minutes = Duration::from_secs(600);
+        for cs in [
+            Sphere::EARTH.conflicts(own, &intruders, nm(5.0), ten_minutes),
+            Ellipsoid::WGS84.conflicts(own, &intruders, nm(5.0), ten_minutes),
+        ] {
+            assert_eq!(vec![3, 1], cs.iter().map(|c| c.0).collect::<Vec<_>>());
+            assert!(cs[0].1.entry() < cs[1].1.entry());
+        }
+        assert!(Sphere::EARTH
+            .conflicts(own, &[], nm(5.0), ten_minutes)
+            .is_empty());
+    }
+
+    // intercept
+
+    fn intruder() -> Vehicle {
+        Vehicle::new(
+            NVector::from_lat_long_degrees(34.0, -50.0),
+            Angle::from_degrees(220.0),
+            Speed::from_knots(600.0),
+        )
+    }
+
+    #[test]
+    fn intercept_impossible() {
+        let p = NVector::from_lat_long_degrees(20.0, -60.0);
+        let slow = Speed::from_knots(50.0);
+        assert!(Sphere::EARTH
+            .intercept(p, slow, intruder(), Length::ZERO)
+            .is_none());
+        assert!(Ellipsoid::WGS84
+            .intercept(p, slow, intruder(), Length::ZERO)
+            .is_none());
+    }
+
+    #[test]
+    fn intercept_lead_and_lag() {
+        let p = NVector::from_lat_long_degrees(20.0, -60.0);
+        let speed = Speed::from_knots(700.0);
+        let offset = Length::from_metres(18_520.0);
+        let s = Sphere::EARTH;
+        let e = Ellipsoid::WGS84;
+        let pure = s.intercept(p, speed, intruder(), Length::ZERO).unwrap();
+        let lag = s.intercept(p, speed, intruder(), offset).unwrap();
+        let lead = s.intercept(p, speed, intruder(), -offset).unwrap();
+        assert!(lead.time() < pure.time() && pure.time() < lag.time());
+        let int_lag = s.position_after(intruder(), lag.time());
+        let int_lead = s.position_after(intruder(), lead.time());
+        assert_eq!(offset, s.distance(lag.position(), int_lag).round_mm());
+        assert_eq!(offset, s.distance(lead.position(), int_lead).round_mm());
+
+        let lag = e.intercept(p, speed, intruder(), offset).unwrap();
+        let int_lag = e.position_after(intruder(), lag.time());
+        assert_eq!(offset, e.distance(lag.position(), int_lag).round_mm());
+    }
+
+    #[test]
+    fn intercept_pure() {
+        let p = NVector::from_lat_long_degrees(20.0, -60.0);
+        let speed = Speed::from_knots(700.0);
+        let s = Sphere::EARTH;
+        let e = Ellipsoid::WGS84;
+        let si = s.intercept(p, speed, intruder(), Length::ZERO).unwrap();
+        let ei = e.intercept(p, speed, intruder(), Length::ZERO).unwrap();
+        assert_eq!(s.time_to_intercept(p, speed, intruder()), Some(si.time()));
+        assert_eq!(
+            Kinematics::time_to_intercept(&e, p, speed, intruder()),
+            Some(ei.time())
+        );
+        assert_eq!(s.position_after(intruder(), si.time()), si.position());
+        assert_eq!(e.position_after(intruder(), ei.time()), ei.position());
+        assert_eq!(Sphere::initial_bearing(p, si.position()), si.bearing());
+        assert_eq!(e.initial_bearing(p, ei.position()), ei.bearing());
+        for i in [si, ei] {
+            assert_eq!(speed, i.speed());
+            // interceptor travels at the given speed.
+            assert!((i.distance() - speed * i.time()).abs() < Length::from_metres(1.0));
+        }
+    }
+
+    // manoeuvres
+
+    fn mps(v: f64) -> Speed {
+        Speed::from_metres_per_second(v)
+    }
+
+    fn mps2(a: f64) -> Acceleration {
+        Acceleration::from_metres_per_second_squared(a)
+    }
+
+    fn secs(s: u64) -> Duration {
+        Duration::from_secs(s)
+    }
+
+    #[test]
+    fn manoeuvres_acceleration() {
+        let v = Vehicle::new(
+            NVector::from_lat_long_degrees(45.0, 10.0),
+            Angle::ZERO,
+            mps(0.0),
+        );
+        let m = [Manoeuvre::accelerating(mps2(1.0), secs(100))];
+        let s = Sphere::EARTH.state_after_manoeuvres(v, &m, secs(100));
+        let e = Ellipsoid::WGS84.state_after_manoeuvres(v, &m, secs(100));
+        assert_eq!(
+            Length::from_metres(5_000.0),
+            Sphere::EARTH
+                .distance(v.position(), s.position())
+                .round_mm()
+        );
+        assert_eq!(
+            Length::from_metres(5_000.0),
+            Ellipsoid::WGS84
+                .distance(v.position(), e.position())
+                .round_mm()
+        );
+        for r in [s, e] {
+            assert_eq!(mps(100.0), r.speed());
+            assert_eq!(Angle::ZERO, r.bearing().round_d7());
+        }
+    }
+
+    #[test]
+    fn manoeuvres_deceleration_stops() {
+        let v = Vehicle::new(
+            NVector::from_lat_long_degrees(45.0, 10.0),
+            Angle::from_degrees(90.0),
+            mps(10.0),
+        );
+        let m = [Manoeuvre::accelerating(mps2(-1.0), secs(20))];
+        let s = Sphere::EARTH.state_after_manoeuvres(v, &m, secs(20));
+        assert_eq!(Speed::ZERO, s.speed());
+        // stops after 10 seconds.
+        assert_eq!(
+            Length::from_metres(50.0),
+            Sphere::EARTH
+                .distance(v.position(), s.position())
+                .round_mm()
+        );
+        assert_eq!(s, Sphere::EARTH.state_after_manoeuvres(v, &m, secs(10)));
+    }
+
+    #[test]
+    fn manoeuvres_full_circle() {
+        let v = Vehicle::new(
+            NVector::from_lat_long_degrees(45.0, 10.0),
+            Angle::from_degrees(30.0),
+            mps(100.0),
+        );
+        // standard rate turn: 360 degrees in 2 minutes.
+        let m = [Manoeuvre::turning(
+            Turn::Rate(Angle::from_degrees(3.0)),
+            secs(120),
+        )];
+        let s = Sphere::EARTH.state_after_manoeuvres(v, &m, secs(120));
+        let e = Ellipsoid::WGS84.state_after_manoeuvres(v, &m, secs(120));
+        assert!(Sphere::EARTH.distance(v.position(), s.position()) < Length::from_metres(0.01));
+        assert!(Ellipsoid::WGS84.distance(v.position(), e.position()) < Length::from_metres(0.01));
+        for r in [s, e] {
+            // the heading changes by slightly less than 360 degrees due to the curvature of the surface.
+            assert!((v.bearing() - r.bearing()).as_degrees().abs() < 1e-4);
+            assert_eq!(v.speed(), r.speed());
+        }
+        // half way: diameter of the turn is 2 * 100 / (3 * PI / 180) metres.
+        let s = Sphere::EARTH.state_after_manoeuvres(v, &m, secs(60));
+        assert_eq!(
+            Length::from_metres(3_819.719),
+            Sphere::EARTH
+                .distance(v.position(), s.position())
+                .round_mm()
+        );
+        // heading relative to true north: the meridians converge towards the pole.
+        assert_eq!(210.0, s.bearing().as_degrees().round());
+    }
+
+    #[test]
+    fn manoeuvres_long_turn() {
+        let v = Vehicle::new(
+            NVector::from_lat_long_degrees(45.0, 10.0),
+            Angle::from_degrees(30.0),
+            mps(100.0),
+        );
+        // 2400 full turns in a day: integrated in steps of 240 degrees.
+        let day = secs(86_400);
+        let m = [Manoeuvre::turning(
+            Turn::Rate(Angle::from_degrees(10.0)),
+            day,
+        )];
+        let s = Sphere::EARTH.state_after_manoeuvres(v, &m, day);
+        assert!(Sphere::EARTH.distance(v.position(), s.position()) < Length::from_metres(1.0));
+        assert!((v.bearing() - s.bearing()).as_degrees().abs() < 0.1);
+        assert_eq!(v.speed(), s.speed());
+    }
+
+    #[test]
+    fn manoeuvres_left_turn_radius() {
+        let v = Vehicle::new(
+            NVector::from_lat_long_degrees(45.0, 10.0),
+            Angle::ZERO,
+            mps(50.0),
+        );
+        let r = 1_000.0;
+        let half_circle = Duration::from_secs_f64(std::f64::consts::PI * r / 50.0);
+        let m = [Manoeuvre::turning(
+            Turn::Radius(Length::from_metres(-r)),
+            half_circle,
+        )];
+        let s = Sphere::EARTH.state_after_manoeuvres(v, &m, half_circle);
+        let e = Ellipsoid::WGS84.state_after_manoeuvres(v, &m, half_circle);
+        assert_eq!(
+            Length::from_metres(2_000.0),
+            Sphere::EARTH.distance(v.position(), s.position()).round_m()
+        );
+        assert_eq!(
+            Length::from_metres(2_000.0),
+            Ellipsoid::WGS84
+                .distance(v.position(), e.position())
+                .round_m()
+        );
+        for r in [s, e] {
+            assert_eq!(180.0, r.bearing().as_degrees().round());
+            // turned left: west of the start.
+            assert!(LatLong::from_nvector(r.position()).longitude() < Angle::from_degrees(10.0));
+        }
+    }
+
+    #[test]
+    fn manoeuvres_rate_turn_stationary() {
+        let v = Vehicle::new(
+            NVector::from_lat_long_degrees(45.0, 10.0),
+            Angle::ZERO,
+            Speed::ZERO,
+        );
+        let m = [Manoeuvre::new(
+            secs(9),
+            Some(Turn::Rate(Angle::from_degrees(10.0))),
+            mps2(-1.0),
+        )];
+        let s = Sphere::EARTH.state_after_manoeuvres(v, &m, secs(9));
+        assert_eq!(v.position(), s.position());
+        assert_eq!(Angle::from_degrees(90.0), s.bearing().round_d7());
+        assert_eq!(Speed::ZERO, s.speed());
+    }
+
+    fn assert_state_eq(expected: Vehicle, actual: Vehicle) {
+        assert_eq!(
+            LatLong::from_nvector(expected.position()).round_d7(),
+            LatLong::from_nvector(actual.position()).round_d7()
+        );
+        assert_eq!(expected.speed(), actual.speed());
+        let d = (expected.bearing() - actual.bearing()).as_radians();
+        assert!(d.sin().abs() < 1e-9 && d.cos() > 0.0);
+    }
+
+    #[test]
+    fn manoeuvres_sequence() {
+        let v = Vehicle::new(
+            NVector::from_lat_long_degrees(45.0, 10.0),
+            Angle::ZERO,
+            mps(100.0),
+        );
+        let m = [
+            Manoeuvre::straight(secs(60)),
+            Manoeuvre::turning(Turn::Rate(Angle::from_degrees(3.0)), secs(30)),
+            Manoeuvre::accelerating(mps2(2.0), secs(10)),
+        ];
+        let k = Sphere::EARTH;
+        let after_straight = k.state_after(v, secs(60));
+        assert_state_eq(after_straight, k.state_after_manoeuvres(v, &m, secs(60)));
+        let after_turn = k.state_after_manoeuvres(after_straight, &m[1..2], secs(30));
+        assert_state_eq(after_turn, k.state_after_manoeuvres(v, &m, secs(90)));
+        assert_eq!(90.0, after_turn.bearing().as_degrees().round());
+        let after_all = k.state_after_manoeuvres(v, &m, secs(100));
+        assert_eq!(mps(120.0), after_all.speed());
+        // constant course and speed after the last manoeuvre.
+        assert_state_eq(
+            k.state_after(after_all, secs(60)),
+            k.state_after_manoeuvres(v, &m, secs(160)),
+        );
+        assert_eq!(v, k.state_after_manoeuvres(v, &m, Duration::ZERO));
+        assert_state_eq(
+            k.state_after(v, secs(60)),
+            k.state_after_manoeuvres(v, &[], secs(60)),
+        );
+    }
+
+    #[test]
+    fn state_after() {
+        let v = Vehicle::new(
+            NVector::from_lat_long_degrees(20.0, -60.0),
+            Angle::from_degrees(10.0),
+            Speed::from_knots(600.0),
+        );
+        let d = secs(3600);
+        let s = Sphere::EARTH.state_after(v, d);
+        assert_eq!(Sphere::EARTH.position_after(v, d), s.position());
+        assert_eq!(
+            Sphere::final_bearing(v.position(), s.position()).round_d7(),
+            s.bearing().round_d7()
+        );
+        let e = Ellipsoid::WGS84.state_after(v, d);
+        assert_eq!(Ellipsoid::WGS84.position_after(v, d), e.position());
+        assert_eq!(
+            Ellipsoid::WGS84
+                .final_bearing(v.position(), e.position())
+                .round_d7(),
+            e.bearing().round_d7()
+        );
+        for r in [s, e] {
+            assert_eq!(v.speed(), r.speed());
+        }
+    }
+
+    #[test]
+    fn state_after_stationary() {
+        let v = Vehicle::new(
+            NVector::from_lat_long_degrees(20.0, -60.0),
+            Angle::from_degrees(10.0),
+            Speed::ZERO,
+        );
+        assert_eq!(v, Sphere::EARTH.state_after(v, secs(60)));
+        assert_eq!(v, Ellipsoid::WGS84.state_after(v, secs(60)));
+    }
+
+    #[test]
+    fn min_speed_intercept() {
+        let p = NVector::from_lat_long_degrees(20.0, -60.0);
+        let s = Sphere::EARTH;
+        let e = Ellipsoid::WGS84;
+        let si = s.min_speed_intercept(p, intruder(), Length::ZERO).unwrap();
+        let ei = e.min_speed_intercept(p, intruder(), Length::ZERO).unwrap();
+        assert_eq!(s.max_time_to_intercept(p, intruder()), Some(si.time()));
+        assert_eq!(
+            Kinematics::max_time_to_intercept(&e, p, intruder()),
+            Some(ei.time())
+        );
+        assert_eq!(53.0, si.speed().as_knots().round());
+        assert_eq!(51.0, ei.speed().as_knots().round());
+        for i in [si, ei] {
+            assert_eq!(i.distance().round_m(), (i.speed() * i.time()).round_m());
+        }
+    }
+
+    #[test]
+    fn min_speed_intercept_same_position() {
+        let i = Sphere::EARTH
+            .min_speed_intercept(intruder().position(), intruder(), Length::ZERO)
+            .unwrap();
+        assert_eq!(Duration::ZERO, i.time());
+        assert_eq!(Speed::ZERO, i.speed());
+    }
+
+    #[test]
+    fn offset_vehicle_along_track() {
+        let s = Sphere::EARTH;
+        let v = intruder();
+        let offset = Length::from_metres(18_520.0);
+        let dp = |p, b, d| s.destination_position(p, b, d);
+        let behind = offset_vehicle(v, offset, dp, Sphere::initial_bearing);
+        let ahead = offset_vehicle(v, -offset, dp, Sphere::initial_bearing);
+        assert_eq!(
+            v,
+            offset_vehicle(v, Length::ZERO, dp, Sphere::initial_bearing)
+        );
+        assert_eq!(v.speed(), behind.speed());
+        assert_eq!(v.speed(), ahead.speed());
+        // both offset vehicles are on the track of the vehicle.
+        let t = Duration::from_secs_f64(offset.as_metres() / v.speed().as_metres_per_second());
+        assert_eq!(
+            LatLong::from_nvector(v.position()).round_d7(),
+            LatLong::from_nvector(s.position_after(behind, t)).round_d7()
+        );
+        assert_eq!(
+            LatLong::from_nvector(ahead.position()).round_d7(),
+            LatLong::from_nvector(s.position_after(v, t)).round_d7()
+        );
+        assert_eq!(
+            Sphere::final_bearing(v.position(), ahead.position()).round_d7(),
+            ahead.bearing().round_d7()
+        );
+    }
+
+    #[test]
+    fn root_bracket_expansion() {
+        let t = root(|t: f64| 1000.0 - t, 1.0).unwrap();
+        assert!((t - 1000.0).abs() < 0.001);
+        let t = root(|t: f64| 10.0 - t, 1000.0).unwrap();
+        assert!((t - 10.0).abs() < 0.001);
+        assert!(root(|_| 1.0, 1.0).is_none());
+    }
+}
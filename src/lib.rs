@@ -23,17 +23,29 @@ pub use crate::mat33::Mat33;
 mod measurement;
 pub use crate::measurement::Measurement;
 
+mod acceleration;
+pub use crate::acceleration::Acceleration;
+
 mod angle;
 pub use crate::angle::Angle;
 
 pub mod ellipsoidal;
 
+#[cfg(feature = "geojson")]
+pub mod geojson;
+
+mod kinematics;
+pub use crate::kinematics::{Conflict, Cpa, Intercept, Kinematics};
+
 mod local_frame;
 pub use crate::local_frame::{r2xyz, r2zyx, xyz2r, zyx2r, LocalFrame, LocalPosition};
 
 mod length;
 pub use crate::length::Length;
 
+mod manoeuvre;
+pub use crate::manoeuvre::{Manoeuvre, Turn};
+
 mod numbers;
 
 mod positions;
@@ -41,6 +53,8 @@ pub use crate::positions::{
     Cartesian3DVector, GeocentricPosition, GeodeticPosition, LatLong, NVector,
 };
 
+mod rhumb;
+
 mod speed;
 pub use crate::speed::Speed;
 
@@ -49,6 +63,9 @@ pub mod spherical;
 mod surface;
 pub use crate::surface::Surface;
 
+mod text;
+pub use crate::text::{LatLongFormat, ParseError};
+
 mod vec3;
 pub use crate::vec3::Vec3;
 
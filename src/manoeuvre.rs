@@ -0,0 +1,88 @@
+use std::time::Duration;
+
+use crate::{Acceleration, Angle, Length};
+
+/// A turn of a vehicle: positive values turn right (clockwise) and negative values turn left (anticlockwise).
+#[derive(PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))] // codecov:ignore:this
+pub enum Turn {
+    /// Turn at a constant rate: the angle turned every second.
+    Rate(Angle),
+    /// Turn at a constant radius: the turn rate is the speed of the vehicle divided by the radius.
+    Radius(Length),
+}
+
+/// A manoeuvre of a vehicle lasting a given time: an optional coordinated [Turn] combined with a constant
+/// acceleration. A vehicle executing a manoeuvre with no turn and no acceleration travels along the shortest path
+/// at constant speed.
+///
+/// If the vehicle decelerates, its speed never becomes negative: the vehicle stops and remains stationary until the
+/// end of the manoeuvre.
+///
+/// See [Kinematics::state_after_manoeuvres](crate::Kinematics::state_after_manoeuvres).
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))] // codecov:ignore:this
+pub struct Manoeuvre {
+    duration: Duration,
+    turn: Option<Turn>,
+    acceleration: Acceleration,
+}
+
+impl Manoeuvre {
+    /// Creates a [Manoeuvre] lasting the given time from the given turn (if any) and acceleration (negative to
+    /// decelerate).
+    pub fn new(duration: Duration, turn: Option<Turn>, acceleration: Acceleration) -> Self {
+        Self {
+            duration,
+            turn,
+            acceleration,
+        }
+    }
+
+    /// Creates a [Manoeuvre] lasting the given time travelling along the shortest path at constant speed.
+    pub fn straight(duration: Duration) -> Self {
+        Self::new(duration, None, Acceleration::ZERO)
+    }
+
+    /// Creates a [Manoeuvre] lasting the given time turning with the given turn at constant speed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use jord::{Acceleration, Angle, Manoeuvre, Turn};
+    ///
+    /// // standard rate turn to the left for 1 minute: 180 degrees.
+    /// let m = Manoeuvre::turning(Turn::Rate(Angle::from_degrees(-3.0)), Duration::from_secs(60));
+    /// assert_eq!(Some(Turn::Rate(Angle::from_degrees(-3.0))), m.turn());
+    /// assert_eq!(Acceleration::ZERO, m.acceleration());
+    /// ```
+    pub fn turning(turn: Turn, duration: Duration) -> Self {
+        Self::new(duration, Some(turn), Acceleration::ZERO)
+    }
+
+    /// Creates a [Manoeuvre] lasting the given time accelerating with the given acceleration - the change of speed
+    /// every second, negative to decelerate - along the shortest path.
+    pub fn accelerating(acceleration: Acceleration, duration: Duration) -> Self {
+        Self::new(duration, None, acceleration)
+    }
+
+    /// Returns the duration of this manoeuvre.
+    #[inline]
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns the turn of this manoeuvre if any.
+    #[inline]
+    pub fn turn(&self) -> Option<Turn> {
+        self.turn
+    }
+
+    /// Returns the acceleration of this manoeuvre.
+    #[inline]
+    pub fn acceleration(&self) -> Acceleration {
+        self.acceleration
+    }
+}
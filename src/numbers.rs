@@ -22,3 +22,35 @@ pub(crate) fn lte(left: f64, right: f64) -> bool {
 pub(crate) fn gte(left: f64, right: f64) -> bool {
     left >= right || eq(left, right)
 }
+
+/// A deterministic pseudo-random number generator (xorshift64): used wherever a fixed but well mixed order is
+/// required, e.g. to shuffle positions, and by tests to generate reproducible inputs.
+pub(crate) struct Xorshift {
+    state: u64,
+}
+
+impl Xorshift {
+    /// Creates a new generator with the given seed, which must not be 0.
+    pub(crate) const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Returns the next pseudo-random integer.
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    /// Returns the next pseudo-random integer in [0, n[.
+    pub(crate) fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+
+    /// Returns the next pseudo-random float in [0, 1[.
+    #[cfg(test)]
+    pub(crate) fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
@@ -2,6 +2,8 @@ use crate::Length;
 
 use {crate::Angle, crate::Vec3};
 
+use crate::text::{format_lat_long, LatLongFormat};
+
 /// Cartesian 3D position vector: allows to represent the position of a general coordinate frame B
 /// relative to a reference coordinate frame A as the position vector from A to B.
 pub trait Cartesian3DVector: Sized {
@@ -180,6 +182,25 @@ impl LatLong {
         self.longitude
     }
 
+    /// Formats this [LatLong] using the given format; the precision is the number of decimal places of the last
+    /// component (degrees, minutes or seconds).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, LatLongFormat};
+    ///
+    /// let p = LatLong::from_degrees(55.605833, -13.001944);
+    ///
+    /// assert_eq!("55.606°N, 13.002°W", p.format(LatLongFormat::DecimalDegrees, 3));
+    /// assert_eq!("55°36.35'N, 13°00.12'W", p.format(LatLongFormat::DegreesMinutes, 2));
+    /// assert_eq!("55°36'21\"N, 13°00'07\"W", p.format(LatLongFormat::DegreesMinutesSeconds, 0));
+    /// assert_eq!("+55.6058-013.0019/", p.format(LatLongFormat::Iso6709, 4));
+    /// ```
+    pub fn format(&self, format: LatLongFormat, precision: usize) -> String {
+        format_lat_long(*self, format, precision)
+    }
+
     /// Rounds the latitude and longitude of this latlong to the nearest decimal degrees with 5 decimal places.
     ///
     /// The precision of the returned latlong corresponds to the accuracy achieved by commercial GPS
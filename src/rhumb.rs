@@ -0,0 +1,192 @@
+// Rhumb line (loxodrome) calculations on a sphere or an ellipsoid of revolution.
+//
+// A rhumb line crosses all meridians at the same angle: it is a straight line on the Mercator projection. The
+// longitude difference is proportional to the difference of isometric latitude and the distance is proportional to
+// the difference of meridian arc length; the meridian arc length and its inverse are computed using the series in
+// the third flattening n (Helmert), which are accurate to better than a millimetre on Earth.
+//
+// For a sphere the flattening is 0 and all series reduce to the spherical formulas.
+
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use crate::{Angle, LatLong, NVector};
+
+/// below this difference of isometric latitude the rhumb line is considered to be along a parallel.
+const PARALLEL_EPS: f64 = 1e-12;
+
+/// Rhumb line calculator for a given surface.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Rhumb {
+    /// equatorial radius.
+    a: f64,
+    /// first eccentricity.
+    e: f64,
+    /// scale of the rectifying latitude: meridian arc length = rectifying latitude * ma.
+    ma: f64,
+    /// coefficients of the rectifying latitude (from geodetic latitude).
+    mu: [f64; 4],
+    /// coefficients of the geodetic latitude (from rectifying latitude).
+    phi: [f64; 4],
+}
+
+/// Solution of the inverse problem: distance in metres and bearing in radians.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Inverse {
+    pub(crate) distance: f64,
+    pub(crate) bearing: f64,
+}
+
+impl Rhumb {
+    /// Creates a new rhumb line calculator for the surface of given equatorial radius (metres) and flattening.
+    pub(crate) fn new(a: f64, f: f64) -> Self {
+        let e = (f * (2.0 - f)).sqrt();
+        let n = f / (2.0 - f);
+        let n2 = n * n;
+        let n3 = n2 * n;
+        let n4 = n3 * n;
+        Self {
+            a,
+            e,
+            ma: a / (1.0 + n) * (1.0 + n2 / 4.0 + n4 / 64.0),
+            mu: [
+                -1.5 * n + 9.0 * n3 / 16.0,
+                15.0 * n2 / 16.0 - 15.0 * n4 / 32.0,
+                -35.0 * n3 / 48.0,
+                315.0 * n4 / 512.0,
+            ],
+            phi: [
+                1.5 * n - 27.0 * n3 / 32.0,
+                21.0 * n2 / 16.0 - 55.0 * n4 / 32.0,
+                151.0 * n3 / 96.0,
+                1097.0 * n4 / 512.0,
+            ],
+        }
+    }
+
+    /// Computes the distance and constant bearing of the rhumb line from p1 to p2.
+    pub(crate) fn inverse(&self, p1: NVector, p2: NVector) -> Inverse {
+        if p1 == p2 {
+            return Inverse {
+                distance: 0.0,
+                bearing: 0.0,
+            };
+        }
+        let ll1 = LatLong::from_nvector(p1);
+        let ll2 = LatLong::from_nvector(p2);
+        let lat1 = ll1.latitude().as_radians();
+        let lat2 = ll2.latitude().as_radians();
+        let dlon = longitude_difference(ll1.longitude(), ll2.longitude());
+        let dpsi = self.isometric_latitude(lat2) - self.isometric_latitude(lat1);
+        // both positions at the same pole.
+        let dpsi = if dpsi.is_nan() { 0.0 } else { dpsi };
+        let dm = self.meridian_arc(lat2) - self.meridian_arc(lat1);
+
+        // ratio between the meridian arc length and the isometric latitude differences: the radius of the
+        // parallel when the rhumb line is (nearly) along a parallel.
+        let q = if dpsi.abs() > PARALLEL_EPS {
+            dm / dpsi
+        } else {
+            self.parallel_radius(lat1)
+        };
+        // at a pole (isometric latitude is infinite) the difference of longitude is irrelevant.
+        let q = if q.is_finite() { q } else { 0.0 };
+
+        let distance = (dm * dm + q * q * dlon * dlon).sqrt();
+        let bearing = if dlon == 0.0 && dpsi == 0.0 {
+            0.0
+        } else {
+            dlon.atan2(dpsi)
+        };
+        Inverse { distance, bearing }
+    }
+
+    /// Computes the position at the given distance (metres) from p0 following the rhumb line of the given bearing
+    /// (radians). The rhumb line ends at the pole if the distance exceeds the distance to the pole.
+    pub(crate) fn direct(&self, p0: NVector, bearing: f64, distance: f64) -> NVector {
+        if distance == 0.0 {
+            return p0;
+        }
+        let ll0 = LatLong::from_nvector(p0);
+        let lat0 = ll0.latitude().as_radians();
+        let m0 = self.meridian_arc(lat0);
+        let m_pole = self.meridian_arc(FRAC_PI_2);
+        let m2 = m0 + distance * bearing.cos();
+        if m2.abs() >= m_pole {
+            let pole = FRAC_PI_2.copysign(m2);
+            return LatLong::new(Angle::from_radians(pole), ll0.longitude()).to_nvector();
+        }
+        let lat2 = self.latitude_from_meridian_arc(m2);
+        let dpsi = self.isometric_latitude(lat2) - self.isometric_latitude(lat0);
+        let dlon = if dpsi.abs() > PARALLEL_EPS {
+            bearing.tan() * dpsi
+        } else {
+            let r = self.parallel_radius(lat0);
+            if r == 0.0 {
+                0.0
+            } else {
+                distance * bearing.sin() / r
+            }
+        };
+        let dlon = if dlon.is_finite() { dlon } else { 0.0 };
+        let lon2 = Angle::from_radians(ll0.longitude().as_radians() + dlon);
+        LatLong::new(Angle::from_radians(lat2), lon2).to_nvector()
+    }
+
+    /// Computes the position at the given fraction of the rhumb line from p1 to p2.
+    pub(crate) fn interpolated(&self, p1: NVector, p2: NVector, f: f64) -> Option<NVector> {
+        if !(0.0..=1.0).contains(&f) {
+            None
+        } else if f == 0.0 {
+            Some(p1)
+        } else if f == 1.0 {
+            Some(p2)
+        } else {
+            let inv = self.inverse(p1, p2);
+            Some(self.direct(p1, inv.bearing, f * inv.distance))
+        }
+    }
+
+    /// isometric latitude of the given geodetic latitude: infinite at the poles.
+    fn isometric_latitude(&self, lat: f64) -> f64 {
+        let s = lat.sin();
+        s.atanh() - self.e * (self.e * s).atanh()
+    }
+
+    /// meridian arc length from the equator to the given geodetic latitude.
+    fn meridian_arc(&self, lat: f64) -> f64 {
+        self.ma * (lat + sin_series(lat, &self.mu))
+    }
+
+    /// geodetic latitude at the given meridian arc length from the equator.
+    fn latitude_from_meridian_arc(&self, m: f64) -> f64 {
+        let mu = m / self.ma;
+        (mu + sin_series(mu, &self.phi)).clamp(-FRAC_PI_2, FRAC_PI_2)
+    }
+
+    /// radius of the parallel at the given geodetic latitude.
+    fn parallel_radius(&self, lat: f64) -> f64 {
+        let s = self.e * lat.sin();
+        (self.a * lat.cos() / (1.0 - s * s).sqrt()).max(0.0)
+    }
+}
+
+/// c[0] * sin(2x) + c[1] * sin(4x) + c[2] * sin(6x) + c[3] * sin(8x)
+fn sin_series(x: f64, c: &[f64; 4]) -> f64 {
+    c.iter()
+        .enumerate()
+        .map(|(i, ci)| ci * (2.0 * (i + 1) as f64 * x).sin())
+        .sum()
+}
+
+/// difference of longitude from lon1 to lon2 in the range [-PI, PI]: rhumb lines never cross the antimeridian the
+/// long way round.
+fn longitude_difference(lon1: Angle, lon2: Angle) -> f64 {
+    let d = (lon2.as_radians() - lon1.as_radians()) % (2.0 * PI);
+    if d > PI {
+        d - 2.0 * PI
+    } else if d < -PI {
+        d + 2.0 * PI
+    } else {
+        d
+    }
+}
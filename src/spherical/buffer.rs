@@ -0,0 +1,440 @@
+// Buffering of minor arcs, chains of minor arcs and loops by a distance.
+//
+// The buffer of a minor arc - the region within a given distance of the arc - is bounded by 2 arcs of small circles
+// parallel to the arc joined by 2 half circles centred at the ends of the arc. Both are approximated by vertices
+// spaced by at most a quarter circle divided by the requested number of segments per quadrant.
+//
+// The buffer of a chain of minor arcs is the union of the buffers of its arcs, and the outward buffer of a loop is the
+// union of the loop with the buffers of its edges: these are computed by successive boolean operations between loops
+// - see clipping. The inward buffer of a loop is made of the holes of the buffer of its boundary that are inside the
+// loop.
+
+use std::f64::consts::FRAC_PI_2;
+
+use crate::{Angle, Length, NVector, Vec3};
+
+use super::{Loop, Polygon, Sphere};
+
+/// Returns the buffer of the minor arc from a to b at the given radius (in radians): empty if the radius is not in
+/// ]0, π/2[.
+pub(crate) fn arc_buffer(a: NVector, b: NVector, radius: f64, quadrant_segments: usize) -> Loop {
+    if radius <= 0.0 || radius >= FRAC_PI_2 {
+        return Loop::EMPTY;
+    }
+    let step = FRAC_PI_2 / (quadrant_segments.max(1) as f64);
+    let va = a.as_vec3();
+    let vb = b.as_vec3();
+    let cross = va.cross_prod(vb);
+    // a normal to any great circle going through a if a and b are coincidental.
+    let normal = if cross == Vec3::ZERO {
+        va.orthogonal().unit()
+    } else {
+        cross.unit()
+    };
+    let length = cross.norm().atan2(va.dot_prod(vb));
+    let ta = normal.cross_prod(va);
+    let tb = normal.cross_prod(vb);
+    let (sin_r, cos_r) = radius.sin_cos();
+    let offset = |p: Vec3, d: Vec3| NVector::new(p * cos_r + d * sin_r);
+
+    let nb_sides = (length / step).ceil() as usize;
+    let sides: Vec<Vec3> = (0..=nb_sides)
+        .map(|k| {
+            if nb_sides == 0 {
+                va
+            } else {
+                let (s, c) = (length * (k as f64) / (nb_sides as f64)).sin_cos();
+                va * c + ta * s
+            }
+        })
+        .collect();
+    let nb_caps = 2 * quadrant_segments.max(1);
+    let cap = |p: Vec3, from: Vec3, through: Vec3| {
+        (1..nb_caps).map(move |k| {
+            let (s, c) = (FRAC_PI_2 * 2.0 * (k as f64) / (nb_caps as f64)).sin_cos();
+            offset(p, from * c + through * s)
+        })
+    };
+
+    let mut vs: Vec<NVector> = Vec::with_capacity(2 * (sides.len() + nb_caps));
+    vs.extend(sides.iter().map(|p| offset(*p, normal)));
+    vs.extend(cap(vb, normal, tb));
+    vs.extend(sides.iter().rev().map(|p| offset(*p, -normal)));
+    vs.extend(cap(va, -normal, -ta));
+    Loop::new(&vs)
+}
+
+/// Returns the buffer of the given chain of vertices at the given radius (in radians): the union of the buffers of
+/// each minor arc of the chain.
+pub(crate) fn chain_buffer(vs: &[NVector], radius: f64, quadrant_segments: usize) -> Polygon {
+    vs.windows(2)
+        .map(|w| arc_buffer(w[0], w[1], radius, quadrant_segments))
+        .fold(Polygon::EMPTY, |region, b| add(&region, &b))
+}
+
+/// Returns the buffer of the given loop at the given radius (in radians): outward if the radius is positive, inward
+/// if negative.
+pub(crate) fn loop_buffer(l: &Loop, radius: f64, quadrant_segments: usize) -> Vec<Polygon> {
+    if l.is_empty() {
+        return Vec::new();
+    }
+    if radius == 0.0 {
+        return vec![Polygon::new(l.clone(), Vec::new())];
+    }
+    if radius.abs() >= FRAC_PI_2 {
+        return Vec::new();
+    }
+    if radius > 0.0 {
+        let region = Polygon::new(l.clone(), Vec::new());
+        vec![l
+            .iter_edges()
+            .map(|e| arc_buffer(e.start(), e.end(), radius, quadrant_segments))
+            .fold(region, |region, b| add(&region, &b))]
+    } else {
+        // the buffer of the boundary covers the boundary of the loop: each of its holes is either inside or outside
+        // of the loop, and the holes inside are the inward buffer.
+        let mut vs: Vec<NVector> = l.iter_vertices().copied().collect();
+        vs.push(vs[0]);
+        chain_buffer(&vs, -radius, quadrant_segments)
+            .iter_holes()
+            .filter(|h| l.contains_position(h.vertex(0)))
+            .map(|h| Polygon::new(h.clone(), Vec::new()))
+            .collect()
+    }
+}
+
+/// Returns the radius in radians of the buffer at the given distance on the given sphere, with the sign of the
+/// distance: `None` if the absolute value of the distance is greater or equal to a quarter of the circumference of the
+/// sphere.
+pub(crate) fn buffer_radius(distance: Length, sphere: &Sphere) -> Option<f64> {
+    if distance.abs() >= Angle::QUARTER_CIRCLE * sphere.radius() {
+        return None;
+    }
+    let radius = sphere.distance_to_angle(distance).as_radians();
+    Some(if distance < Length::ZERO {
+        -radius
+    } else {
+        radius
+    })
+}
+
+/// Returns the union of the given region and the given loop, assuming that they intersect.
+fn add(region: &Polygon, l: &Loop) -> Polygon {
+    if region.is_empty() {
+        return Polygon::new(l.clone(), Vec::new());
+    }
+    let union = region.shell().union(l);
+    // the union of 2 intersecting loops is a single polygon, possibly with holes.
+    let main = union.into_iter().max_by(|a, b| {
+        a.spherical_excess()
+            .as_radians()
+            .total_cmp(&b.spherical_excess().as_radians())
+    });
+    match main {
+        None => region.clone(),
+        Some(m) => {
+            let mut holes: Vec<Loop> = m.iter_holes().cloned().collect();
+            for h in region.iter_holes() {
+                holes.extend(h.difference(l).iter().map(|p| p.shell().clone()));
+            }
+            Polygon::new(m.shell().clone(), holes)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use crate::{
+        spherical::{Loop, MinorArc, Polygon, Sphere},
+        Angle, Length, NVector,
+    };
+
+    use super::{arc_buffer, buffer_radius, chain_buffer, loop_buffer};
+
+    fn nv(lat: f64, lon: f64) -> NVector {
+        NVector::from_lat_long_degrees(lat, lon)
+    }
+
+    fn lp(lls: &[(f64, f64)]) -> Loop {
+        let vs: Vec<NVector> = lls.iter().map(|ll| nv(ll.0, ll.1)).collect();
+        Loop::new(&vs)
+    }
+
+    /// distance in radians from the given position to the closest of the given edges.
+    fn distance_to(p: NVector, edges: &[MinorArc]) -> f64 {
+        edges
+            .iter()
+            .map(|e| e.distance_to(p).to_angle().as_radians())
+            .fold(f64::MAX, f64::min)
+    }
+
+    fn contains(ps: &[Polygon], p: NVector) -> bool {
+        ps.iter().any(|poly| poly.contains_position(p))
+    }
+
+    #[test]
+    fn arc_buffer_invalid_radius() {
+        let (a, b) = (nv(0.0, 0.0), nv(0.0, 1.0));
+        assert!(arc_buffer(a, b, 0.0, 4).is_empty());
+        assert!(arc_buffer(a, b, -0.01, 4).is_empty());
+        assert!(arc_buffer(a, b, FRAC_PI_2, 4).is_empty());
+    }
+
+    #[test]
+    fn arc_buffer_vertices_at_radius() {
+        let (a, b) = (nv(10.0, 20.0), nv(12.0, 24.0));
+        let r = 0.01;
+        let l = arc_buffer(a, b, r, 6);
+        assert!(!l.is_empty());
+        assert!(l.is_simple());
+        assert!(l.is_convex());
+        let e = [MinorArc::new(a, b)];
+        for v in l.iter_vertices() {
+            assert!((distance_to(*v, &e) - r).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn arc_buffer_contains() {
+        let (a, b) = (nv(10.0, 20.0), nv(12.0, 24.0));
+        let r = 0.01;
+        let l = arc_buffer(a, b, r, 6);
+        let ma = MinorArc::new(a, b);
+        let n = ma.normal();
+        let offset = |p: NVector, d: f64| NVector::new((p.as_vec3() + n * d).unit());
+        let mid = NVector::new((a.as_vec3() + b.as_vec3()).unit());
+        for p in [a, b, mid] {
+            assert!(l.contains_position(p));
+            assert!(l.contains_position(offset(p, 0.95 * r)));
+            assert!(l.contains_position(offset(p, -0.95 * r)));
+            assert!(!l.contains_position(offset(p, 1.05 * r)));
+            assert!(!l.contains_position(offset(p, -1.05 * r)));
+        }
+    }
+
+    #[test]
+    fn arc_buffer_long_arc_densified() {
+        let (a, b) = (nv(0.0, 0.0), nv(0.0, 60.0));
+        let l = arc_buffer(a, b, 0.1, 2);
+        // sides: 60 degrees / 45 degrees -> 2 segments: 3 vertices, caps: 3 vertices.
+        assert_eq!(12, l.num_vertices());
+    }
+
+    #[test]
+    fn arc_buffer_coincidental_positions() {
+        let p = nv(45.0, 45.0);
+        let l = arc_buffer(p, p, 0.02, 3);
+        assert_eq!(12, l.num_vertices());
+        for v in l.iter_vertices() {
+            let a = v.as_vec3().cross_prod(p.as_vec3()).norm().asin();
+            assert!((a - 0.02).abs() < 1e-12);
+        }
+        assert!(l.contains_position(p));
+    }
+
+    #[test]
+    fn arc_buffer_quadrant_segments_at_least_one() {
+        let (a, b) = (nv(0.0, 0.0), nv(0.0, 1.0));
+        assert_eq!(arc_buffer(a, b, 0.01, 1), arc_buffer(a, b, 0.01, 0));
+        assert_eq!(6, arc_buffer(a, b, 0.01, 1).num_vertices());
+    }
+
+    #[test]
+    fn chain_buffer_empty() {
+        assert!(chain_buffer(&[], 0.01, 4).is_empty());
+        assert!(chain_buffer(&[nv(0.0, 0.0), nv(1.0, 1.0)], 0.0, 4).is_empty());
+    }
+
+    #[test]
+    fn chain_buffer_turns() {
+        let vs = vec![nv(0.0, 0.0), nv(0.0, 2.0), nv(2.0, 2.5), nv(0.5, 4.0)];
+        let edges: Vec<MinorArc> = vs.windows(2).map(|w| MinorArc::new(w[0], w[1])).collect();
+        let r = 0.25f64.to_radians();
+        let b = chain_buffer(&vs, r, 8);
+        assert_eq!(0, b.num_holes());
+        assert!(b.shell().is_simple());
+        for v in b.shell().iter_vertices() {
+            // sides are approximated by minor arcs which are slightly further than r from the edge.
+            assert!(distance_to(*v, &edges) < 1.001 * r);
+        }
+        for lat in -10..=30 {
+            for lon in -10..=45 {
+                let p = nv(lat as f64 / 10.0, lon as f64 / 10.0);
+                let d = distance_to(p, &edges);
+                if d < 0.95 * r {
+                    assert!(b.contains_position(p));
+                } else if d > r {
+                    assert!(!b.contains_position(p));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn chain_buffer_with_hole() {
+        let vs = vec![
+            nv(0.0, 0.0),
+            nv(0.0, 2.0),
+            nv(2.0, 2.0),
+            nv(2.0, 0.0),
+            nv(0.1, 0.0),
+        ];
+        let b = chain_buffer(&vs, 0.2f64.to_radians(), 4);
+        assert_eq!(1, b.num_holes());
+        assert!(!b.contains_position(nv(1.0, 1.0)));
+        assert!(b.contains_position(nv(0.0, 1.0)));
+        assert!(b.contains_position(nv(0.05, 0.0)));
+        assert!(!b.contains_position(nv(-0.5, 1.0)));
+    }
+
+    #[test]
+    fn buffer_radius_limits() {
+        let quarter = Angle::QUARTER_CIRCLE * Sphere::EARTH.radius();
+        assert_eq!(None, buffer_radius(quarter, &Sphere::EARTH));
+        assert_eq!(None, buffer_radius(-quarter, &Sphere::EARTH));
+        assert_eq!(Some(0.0), buffer_radius(Length::ZERO, &Sphere::EARTH));
+        let d = Length::from_kilometres(10.0);
+        let r = Sphere::EARTH.distance_to_angle(d).as_radians();
+        assert_eq!(Some(r), buffer_radius(d, &Sphere::EARTH));
+        assert_eq!(Some(-r), buffer_radius(-d, &Sphere::EARTH));
+    }
+
+    #[test]
+    fn loop_buffer_empty_or_zero() {
+        assert!(loop_buffer(&Loop::EMPTY, 0.01, 4).is_empty());
+        let l = lp(&[(0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0)]);
+        assert_eq!(
+            vec![Polygon::new(l.clone(), Vec::new())],
+            loop_buffer(&l, 0.0, 4)
+        );
+        assert!(loop_buffer(&l, FRAC_PI_2, 4).is_empty());
+        assert!(loop_buffer(&l, -FRAC_PI_2, 4).is_empty());
+    }
+
+    #[test]
+    fn loop_buffer_outward() {
+        let l = lp(&[(0.0, 0.0), (0.0, 2.0), (2.0, 2.0), (1.0, 1.0), (2.0, 0.0)]);
+        let edges: Vec<MinorArc> = l.iter_edges().copied().collect();
+        let r = 0.2f64.to_radians();
+        let b = loop_buffer(&l, r, 8);
+        assert_eq!(1, b.len());
+        assert_eq!(0, b[0].num_holes());
+        for lat in -5..=25 {
+            for lon in -5..=25 {
+                let p = nv(lat as f64 / 10.0, lon as f64 / 10.0);
+                let d = distance_to(p, &edges);
+                if l.contains_position(p) || d < 0.95 * r {
+                    assert!(contains(&b, p));
+                } else if d > 1.001 * r {
+                    assert!(!contains(&b, p));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn loop_buffer_outward_closes_concavity() {
+        // square with a square hole connected to the outside by a slit narrower than twice the buffer distance.
+        let l = lp(&[
+            (0.0, 0.0),
+            (0.0, 3.0),
+            (3.0, 3.0),
+            (3.0, 1.55),
+            (2.0, 1.55),
+            (2.0, 2.0),
+            (1.0, 2.0),
+            (1.0, 1.0),
+            (2.0, 1.0),
+            (2.0, 1.45),
+            (3.0, 1.45),
+            (3.0, 0.0),
+        ]);
+        let b = loop_buffer(&l, 0.1f64.to_radians(), 4);
+        assert_eq!(1, b.len());
+        assert_eq!(1, b[0].num_holes());
+        assert!(!b[0].contains_position(nv(1.5, 1.5)));
+        assert!(b[0].contains_position(nv(2.5, 1.5)));
+        assert!(b[0].contains_position(nv(1.5, 1.05)));
+    }
+
+    #[test]
+    fn loop_buffer_inward() {
+        let l = lp(&[(0.0, 0.0), (0.0, 2.0), (2.0, 2.0), (2.0, 0.0)]);
+        let edges: Vec<MinorArc> = l.iter_edges().copied().collect();
+        let r = 0.2f64.to_radians();
+        let b = loop_buffer(&l, -r, 8);
+        assert_eq!(1, b.len());
+        assert_eq!(0, b[0].num_holes());
+        for lat in 0..=20 {
+            for lon in 0..=20 {
+                let p = nv(lat as f64 / 10.0, lon as f64 / 10.0);
+                let d = distance_to(p, &edges);
+                if !l.contains_position(p) || d < 0.95 * r {
+                    assert!(!contains(&b, p));
+                } else if d > 1.05 * r {
+                    assert!(contains(&b, p));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn loop_buffer_inward_around_concavity() {
+        // square with a square hole connected to the outside by a slit: the buffer of the boundary has a hole in the
+        // concavity, which is outside of the loop.
+        let l = lp(&[
+            (0.0, 0.0),
+            (0.0, 3.0),
+            (3.0, 3.0),
+            (3.0, 1.55),
+            (2.0, 1.55),
+            (2.0, 2.0),
+            (1.0, 2.0),
+            (1.0, 1.0),
+            (2.0, 1.0),
+            (2.0, 1.45),
+            (3.0, 1.45),
+            (3.0, 0.0),
+        ]);
+        let b = loop_buffer(&l, -0.01f64.to_radians(), 4);
+        assert_eq!(1, b.len());
+        assert_eq!(0, b[0].num_holes());
+        assert!(b[0].contains_position(nv(0.5, 0.5)));
+        assert!(b[0].contains_position(nv(2.5, 2.5)));
+        assert!(!b[0].contains_position(nv(1.5, 1.5)));
+        assert!(!b[0].contains_position(nv(2.5, 1.5)));
+        assert!(!b[0].contains_position(nv(0.005, 1.5)));
+    }
+
+    #[test]
+    fn loop_buffer_inward_splits() {
+        // 2 squares joined by a narrow corridor.
+        let l = lp(&[
+            (0.0, 0.0),
+            (0.0, 2.0),
+            (0.9, 2.0),
+            (0.9, 3.0),
+            (0.0, 3.0),
+            (0.0, 5.0),
+            (2.0, 5.0),
+            (2.0, 3.0),
+            (1.1, 3.0),
+            (1.1, 2.0),
+            (2.0, 2.0),
+            (2.0, 0.0),
+        ]);
+        let b = loop_buffer(&l, -0.2f64.to_radians(), 4);
+        assert_eq!(2, b.len());
+        assert!(contains(&b, nv(1.0, 1.0)));
+        assert!(contains(&b, nv(1.0, 4.0)));
+        assert!(!contains(&b, nv(1.0, 2.5)));
+    }
+
+    #[test]
+    fn loop_buffer_inward_vanishes() {
+        let l = lp(&[(0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0)]);
+        assert!(loop_buffer(&l, -0.6f64.to_radians(), 4).is_empty());
+    }
+}
@@ -1,8 +1,28 @@
-use std::f64::consts::PI;
+use std::{f64::consts::PI, fmt};
 
-use crate::{Angle, LatLong, Mat33, NVector, Vec3};
+use crate::{numbers::Xorshift, Angle, LatLong, Length, Mat33, NVector, Vec3};
 
-use super::{ChordLength, Sphere};
+use super::{ChordLength, Loop, Rectangle, Sphere};
+
+/// Error returned when computing the [minimal enclosing cap](crate::spherical::Cap::minimal_enclosing) of positions.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum EnclosingCapError {
+    /// The positions do not fit in an open hemisphere: any enclosing cap has a radius of at least 90 degrees and
+    /// the minimal enclosing cap is not unique.
+    NotWithinHemisphere,
+}
+
+impl fmt::Display for EnclosingCapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EnclosingCapError::NotWithinHemisphere => {
+                write!(f, "positions do not fit in an open hemisphere")
+            }
+        }
+    }
+}
+
+impl std::error::Error for EnclosingCapError {}
 
 /// A [spherical cap](https://en.wikipedia.org/wiki/Spherical_cap): a portion of a sphere cut off by a plane.
 /// This struct and implementation is very much based on [S2Cap](https://github.com/google/s2geometry/blob/master/src/s2/s2cap.h).
@@ -61,6 +81,93 @@ impl Cap {
         Self { centre, radius }
     }
 
+    /// Computes the smallest cap which contains all the given positions using
+    /// [Welzl's algorithm](https://en.wikipedia.org/wiki/Smallest-circle_problem#Welzl's_algorithm).
+    ///
+    /// The minimal enclosing cap is only well defined if the given positions fit in an open hemisphere (i.e. the
+    /// radius of the cap is less than 90 degrees): [EnclosingCapError::NotWithinHemisphere] is returned otherwise. An
+    /// [empty](crate::spherical::Cap::EMPTY) cap is returned if no position is given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong, NVector};
+    /// use jord::spherical::{Cap, EnclosingCapError};
+    ///
+    /// let ps = vec![
+    ///     NVector::from_lat_long_degrees(0.0, -10.0),
+    ///     NVector::from_lat_long_degrees(0.0, 10.0),
+    ///     NVector::from_lat_long_degrees(1.0, 0.0),
+    /// ];
+    /// let cap = Cap::minimal_enclosing(&ps).unwrap();
+    /// assert_eq!(LatLong::from_degrees(0.0, 0.0), LatLong::from_nvector(cap.centre()).round_d7());
+    /// assert_eq!(Angle::from_degrees(10.0), cap.radius().round_d7());
+    ///
+    /// let ps = vec![
+    ///     NVector::from_lat_long_degrees(90.0, 0.0),
+    ///     NVector::from_lat_long_degrees(-30.0, 0.0),
+    ///     NVector::from_lat_long_degrees(-30.0, 120.0),
+    ///     NVector::from_lat_long_degrees(-30.0, -120.0),
+    /// ];
+    /// assert_eq!(Err(EnclosingCapError::NotWithinHemisphere), Cap::minimal_enclosing(&ps));
+    /// ```
+    pub fn minimal_enclosing(ps: &[NVector]) -> Result<Self, EnclosingCapError> {
+        // Welzl's algorithm runs in expected linear time only if the positions are processed in random order: ordered
+        // inputs (e.g. a track) are otherwise cubic.
+        let ps = shuffled(ps);
+        let mut cap = Self::EMPTY;
+        for (i, pi) in ps.iter().enumerate() {
+            if cap.contains_position(*pi) {
+                continue;
+            }
+            // pi is on the boundary of the minimal enclosing cap of ps[0..=i].
+            cap = Self::from_centre_and_radius(*pi, Angle::ZERO);
+            for (j, pj) in ps[..i].iter().enumerate() {
+                if cap.contains_position(*pj) {
+                    continue;
+                }
+                // pi and pj are on the boundary of the minimal enclosing cap of ps[0..=j] and pi.
+                cap = Self::from_diameter(*pi, *pj)?;
+                for pk in ps[..j].iter() {
+                    if !cap.contains_position(*pk) {
+                        cap = Self::from_triangle(*pi, *pj, *pk);
+                        if cap.radius() >= Angle::QUARTER_CIRCLE {
+                            return Err(EnclosingCapError::NotWithinHemisphere);
+                        }
+                    }
+                }
+            }
+        }
+        if cap.is_empty() {
+            return Ok(cap);
+        }
+        // make sure that floating-point errors do not leave any position outside of the cap.
+        let radius = ps
+            .iter()
+            .map(|p| ChordLength::new(cap.centre, *p))
+            .max()
+            .unwrap_or(ChordLength::ZERO);
+        if radius >= ChordLength::from_angle(Angle::QUARTER_CIRCLE) {
+            Err(EnclosingCapError::NotWithinHemisphere)
+        } else {
+            Ok(Self {
+                centre: cap.centre,
+                radius,
+            })
+        }
+    }
+
+    /// Returns the smallest cap whose boundary passes by the 2 given positions, or an error if the given positions
+    /// are antipodal.
+    fn from_diameter(a: NVector, b: NVector) -> Result<Self, EnclosingCapError> {
+        if a.is_antipode_of(b) {
+            return Err(EnclosingCapError::NotWithinHemisphere);
+        }
+        let centre = NVector::new((a.as_vec3() + b.as_vec3()).unit());
+        let radius = ChordLength::new(a, centre).max(ChordLength::new(b, centre));
+        Ok(Self { centre, radius })
+    }
+
     /// Determines whether this cap is [full](crate::spherical::Cap::FULL).
     pub fn is_full(&self) -> bool {
         self.radius == ChordLength::MAX
@@ -153,11 +260,113 @@ impl Cap {
         if self.is_full() || other.is_empty() {
             true
         } else {
-            self.radius.length2()
-                >= ChordLength::new(self.centre, other.centre).length2() + other.radius.length2()
+            self.radius() >= Sphere::angle(self.centre, other.centre) + other.radius()
+        }
+    }
+
+    /// Determines whether this cap intersects the given cap (including the boundary of both caps): i.e. whether both
+    /// caps have at least one position in common.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, NVector};
+    /// use jord::spherical::Cap;
+    ///
+    /// let cap1 = Cap::from_centre_and_radius(
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     Angle::from_degrees(10.0)
+    /// );
+    ///
+    /// let cap2 = Cap::from_centre_and_radius(
+    ///     NVector::from_lat_long_degrees(0.0, 15.0),
+    ///     Angle::from_degrees(10.0)
+    /// );
+    ///
+    /// let cap3 = Cap::from_centre_and_radius(
+    ///     NVector::from_lat_long_degrees(0.0, 25.0),
+    ///     Angle::from_degrees(10.0)
+    /// );
+    ///
+    /// assert!(cap1.intersects(cap2));
+    /// assert!(!cap1.intersects(cap3));
+    /// ```
+    pub fn intersects(&self, other: Self) -> bool {
+        if self.is_empty() || other.is_empty() {
+            false
+        } else if self.is_full() || other.is_full() {
+            true
+        } else {
+            Sphere::angle(self.centre, other.centre) <= self.radius() + other.radius()
         }
     }
 
+    /// Determines whether this cap intersects the given loop (including the boundary of both): i.e. whether this
+    /// cap and the loop have at least one position in common.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, NVector};
+    /// use jord::spherical::{Cap, Loop};
+    ///
+    /// let l = Loop::new(&vec![
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(0.0, 10.0),
+    ///     NVector::from_lat_long_degrees(10.0, 5.0),
+    /// ]);
+    ///
+    /// // the cap intersects an edge of the loop but contains none of its vertices.
+    /// let cap = Cap::from_centre_and_radius(
+    ///     NVector::from_lat_long_degrees(-1.0, 5.0),
+    ///     Angle::from_degrees(2.0)
+    /// );
+    /// assert!(cap.intersects_loop(&l));
+    ///
+    /// let cap = Cap::from_centre_and_radius(
+    ///     NVector::from_lat_long_degrees(-3.0, 5.0),
+    ///     Angle::from_degrees(2.0)
+    /// );
+    /// assert!(!cap.intersects_loop(&l));
+    /// ```
+    pub fn intersects_loop(&self, l: &Loop) -> bool {
+        self.intersects_loop_impl(l, false)
+    }
+
+    /// Determines whether the interior of this cap intersects the given loop.
+    pub(crate) fn interior_intersects_loop(&self, l: &Loop) -> bool {
+        self.intersects_loop_impl(l, true)
+    }
+
+    /// Determines whether this cap (or its interior) intersects the given loop.
+    fn intersects_loop_impl(&self, l: &Loop, interior: bool) -> bool {
+        if self.is_empty() || l.is_empty() {
+            return false;
+        }
+        let inside = |p: NVector| {
+            if interior {
+                self.interior_contains_position(p)
+            } else {
+                self.contains_position(p)
+            }
+        };
+        if l.iter_vertices().any(|v| inside(*v)) {
+            return true;
+        }
+        // none of the vertices is in the cap: either the cap is within the loop, or the cap intersects an edge or
+        // the cap and the loop are disjoint.
+        if l.contains_position(self.centre) || l.any_edge_contains_position(self.centre) {
+            return true;
+        }
+        for e in l.iter_edges() {
+            let d = e.distance_to(self.centre);
+            if d < self.radius || (!interior && d == self.radius) {
+                return true;
+            }
+        }
+        false
+    }
+
     /// Returns the smallest cap which encloses this cap and the other given cap.
     pub fn union(&self, other: Self) -> Self {
         if self.radius < other.radius {
@@ -211,6 +420,158 @@ impl Cap {
         self.radius.to_angle()
     }
 
+    /// Returns the minimal [Rectangle] containing this cap. The returned rectangle is expanded by 1e-7 degrees to
+    /// absorb the floating-point error introduced when converting between [NVector] and [LatLong].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong, NVector};
+    /// use jord::spherical::Cap;
+    ///
+    /// let cap = Cap::from_centre_and_radius(
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     Angle::from_degrees(10.0)
+    /// );
+    ///
+    /// let r = cap.bound();
+    /// assert_eq!(LatLong::from_degrees(10.0, 10.0), r.north_east().round_d5());
+    /// assert_eq!(LatLong::from_degrees(-10.0, -10.0), r.south_west().round_d5());
+    /// ```
+    pub fn bound(&self) -> Rectangle {
+        if self.is_empty() {
+            return Rectangle::EMPTY;
+        }
+        let centre = LatLong::from_nvector(self.centre);
+        let radius = self.radius().as_radians();
+        let lat = centre.latitude().as_radians();
+        let lng = centre.longitude().as_radians();
+        let mut all_longitudes = false;
+
+        let mut south = lat - radius;
+        if south <= -PI / 2.0 {
+            south = -PI / 2.0;
+            all_longitudes = true;
+        }
+        let mut north = lat + radius;
+        if north >= PI / 2.0 {
+            north = PI / 2.0;
+            all_longitudes = true;
+        }
+
+        let mut west = -PI;
+        let mut east = PI;
+        if !all_longitudes {
+            // law of sines for the spherical triangle formed by the north pole, the centre of the cap and the
+            // position of the boundary of the cap with the greatest longitude.
+            let sin_a = radius.sin();
+            let sin_c = lat.cos();
+            if sin_a <= sin_c {
+                let angle_a = (sin_a / sin_c).asin();
+                west = remainder(lng - angle_a);
+                east = remainder(lng + angle_a);
+                if west == -PI {
+                    west = PI;
+                }
+            }
+        }
+        Rectangle::from_nesw(
+            Angle::from_radians(north),
+            Angle::from_radians(east),
+            Angle::from_radians(south),
+            Angle::from_radians(west),
+        )
+        .expand(Angle::from_degrees(1.0e-7))
+    }
+
+    /// Returns the spherical excess of this cap: i.e. the solid angle subtended by this cap, which is also the
+    /// area of this cap on the unit sphere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64::consts::PI;
+    ///
+    /// use jord::{Angle, NVector};
+    /// use jord::spherical::{Cap, Sphere};
+    ///
+    /// // northern hemisphere.
+    /// let cap = Cap::from_centre_and_radius(
+    ///     NVector::from_lat_long_degrees(90.0, 0.0),
+    ///     Angle::QUARTER_CIRCLE
+    /// );
+    ///
+    /// let se = cap.spherical_excess();
+    /// assert_eq!(Angle::from_radians(2.0 * PI), se.round_d7());
+    ///
+    /// // area in km^2 (on Earth):
+    /// let r = Sphere::EARTH.radius().as_kilometres();
+    /// assert_eq!(255_032_300.0, (se.as_radians() * r * r).round());
+    /// ```
+    pub fn spherical_excess(&self) -> Angle {
+        if self.is_empty() {
+            Angle::ZERO
+        } else {
+            // 2 * PI * height of the cap, where height = 1 - cos(radius) = chord length squared / 2.
+            Angle::from_radians(PI * self.radius.length2())
+        }
+    }
+
+    /// Returns a cap with the same centre as this cap and whose radius is the radius of this cap expanded by the
+    /// given angle. A negative angle shrinks this cap; the returned cap is [empty](crate::spherical::Cap::EMPTY) if
+    /// the resulting radius is negative and [full](crate::spherical::Cap::FULL) if it is at least 180 degrees. The
+    /// empty cap is returned unmodified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, NVector};
+    /// use jord::spherical::Cap;
+    ///
+    /// let cap = Cap::from_centre_and_radius(
+    ///     NVector::from_lat_long_degrees(55.6050, 13.0038),
+    ///     Angle::from_degrees(1.0)
+    /// );
+    ///
+    /// let expanded = cap.expand(Angle::from_degrees(0.5));
+    /// assert_eq!(cap.centre(), expanded.centre());
+    /// assert_eq!(Angle::from_degrees(1.5), expanded.radius().round_d7());
+    /// assert!(cap.expand(Angle::from_degrees(-2.0)).is_empty());
+    /// ```
+    pub fn expand(&self, amount: Angle) -> Self {
+        if self.is_empty() {
+            return *self;
+        }
+        let radius = self.radius() + amount;
+        if radius < Angle::ZERO {
+            Self::EMPTY
+        } else if radius >= Angle::HALF_CIRCLE {
+            Self::FULL
+        } else {
+            Self::from_centre_and_radius(self.centre, radius)
+        }
+    }
+
+    /// Returns a cap with the same centre as this cap and whose radius is the radius of this cap expanded by the
+    /// given distance on the given sphere - see [expand](crate::spherical::Cap::expand).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, Length, NVector};
+    /// use jord::spherical::{Cap, Sphere};
+    ///
+    /// let centre = NVector::from_lat_long_degrees(55.6050, 13.0038);
+    /// let cap = Cap::from_centre_and_radius(centre, Angle::from_degrees(1.0));
+    ///
+    /// // 1 degree is ~111.2 km on Earth.
+    /// let expanded = cap.expand_by_distance(Length::from_kilometres(111.195), &Sphere::EARTH);
+    /// assert_eq!(Angle::from_degrees(2.0), expanded.radius().round_d5());
+    /// ```
+    pub fn expand_by_distance(&self, distance: Length, sphere: &Sphere) -> Self {
+        self.expand(sphere.distance_to_angle(distance))
+    }
+
     /// Returns the list of vertices defining the boundary of this cap. If this cap is [empty](crate::spherical::Cap::EMPTY)
     /// or [full](crate::spherical::Cap::FULL) the returned vector is empty, otherwise it contains `max(3, nb_vertices)` vertices.
     ///
@@ -282,9 +643,30 @@ impl Cap {
     }
 }
 
+/// the given angle (radians) in the range [-PI, PI].
+fn remainder(a: f64) -> f64 {
+    a - 2.0 * PI * (a / (2.0 * PI)).round()
+}
+
+/// Returns a copy of the given positions shuffled in a deterministic pseudo-random order (Fisher-Yates shuffle
+/// driven by a fixed-seed generator).
+fn shuffled(ps: &[NVector]) -> Vec<NVector> {
+    let mut res = ps.to_vec();
+    let mut rng = Xorshift::new(0x9E37_79B9_7F4A_7C15);
+    for i in (1..res.len()).rev() {
+        let j = rng.below(i as u64 + 1) as usize;
+        res.swap(i, j);
+    }
+    res
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{positions::assert_nv_eq_d7, spherical::Cap, Angle, LatLong, NVector};
+    use crate::{
+        positions::assert_nv_eq_d7,
+        spherical::{Cap, EnclosingCapError, Loop, Sphere},
+        Angle, LatLong, Length, NVector,
+    };
     use std::f64::consts::PI;
 
     #[test]
@@ -318,6 +700,117 @@ mod tests {
         assert!((o.radius.length2() - cap.radius.length2()).abs() < 1e-16);
     }
 
+    #[test]
+    fn minimal_enclosing_empty_and_single() {
+        assert_eq!(Ok(Cap::EMPTY), Cap::minimal_enclosing(&[]));
+        let p = NVector::from_lat_long_degrees(45.0, 45.0);
+        let c = Cap::minimal_enclosing(&[p, p]).unwrap();
+        assert_eq!(p, c.centre());
+        assert_eq!(Angle::ZERO, c.radius());
+    }
+
+    #[test]
+    fn minimal_enclosing_two_positions() {
+        let c = Cap::minimal_enclosing(&[
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(0.0, 20.0),
+        ])
+        .unwrap();
+        assert_eq!(
+            LatLong::from_degrees(0.0, 10.0),
+            LatLong::from_nvector(c.centre()).round_d7()
+        );
+        assert_eq!(Angle::from_degrees(10.0), c.radius().round_d7());
+    }
+
+    #[test]
+    fn minimal_enclosing_obtuse_triangle() {
+        // the circumcircle is not the minimal enclosing cap of an obtuse triangle.
+        let a = NVector::from_lat_long_degrees(0.0, -10.0);
+        let b = NVector::from_lat_long_degrees(0.0, 10.0);
+        let c = NVector::from_lat_long_degrees(1.0, 0.0);
+        let cap = Cap::minimal_enclosing(&[c, a, b]).unwrap();
+        assert!(cap.radius() < Cap::from_triangle(a, b, c).radius());
+        assert_eq!(Angle::from_degrees(10.0), cap.radius().round_d7());
+    }
+
+    #[test]
+    fn minimal_enclosing_acute_triangle() {
+        let a = NVector::from_lat_long_degrees(0.0, 0.0);
+        let b = NVector::from_lat_long_degrees(0.0, 10.0);
+        let c = NVector::from_lat_long_degrees(8.0, 5.0);
+        let cap = Cap::minimal_enclosing(&[a, b, c]).unwrap();
+        let circumcircle = Cap::from_triangle(a, b, c);
+        assert_nv_eq_d7(circumcircle.centre(), cap.centre());
+        assert_eq!(circumcircle.radius().round_d7(), cap.radius().round_d7());
+    }
+
+    #[test]
+    fn minimal_enclosing_many_positions() {
+        let mut ps = Vec::new();
+        for lat in -5..=5 {
+            for lng in -5..=5 {
+                ps.push(NVector::from_lat_long_degrees(
+                    lat as f64 + 50.0,
+                    lng as f64 * 1.5 + 10.0,
+                ));
+            }
+        }
+        let cap = Cap::minimal_enclosing(&ps).unwrap();
+        assert!(ps.iter().all(|p| cap.contains_position(*p)));
+        // at least 2 positions are on the boundary: the cap cannot be shrunk.
+        let on_boundary = ps
+            .iter()
+            .filter(|p| {
+                (Sphere::angle(cap.centre(), **p) - cap.radius()).abs() < Angle::from_degrees(1e-9)
+            })
+            .count();
+        assert!(on_boundary >= 2);
+        let shrunk = cap.expand(Angle::from_degrees(-1e-6));
+        assert!(!ps.iter().all(|p| shrunk.contains_position(*p)));
+    }
+
+    #[test]
+    fn minimal_enclosing_ordered_positions() {
+        // positions along a spiral track: processed in the given order, Welzl's algorithm would be cubic.
+        let n = 50_000;
+        let ps: Vec<NVector> = (0..n)
+            .map(|i| {
+                let t = i as f64 / n as f64;
+                let a = t * 40.0 * std::f64::consts::PI;
+                NVector::from_lat_long_degrees(45.0 + 5.0 * t * a.sin(), 5.0 * t * a.cos())
+            })
+            .collect();
+        let cap = Cap::minimal_enclosing(&ps).unwrap();
+        assert!(ps.iter().all(|p| cap.contains_position(*p)));
+        let shrunk = cap.expand(Angle::from_degrees(-1e-6));
+        assert!(!ps.iter().all(|p| shrunk.contains_position(*p)));
+    }
+
+    #[test]
+    fn minimal_enclosing_not_within_hemisphere() {
+        assert_eq!(
+            Err(EnclosingCapError::NotWithinHemisphere),
+            Cap::minimal_enclosing(&[
+                NVector::from_lat_long_degrees(10.0, 20.0),
+                NVector::from_lat_long_degrees(-10.0, -160.0),
+            ])
+        );
+        assert_eq!(
+            Err(EnclosingCapError::NotWithinHemisphere),
+            Cap::minimal_enclosing(&[
+                NVector::from_lat_long_degrees(90.0, 0.0),
+                NVector::from_lat_long_degrees(-30.0, 0.0),
+                NVector::from_lat_long_degrees(-30.0, 120.0),
+                NVector::from_lat_long_degrees(-30.0, -120.0),
+            ])
+        );
+        assert_eq!(
+            "positions do not fit in an open hemisphere",
+            EnclosingCapError::NotWithinHemisphere.to_string()
+        );
+    }
+
     #[test]
     fn complement() {
         let np = NVector::from_lat_long_degrees(90.0, 0.0);
@@ -369,6 +862,22 @@ mod tests {
         );
         assert!(!c.contains_cap(o));
         assert!(o.contains_cap(c));
+
+        // different centres.
+        let a = Cap::from_centre_and_radius(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            Angle::from_degrees(10.0),
+        );
+        let b = Cap::from_centre_and_radius(
+            NVector::from_lat_long_degrees(0.0, 5.0),
+            Angle::from_degrees(4.0),
+        );
+        let c = Cap::from_centre_and_radius(
+            NVector::from_lat_long_degrees(0.0, 5.0),
+            Angle::from_degrees(6.0),
+        );
+        assert!(a.contains_cap(b));
+        assert!(!a.contains_cap(c));
     }
 
     #[test]
@@ -470,4 +979,177 @@ mod tests {
                 .collect::<Vec<_>>()
         );
     }
+
+    #[test]
+    fn intersects() {
+        let a = Cap::from_centre_and_radius(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            Angle::from_degrees(10.0),
+        );
+        let b = Cap::from_centre_and_radius(
+            NVector::from_lat_long_degrees(0.0, 19.0),
+            Angle::from_degrees(10.0),
+        );
+        let c = Cap::from_centre_and_radius(
+            NVector::from_lat_long_degrees(0.0, 21.0),
+            Angle::from_degrees(10.0),
+        );
+        assert!(a.intersects(b));
+        assert!(b.intersects(a));
+        assert!(!a.intersects(c));
+        assert!(a.intersects(a));
+        assert!(a.intersects(Cap::FULL));
+        assert!(!a.intersects(Cap::EMPTY));
+        assert!(!Cap::EMPTY.intersects(Cap::FULL));
+    }
+
+    #[test]
+    fn intersects_loop() {
+        let l = Loop::new(&[
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(0.0, 10.0),
+            NVector::from_lat_long_degrees(10.0, 10.0),
+            NVector::from_lat_long_degrees(10.0, 0.0),
+        ]);
+
+        // contains a vertex.
+        let c = Cap::from_centre_and_radius(
+            NVector::from_lat_long_degrees(-1.0, -1.0),
+            Angle::from_degrees(2.0),
+        );
+        assert!(c.intersects_loop(&l));
+
+        // within the loop.
+        let c = Cap::from_centre_and_radius(
+            NVector::from_lat_long_degrees(5.0, 5.0),
+            Angle::from_degrees(1.0),
+        );
+        assert!(c.intersects_loop(&l));
+
+        // crosses an edge.
+        let c = Cap::from_centre_and_radius(
+            NVector::from_lat_long_degrees(5.0, 11.0),
+            Angle::from_degrees(2.0),
+        );
+        assert!(c.intersects_loop(&l));
+
+        // disjoint.
+        let c = Cap::from_centre_and_radius(
+            NVector::from_lat_long_degrees(5.0, 13.0),
+            Angle::from_degrees(2.0),
+        );
+        assert!(!c.intersects_loop(&l));
+
+        assert!(Cap::FULL.intersects_loop(&l));
+        assert!(!Cap::EMPTY.intersects_loop(&l));
+        assert!(!Cap::FULL.intersects_loop(&Loop::EMPTY));
+    }
+
+    #[test]
+    fn interior_intersects_loop() {
+        let l = Loop::new(&[
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(0.0, 10.0),
+            NVector::from_lat_long_degrees(10.0, 10.0),
+            NVector::from_lat_long_degrees(10.0, 0.0),
+        ]);
+        // touches a vertex of the loop.
+        let c = Cap::from_centre_and_boundary_position(
+            NVector::from_lat_long_degrees(-5.0, -5.0),
+            NVector::from_lat_long_degrees(0.0, 0.0),
+        );
+        assert!(c.intersects_loop(&l));
+        assert!(!c.interior_intersects_loop(&l));
+    }
+
+    #[test]
+    fn bound() {
+        assert!(Cap::EMPTY.bound().is_empty());
+        assert!(Cap::FULL.bound().is_full());
+
+        let r = Cap::from_centre_and_radius(
+            NVector::from_lat_long_degrees(45.0, 90.0),
+            Angle::from_degrees(10.0),
+        )
+        .bound();
+        assert_eq!(
+            Angle::from_degrees(55.0),
+            r.north_east().latitude().round_d5()
+        );
+        assert_eq!(
+            Angle::from_degrees(35.0),
+            r.south_west().latitude().round_d5()
+        );
+        // asin(sin(10) / cos(45)).
+        assert_eq!(
+            Angle::from_degrees(104.21585),
+            r.north_east().longitude().round_d5()
+        );
+        assert_eq!(
+            Angle::from_degrees(75.78415),
+            r.south_west().longitude().round_d5()
+        );
+
+        // includes the south pole.
+        let r = Cap::from_centre_and_radius(
+            NVector::from_lat_long_degrees(-85.0, 0.0),
+            Angle::from_degrees(10.0),
+        )
+        .bound();
+        assert!(r.is_longitude_full());
+        assert_eq!(
+            Angle::from_degrees(-75.0),
+            r.north_east().latitude().round_d5()
+        );
+    }
+
+    #[test]
+    fn spherical_excess() {
+        assert_eq!(Angle::ZERO, Cap::EMPTY.spherical_excess());
+        assert_eq!(Angle::from_radians(4.0 * PI), Cap::FULL.spherical_excess());
+        let c = Cap::from_centre_and_radius(
+            NVector::from_lat_long_degrees(10.0, 20.0),
+            Angle::from_degrees(60.0),
+        );
+        // 2 * PI * (1 - cos(60))
+        assert_eq!(Angle::from_radians(PI), c.spherical_excess().round_d7());
+    }
+
+    #[test]
+    fn expand() {
+        let c = Cap::from_centre_and_radius(
+            NVector::from_lat_long_degrees(10.0, 20.0),
+            Angle::from_degrees(5.0),
+        );
+        let e = c.expand(Angle::from_degrees(5.0));
+        assert_eq!(c.centre(), e.centre());
+        assert_eq!(Angle::from_degrees(10.0), e.radius().round_d7());
+        assert!(e.contains_cap(c));
+
+        let s = c.expand(Angle::from_degrees(-4.0));
+        assert_eq!(Angle::from_degrees(1.0), s.radius().round_d7());
+        assert_eq!(
+            Angle::ZERO,
+            c.expand(Angle::from_degrees(-5.0)).radius().round_d7()
+        );
+        assert!(c.expand(Angle::from_degrees(-6.0)).is_empty());
+        assert!(c.expand(Angle::from_degrees(175.0)).is_full());
+        assert!(Cap::EMPTY.expand(Angle::from_degrees(10.0)).is_empty());
+        assert!(!Cap::FULL.expand(Angle::from_degrees(-10.0)).is_full());
+    }
+
+    #[test]
+    fn expand_by_distance() {
+        let c = Cap::from_centre_and_radius(
+            NVector::from_lat_long_degrees(10.0, 20.0),
+            Angle::from_degrees(5.0),
+        );
+        let d = Length::from_kilometres(100.0);
+        let e = c.expand_by_distance(d, &Sphere::EARTH);
+        let b = e.boundary(3)[0];
+        assert_eq!(
+            (Sphere::EARTH.angle_to_distance(Angle::from_degrees(5.0)) + d).round_mm(),
+            Sphere::EARTH.distance(c.centre(), b).round_mm()
+        );
+    }
 }
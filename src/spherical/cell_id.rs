@@ -0,0 +1,722 @@
+use std::fmt;
+
+use crate::{NVector, Vec3};
+
+use super::{Cap, ChordLength, Loop, Rectangle};
+
+/// number of levels below the face cells.
+const MAX_LEVEL: u8 = 30;
+
+/// number of bits of the position along the Hilbert curve (including the trailing 1).
+const POS_BITS: u32 = 2 * MAX_LEVEL as u32 + 1;
+
+/// number of leaf cells along each side of a face.
+const MAX_SIZE: i64 = 1 << MAX_LEVEL;
+
+/// Hilbert curve: position of the sub-cell (i, j) (given as `2 * i + j`) for each orientation.
+const IJ_TO_POS: [[u64; 4]; 4] = [[0, 1, 3, 2], [0, 3, 1, 2], [2, 3, 1, 0], [2, 1, 3, 0]];
+
+/// Hilbert curve: sub-cell (i, j) (given as `2 * i + j`) of the position for each orientation.
+const POS_TO_IJ: [[u64; 4]; 4] = [[0, 1, 3, 2], [0, 2, 3, 1], [3, 2, 0, 1], [3, 1, 0, 2]];
+
+/// Hilbert curve: orientation change (swap and/or invert) of the sub-cell at each position.
+const POS_TO_ORIENTATION: [u8; 4] = [SWAP_MASK, 0, 0, INVERT_MASK | SWAP_MASK];
+
+const SWAP_MASK: u8 = 1;
+
+const INVERT_MASK: u8 = 2;
+
+/// A 64-bit identifier of a cell of a hierarchical decomposition of the unit sphere. This struct and
+/// implementation is very much based on [S2CellId](https://github.com/google/s2geometry/blob/master/src/s2/s2cell_id.h)
+/// and identifiers are compatible with the S2 library.
+///
+/// The sphere is projected onto the 6 faces of a cube (quadratic projection); each face is recursively subdivided
+/// into 4 cells down to 30 levels. Level 0 cells are the 6 faces and level 30 cells - or 'leaf' cells, are about
+/// 1 centimetre across on Earth. The cells of each level are ordered along a [Hilbert curve](https://en.wikipedia.org/wiki/Hilbert_curve)
+/// which preserves spatial locality: the identifiers of all the descendants of a cell are within a contiguous
+/// range.
+///
+/// Cell edges are great circle arcs, therefore a cell is exactly represented by the [Loop] of its 4 vertices.
+///
+/// # Examples
+///
+/// ```
+/// use jord::NVector;
+/// use jord::spherical::CellId;
+///
+/// let p = NVector::from_lat_long_degrees(55.605, 13.0038);
+/// let leaf = CellId::from_nvector(p);
+/// assert_eq!(30, leaf.level());
+///
+/// let cell = leaf.parent_at_level(10);
+/// assert_eq!(10, cell.level());
+/// assert!(cell.contains(leaf));
+/// assert!(cell.to_loop().contains_position(p));
+/// assert_eq!(Some(cell), CellId::from_token(&cell.to_token()));
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))] // codecov:ignore:this
+pub struct CellId(u64);
+
+impl CellId {
+    /// Maximum cell level: the level of leaf cells.
+    pub const MAX_LEVEL: u8 = MAX_LEVEL;
+
+    /// Creates a [CellId] from the given 64-bit identifier; the identifier is not checked, see
+    /// [is_valid](crate::spherical::CellId::is_valid).
+    pub const fn new(id: u64) -> Self {
+        Self(id)
+    }
+
+    /// Returns the cell corresponding to the given face (in [0, 5]).
+    ///
+    /// # Panics
+    ///
+    /// If the given face is greater than 5.
+    pub fn from_face(face: u8) -> Self {
+        assert!(face < 6, "invalid face: {}", face);
+        Self(((face as u64) << POS_BITS) + lsb_for_level(0))
+    }
+
+    /// Returns the leaf cell containing the given position.
+    pub fn from_nvector(p: NVector) -> Self {
+        let (face, u, v) = xyz_to_face_uv(p.as_vec3());
+        from_face_ij(face, st_to_ij(uv_to_st(u)), st_to_ij(uv_to_st(v)))
+    }
+
+    /// Returns the cell at the given level containing the given position.
+    ///
+    /// # Panics
+    ///
+    /// If the given level is greater than [MAX_LEVEL](crate::spherical::CellId::MAX_LEVEL).
+    pub fn from_nvector_at_level(p: NVector, level: u8) -> Self {
+        Self::from_nvector(p).parent_at_level(level)
+    }
+
+    /// Returns the token of this cell: a compact hexadecimal representation of its identifier without trailing
+    /// zeros; `X` denotes the invalid identifier 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::spherical::CellId;
+    ///
+    /// assert_eq!("1", CellId::from_face(0).to_token());
+    /// assert_eq!("b", CellId::from_face(5).to_token());
+    /// assert_eq!("0c", CellId::from_face(0).child(1).to_token());
+    /// ```
+    pub fn to_token(&self) -> String {
+        if self.0 == 0 {
+            return "X".to_string();
+        }
+        let digits = 16 - (self.0.trailing_zeros() / 4) as usize;
+        format!("{:016x}", self.0)[..digits].to_string()
+    }
+
+    /// Returns the cell of the given token if it denotes a valid cell, see [to_token](crate::spherical::CellId::to_token).
+    pub fn from_token(token: &str) -> Option<Self> {
+        if token.is_empty() || token.len() > 16 || !token.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let id = u64::from_str_radix(token, 16).ok()? << (4 * (16 - token.len()));
+        Some(Self(id)).filter(|c| c.is_valid())
+    }
+
+    /// Returns the 64-bit identifier of this cell.
+    #[inline]
+    pub fn id(&self) -> u64 {
+        self.0
+    }
+
+    /// Determines whether this identifier denotes a valid cell: a face in [0, 5] and a level in [0, 30].
+    pub fn is_valid(&self) -> bool {
+        self.face() < 6 && (self.lsb() & 0x1555555555555555) != 0
+    }
+
+    /// Returns the face (in [0, 5]) of this cell.
+    #[inline]
+    pub fn face(&self) -> u8 {
+        (self.0 >> POS_BITS) as u8
+    }
+
+    /// Returns the level (in [0, 30]) of this cell.
+    pub fn level(&self) -> u8 {
+        MAX_LEVEL.saturating_sub((self.0.trailing_zeros() >> 1) as u8)
+    }
+
+    /// Determines whether this cell is a leaf cell.
+    pub fn is_leaf(&self) -> bool {
+        self.0 & 1 != 0
+    }
+
+    /// Determines whether this cell is a face cell.
+    pub fn is_face(&self) -> bool {
+        self.0 & (lsb_for_level(0) - 1) == 0
+    }
+
+    /// Returns the parent of this cell or None if this cell is a face cell.
+    pub fn parent(&self) -> Option<Self> {
+        if self.is_face() {
+            None
+        } else {
+            Some(self.parent_at_level(self.level() - 1))
+        }
+    }
+
+    /// Returns the ancestor of this cell at the given level, or this cell if the given level is the level of this
+    /// cell.
+    ///
+    /// # Panics
+    ///
+    /// If the given level is greater than the level of this cell.
+    pub fn parent_at_level(&self, level: u8) -> Self {
+        assert!(
+            level <= self.level(),
+            "level {} greater than cell level {}",
+            level,
+            self.level()
+        );
+        let lsb = lsb_for_level(level);
+        Self((self.0 & lsb.wrapping_neg()) | lsb)
+    }
+
+    /// Returns the child at the given position (in [0, 3], along the Hilbert curve) of this cell.
+    ///
+    /// # Panics
+    ///
+    /// If this cell is a leaf cell or if the given position is greater than 3.
+    pub fn child(&self, position: u8) -> Self {
+        assert!(!self.is_leaf(), "leaf cells have no children");
+        assert!(position < 4, "invalid child position: {}", position);
+        let lsb = self.lsb() >> 2;
+        Self(self.0 - self.lsb() + (2 * position as u64 + 1) * lsb)
+    }
+
+    /// Returns the 4 children of this cell in Hilbert curve order, or None if this cell is a leaf cell.
+    pub fn children(&self) -> Option<[Self; 4]> {
+        if self.is_leaf() {
+            None
+        } else {
+            Some([self.child(0), self.child(1), self.child(2), self.child(3)])
+        }
+    }
+
+    /// Returns the minimum identifier of all the descendants of this cell (leaf cell).
+    pub fn range_min(&self) -> Self {
+        Self(self.0 - (self.lsb() - 1))
+    }
+
+    /// Returns the maximum identifier of all the descendants of this cell (leaf cell).
+    pub fn range_max(&self) -> Self {
+        Self(self.0 + (self.lsb() - 1))
+    }
+
+    /// Determines whether this cell contains the given cell: i.e. whether the given cell is this cell or one of its
+    /// descendants.
+    pub fn contains(&self, o: Self) -> bool {
+        o >= self.range_min() && o <= self.range_max()
+    }
+
+    /// Determines whether this cell intersects the given cell: i.e. whether one contains the other.
+    pub fn intersects(&self, o: Self) -> bool {
+        o.range_min() <= self.range_max() && o.range_max() >= self.range_min()
+    }
+
+    /// Returns the 4 cells at the same level which share an edge with this cell, in order: bottom, right, top and
+    /// left (in the (u, v) coordinates of the face of this cell).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::spherical::CellId;
+    ///
+    /// // each face is adjacent to 4 of the other 5 faces.
+    /// let ns = CellId::from_face(0).edge_neighbours();
+    /// assert_eq!([5, 1, 2, 4], ns.map(|n| n.face()));
+    /// ```
+    pub fn edge_neighbours(&self) -> [Self; 4] {
+        let level = self.level();
+        let size = size_ij(level);
+        let (face, i, j, _) = self.to_face_ij_orientation();
+        [
+            from_face_ij_same(face, i, j - size, j - size >= 0),
+            from_face_ij_same(face, i + size, j, i + size < MAX_SIZE),
+            from_face_ij_same(face, i, j + size, j + size < MAX_SIZE),
+            from_face_ij_same(face, i - size, j, i - size >= 0),
+        ]
+        .map(|c| c.parent_at_level(level))
+    }
+
+    /// Returns the cells at the given level which share the vertex closest to this cell: 4 cells in general, or 3
+    /// cells if that vertex is a vertex of the cube. The first returned cell is the ancestor of this cell at the
+    /// given level.
+    ///
+    /// # Panics
+    ///
+    /// If the given level is not less than the level of this cell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::NVector;
+    /// use jord::spherical::CellId;
+    ///
+    /// let leaf = CellId::from_nvector(NVector::from_lat_long_degrees(55.605, 13.0038));
+    /// let ns = leaf.vertex_neighbours(10);
+    /// assert_eq!(4, ns.len());
+    /// assert!(ns[0].contains(leaf));
+    /// ```
+    pub fn vertex_neighbours(&self, level: u8) -> Vec<Self> {
+        assert!(
+            level < self.level(),
+            "level {} not less than cell level {}",
+            level,
+            self.level()
+        );
+        let (face, i, j, _) = self.to_face_ij_orientation();
+        let half_size = size_ij(level + 1);
+        let size = half_size << 1;
+        let (i_offset, i_same) = if i & half_size != 0 {
+            (size, i + size < MAX_SIZE)
+        } else {
+            (-size, i - size >= 0)
+        };
+        let (j_offset, j_same) = if j & half_size != 0 {
+            (size, j + size < MAX_SIZE)
+        } else {
+            (-size, j - size >= 0)
+        };
+        let mut res = vec![
+            self.parent_at_level(level),
+            from_face_ij_same(face, i + i_offset, j, i_same).parent_at_level(level),
+            from_face_ij_same(face, i, j + j_offset, j_same).parent_at_level(level),
+        ];
+        // if neither neighbour is on the same face, the vertex is a vertex of the cube shared by 3 cells only.
+        if i_same || j_same {
+            res.push(
+                from_face_ij_same(face, i + i_offset, j + j_offset, i_same && j_same)
+                    .parent_at_level(level),
+            );
+        }
+        res
+    }
+
+    /// Returns the centre of this cell.
+    ///
+    /// This is not the centroid of the cell on the sphere but the position corresponding to the centre of the
+    /// cell in the cube-face projection.
+    pub fn to_nvector(&self) -> NVector {
+        let (i0, j0, size) = self.ij_bounds();
+        let centre = |x0: i64| st_to_uv((2 * x0 + size) as f64 / (2 * MAX_SIZE) as f64);
+        NVector::new(face_uv_to_xyz(self.face(), centre(i0), centre(j0)).unit())
+    }
+
+    /// Returns the 4 vertices of this cell in counterclockwise order.
+    pub fn vertices(&self) -> [NVector; 4] {
+        let (face, u0, u1, v0, v1) = self.uv_bounds();
+        [(u0, v0), (u1, v0), (u1, v1), (u0, v1)]
+            .map(|(u, v)| NVector::new(face_uv_to_xyz(face, u, v).unit()))
+    }
+
+    /// Returns the [Loop] of the 4 vertices of this cell: cell edges are great circle arcs.
+    pub fn to_loop(&self) -> Loop {
+        Loop::new(&self.vertices())
+    }
+
+    /// Returns the [minimum bounding rectangle](crate::spherical::Loop::bound) of this cell.
+    pub fn rect_bound(&self) -> Rectangle {
+        self.to_loop().bound()
+    }
+
+    /// Returns a [Cap] containing this cell: centred at the [centre](crate::spherical::CellId::to_nvector) of this
+    /// cell and passing by its farthest vertex.
+    pub fn cap_bound(&self) -> Cap {
+        let centre = self.to_nvector();
+        let farthest = self
+            .vertices()
+            .into_iter()
+            .max_by_key(|v| ChordLength::new(centre, *v))
+            .unwrap_or(centre);
+        Cap::from_centre_and_boundary_position(centre, farthest)
+    }
+
+    /// lowest set bit of this identifier.
+    fn lsb(&self) -> u64 {
+        self.0 & self.0.wrapping_neg()
+    }
+
+    /// face, (i, j) coordinates of a leaf cell within this cell and the Hilbert curve orientation of that leaf cell.
+    fn to_face_ij_orientation(self) -> (u8, i64, i64, u8) {
+        let face = self.face();
+        let mut orientation = face & SWAP_MASK;
+        let mut i: i64 = 0;
+        let mut j: i64 = 0;
+        for level in 0..MAX_LEVEL {
+            let shift = 2 * (MAX_LEVEL - 1 - level) as u64 + 1;
+            let pos = ((self.0 >> shift) & 3) as usize;
+            let ij = POS_TO_IJ[orientation as usize][pos];
+            i = (i << 1) | (ij >> 1) as i64;
+            j = (j << 1) | (ij & 1) as i64;
+            orientation ^= POS_TO_ORIENTATION[pos];
+        }
+        (face, i, j, orientation)
+    }
+
+    /// (i, j) of the lower left leaf cell of this cell and size of this cell in leaf cells.
+    fn ij_bounds(&self) -> (i64, i64, i64) {
+        let size = size_ij(self.level());
+        let (_, i, j, _) = self.to_face_ij_orientation();
+        (i & -size, j & -size, size)
+    }
+
+    /// face and (u, v) bounds of this cell.
+    fn uv_bounds(&self) -> (u8, f64, f64, f64, f64) {
+        let (i0, j0, size) = self.ij_bounds();
+        let uv = |x: i64| st_to_uv(x as f64 / MAX_SIZE as f64);
+        (self.face(), uv(i0), uv(i0 + size), uv(j0), uv(j0 + size))
+    }
+}
+
+impl fmt::Display for CellId {
+    /// Formats this cell as its face followed by the position of each level along the Hilbert curve, e.g.
+    /// `3/0213`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.is_valid() {
+            return write!(f, "Invalid: {:016x}", self.0);
+        }
+        write!(f, "{}/", self.face())?;
+        for level in 1..=self.level() {
+            let shift = 2 * (MAX_LEVEL - level) as u64 + 1;
+            write!(f, "{}", (self.0 >> shift) & 3)?;
+        }
+        Ok(())
+    }
+}
+
+/// lowest set bit of the identifiers of the cells at the given level.
+fn lsb_for_level(level: u8) -> u64 {
+    1 << (2 * (MAX_LEVEL - level))
+}
+
+/// size of the cells at the given level in leaf cells.
+fn size_ij(level: u8) -> i64 {
+    1 << (MAX_LEVEL - level)
+}
+
+/// leaf cell at the given face and (i, j) coordinates.
+fn from_face_ij(face: u8, i: i64, j: i64) -> CellId {
+    let mut orientation = face & SWAP_MASK;
+    let mut pos: u64 = 0;
+    for level in 0..MAX_LEVEL {
+        let shift = MAX_LEVEL - 1 - level;
+        let ij = (((i >> shift) & 1) << 1 | ((j >> shift) & 1)) as usize;
+        let p = IJ_TO_POS[orientation as usize][ij];
+        pos = (pos << 2) | p;
+        orientation ^= POS_TO_ORIENTATION[p as usize];
+    }
+    CellId(((face as u64) << POS_BITS) | (pos << 1) | 1)
+}
+
+/// leaf cell at the given face and (i, j) coordinates, (i, j) being possibly outside of the face.
+fn from_face_ij_same(face: u8, i: i64, j: i64, same_face: bool) -> CellId {
+    if same_face {
+        from_face_ij(face, i, j)
+    } else {
+        from_face_ij_wrap(face, i, j)
+    }
+}
+
+/// leaf cell at the given (i, j) coordinates just outside of the given face: the (u, v) coordinates of the
+/// position are projected to the adjacent face.
+fn from_face_ij_wrap(face: u8, i: i64, j: i64) -> CellId {
+    let i = i.clamp(-1, MAX_SIZE);
+    let j = j.clamp(-1, MAX_SIZE);
+    // linear projection is sufficient since only the leaf cell containing the position is of interest.
+    let scale = 1.0 / MAX_SIZE as f64;
+    let limit = 1.0 + f64::EPSILON;
+    let u = (scale * (2 * (i - MAX_SIZE / 2) + 1) as f64).clamp(-limit, limit);
+    let v = (scale * (2 * (j - MAX_SIZE / 2) + 1) as f64).clamp(-limit, limit);
+    let (face, u, v) = xyz_to_face_uv(face_uv_to_xyz(face, u, v));
+    from_face_ij(face, st_to_ij(0.5 * (u + 1.0)), st_to_ij(0.5 * (v + 1.0)))
+}
+
+/// face and (u, v) coordinates of the given vector.
+fn xyz_to_face_uv(p: Vec3) -> (u8, f64, f64) {
+    let (x, y, z) = (p.x(), p.y(), p.z());
+    let (ax, ay, az) = (x.abs(), y.abs(), z.abs());
+    let axis = if ax > ay {
+        if ax > az {
+            0
+        } else {
+            2
+        }
+    } else if ay > az {
+        1
+    } else {
+        2
+    };
+    let negative = [x, y, z][axis] < 0.0;
+    let face = if negative { axis + 3 } else { axis };
+    let (u, v) = match face {
+        0 => (y / x, z / x),
+        1 => (-x / y, z / y),
+        2 => (-x / z, -y / z),
+        3 => (z / x, y / x),
+        4 => (z / y, -x / y),
+        _ => (-y / z, -x / z),
+    };
+    (face as u8, u, v)
+}
+
+/// (non unit) vector of the given face and (u, v) coordinates.
+fn face_uv_to_xyz(face: u8, u: f64, v: f64) -> Vec3 {
+    match face {
+        0 => Vec3::new(1.0, u, v),
+        1 => Vec3::new(-u, 1.0, v),
+        2 => Vec3::new(-u, -v, 1.0),
+        3 => Vec3::new(-1.0, -v, -u),
+        4 => Vec3::new(v, -1.0, -u),
+        _ => Vec3::new(v, u, -1.0),
+    }
+}
+
+/// quadratic projection from (s, t) in [0, 1] to (u, v) in [-1, 1].
+fn st_to_uv(s: f64) -> f64 {
+    if s >= 0.5 {
+        (4.0 * s * s - 1.0) / 3.0
+    } else {
+        (1.0 - 4.0 * (1.0 - s) * (1.0 - s)) / 3.0
+    }
+}
+
+/// inverse of [st_to_uv].
+fn uv_to_st(u: f64) -> f64 {
+    if u >= 0.0 {
+        0.5 * (1.0 + 3.0 * u).sqrt()
+    } else {
+        1.0 - 0.5 * (1.0 - 3.0 * u).sqrt()
+    }
+}
+
+/// leaf cell (i or j) coordinate of the given (s or t) coordinate.
+fn st_to_ij(s: f64) -> i64 {
+    ((MAX_SIZE as f64 * s).floor() as i64).clamp(0, MAX_SIZE - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::{
+        spherical::{ChordLength, Sphere},
+        Angle, LatLong, NVector,
+    };
+
+    use super::CellId;
+
+    fn positions() -> Vec<NVector> {
+        let mut ps = Vec::new();
+        for lat in (-90..=90).step_by(15) {
+            for lon in (-180..180).step_by(20) {
+                ps.push(NVector::from_lat_long_degrees(
+                    lat as f64 + 0.123,
+                    lon as f64 + 0.456,
+                ));
+            }
+        }
+        ps.push(NVector::from_lat_long_degrees(90.0, 0.0));
+        ps.push(NVector::from_lat_long_degrees(-90.0, 0.0));
+        ps
+    }
+
+    /// all cells at the given level in Hilbert curve order.
+    fn all_cells(level: u8) -> Vec<CellId> {
+        let mut cells: Vec<CellId> = (0..6).map(CellId::from_face).collect();
+        for _ in 0..level {
+            cells = cells.iter().flat_map(|c| c.children().unwrap()).collect();
+        }
+        cells
+    }
+
+    #[test]
+    fn faces() {
+        for face in 0..6 {
+            let c = CellId::from_face(face);
+            assert_eq!((2 * face as u64 + 1) << 60, c.id());
+            assert_eq!(face, c.face());
+            assert_eq!(0, c.level());
+            assert!(c.is_face());
+            assert!(c.is_valid());
+            assert_eq!(None, c.parent());
+        }
+    }
+
+    #[test]
+    fn s2_compatibility() {
+        // face 0 centre.
+        assert_eq!(
+            0x1000000000000001,
+            CellId::from_nvector(NVector::from_lat_long_degrees(0.0, 0.0)).id()
+        );
+        // faces of the axes.
+        let faces = [
+            (0.0, 0.0),
+            (0.0, 90.0),
+            (90.0, 0.0),
+            (0.0, 180.0),
+            (0.0, -90.0),
+            (-90.0, 0.0),
+        ];
+        for (face, (lat, lon)) in faces.iter().enumerate() {
+            let c = CellId::from_nvector(NVector::from_lat_long_degrees(*lat, *lon));
+            assert_eq!(face as u8, c.face());
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(!CellId::default().is_valid());
+        assert!(!CellId::new(0xd000000000000000).is_valid());
+        // lsb at an odd position.
+        assert!(!CellId::new(0x1000000000000002).is_valid());
+        assert_eq!(None, CellId::from_token("X"));
+        assert_eq!(None, CellId::from_token("zz"));
+        assert_eq!(None, CellId::from_token(""));
+        assert_eq!("X", CellId::default().to_token());
+    }
+
+    #[test]
+    fn parent_child() {
+        let leaf = CellId::from_nvector(NVector::from_lat_long_degrees(55.605, 13.0038));
+        assert!(leaf.is_leaf());
+        assert_eq!(30, leaf.level());
+        assert_eq!(None, leaf.children());
+        let mut c = leaf;
+        while let Some(p) = c.parent() {
+            assert_eq!(c.level() - 1, p.level());
+            assert!(p.contains(c));
+            assert!(p.contains(leaf));
+            assert!(p.intersects(c));
+            assert!(c.intersects(p));
+            assert!(p.children().unwrap().contains(&c));
+            c = p;
+        }
+        assert!(c.is_face());
+
+        let cell = leaf.parent_at_level(12);
+        let children = cell.children().unwrap();
+        assert_eq!(cell.range_min(), children[0].range_min());
+        assert_eq!(cell.range_max(), children[3].range_max());
+        for w in children.windows(2) {
+            assert_eq!(w[0].range_max().id() + 2, w[1].range_min().id());
+            assert!(!w[0].intersects(w[1]));
+        }
+    }
+
+    #[test]
+    fn tokens() {
+        for p in positions() {
+            for level in [0, 1, 7, 15, 29, 30] {
+                let c = CellId::from_nvector_at_level(p, level);
+                assert_eq!(Some(c), CellId::from_token(&c.to_token()));
+            }
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!("0/", CellId::from_face(0).to_string());
+        assert_eq!(
+            "3/0213",
+            CellId::from_face(3)
+                .child(0)
+                .child(2)
+                .child(1)
+                .child(3)
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        // leaf cells are less than 1e-8 radians across.
+        for p in positions() {
+            let c = CellId::from_nvector(p);
+            assert!(Sphere::angle(p, c.to_nvector()) < Angle::from_radians(1e-8));
+        }
+    }
+
+    #[test]
+    fn contains_position() {
+        for p in positions() {
+            for level in [0, 3, 10, 20] {
+                let c = CellId::from_nvector_at_level(p, level);
+                assert!(c.cap_bound().contains_position(p));
+                assert!(c.rect_bound().contains_position(LatLong::from_nvector(p)));
+                let l = c.to_loop();
+                assert!(l.contains_position(p) || l.any_edge_contains_position(p));
+                assert_eq!(c, CellId::from_nvector_at_level(c.to_nvector(), level));
+            }
+        }
+    }
+
+    #[test]
+    fn bounds() {
+        for c in all_cells(2) {
+            let cap = c.cap_bound();
+            let rect = c.rect_bound();
+            for v in c.vertices() {
+                assert!(cap.contains_position(v));
+                assert!(rect.contains_position(LatLong::from_nvector(v)));
+            }
+        }
+    }
+
+    #[test]
+    fn hilbert_curve_continuity() {
+        // consecutive cells along the Hilbert curve are edge neighbours, including across faces.
+        for level in [1, 2, 4] {
+            let cells = all_cells(level);
+            assert_eq!(6 * 4usize.pow(level as u32), cells.len());
+            for w in cells.windows(2) {
+                assert!(w[0] < w[1]);
+                assert!(w[0].edge_neighbours().contains(&w[1]), "{} {}", w[0], w[1]);
+            }
+        }
+    }
+
+    #[test]
+    fn edge_neighbours() {
+        for level in [0, 1, 3] {
+            for c in all_cells(level) {
+                let ns = c.edge_neighbours();
+                let distinct: HashSet<CellId> = ns.iter().copied().collect();
+                assert_eq!(4, distinct.len());
+                for n in ns {
+                    assert_eq!(level, n.level());
+                    assert!(n.edge_neighbours().contains(&c));
+                    // neighbours share 2 vertices.
+                    let shared = c
+                        .vertices()
+                        .iter()
+                        .filter(|v| {
+                            n.vertices().iter().any(|w| {
+                                ChordLength::new(**v, *w) < ChordLength::from_squared_length(1e-24)
+                            })
+                        })
+                        .count();
+                    assert_eq!(2, shared);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn leaf_neighbours() {
+        let c = CellId::from_nvector(NVector::from_lat_long_degrees(45.0, 45.0));
+        for n in c.edge_neighbours() {
+            assert!(n.is_leaf());
+            assert!(Sphere::angle(c.to_nvector(), n.to_nvector()) < Angle::from_radians(1e-8));
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use crate::NVector;
+
+use super::CellId;
+
+/// A set of disjoint [cells](crate::spherical::CellId) sorted by identifier, typically used to approximate a
+/// [Region](crate::spherical::Region) - see [RegionCoverer](crate::spherical::RegionCoverer).
+///
+/// This struct and implementation is very much based on [S2CellUnion](https://github.com/google/s2geometry/blob/master/src/s2/s2cell_union.h).
+///
+/// # Examples
+///
+/// ```
+/// use jord::NVector;
+/// use jord::spherical::{CellId, CellUnion};
+///
+/// let p = NVector::from_lat_long_degrees(55.605, 13.0038);
+/// let cell = CellId::from_nvector_at_level(p, 10);
+///
+/// // cells contained by other cells are removed.
+/// let u = CellUnion::new(&[cell.children().unwrap()[0], cell, CellId::from_face(0)]);
+/// assert_eq!(2, u.len());
+/// assert!(u.contains_position(p));
+/// assert!(u.contains_cell(cell.children().unwrap()[3]));
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))] // codecov:ignore:this
+pub struct CellUnion {
+    /// sorted and disjoint cells.
+    cells: Vec<CellId>,
+}
+
+impl CellUnion {
+    /// An empty [CellUnion]: contains no cell.
+    pub const EMPTY: Self = Self { cells: Vec::new() };
+
+    /// Creates a new cell union from the given cells: the given cells are sorted and any cell contained by another
+    /// given cell is discarded.
+    pub fn new(cells: &[CellId]) -> Self {
+        let mut sorted = cells.to_vec();
+        sorted.sort_unstable();
+        let mut res: Vec<CellId> = Vec::with_capacity(sorted.len());
+        for c in sorted {
+            // cells are sorted by identifier so c can only be contained by the last retained cell, however c may
+            // contain some of the last retained cells.
+            if res.last().map_or(false, |l| l.contains(c)) {
+                continue;
+            }
+            while res.last().map_or(false, |l| c.contains(*l)) {
+                res.pop();
+            }
+            res.push(c);
+        }
+        Self { cells: res }
+    }
+
+    /// Returns the cells of this union, sorted by identifier.
+    pub fn cell_ids(&self) -> &[CellId] {
+        &self.cells
+    }
+
+    /// Returns a iterator over the cells of this union, sorted by identifier.
+    pub fn iter(&self) -> impl Iterator<Item = &CellId> {
+        self.cells.iter()
+    }
+
+    /// Returns the number of cells of this union.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Determines whether this union contains no cell.
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Determines whether this union contains the given cell: i.e. whether the given cell is a cell of this union or
+    /// a descendant of a cell of this union.
+    pub fn contains_cell(&self, cell: CellId) -> bool {
+        let i = self.cells.partition_point(|c| *c < cell);
+        if i < self.cells.len() && self.cells[i].range_min() <= cell {
+            return true;
+        }
+        i > 0 && self.cells[i - 1].range_max() >= cell
+    }
+
+    /// Determines whether this union intersects the given cell: i.e. whether the given cell contains or is contained
+    /// by a cell of this union.
+    pub fn intersects_cell(&self, cell: CellId) -> bool {
+        let i = self.cells.partition_point(|c| *c < cell);
+        if i < self.cells.len() && self.cells[i].range_min() <= cell.range_max() {
+            return true;
+        }
+        i > 0 && self.cells[i - 1].range_max() >= cell.range_min()
+    }
+
+    /// Determines whether this union contains the given position: i.e. whether the [leaf cell](crate::spherical::CellId::from_nvector)
+    /// of the given position is contained by this union.
+    pub fn contains_position(&self, p: NVector) -> bool {
+        self.contains_cell(CellId::from_nvector(p))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{spherical::CellId, NVector};
+
+    use super::CellUnion;
+
+    #[test]
+    fn new_normalises() {
+        let c = CellId::from_nvector_at_level(NVector::from_lat_long_degrees(10.0, 20.0), 5);
+        let children = c.children().unwrap();
+        let other = CellId::from_face(3);
+        let u = CellUnion::new(&[children[2], other, c, children[1], c]);
+        assert_eq!(vec![c, other], u.cell_ids());
+        assert_eq!(2, u.len());
+        assert!(!u.is_empty());
+        assert!(CellUnion::new(&[]).is_empty());
+        assert_eq!(CellUnion::EMPTY, CellUnion::new(&[]));
+    }
+
+    #[test]
+    fn contains_and_intersects_cell() {
+        let c = CellId::from_nvector_at_level(NVector::from_lat_long_degrees(10.0, 20.0), 5);
+        let children = c.children().unwrap();
+        let u = CellUnion::new(&[children[0], children[3]]);
+
+        assert!(u.contains_cell(children[0]));
+        assert!(u.contains_cell(children[3].children().unwrap()[2]));
+        assert!(!u.contains_cell(children[1]));
+        assert!(!u.contains_cell(c));
+        assert!(!u.contains_cell(CellId::from_face(0)));
+
+        assert!(u.intersects_cell(c));
+        assert!(u.intersects_cell(CellId::from_face(c.face())));
+        assert!(u.intersects_cell(children[3].children().unwrap()[2]));
+        assert!(!u.intersects_cell(children[1]));
+        assert!(!u.intersects_cell(children[2].children().unwrap()[0]));
+        assert!(!CellUnion::EMPTY.intersects_cell(c));
+    }
+
+    #[test]
+    fn contains_position() {
+        let p = NVector::from_lat_long_degrees(-33.8678, 151.2153);
+        let u = CellUnion::new(&[CellId::from_nvector_at_level(p, 12)]);
+        assert!(u.contains_position(p));
+        assert!(!u.contains_position(NVector::from_lat_long_degrees(55.605, 13.0038)));
+    }
+}
@@ -0,0 +1,711 @@
+// Boolean operations between loops by overlaying their boundaries.
+//
+// The edges of both loops are split at every position where the boundaries meet - crossings of edges, vertices of
+// one loop on an edge of the other loop and vertices shared by both loops - so that the resulting sub-edges either lie
+// entirely inside or outside the other loop or are shared by both loops (same or opposite direction). The sub-edges
+// bounding the result of the operation are then selected and linked into rings, which are assembled into polygons.
+//
+// Degenerate configurations - a vertex of one loop on the boundary of the other loop, shared edges - are handled
+// explicitly: no vertex is perturbed and the vertices of the resulting rings are either vertices of the given loops or
+// positions where their boundaries cross.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{Angle, NVector};
+
+use super::{is_loop_clockwise, ChordLength, Loop, MinorArc, Polygon};
+
+/// angle in radians below which 2 positions are considered equal or a position is considered on an edge: less than
+/// a tenth of a millimetre on Earth.
+const SNAP: f64 = 1e-11;
+
+/// Boolean operation.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub(crate) enum Operation {
+    Intersection,
+    Union,
+    Difference,
+}
+
+/// Location of a sub-edge of a loop relative to the other loop.
+#[derive(PartialEq, Clone, Copy, Debug)]
+enum Location {
+    Inside,
+    Outside,
+    /// sub-edge of both loops in the same direction.
+    Shared,
+    /// sub-edge of both loops in opposite directions.
+    Opposite,
+}
+
+/// Computes the given boolean operation between the 2 given loops.
+pub(crate) fn clip(a: &Loop, b: &Loop, op: Operation) -> Vec<Polygon> {
+    if a.is_empty() || b.is_empty() {
+        return match op {
+            Operation::Intersection => Vec::new(),
+            Operation::Union => [a, b]
+                .iter()
+                .filter(|l| !l.is_empty())
+                .map(|l| Polygon::new((*l).clone(), Vec::new()))
+                .collect(),
+            Operation::Difference => to_polygons(a),
+        };
+    }
+
+    let snap = ChordLength::from_angle(Angle::from_radians(SNAP));
+
+    // nodes: vertices of a, vertices of b not equal to a vertex of a and crossings.
+    let mut nodes: Vec<NVector> = a.iter_vertices().copied().collect();
+    let a_ids: Vec<usize> = (0..nodes.len()).collect();
+    let mut b_ids: Vec<usize> = Vec::with_capacity(b.num_vertices());
+    for v in b.iter_vertices() {
+        match a_ids
+            .iter()
+            .find(|i| ChordLength::new(nodes[**i], *v) <= snap)
+        {
+            Some(i) => b_ids.push(*i),
+            None => {
+                b_ids.push(nodes.len());
+                nodes.push(*v);
+            }
+        }
+    }
+
+    // nodes inside each edge of a and b.
+    let a_edges: Vec<MinorArc> = a.iter_edges().copied().collect();
+    let b_edges: Vec<MinorArc> = b.iter_edges().copied().collect();
+    let mut a_splits: Vec<Vec<usize>> = vec![Vec::new(); a_edges.len()];
+    let mut b_splits: Vec<Vec<usize>> = vec![Vec::new(); b_edges.len()];
+    let mut meet = false;
+    for (i, ea) in a_edges.iter().enumerate() {
+        let (a0, a1) = (a_ids[i], a_ids[(i + 1) % a_ids.len()]);
+        for (j, eb) in b_edges.iter().enumerate() {
+            let (b0, b1) = (b_ids[j], b_ids[(j + 1) % b_ids.len()]);
+            let on = |n: usize, e0: usize, e1: usize, e: &MinorArc| {
+                n == e0 || n == e1 || e.distance_to(nodes[n]) <= snap
+            };
+            let a0_on = on(a0, b0, b1, eb);
+            let b0_on = on(b0, a0, a1, ea);
+            // each vertex is the start of exactly one edge: split the other edge once.
+            if a0_on && a0 != b0 && a0 != b1 {
+                b_splits[j].push(a0);
+            }
+            if b0_on && b0 != a0 && b0 != a1 {
+                a_splits[i].push(b0);
+            }
+            if a0_on || b0_on || on(a1, b0, b1, eb) || on(b1, a0, a1, ea) {
+                // minor arcs meet at most once unless collinear, in which case they only meet at their ends.
+                meet = true;
+            } else if let Some(p) = ea.intersection(*eb) {
+                meet = true;
+                a_splits[i].push(nodes.len());
+                b_splits[j].push(nodes.len());
+                nodes.push(p);
+            }
+        }
+    }
+
+    if !meet {
+        return disjoint_or_nested(a, b, op);
+    }
+
+    let a_subs = sub_edges(&a_ids, &mut a_splits, &nodes);
+    let b_subs = sub_edges(&b_ids, &mut b_splits, &nodes);
+    let a_locs = locate(&a_subs, &b_subs, b, &nodes);
+    let b_locs = locate(&b_subs, &a_subs, a, &nodes);
+
+    // all edges are directed with the interior of the result on their right: clockwise shells, anti-clockwise holes.
+    // Shared edges are selected from a only.
+    let mut selected: Vec<(usize, usize)> = Vec::new();
+    for (e, l) in a_subs.iter().zip(a_locs) {
+        let keep = match op {
+            Operation::Intersection => l == Location::Inside || l == Location::Shared,
+            Operation::Union => l == Location::Outside || l == Location::Shared,
+            Operation::Difference => l == Location::Outside || l == Location::Opposite,
+        };
+        if keep {
+            selected.push(*e);
+        }
+    }
+    for (e, l) in b_subs.iter().zip(b_locs) {
+        match (op, l) {
+            (Operation::Intersection, Location::Inside) | (Operation::Union, Location::Outside) => {
+                selected.push(*e)
+            }
+            (Operation::Difference, Location::Inside) => selected.push((e.1, e.0)),
+            _ => (),
+        }
+    }
+
+    assemble(link(&selected, &nodes))
+}
+
+/// Splits the edges of a loop - given by the node of each vertex - at the given nodes and returns the resulting
+/// sub-edges, in order.
+fn sub_edges(ids: &[usize], splits: &mut [Vec<usize>], nodes: &[NVector]) -> Vec<(usize, usize)> {
+    let len = ids.len();
+    let mut res = Vec::with_capacity(len);
+    for (i, s) in splits.iter_mut().enumerate() {
+        let start = nodes[ids[i]];
+        s.sort_by_key(|n| ChordLength::new(start, nodes[*n]));
+        let mut prev = ids[i];
+        for n in s.iter().copied().chain(std::iter::once(ids[(i + 1) % len])) {
+            if n != prev {
+                res.push((prev, n));
+                prev = n;
+            }
+        }
+    }
+    res
+}
+
+/// Locates each of the given sub-edges relative to the other loop, given by its sub-edges.
+fn locate(
+    subs: &[(usize, usize)],
+    other_subs: &[(usize, usize)],
+    other: &Loop,
+    nodes: &[NVector],
+) -> Vec<Location> {
+    let others: HashSet<(usize, usize)> = other_subs.iter().copied().collect();
+    subs.iter()
+        .map(|(u, v)| {
+            if others.contains(&(*u, *v)) {
+                Location::Shared
+            } else if others.contains(&(*v, *u)) {
+                Location::Opposite
+            } else {
+                // the sub-edge does not cross the boundary of the other loop: locate its mid-position.
+                let m = NVector::new((nodes[*u].as_vec3() + nodes[*v].as_vec3()).unit());
+                if other.contains_position(m) {
+                    Location::Inside
+                } else {
+                    Location::Outside
+                }
+            }
+        })
+        .collect()
+}
+
+/// Links the given directed edges into rings. When several edges leave the same node, the edge making the sharpest
+/// right turn is followed so that rings touching at a node are kept apart.
+fn link(edges: &[(usize, usize)], nodes: &[NVector]) -> Vec<Vec<NVector>> {
+    let mut outgoing: HashMap<usize, Vec<usize>> = HashMap::new();
+    for (i, e) in edges.iter().enumerate() {
+        outgoing.entry(e.0).or_default().push(i);
+    }
+    let mut used = vec![false; edges.len()];
+    let mut rings = Vec::new();
+    for s in 0..edges.len() {
+        if used[s] {
+            continue;
+        }
+        let start = edges[s].0;
+        let mut ring: Vec<NVector> = Vec::new();
+        let mut e = s;
+        loop {
+            used[e] = true;
+            let (u, v) = edges[e];
+            ring.push(nodes[u]);
+            if v == start {
+                break;
+            }
+            let next = outgoing.get(&v).and_then(|es| {
+                es.iter().copied().filter(|n| !used[*n]).min_by(|x, y| {
+                    let tx = turn(nodes[u], nodes[v], nodes[edges[*x].1]);
+                    let ty = turn(nodes[u], nodes[v], nodes[edges[*y].1]);
+                    tx.total_cmp(&ty)
+                })
+            });
+            match next {
+                Some(n) => e = n,
+                None => {
+                    // dangling chain: cannot be closed.
+                    ring.clear();
+                    break;
+                }
+            }
+        }
+        if ring.len() > 2 {
+            rings.push(ring);
+        }
+    }
+    rings
+}
+
+/// Computes the angle in radians, in ]0, 2 * PI], swept anti-clockwise at `v` from the direction of `u` to the
+/// direction of `w`, looking at the sphere from outside.
+fn turn(u: NVector, v: NVector, w: NVector) -> f64 {
+    let vv = v.as_vec3();
+    let tu = u.as_vec3() - vv * vv.dot_prod(u.as_vec3());
+    let tw = w.as_vec3() - vv * vv.dot_prod(w.as_vec3());
+    let a = tu.cross_prod(tw).dot_prod(vv).atan2(tu.dot_prod(tw));
+    if a <= 0.0 {
+        a + 2.0 * std::f64::consts::PI
+    } else {
+        a
+    }
+}
+
+/// Computes the given boolean operation when the boundaries of the given loops do not intersect.
+fn disjoint_or_nested(a: &Loop, b: &Loop, op: Operation) -> Vec<Polygon> {
+    let a_in_b = b.contains_position(a.vertex(0));
+    let b_in_a = a.contains_position(b.vertex(0));
+    match op {
+        Operation::Intersection => {
+            if a_in_b {
+                to_polygons(a)
+            } else if b_in_a {
+                to_polygons(b)
+            } else {
+                Vec::new()
+            }
+        }
+        Operation::Union => {
+            if a_in_b {
+                to_polygons(b)
+            } else if b_in_a {
+                to_polygons(a)
+            } else {
+                vec![
+                    Polygon::new(a.clone(), Vec::new()),
+                    Polygon::new(b.clone(), Vec::new()),
+                ]
+            }
+        }
+        Operation::Difference => {
+            if a_in_b {
+                Vec::new()
+            } else if b_in_a {
+                vec![Polygon::new(a.clone(), vec![b.clone()])]
+            } else {
+                to_polygons(a)
+            }
+        }
+    }
+}
+
+fn to_polygons(l: &Loop) -> Vec<Polygon> {
+    if l.is_empty() {
+        Vec::new()
+    } else {
+        vec![Polygon::new(l.clone(), Vec::new())]
+    }
+}
+
+/// Assembles the given rings into polygons: clockwise rings are shells and anti-clockwise rings are holes.
+fn assemble(rings: Vec<Vec<NVector>>) -> Vec<Polygon> {
+    let mut shells: Vec<Loop> = Vec::new();
+    let mut holes: Vec<Loop> = Vec::new();
+    for r in rings {
+        let l = Loop::new(&r);
+        if l.is_empty() {
+            continue;
+        }
+        if is_loop_clockwise(&r) {
+            shells.push(l);
+        } else {
+            holes.push(l);
+        }
+    }
+    // rings do not cross each other: a hole is inside every shell that none of its vertices is outside of, and
+    // belongs to the smallest of them since a shell can lie within the hole of a larger shell.
+    let excesses: Vec<Angle> = shells.iter().map(|s| s.spherical_excess()).collect();
+    let mut shell_holes: Vec<Vec<Loop>> = vec![Vec::new(); shells.len()];
+    for h in holes {
+        let containing = shells
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| {
+                h.iter_vertices()
+                    .all(|v| s.contains_position(*v) || s.any_edge_contains_position(*v))
+            })
+            .map(|(i, _)| i)
+            .min_by(|i, j| excesses[*i].partial_cmp(&excesses[*j]).unwrap());
+        debug_assert!(containing.is_some(), "hole outside of every shell");
+        if let Some(i) = containing {
+            shell_holes[i].push(h);
+        }
+    }
+    shells
+        .into_iter()
+        .zip(shell_holes)
+        .map(|(s, hs)| Polygon::new(s, hs))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{numbers::Xorshift, Angle, LatLong, NVector};
+
+    use crate::spherical::{Loop, Polygon};
+
+    use super::assemble;
+
+    fn lp(lls: &[(f64, f64)]) -> Loop {
+        let vs: Vec<NVector> = lls
+            .iter()
+            .map(|ll| NVector::from_lat_long_degrees(ll.0, ll.1))
+            .collect();
+        Loop::new(&vs)
+    }
+
+    fn excess(ps: &[Polygon]) -> Angle {
+        ps.iter()
+            .fold(Angle::ZERO, |acc, p| acc + p.spherical_excess())
+    }
+
+    fn contains(ps: &[Polygon], lat: f64, lon: f64) -> bool {
+        let p = NVector::from_lat_long_degrees(lat, lon);
+        ps.iter().any(|pl| pl.contains_position(p))
+    }
+
+    fn square(lat: f64, lon: f64, size: f64) -> Loop {
+        lp(&[
+            (lat, lon),
+            (lat + size, lon),
+            (lat + size, lon + size),
+            (lat, lon + size),
+        ])
+    }
+
+    #[test]
+    fn empty() {
+        let a = square(0.0, 0.0, 10.0);
+        assert!(a.intersection(&Loop::EMPTY).is_empty());
+        assert!(Loop::EMPTY.intersection(&a).is_empty());
+        assert_eq!(
+            vec![Polygon::new(a.clone(), Vec::new())],
+            a.union(&Loop::EMPTY)
+        );
+        assert_eq!(
+            vec![Polygon::new(a.clone(), Vec::new())],
+            Loop::EMPTY.union(&a)
+        );
+        assert_eq!(
+            vec![Polygon::new(a.clone(), Vec::new())],
+            a.difference(&Loop::EMPTY)
+        );
+        assert!(Loop::EMPTY.difference(&a).is_empty());
+    }
+
+    #[test]
+    fn disjoint() {
+        let a = square(0.0, 0.0, 10.0);
+        let b = square(20.0, 20.0, 10.0);
+        assert!(a.intersection(&b).is_empty());
+        assert_eq!(
+            vec![
+                Polygon::new(a.clone(), Vec::new()),
+                Polygon::new(b.clone(), Vec::new())
+            ],
+            a.union(&b)
+        );
+        assert_eq!(vec![Polygon::new(a.clone(), Vec::new())], a.difference(&b));
+    }
+
+    #[test]
+    fn nested() {
+        let a = square(0.0, 0.0, 10.0);
+        let b = square(2.0, 2.0, 5.0);
+
+        assert_eq!(
+            vec![Polygon::new(b.clone(), Vec::new())],
+            a.intersection(&b)
+        );
+        assert_eq!(
+            vec![Polygon::new(b.clone(), Vec::new())],
+            b.intersection(&a)
+        );
+
+        assert_eq!(vec![Polygon::new(a.clone(), Vec::new())], a.union(&b));
+        assert_eq!(vec![Polygon::new(a.clone(), Vec::new())], b.union(&a));
+
+        assert_eq!(
+            vec![Polygon::new(a.clone(), vec![b.clone()])],
+            a.difference(&b)
+        );
+        assert!(b.difference(&a).is_empty());
+    }
+
+    #[test]
+    fn overlapping() {
+        let a = square(0.0, 0.0, 10.0);
+        let b = square(5.0, 5.0, 10.0);
+
+        let i = a.intersection(&b);
+        assert_eq!(1, i.len());
+        assert_eq!(4, i[0].shell().num_vertices());
+        assert!(contains(&i, 7.0, 7.0));
+        assert!(!contains(&i, 2.0, 2.0));
+        assert!(!contains(&i, 12.0, 12.0));
+
+        let u = a.union(&b);
+        assert_eq!(1, u.len());
+        assert_eq!(8, u[0].shell().num_vertices());
+        assert_eq!(0, u[0].num_holes());
+        assert!(contains(&u, 2.0, 2.0));
+        assert!(contains(&u, 7.0, 7.0));
+        assert!(contains(&u, 12.0, 12.0));
+        assert!(!contains(&u, 12.0, 2.0));
+
+        let d = a.difference(&b);
+        assert_eq!(1, d.len());
+        assert_eq!(6, d[0].shell().num_vertices());
+        assert!(contains(&d, 2.0, 2.0));
+        assert!(!contains(&d, 7.0, 7.0));
+        assert!(!contains(&d, 12.0, 12.0));
+
+        let ea = a.spherical_excess();
+        let eb = b.spherical_excess();
+        let ei = excess(&i);
+        assert_eq!(ea.round_d7(), (excess(&d) + ei).round_d7());
+        assert_eq!((ea + eb - ei).round_d7(), excess(&u).round_d7());
+    }
+
+    #[test]
+    fn commutative() {
+        let a = square(0.0, 0.0, 10.0);
+        let b = square(5.0, 5.0, 10.0);
+        assert_eq!(
+            excess(&a.intersection(&b)).round_d7(),
+            excess(&b.intersection(&a)).round_d7()
+        );
+        assert_eq!(
+            excess(&a.union(&b)).round_d7(),
+            excess(&b.union(&a)).round_d7()
+        );
+    }
+
+    #[test]
+    fn several_pieces_and_hole() {
+        // U shape opened towards the north.
+        let u = lp(&[
+            (0.0, 0.0),
+            (10.0, 0.0),
+            (10.0, 3.0),
+            (3.0, 3.0),
+            (3.0, 7.0),
+            (10.0, 7.0),
+            (10.0, 10.0),
+            (0.0, 10.0),
+        ]);
+        // bar crossing both arms of the U.
+        let bar = lp(&[(8.0, -1.0), (12.0, -1.0), (12.0, 11.0), (8.0, 11.0)]);
+
+        let i = u.intersection(&bar);
+        assert_eq!(2, i.len());
+        assert!(contains(&i, 9.0, 1.0));
+        assert!(contains(&i, 9.0, 9.0));
+        assert!(!contains(&i, 9.0, 5.0));
+
+        let un = u.union(&bar);
+        assert_eq!(1, un.len());
+        assert_eq!(1, un[0].num_holes());
+        assert!(contains(&un, 1.0, 5.0));
+        assert!(contains(&un, 11.0, 5.0));
+        assert!(!contains(&un, 6.0, 5.0));
+
+        let d = u.difference(&bar);
+        assert_eq!(1, d.len());
+        assert!(contains(&d, 1.0, 5.0));
+        assert!(!contains(&d, 9.0, 1.0));
+
+        // bar minus both arms: remains connected by the northern part of the bar.
+        let d = bar.difference(&u);
+        assert_eq!(1, d.len());
+        assert!(contains(&d, 11.0, 5.0));
+        assert!(contains(&d, 9.0, 5.0));
+        assert!(!contains(&d, 9.0, 1.0));
+
+        let eu = u.spherical_excess();
+        let eb = bar.spherical_excess();
+        let ei = excess(&i);
+        assert_eq!((eu + eb - ei).round_d7(), excess(&un).round_d7());
+        assert_eq!((eb - ei).round_d7(), excess(&d).round_d7());
+    }
+
+    #[test]
+    fn shared_edge() {
+        let a = square(0.0, 0.0, 10.0);
+        let b = lp(&[(0.0, 10.0), (10.0, 10.0), (10.0, 20.0), (0.0, 20.0)]);
+
+        let u = a.union(&b);
+        assert_eq!(1, u.len());
+        assert!(contains(&u, 5.0, 5.0));
+        assert!(contains(&u, 5.0, 15.0));
+        assert_eq!(
+            (a.spherical_excess() + b.spherical_excess()).round_d7(),
+            excess(&u).round_d7()
+        );
+
+        assert_eq!(Angle::ZERO, excess(&a.intersection(&b)).round_d7());
+        assert_eq!(
+            a.spherical_excess().round_d7(),
+            excess(&a.difference(&b)).round_d7()
+        );
+    }
+
+    #[test]
+    fn vertex_on_edge() {
+        let a = square(0.0, 0.0, 10.0);
+        // triangle with one vertex on the western edge of a.
+        let b = lp(&[(5.0, 0.0), (10.0, -5.0), (0.0, -5.0)]);
+        let c = lp(&[(5.0, 0.0), (8.0, 5.0), (2.0, 5.0)]);
+
+        assert_eq!(Angle::ZERO, excess(&a.intersection(&b)).round_d7());
+        assert_eq!(
+            c.spherical_excess().round_d7(),
+            excess(&a.intersection(&c)).round_d7()
+        );
+        assert_eq!(
+            (a.spherical_excess() - c.spherical_excess()).round_d7(),
+            excess(&a.difference(&c)).round_d7()
+        );
+    }
+
+    #[test]
+    fn assemble_nested_shells() {
+        let outer = square(0.0, 0.0, 10.0);
+        let island = square(3.0, 3.0, 4.0);
+        let rings = vec![
+            vertices(&outer),
+            reversed(&square(2.0, 2.0, 6.0)),
+            vertices(&island),
+            reversed(&square(4.0, 4.0, 2.0)),
+        ];
+        let actual = assemble(rings);
+        assert_eq!(2, actual.len());
+        assert_eq!(&outer, actual[0].shell());
+        assert_eq!(1, actual[0].num_holes());
+        assert_eq!(&island, actual[1].shell());
+        assert_eq!(1, actual[1].num_holes());
+        assert!(contains(&actual, 1.0, 1.0));
+        assert!(!contains(&actual, 2.5, 2.5));
+        assert!(contains(&actual, 3.5, 3.5));
+        assert!(!contains(&actual, 5.0, 5.0));
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "hole outside of every shell")]
+    fn assemble_hole_outside_shells() {
+        assemble(vec![
+            vertices(&square(0.0, 0.0, 10.0)),
+            reversed(&square(20.0, 20.0, 5.0)),
+        ]);
+    }
+
+    fn vertices(l: &Loop) -> Vec<NVector> {
+        l.iter_vertices().copied().collect()
+    }
+
+    fn reversed(l: &Loop) -> Vec<NVector> {
+        let mut vs = vertices(l);
+        vs.reverse();
+        vs
+    }
+
+    #[test]
+    fn triangle_and_pentagon() {
+        let a = lp(&[(-2.0, -1.0), (3.0, -1.0), (7.0, 10.0)]);
+        let b = lp(&[
+            (0.0, 5.0),
+            (0.0, 1.0),
+            (2.0, -2.0),
+            (11.0, -1.0),
+            (6.0, 3.0),
+        ]);
+        assert!(a.is_simple());
+        assert!(b.is_simple());
+        assert_area_identities(&a, &b);
+        assert_area_identities(&b, &a);
+
+        let ea = a.spherical_excess();
+        let eb = b.spherical_excess();
+        let ei = excess(&a.intersection(&b));
+        // checked by numerical integration over a grid of 0.01 degrees.
+        assert_eq!(Angle::from_degrees(0.264671), ei.round_d6());
+        assert_eq!(
+            excess(&a.union(&b)).round_d7(),
+            excess(&b.union(&a)).round_d7()
+        );
+        assert_eq!((ea + eb - ei).round_d7(), excess(&a.union(&b)).round_d7());
+        assert_eq!((ea - ei).round_d7(), excess(&a.difference(&b)).round_d7());
+        assert_eq!((eb - ei).round_d7(), excess(&b.difference(&a)).round_d7());
+    }
+
+    #[test]
+    fn result_vertices_are_vertices_or_crossings() {
+        let a = square(0.0, 0.0, 10.0);
+        let c = lp(&[(5.0, 0.0), (8.0, 5.0), (2.0, 5.0)]);
+        let d = a.difference(&c);
+        for v in d.iter().flat_map(|p| p.shell().iter_vertices()) {
+            assert!(a.has_vertex(*v) || c.has_vertex(*v));
+        }
+    }
+
+    #[test]
+    fn identical() {
+        let a = square(0.0, 0.0, 10.0);
+        assert_eq!(
+            a.spherical_excess().round_d7(),
+            excess(&a.intersection(&a)).round_d7()
+        );
+        assert_eq!(
+            a.spherical_excess().round_d7(),
+            excess(&a.union(&a)).round_d7()
+        );
+        assert!(a.difference(&a).is_empty());
+    }
+
+    #[test]
+    fn random_grid_loops() {
+        // small integer grid: many shared vertices, vertices on edges and shared edges.
+        let mut rng = Xorshift::new(0x9E37_79B9_7F4A_7C15);
+        let mut next = |n: u64| rng.below(n) as f64;
+        let mut random_loop = || loop {
+            let len = 3 + next(6) as usize;
+            let mut lls: Vec<(f64, f64)> =
+                (0..len).map(|_| (next(9) - 4.0, next(9) - 4.0)).collect();
+            lls.sort_by(|x, y| x.partial_cmp(y).unwrap());
+            lls.dedup();
+            let len = lls.len();
+            // star shaped around the mean position.
+            let c = lls
+                .iter()
+                .fold((0.0, 0.0), |acc, ll| (acc.0 + ll.0, acc.1 + ll.1));
+            let c = (c.0 / len as f64, c.1 / len as f64);
+            lls.sort_by(|x, y| {
+                let ax = (x.0 - c.0).atan2(x.1 - c.1);
+                let ay = (y.0 - c.0).atan2(y.1 - c.1);
+                ax.total_cmp(&ay)
+            });
+            let l = lp(&lls);
+            if !l.is_empty() && l.is_simple() {
+                return l;
+            }
+        };
+        for _ in 0..500 {
+            let a = random_loop();
+            let b = random_loop();
+            assert_area_identities(&a, &b);
+        }
+    }
+
+    fn assert_area_identities(a: &Loop, b: &Loop) {
+        let ea = a.spherical_excess().as_degrees();
+        let eb = b.spherical_excess().as_degrees();
+        let ei = excess(&a.intersection(b)).as_degrees();
+        let eu = excess(&a.union(b)).as_degrees();
+        let ed = excess(&a.difference(b)).as_degrees();
+        let lls = |l: &Loop| -> Vec<LatLong> {
+            l.iter_vertices()
+                .map(|v| LatLong::from_nvector(*v).round_d7())
+                .collect()
+        };
+        let msg = format!("{:?} and {:?}", lls(a), lls(b));
+        assert!(ei <= ea.min(eb) + 1e-9, "{}", msg);
+        assert!((ea + eb - ei - eu).abs() < 1e-9, "union of {}", msg);
+        assert!((ea - ei - ed).abs() < 1e-9, "difference of {}", msg);
+    }
+}
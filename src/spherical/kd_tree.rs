@@ -0,0 +1,609 @@
+use std::{
+    cmp::Ordering,
+    collections::BinaryHeap,
+    f64::consts::{FRAC_PI_2, PI},
+};
+
+use crate::{Angle, LatLong, NVector, Vec3};
+
+use super::{Cap, ChordLength, Rectangle};
+
+/// A [k-d tree](https://en.wikipedia.org/wiki/K-d_tree) of positions: the n-vectors are indexed as 3D unit vectors,
+/// each position being associated with a value (e.g. an identifier).
+///
+/// Since the chord length between 2 positions increases monotonically with the central angle, nearest neighbours
+/// in 3D Euclidean space are also nearest neighbours on the sphere.
+///
+/// The tree is balanced when [built from all entries](crate::spherical::KdTree::from_entries); [inserting](crate::spherical::KdTree::insert)
+/// entries one by one does not rebalance the tree.
+///
+/// # Examples
+///
+/// ```
+/// use jord::{Angle, NVector};
+/// use jord::spherical::{Cap, KdTree};
+///
+/// let tree = KdTree::from_entries(vec![
+///     (NVector::from_lat_long_degrees(55.605, 13.0038), "Malmö"),
+///     (NVector::from_lat_long_degrees(55.6761, 12.5683), "Copenhagen"),
+///     (NVector::from_lat_long_degrees(59.3293, 18.0686), "Stockholm"),
+/// ]);
+///
+/// let lund = NVector::from_lat_long_degrees(55.7047, 13.191);
+/// let nearest: Vec<&str> = tree.nearest(lund, 2).into_iter().map(|(_, v)| *v).collect();
+/// assert_eq!(vec!["Malmö", "Copenhagen"], nearest);
+///
+/// let cap = Cap::from_centre_and_radius(lund, Angle::from_degrees(0.2));
+/// let within: Vec<&str> = tree.within_cap(cap).into_iter().map(|(_, v)| *v).collect();
+/// assert_eq!(vec!["Malmö"], within);
+/// ```
+#[derive(PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))] // codecov:ignore:this
+pub struct KdTree<T> {
+    nodes: Vec<Node<T>>,
+    root: Option<usize>,
+}
+
+#[derive(PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))] // codecov:ignore:this
+struct Node<T> {
+    position: NVector,
+    value: T,
+    /// splitting axis: 0 (x), 1 (y) or 2 (z).
+    axis: usize,
+    left: Option<usize>,
+    right: Option<usize>,
+}
+
+impl<T> Default for KdTree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> KdTree<T> {
+    /// Creates a new empty tree.
+    pub const fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            root: None,
+        }
+    }
+
+    /// Creates a new balanced tree containing all the given entries.
+    pub fn from_entries(entries: Vec<(NVector, T)>) -> Self {
+        let mut slots: Vec<Option<(NVector, T)>> = entries.into_iter().map(Some).collect();
+        let mut order: Vec<usize> = (0..slots.len()).collect();
+        let mut tree = Self {
+            nodes: Vec::with_capacity(slots.len()),
+            root: None,
+        };
+        tree.root = tree.build(&mut slots, &mut order, 0);
+        tree
+    }
+
+    /// Inserts the given position and associated value into this tree.
+    pub fn insert(&mut self, position: NVector, value: T) {
+        let index = self.nodes.len();
+        let mut current = match self.root {
+            None => {
+                self.nodes.push(Node::leaf(position, value, 0));
+                self.root = Some(index);
+                return;
+            }
+            Some(r) => r,
+        };
+        loop {
+            let node = &self.nodes[current];
+            let axis = node.axis;
+            let go_left = coordinate(position, axis) < coordinate(node.position, axis);
+            let child = if go_left { node.left } else { node.right };
+            match child {
+                Some(c) => current = c,
+                None => {
+                    self.nodes.push(Node::leaf(position, value, (axis + 1) % 3));
+                    let node = &mut self.nodes[current];
+                    if go_left {
+                        node.left = Some(index);
+                    } else {
+                        node.right = Some(index);
+                    }
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Returns the number of entries in this tree.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Determines whether this tree is empty.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns an iterator over all entries of this tree, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (NVector, &T)> {
+        self.nodes.iter().map(|n| (n.position, &n.value))
+    }
+
+    /// Returns the (at most) `k` entries nearest to the given position, ordered by increasing distance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::NVector;
+    /// use jord::spherical::KdTree;
+    ///
+    /// let mut tree = KdTree::new();
+    /// for lon in 0..10 {
+    ///     tree.insert(NVector::from_lat_long_degrees(0.0, lon as f64), lon);
+    /// }
+    ///
+    /// let p = NVector::from_lat_long_degrees(1.0, 6.2);
+    /// let nearest: Vec<i32> = tree.nearest(p, 3).into_iter().map(|(_, v)| *v).collect();
+    /// assert_eq!(vec![6, 7, 5], nearest);
+    /// ```
+    pub fn nearest(&self, p: NVector, k: usize) -> Vec<(NVector, &T)> {
+        if k == 0 {
+            return Vec::new();
+        }
+        let mut heap: BinaryHeap<Candidate> = BinaryHeap::with_capacity(k + 1);
+        // nodes to visit with a lower bound of the squared distance to any of their entries.
+        let mut stack: Vec<(usize, f64)> = self.root.map(|r| (r, 0.0)).into_iter().collect();
+        while let Some((index, bound)) = stack.pop() {
+            if heap.len() == k && heap.peek().map_or(false, |w| bound > w.distance2) {
+                continue;
+            }
+            let node = &self.nodes[index];
+            let distance2 = ChordLength::new(p, node.position).length2();
+            if heap.len() < k {
+                heap.push(Candidate { distance2, index });
+            } else if heap.peek().map_or(false, |w| distance2 < w.distance2) {
+                heap.pop();
+                heap.push(Candidate { distance2, index });
+            }
+            let diff = coordinate(p, node.axis) - coordinate(node.position, node.axis);
+            let (near, far) = if diff < 0.0 {
+                (node.left, node.right)
+            } else {
+                (node.right, node.left)
+            };
+            if let Some(f) = far {
+                stack.push((f, bound.max(diff * diff)));
+            }
+            if let Some(n) = near {
+                stack.push((n, bound));
+            }
+        }
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|c| self.entry(c.index))
+            .collect()
+    }
+
+    /// Returns all the entries whose position is inside the given cap, in no particular order.
+    pub fn within_cap(&self, cap: Cap) -> Vec<(NVector, &T)> {
+        if cap.is_empty() {
+            return Vec::new();
+        }
+        if cap.is_full() {
+            return self.iter().collect();
+        }
+        let centre = cap.centre();
+        // the cap is inside the ball around its centre whose radius is the chord length of the cap radius (with a
+        // small margin to absorb rounding errors).
+        let radius2 = ChordLength::from_angle(cap.radius()).length2() + 1e-12;
+        self.search(
+            |axis, lo, hi| {
+                let c = coordinate(centre, axis);
+                let d = if c < lo {
+                    lo - c
+                } else if c > hi {
+                    c - hi
+                } else {
+                    0.0
+                };
+                d * d <= radius2
+            },
+            |p| cap.contains_position(p),
+        )
+    }
+
+    /// Returns all the entries whose position is inside the given rectangle, in no particular order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, NVector};
+    /// use jord::spherical::{KdTree, Rectangle};
+    ///
+    /// let mut tree = KdTree::new();
+    /// for lon in -5..5 {
+    ///     tree.insert(NVector::from_lat_long_degrees(0.0, 10.0 * lon as f64), lon);
+    /// }
+    ///
+    /// let r = Rectangle::from_nesw(
+    ///     Angle::from_degrees(1.0),
+    ///     Angle::from_degrees(25.0),
+    ///     Angle::from_degrees(-1.0),
+    ///     Angle::from_degrees(5.0),
+    /// );
+    /// let mut within: Vec<i32> = tree.within_rectangle(r).into_iter().map(|(_, v)| *v).collect();
+    /// within.sort();
+    /// assert_eq!(vec![1, 2], within);
+    /// ```
+    pub fn within_rectangle(&self, r: Rectangle) -> Vec<(NVector, &T)> {
+        if r.is_empty() {
+            return Vec::new();
+        }
+        let (min, max) = bounding_box(r);
+        self.search(
+            |axis, lo, hi| hi >= min[axis] && lo <= max[axis],
+            |p| r.contains_position(LatLong::from_nvector(p)),
+        )
+    }
+
+    /// Returns all the entries accepted by the given filter, visiting only the nodes whose bounding box along an
+    /// axis (axis, lower, upper) may contain accepted entries.
+    fn search<B, F>(&self, may_contain: B, accept: F) -> Vec<(NVector, &T)>
+    where
+        B: Fn(usize, f64, f64) -> bool,
+        F: Fn(NVector) -> bool,
+    {
+        let mut result = Vec::new();
+        // nodes to visit with their bounding box.
+        let mut stack: Vec<(usize, [f64; 3], [f64; 3])> = self
+            .root
+            .map(|r| (r, [-1.0; 3], [1.0; 3]))
+            .into_iter()
+            .collect();
+        while let Some((index, lo, hi)) = stack.pop() {
+            if !(0..3).all(|axis| may_contain(axis, lo[axis], hi[axis])) {
+                continue;
+            }
+            let node = &self.nodes[index];
+            if accept(node.position) {
+                result.push(self.entry(index));
+            }
+            let split = coordinate(node.position, node.axis);
+            if let Some(l) = node.left {
+                let mut h = hi;
+                h[node.axis] = split;
+                stack.push((l, lo, h));
+            }
+            if let Some(r) = node.right {
+                let mut l = lo;
+                l[node.axis] = split;
+                stack.push((r, l, hi));
+            }
+        }
+        result
+    }
+
+    fn entry(&self, index: usize) -> (NVector, &T) {
+        let n = &self.nodes[index];
+        (n.position, &n.value)
+    }
+
+    /// builds the subtree of the entries at the given indices, returns the index of the root node.
+    fn build(
+        &mut self,
+        slots: &mut [Option<(NVector, T)>],
+        order: &mut [usize],
+        axis: usize,
+    ) -> Option<usize> {
+        if order.is_empty() {
+            return None;
+        }
+        let mid = order.len() / 2;
+        order.select_nth_unstable_by(mid, |a, b| {
+            let pa = slots[*a].as_ref().map_or(0.0, |e| coordinate(e.0, axis));
+            let pb = slots[*b].as_ref().map_or(0.0, |e| coordinate(e.0, axis));
+            pa.total_cmp(&pb)
+        });
+        // entries equal to the median along the axis may be in either subtree: both half-spaces are closed.
+        let (position, value) = slots[order[mid]].take()?;
+        let index = self.nodes.len();
+        self.nodes.push(Node::leaf(position, value, axis));
+        let (left, rest) = order.split_at_mut(mid);
+        let next = (axis + 1) % 3;
+        let l = self.build(slots, left, next);
+        let r = self.build(slots, &mut rest[1..], next);
+        self.nodes[index].left = l;
+        self.nodes[index].right = r;
+        Some(index)
+    }
+}
+
+impl<T> Node<T> {
+    fn leaf(position: NVector, value: T, axis: usize) -> Self {
+        Self {
+            position,
+            value,
+            axis,
+            left: None,
+            right: None,
+        }
+    }
+}
+
+/// A candidate nearest neighbour ordered by squared chord length.
+#[derive(Clone, Copy, Debug)]
+struct Candidate {
+    distance2: f64,
+    index: usize,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance2
+            .total_cmp(&other.distance2)
+            .then(self.index.cmp(&other.index))
+    }
+}
+
+fn coordinate(p: NVector, axis: usize) -> f64 {
+    let v: Vec3 = p.as_vec3();
+    match axis {
+        0 => v.x(),
+        1 => v.y(),
+        _ => v.z(),
+    }
+}
+
+/// 3D bounding box (min, max) of the given non-empty rectangle.
+fn bounding_box(r: Rectangle) -> ([f64; 3], [f64; 3]) {
+    let lat_lo = r.south_west().latitude().as_radians();
+    let lat_hi = r.north_east().latitude().as_radians();
+    // cos(lat) over the latitude interval: maximum at the latitude closest to the equator.
+    let cos_lat_min = lat_lo.cos().min(lat_hi.cos());
+    let cos_lat_max = if lat_lo <= 0.0 && lat_hi >= 0.0 {
+        1.0
+    } else {
+        lat_lo.cos().max(lat_hi.cos())
+    };
+    let (x, y) = if r.is_longitude_full() {
+        ((-1.0, 1.0), (-1.0, 1.0))
+    } else {
+        let lon_lo = r.south_west().longitude();
+        let lon_hi = r.north_east().longitude();
+        longitude_ranges(lon_lo, lon_hi)
+    };
+    let scale = |(lo, hi): (f64, f64)| {
+        let products = [
+            lo * cos_lat_min,
+            lo * cos_lat_max,
+            hi * cos_lat_min,
+            hi * cos_lat_max,
+        ];
+        (
+            products.iter().copied().fold(f64::INFINITY, f64::min),
+            products.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+        )
+    };
+    let (x_min, x_max) = scale(x);
+    let (y_min, y_max) = scale(y);
+    // small margin to absorb rounding errors.
+    let e = 1e-12;
+    (
+        [x_min - e, y_min - e, lat_lo.sin() - e],
+        [x_max + e, y_max + e, lat_hi.sin() + e],
+    )
+}
+
+/// ranges of cos(lon) and sin(lon) over the longitude interval from lo to hi (eastward, possibly crossing the
+/// antimeridian).
+fn longitude_ranges(lo: Angle, hi: Angle) -> ((f64, f64), (f64, f64)) {
+    let lo = lo.as_radians();
+    let mut hi = hi.as_radians();
+    if hi < lo {
+        hi += 2.0 * PI;
+    }
+    let contains = |a: f64| {
+        // any a + 2k.PI in [lo, hi]
+        let k = ((lo - a) / (2.0 * PI)).ceil();
+        a + k * 2.0 * PI <= hi
+    };
+    let cos = (
+        if contains(PI) {
+            -1.0
+        } else {
+            lo.cos().min(hi.cos())
+        },
+        if contains(0.0) {
+            1.0
+        } else {
+            lo.cos().max(hi.cos())
+        },
+    );
+    let sin = (
+        if contains(-FRAC_PI_2) {
+            -1.0
+        } else {
+            lo.sin().min(hi.sin())
+        },
+        if contains(FRAC_PI_2) {
+            1.0
+        } else {
+            lo.sin().max(hi.sin())
+        },
+    );
+    (cos, sin)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        numbers::Xorshift,
+        spherical::{Cap, ChordLength, Rectangle},
+        Angle, LatLong, NVector,
+    };
+
+    use super::KdTree;
+
+    /// deterministic pseudo-random positions.
+    fn positions(n: usize) -> Vec<NVector> {
+        let mut rng = Xorshift::new(42);
+        let mut next = || rng.next_f64();
+        (0..n)
+            .map(|_| {
+                let lat = (2.0 * next() - 1.0).asin().to_degrees();
+                let lon = 360.0 * next() - 180.0;
+                NVector::from_lat_long_degrees(lat, lon)
+            })
+            .collect()
+    }
+
+    fn sorted(mut vs: Vec<usize>) -> Vec<usize> {
+        vs.sort_unstable();
+        vs
+    }
+
+    fn bulk(ps: &[NVector]) -> KdTree<usize> {
+        KdTree::from_entries(
+            ps.iter()
+                .copied()
+                .enumerate()
+                .map(|(i, p)| (p, i))
+                .collect(),
+        )
+    }
+
+    fn inserted(ps: &[NVector]) -> KdTree<usize> {
+        let mut tree = KdTree::new();
+        for (i, p) in ps.iter().enumerate() {
+            tree.insert(*p, i);
+        }
+        tree
+    }
+
+    #[test]
+    fn empty() {
+        let tree: KdTree<()> = KdTree::new();
+        assert!(tree.is_empty());
+        let p = NVector::from_lat_long_degrees(0.0, 0.0);
+        assert!(tree.nearest(p, 3).is_empty());
+        assert!(tree.within_cap(Cap::FULL).is_empty());
+        assert!(tree.within_rectangle(Rectangle::FULL).is_empty());
+    }
+
+    #[test]
+    fn len() {
+        let ps = positions(100);
+        assert_eq!(100, bulk(&ps).len());
+        assert_eq!(100, inserted(&ps).len());
+        assert_eq!(100, bulk(&ps).iter().count());
+    }
+
+    #[test]
+    fn nearest_brute_force() {
+        let ps = positions(2000);
+        let queries = positions(2050);
+        for tree in [bulk(&ps), inserted(&ps)] {
+            for q in &queries[2000..] {
+                let mut expected: Vec<usize> = (0..ps.len()).collect();
+                expected.sort_by_key(|i| ChordLength::new(*q, ps[*i]));
+                expected.truncate(7);
+                let actual: Vec<usize> = tree.nearest(*q, 7).into_iter().map(|(_, i)| *i).collect();
+                assert_eq!(expected, actual);
+            }
+        }
+    }
+
+    #[test]
+    fn nearest_more_than_len() {
+        let ps = positions(5);
+        let tree = bulk(&ps);
+        assert_eq!(5, tree.nearest(ps[0], 10).len());
+        assert_eq!(0, tree.nearest(ps[0], 0).len());
+        assert_eq!(0, *tree.nearest(ps[0], 1)[0].1);
+    }
+
+    #[test]
+    fn duplicates() {
+        let p = NVector::from_lat_long_degrees(10.0, 10.0);
+        let tree = KdTree::from_entries(vec![(p, 1), (p, 2), (p, 3)]);
+        let mut all: Vec<i32> = tree
+            .within_cap(Cap::from_centre_and_radius(p, Angle::ZERO))
+            .into_iter()
+            .map(|(_, v)| *v)
+            .collect();
+        all.sort_unstable();
+        assert_eq!(vec![1, 2, 3], all);
+
+        let mut tree = KdTree::new();
+        tree.insert(p, 1);
+        tree.insert(p, 2);
+        assert_eq!(2, tree.nearest(p, 5).len());
+    }
+
+    #[test]
+    fn within_cap_brute_force() {
+        let ps = positions(2000);
+        let tree = bulk(&ps);
+        for (centre, radius) in [
+            (NVector::from_lat_long_degrees(0.0, 0.0), 10.0),
+            (NVector::from_lat_long_degrees(90.0, 0.0), 25.0),
+            (NVector::from_lat_long_degrees(-30.0, 179.0), 40.0),
+            (NVector::from_lat_long_degrees(45.0, -90.0), 120.0),
+        ] {
+            let cap = Cap::from_centre_and_radius(centre, Angle::from_degrees(radius));
+            let expected: Vec<usize> = (0..ps.len())
+                .filter(|i| cap.contains_position(ps[*i]))
+                .collect();
+            assert!(!expected.is_empty());
+            let actual: Vec<usize> = tree.within_cap(cap).into_iter().map(|(_, i)| *i).collect();
+            assert_eq!(expected, sorted(actual));
+        }
+        assert_eq!(2000, tree.within_cap(Cap::FULL).len());
+        assert!(tree.within_cap(Cap::EMPTY).is_empty());
+    }
+
+    #[test]
+    fn within_rectangle_brute_force() {
+        let ps = positions(2000);
+        let tree = inserted(&ps);
+        for (n, e, s, w) in [
+            (10.0, 20.0, -10.0, -20.0),
+            (80.0, 30.0, 40.0, 100.0),
+            (-20.0, -170.0, -60.0, 150.0),
+            (90.0, 180.0, 70.0, -180.0),
+        ] {
+            let r = Rectangle::from_nesw(
+                Angle::from_degrees(n),
+                Angle::from_degrees(e),
+                Angle::from_degrees(s),
+                Angle::from_degrees(w),
+            );
+            let expected: Vec<usize> = (0..ps.len())
+                .filter(|i| r.contains_position(LatLong::from_nvector(ps[*i])))
+                .collect();
+            assert!(!expected.is_empty());
+            let actual: Vec<usize> = tree
+                .within_rectangle(r)
+                .into_iter()
+                .map(|(_, i)| *i)
+                .collect();
+            assert_eq!(expected, sorted(actual));
+        }
+        assert_eq!(2000, tree.within_rectangle(Rectangle::FULL).len());
+        assert!(tree.within_rectangle(Rectangle::EMPTY).is_empty());
+    }
+}
@@ -1,6 +1,10 @@
-use crate::{numbers::eq_zero, spherical::ChordLength, Angle, NVector, Vec3};
+use crate::{numbers::eq_zero, spherical::ChordLength, Angle, Length, NVector, Vec3};
 
-use super::base::{angle_radians_between, side};
+use super::{
+    base::{angle_radians_between, side},
+    buffer::{arc_buffer, buffer_radius},
+    Loop, Sphere,
+};
 
 /// Oriented minor arc of a great circle between two positions: shortest path between positions
 /// on a great circle.
@@ -130,8 +134,10 @@ impl MinorArc {
             // equal or opposite minor arcs: no intersection
             None
         } else {
-            // select nearest intersection to start of first minor arc.
-            let potential = if self.start.as_vec3().dot_prod(i) > 0.0 {
+            // select the intersection on the side of the first minor arc: all positions of a minor arc are within
+            // 90 degrees of its mid-position.
+            let mid = self.start.as_vec3() + self.end.as_vec3();
+            let potential = if mid.dot_prod(i) > 0.0 {
                 i
             } else {
                 // antipode of i.
@@ -298,6 +304,37 @@ impl MinorArc {
         }
     }
 
+    /// Computes the buffer of this minor arc on the given sphere: the [Loop] enclosing all positions within the
+    /// given distance of this minor arc. The buffer is bounded by 2 arcs parallel to this minor arc joined by 2 half
+    /// circles centred at the start and end positions; both are approximated by vertices spaced by at most a quarter
+    /// circle divided by the given number of segments per quadrant (at least 1).
+    ///
+    /// An [empty](crate::spherical::Loop::EMPTY) loop is returned if the given distance is not positive or if it is
+    /// greater or equal to a quarter of the circumference of the sphere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Length, NVector};
+    /// use jord::spherical::{MinorArc, Sphere};
+    ///
+    /// let ma = MinorArc::new(
+    ///     NVector::from_lat_long_degrees(55.605, 13.0038),
+    ///     NVector::from_lat_long_degrees(55.4295, 13.82),
+    /// );
+    ///
+    /// // 10 nautical miles around the route.
+    /// let b = ma.buffer(Length::from_metres(18_520.0), &Sphere::EARTH, 8);
+    /// assert_eq!(34, b.num_vertices());
+    /// assert!(b.contains_position(NVector::from_lat_long_degrees(55.6, 13.5)));
+    /// assert!(!b.contains_position(NVector::from_lat_long_degrees(55.9, 13.5)));
+    /// ```
+    pub fn buffer(&self, distance: Length, sphere: &Sphere, quadrant_segments: usize) -> Loop {
+        buffer_radius(distance, sphere).map_or(Loop::EMPTY, |r| {
+            arc_buffer(self.start, self.end, r, quadrant_segments)
+        })
+    }
+
     /// Determines whether this minor arc contains the given point which is assumed to be on the great circle.
     fn contains_vec3(&self, v: Vec3) -> bool {
         // v is left of (normal, start)
@@ -326,7 +363,7 @@ mod tests {
             NVector::from_lat_long_degrees(0.0, 0.0),
             NVector::from_lat_long_degrees(0.0, 10.0),
         );
-        let p = NVector::from_lat_long_degrees(-1.0 / 3600000_000.0, 0.0);
+        let p = NVector::from_lat_long_degrees(-1.0 / 3_600_000_000.0, 0.0);
         let actual = e.distance_to(p);
         let projection = e.projection(p).unwrap();
         let expected = ChordLength::new(p, projection);
@@ -339,7 +376,7 @@ mod tests {
             NVector::from_lat_long_degrees(0.0, 0.0),
             NVector::from_lat_long_degrees(0.0, 10.0),
         );
-        let p = NVector::from_lat_long_degrees(1.0, -1.0 / 3600000_000.0);
+        let p = NVector::from_lat_long_degrees(1.0, -1.0 / 3_600_000_000.0);
         let actual: ChordLength = e.distance_to(p);
         let expected = ChordLength::new(p, e.start());
         assert_eq!(expected, actual);
@@ -519,6 +556,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn intersection_far_from_start() {
+        // the intersection is more than 90 degrees away from the start of the first minor arc.
+        let arc1 = MinorArc::new(
+            NVector::from_lat_long_degrees(0.0, -100.0),
+            NVector::from_lat_long_degrees(0.0, 40.0),
+        );
+        let arc2 = MinorArc::new(
+            NVector::from_lat_long_degrees(-10.0, 20.0),
+            NVector::from_lat_long_degrees(10.0, 20.0),
+        );
+        assert_intersection(NVector::from_lat_long_degrees(0.0, 20.0), arc1, arc2);
+    }
+
     #[test]
     fn intersection_null_island() {
         let arc1 = MinorArc::new(
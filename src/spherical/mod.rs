@@ -2,21 +2,48 @@
 
 mod base;
 
+mod buffer;
+
 mod cap;
-pub use cap::Cap;
+pub use cap::{Cap, EnclosingCapError};
+
+mod cell_id;
+pub use cell_id::CellId;
+
+mod cell_union;
+pub use cell_union::CellUnion;
 
 mod chord_length;
 pub use chord_length::ChordLength;
 
+mod clipping;
+
 mod great_circle;
 pub use great_circle::GreatCircle;
 
+mod kd_tree;
+pub use kd_tree::KdTree;
+
 mod minor_arc;
 pub use minor_arc::MinorArc;
 
+mod polygon;
+pub use polygon::Polygon;
+
+mod polyline;
+pub use polyline::Polyline;
+
 mod rectangle;
 pub use rectangle::Rectangle;
 
+mod region;
+pub use region::Region;
+
+mod region_coverer;
+pub use region_coverer::RegionCoverer;
+
+mod simplification;
+
 mod sloop;
 pub use sloop::is_loop_clockwise;
 pub use sloop::Loop;
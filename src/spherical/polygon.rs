@@ -0,0 +1,748 @@
+use crate::{Angle, NVector, Vec3};
+
+use super::{
+    base::{angle_radians_between, side},
+    sloop::triangulate_chain,
+    ChordLength, Loop, MinorArc, Rectangle, Sphere,
+};
+
+/// A polygon on the sphere: an outer [Loop] (the shell) and zero or more inner [Loop]s (the holes).
+///
+/// Polygons are either:
+/// - valid - the holes are inside the shell and do not intersect each other nor the shell - this property is not
+///   enforced at runtime, therefore operations are undefined on invalid polygons
+/// - or, [empty](crate::spherical::Polygon::is_empty).
+#[derive(PartialEq, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))] // codecov:ignore:this
+pub struct Polygon {
+    shell: Loop,
+    holes: Vec<Loop>,
+}
+
+impl Polygon {
+    /// an empty [Polygon]: empty shell and no hole.
+    pub const EMPTY: Self = Self {
+        shell: Loop::EMPTY,
+        holes: Vec::new(),
+    };
+
+    /// Creates a new polygon from the given shell and holes.
+    ///
+    /// [Empty](crate::spherical::Loop::is_empty) holes are ignored and an [empty](crate::spherical::Polygon::EMPTY)
+    /// polygon is returned if the given shell is [empty](crate::spherical::Loop::is_empty).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::NVector;
+    /// use jord::spherical::{Loop, Polygon};
+    ///
+    /// let shell = Loop::new(&[
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(10.0, 0.0),
+    ///     NVector::from_lat_long_degrees(10.0, 10.0),
+    ///     NVector::from_lat_long_degrees(0.0, 10.0),
+    /// ]);
+    ///
+    /// let hole = Loop::new(&[
+    ///     NVector::from_lat_long_degrees(4.0, 4.0),
+    ///     NVector::from_lat_long_degrees(6.0, 4.0),
+    ///     NVector::from_lat_long_degrees(6.0, 6.0),
+    ///     NVector::from_lat_long_degrees(4.0, 6.0),
+    /// ]);
+    ///
+    /// let p = Polygon::new(shell, vec![hole, Loop::EMPTY]);
+    /// assert_eq!(1, p.num_holes());
+    ///
+    /// assert!(Polygon::new(Loop::EMPTY, Vec::new()).is_empty());
+    /// ```
+    pub fn new(shell: Loop, holes: Vec<Loop>) -> Self {
+        if shell.is_empty() {
+            Self::EMPTY
+        } else {
+            Self {
+                shell,
+                holes: holes.into_iter().filter(|h| !h.is_empty()).collect(),
+            }
+        }
+    }
+
+    /// Determines whether this polygon is empty, i.e. its shell is [empty](crate::spherical::Loop::is_empty).
+    pub fn is_empty(&self) -> bool {
+        self.shell.is_empty()
+    }
+
+    /// Returns the shell (outer loop) of this polygon.
+    pub fn shell(&self) -> &Loop {
+        &self.shell
+    }
+
+    /// Returns the number of holes of this polygon.
+    pub fn num_holes(&self) -> usize {
+        self.holes.len()
+    }
+
+    /// Returns a iterator over the holes (inner loops) of this polygon.
+    pub fn iter_holes(&self) -> impl Iterator<Item = &Loop> {
+        self.holes.iter()
+    }
+
+    /// Calculates the [minimum bounding rectangle](crate::spherical::Rectangle) of this polygon: since holes are
+    /// inside the shell, this is the [bound](crate::spherical::Loop::bound) of the shell.
+    pub fn bound(&self) -> Rectangle {
+        self.shell.bound()
+    }
+
+    /// Determines whether the **interior** of this polygon contains the given position: the interior of the shell
+    /// contains the position and no hole contains the position - including on its boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::NVector;
+    /// use jord::spherical::{Loop, Polygon};
+    ///
+    /// let shell = Loop::new(&[
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(10.0, 0.0),
+    ///     NVector::from_lat_long_degrees(10.0, 10.0),
+    ///     NVector::from_lat_long_degrees(0.0, 10.0),
+    /// ]);
+    ///
+    /// let hole = Loop::new(&[
+    ///     NVector::from_lat_long_degrees(4.0, 4.0),
+    ///     NVector::from_lat_long_degrees(6.0, 4.0),
+    ///     NVector::from_lat_long_degrees(6.0, 6.0),
+    ///     NVector::from_lat_long_degrees(4.0, 6.0),
+    /// ]);
+    ///
+    /// let p = Polygon::new(shell, vec![hole]);
+    ///
+    /// assert!(p.contains_position(NVector::from_lat_long_degrees(2.0, 2.0)));
+    /// assert!(!p.contains_position(NVector::from_lat_long_degrees(5.0, 5.0)));
+    /// assert!(!p.contains_position(NVector::from_lat_long_degrees(11.0, 11.0)));
+    /// ```
+    pub fn contains_position(&self, p: NVector) -> bool {
+        self.shell.contains_position(p)
+            && self
+                .holes
+                .iter()
+                .all(|h| !h.contains_position(p) && !h.any_edge_contains_position(p))
+    }
+
+    /// Computes the distance from the given position to the boundary of this polygon: the closest edge of either the
+    /// shell or any hole.
+    ///
+    /// Note: if the given position is inside this polygon a non-zero length is returned. If this is not desirable,
+    /// use [contains_position](crate::spherical::Polygon::contains_position) beforehand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::NVector;
+    /// use jord::spherical::{ChordLength, Loop, Polygon};
+    ///
+    /// let shell = Loop::new(&[
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(10.0, 0.0),
+    ///     NVector::from_lat_long_degrees(10.0, 10.0),
+    ///     NVector::from_lat_long_degrees(0.0, 10.0),
+    /// ]);
+    ///
+    /// let hole = Loop::new(&[
+    ///     NVector::from_lat_long_degrees(4.0, 4.0),
+    ///     NVector::from_lat_long_degrees(6.0, 4.0),
+    ///     NVector::from_lat_long_degrees(6.0, 6.0),
+    ///     NVector::from_lat_long_degrees(4.0, 6.0),
+    /// ]);
+    ///
+    /// let p = Polygon::new(shell, vec![hole]);
+    ///
+    /// // closest to a vertex of the hole.
+    /// let p1 = NVector::from_lat_long_degrees(3.9, 3.9);
+    /// assert_eq!(
+    ///     ChordLength::new(p1, NVector::from_lat_long_degrees(4.0, 4.0)),
+    ///     p.distance_to_boundary(p1)
+    /// );
+    /// ```
+    pub fn distance_to_boundary(&self, p: NVector) -> ChordLength {
+        self.holes
+            .iter()
+            .map(|h| h.distance_to_boundary(p))
+            .fold(self.shell.distance_to_boundary(p), |acc, d| acc.min(d))
+    }
+
+    /// Calculates the [spherical excess](https://en.wikipedia.org/wiki/Spherical_trigonometry#Area_and_spherical_excess)
+    /// of this polygon: the spherical excess of the shell minus the spherical excess of each hole.
+    ///
+    /// The area of this polygon can be obtained by multiplying the spherical excess by the sphere radius squared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, NVector};
+    /// use jord::spherical::{Loop, Polygon, Sphere};
+    ///
+    /// let shell = Loop::new(&[
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(10.0, 0.0),
+    ///     NVector::from_lat_long_degrees(10.0, 10.0),
+    ///     NVector::from_lat_long_degrees(0.0, 10.0),
+    /// ]);
+    ///
+    /// let hole = Loop::new(&[
+    ///     NVector::from_lat_long_degrees(4.0, 4.0),
+    ///     NVector::from_lat_long_degrees(6.0, 4.0),
+    ///     NVector::from_lat_long_degrees(6.0, 6.0),
+    ///     NVector::from_lat_long_degrees(4.0, 6.0),
+    /// ]);
+    ///
+    /// let p = Polygon::new(shell.clone(), vec![hole.clone()]);
+    ///
+    /// assert_eq!(
+    ///     shell.spherical_excess() - hole.spherical_excess(),
+    ///     p.spherical_excess()
+    /// );
+    ///
+    /// // area in km^2 (on Earth):
+    /// let r = Sphere::EARTH.radius().as_kilometres();
+    /// assert_eq!(1_183_930.0, (p.spherical_excess().as_radians() * r * r).round());
+    /// ```
+    pub fn spherical_excess(&self) -> Angle {
+        self.holes
+            .iter()
+            .fold(self.shell.spherical_excess(), |acc, h| {
+                acc - h.spherical_excess()
+            })
+    }
+
+    /// Triangulates this polygon using the [Ear Clipping](https://www.geometrictools.com/Documentation/TriangulationByEarClipping.pdf)
+    /// method: each hole is first connected to the shell by a bridge - a minor arc between mutually visible vertices -
+    /// and the resulting chain is then triangulated.
+    ///
+    /// This method returns either (number of vertices of the shell and holes + 2 * number of holes - 2) triangles - as
+    /// triples of [NVector]s, if the triangulation succeeds, or [empty](Vec::new) if the triangulation fails - which
+    /// should only occur for invalid polygons.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::NVector;
+    /// use jord::spherical::{Loop, Polygon};
+    ///
+    /// let shell = Loop::new(&[
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(10.0, 0.0),
+    ///     NVector::from_lat_long_degrees(10.0, 10.0),
+    ///     NVector::from_lat_long_degrees(0.0, 10.0),
+    /// ]);
+    ///
+    /// let hole = Loop::new(&[
+    ///     NVector::from_lat_long_degrees(4.0, 4.0),
+    ///     NVector::from_lat_long_degrees(6.0, 4.0),
+    ///     NVector::from_lat_long_degrees(6.0, 6.0),
+    ///     NVector::from_lat_long_degrees(4.0, 6.0),
+    /// ]);
+    ///
+    /// let p = Polygon::new(shell, vec![hole]);
+    ///
+    /// assert_eq!(8, p.triangulate().len());
+    /// ```
+    pub fn triangulate(&self) -> Vec<(NVector, NVector, NVector)> {
+        if self.holes.is_empty() {
+            self.shell.triangulate()
+        } else {
+            let chain = self.bridged_chain();
+            triangulate_chain(&chain)
+        }
+    }
+
+    /// Connects every hole to the shell, returning the vertices of resulting chain in clockwise order or empty if
+    /// a bridge cannot be found for a hole.
+    fn bridged_chain(&self) -> Vec<NVector> {
+        let mut chain: Vec<NVector> = self.shell.iter_vertices().copied().collect();
+        for (i, hole) in self.holes.iter().enumerate() {
+            // traverse the hole anti-clockwise so that the interior of the polygon is always on the same side.
+            let mut hvs: Vec<NVector> = hole.iter_vertices().copied().collect();
+            hvs.reverse();
+            match self.find_bridge(&chain, &hvs, &self.holes[i + 1..]) {
+                Some((ci, hi)) => {
+                    let mut merged = Vec::with_capacity(chain.len() + hvs.len() + 2);
+                    merged.extend_from_slice(&chain[..=ci]);
+                    merged.extend_from_slice(&hvs[hi..]);
+                    merged.extend_from_slice(&hvs[..=hi]);
+                    merged.extend_from_slice(&chain[ci..]);
+                    chain = merged;
+                }
+                None => return Vec::new(),
+            }
+        }
+        chain
+    }
+
+    /// Finds a bridge between a vertex of the given chain and a vertex of the given hole which crosses neither the
+    /// chain, the hole nor the remaining holes and lies inside this polygon. Returns the index of both vertices.
+    ///
+    /// The bridge is first searched by casting a ray from the vertex of the hole farthest from its centroid (see
+    /// [visible_vertex]); if that fails, all pairs of vertices are scanned by increasing length.
+    fn find_bridge(
+        &self,
+        chain: &[NVector],
+        hole: &[NVector],
+        remaining: &[Loop],
+    ) -> Option<(usize, usize)> {
+        let mut edges: Vec<MinorArc> = chain_edges(chain);
+        edges.extend(chain_edges(hole));
+        for r in remaining {
+            edges.extend(r.iter_edges());
+        }
+
+        visible_vertex(chain, hole, remaining)
+            .filter(|(ci, hi)| self.is_bridge(chain[*ci], hole[*hi], &edges))
+            .or_else(|| self.scan_bridges(chain, hole, &edges))
+    }
+
+    /// Finds the shortest bridge between a vertex of the given chain and a vertex of the given hole which crosses
+    /// none of the given edges and lies inside this polygon. Returns the index of both vertices.
+    fn scan_bridges(
+        &self,
+        chain: &[NVector],
+        hole: &[NVector],
+        edges: &[MinorArc],
+    ) -> Option<(usize, usize)> {
+        let mut candidates: Vec<(usize, usize, ChordLength)> = Vec::new();
+        for (ci, c) in chain.iter().enumerate() {
+            if is_duplicated(chain, ci) {
+                continue;
+            }
+            for (hi, h) in hole.iter().enumerate() {
+                candidates.push((ci, hi, ChordLength::new(*c, *h)));
+            }
+        }
+        candidates.sort_by_key(|c| c.2);
+
+        candidates
+            .into_iter()
+            .find(|(ci, hi, _)| self.is_bridge(chain[*ci], hole[*hi], edges))
+            .map(|(ci, hi, _)| (ci, hi))
+    }
+
+    /// Determines whether the minor arc from `a` to `b` crosses none of the given edges and lies inside this polygon.
+    fn is_bridge(&self, a: NVector, b: NVector, edges: &[MinorArc]) -> bool {
+        let bridge = MinorArc::new(a, b);
+        let crosses = edges
+            .iter()
+            .filter(|e| e.start() != a && e.end() != a && e.start() != b && e.end() != b)
+            .any(|e| bridge.intersection(*e).is_some());
+        if crosses {
+            false
+        } else {
+            // the bridge crosses no edge: it is inside if its mid-position is inside.
+            match Sphere::interpolated_position(a, b, 0.5) {
+                Some(m) => {
+                    self.shell.contains_position(m)
+                        && self.holes.iter().all(|h| !h.contains_position(m))
+                }
+                None => false,
+            }
+        }
+    }
+}
+
+/// Finds a vertex of the given chain visible from the vertex `M` of the given hole farthest from the centroid of the
+/// hole - as done when eliminating holes before ear clipping a polygon:
+/// - a ray is cast from `M` away from the centroid: it cannot cross the hole and first hits the edge of the chain at
+///   `I`,
+/// - `P` is the end of that edge farthest along the ray,
+/// - the visible vertex is the vertex inside the triangle (`M`, `I`, `P`) - if any, otherwise `P` - which minimises
+///   the angle with the ray at `M`, and then the distance to `M`.
+///
+/// Returns the index of both vertices or `None` if the ray first hits one of the remaining holes or if the visible
+/// vertex is already used by a bridge.
+fn visible_vertex(
+    chain: &[NVector],
+    hole: &[NVector],
+    remaining: &[Loop],
+) -> Option<(usize, usize)> {
+    let hvs: Vec<Vec3> = hole.iter().map(|v| v.as_vec3()).collect();
+    let c = Vec3::mean(&hvs);
+    let (hi, m) = hvs
+        .iter()
+        .copied()
+        .enumerate()
+        .min_by(|a, b| a.1.dot_prod(c).total_cmp(&b.1.dot_prod(c)))?;
+    // direction of the ray at M.
+    let d = (m * c.dot_prod(m) - c).unit();
+    if d == Vec3::ZERO {
+        return None;
+    }
+
+    let (ei, i, angle) = ray_cast(m, d, chain_edges(chain).into_iter())?;
+    let blocked = ray_cast(m, d, remaining.iter().flat_map(|r| r.iter_edges().copied()))
+        .map_or(false, |(_, _, a)| a < angle);
+    if blocked {
+        return None;
+    }
+
+    let len = chain.len();
+    let (a, b) = (ei, (ei + 1) % len);
+    let p = if chain[a].as_vec3().dot_prod(d) >= chain[b].as_vec3().dot_prod(d) {
+        a
+    } else {
+        b
+    };
+    let pv = chain[p].as_vec3();
+    // angle at M between the ray and the given vertex, then distance.
+    let key = |v: Vec3| {
+        (
+            angle_radians_between(m.cross_prod(d), m.cross_prod(v), None),
+            m.dot_prod(v),
+        )
+    };
+    let ci = (0..len)
+        .filter(|j| *j == p || in_triangle(chain[*j].as_vec3(), m, i, pv))
+        .min_by(|x, y| {
+            let (ax, dx) = key(chain[*x].as_vec3());
+            let (ay, dy) = key(chain[*y].as_vec3());
+            ax.total_cmp(&ay).then(dy.total_cmp(&dx))
+        })?;
+    if is_duplicated(chain, ci) {
+        None
+    } else {
+        Some((ci, hi))
+    }
+}
+
+/// Casts a ray from `m` in direction `d` (unit vector orthogonal to `m`) along the great circle and returns the index
+/// of the first crossed edge, the crossing position and the angle travelled from `m` (in ]0, PI[).
+fn ray_cast<I>(m: Vec3, d: Vec3, edges: I) -> Option<(usize, Vec3, f64)>
+where
+    I: Iterator<Item = MinorArc>,
+{
+    let g = m.cross_prod(d);
+    edges
+        .enumerate()
+        .filter_map(|(i, e)| {
+            // edges along the great circle of the ray are not crossed.
+            let x = g.cross_prod(e.normal()).unit();
+            if x == Vec3::ZERO {
+                return None;
+            }
+            let x = if x.dot_prod(d) < 0.0 { -x } else { x };
+            let along = x.dot_prod(d);
+            if along <= 0.0 || !e.contains_position(NVector::new(x)) {
+                None
+            } else {
+                Some((i, x, along.atan2(x.dot_prod(m))))
+            }
+        })
+        .min_by(|a, b| a.2.total_cmp(&b.2))
+}
+
+/// Determines whether the given position is inside or on the boundary of the triangle (`a`, `b`, `c`).
+fn in_triangle(v: Vec3, a: Vec3, b: Vec3, c: Vec3) -> bool {
+    let s = [side(v, a, b), side(v, b, c), side(v, c, a)];
+    s.iter().all(|s| *s >= 0) || s.iter().all(|s| *s <= 0)
+}
+
+/// Determines whether the vertex at the given index appears more than once in the given chain: vertices already used
+/// by a bridge appear twice.
+fn is_duplicated(chain: &[NVector], i: usize) -> bool {
+    chain.iter().filter(|v| **v == chain[i]).count() > 1
+}
+
+/// Returns the edges of the closed chain defined by the given vertices.
+fn chain_edges(vs: &[NVector]) -> Vec<MinorArc> {
+    let len = vs.len();
+    (0..len)
+        .map(|i| MinorArc::new(vs[i], vs[(i + 1) % len]))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        spherical::{Loop, Polygon, Rectangle},
+        Angle, LatLong, NVector,
+    };
+
+    use super::visible_vertex;
+
+    fn shell() -> Loop {
+        Loop::new(&[
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(10.0, 0.0),
+            NVector::from_lat_long_degrees(10.0, 10.0),
+            NVector::from_lat_long_degrees(0.0, 10.0),
+        ])
+    }
+
+    fn hole1() -> Loop {
+        Loop::new(&[
+            NVector::from_lat_long_degrees(2.0, 2.0),
+            NVector::from_lat_long_degrees(4.0, 2.0),
+            NVector::from_lat_long_degrees(4.0, 4.0),
+            NVector::from_lat_long_degrees(2.0, 4.0),
+        ])
+    }
+
+    fn hole2() -> Loop {
+        Loop::new(&[
+            NVector::from_lat_long_degrees(6.0, 6.0),
+            NVector::from_lat_long_degrees(8.0, 6.0),
+            NVector::from_lat_long_degrees(7.0, 8.0),
+        ])
+    }
+
+    fn polygon() -> Polygon {
+        Polygon::new(shell(), vec![hole1(), hole2()])
+    }
+
+    #[test]
+    fn empty() {
+        assert!(Polygon::EMPTY.is_empty());
+        assert_eq!(0, Polygon::EMPTY.num_holes());
+        assert_eq!(Angle::ZERO, Polygon::EMPTY.spherical_excess());
+        assert!(Polygon::EMPTY.triangulate().is_empty());
+        assert!(!Polygon::EMPTY.contains_position(NVector::from_lat_long_degrees(0.0, 0.0)));
+        assert_eq!(Polygon::EMPTY, Polygon::new(Loop::EMPTY, vec![hole1()]));
+    }
+
+    #[test]
+    fn new_ignores_empty_holes() {
+        let p = Polygon::new(shell(), vec![Loop::EMPTY, hole1()]);
+        assert_eq!(1, p.num_holes());
+        assert_eq!(Some(&hole1()), p.iter_holes().next());
+        assert_eq!(&shell(), p.shell());
+    }
+
+    // contains_position
+
+    #[test]
+    fn contains_position() {
+        let p = polygon();
+        assert!(p.contains_position(NVector::from_lat_long_degrees(1.0, 1.0)));
+        assert!(p.contains_position(NVector::from_lat_long_degrees(5.0, 5.0)));
+        assert!(p.contains_position(NVector::from_lat_long_degrees(9.0, 1.0)));
+    }
+
+    #[test]
+    fn does_not_contain_position_in_hole() {
+        let p = polygon();
+        assert!(!p.contains_position(NVector::from_lat_long_degrees(3.0, 3.0)));
+        assert!(!p.contains_position(NVector::from_lat_long_degrees(7.0, 7.0)));
+    }
+
+    #[test]
+    fn does_not_contain_position_on_hole_boundary() {
+        let p = polygon();
+        assert!(!p.contains_position(NVector::from_lat_long_degrees(2.0, 2.0)));
+        // on the meridian edge of the hole.
+        assert!(!p.contains_position(NVector::from_lat_long_degrees(3.0, 2.0)));
+    }
+
+    #[test]
+    fn does_not_contain_position_outside_shell() {
+        let p = polygon();
+        assert!(!p.contains_position(NVector::from_lat_long_degrees(11.0, 5.0)));
+        assert!(!p.contains_position(NVector::from_lat_long_degrees(0.0, 5.0)));
+    }
+
+    // distance_to_boundary
+
+    #[test]
+    fn distance_to_boundary() {
+        let p = polygon();
+        let inside_hole = NVector::from_lat_long_degrees(3.0, 3.0);
+        assert_eq!(
+            hole1().distance_to_boundary(inside_hole),
+            p.distance_to_boundary(inside_hole)
+        );
+
+        let near_shell = NVector::from_lat_long_degrees(9.9, 5.0);
+        assert_eq!(
+            shell().distance_to_boundary(near_shell),
+            p.distance_to_boundary(near_shell)
+        );
+
+        let on_hole = NVector::from_lat_long_degrees(6.0, 6.0);
+        assert_eq!(
+            Angle::ZERO,
+            p.distance_to_boundary(on_hole).to_angle().round_d7()
+        );
+    }
+
+    // bound
+
+    #[test]
+    fn bound() {
+        let b = polygon().bound();
+        assert_eq!(shell().bound(), b);
+        assert_ne!(Rectangle::EMPTY, b);
+        assert!(b.contains_position(LatLong::from_degrees(3.0, 3.0)));
+    }
+
+    // spherical_excess
+
+    #[test]
+    fn spherical_excess() {
+        let expected =
+            shell().spherical_excess() - hole1().spherical_excess() - hole2().spherical_excess();
+        assert_eq!(expected, polygon().spherical_excess());
+    }
+
+    #[test]
+    fn spherical_excess_no_hole() {
+        assert_eq!(
+            shell().spherical_excess(),
+            Polygon::new(shell(), Vec::new()).spherical_excess()
+        );
+    }
+
+    // triangulate
+
+    #[test]
+    fn triangulate_no_hole() {
+        assert_eq!(
+            shell().triangulate(),
+            Polygon::new(shell(), Vec::new()).triangulate()
+        );
+    }
+
+    #[test]
+    fn triangulate_one_hole() {
+        let p = Polygon::new(shell(), vec![hole1()]);
+        assert_triangulation(&p, 8);
+    }
+
+    #[test]
+    fn triangulate_two_holes() {
+        let p = polygon();
+        // 4 + 4 + 3 vertices + 2 * 2 holes - 2.
+        assert_triangulation(&p, 13);
+    }
+
+    #[test]
+    fn triangulate_concave_shell() {
+        let shell = Loop::new(&[
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(10.0, 0.0),
+            NVector::from_lat_long_degrees(10.0, 2.0),
+            NVector::from_lat_long_degrees(2.0, 2.0),
+            NVector::from_lat_long_degrees(2.0, 8.0),
+            NVector::from_lat_long_degrees(10.0, 8.0),
+            NVector::from_lat_long_degrees(10.0, 10.0),
+            NVector::from_lat_long_degrees(0.0, 10.0),
+        ]);
+        let hole = Loop::new(&[
+            NVector::from_lat_long_degrees(0.5, 4.0),
+            NVector::from_lat_long_degrees(1.5, 4.0),
+            NVector::from_lat_long_degrees(1.5, 6.0),
+            NVector::from_lat_long_degrees(0.5, 6.0),
+        ]);
+        let p = Polygon::new(shell, vec![hole]);
+        assert_triangulation(&p, 12);
+    }
+
+    #[test]
+    fn triangulate_many_vertices() {
+        let circle = |radius: f64, n: usize| {
+            let vs: Vec<NVector> = (0..n)
+                .map(|i| {
+                    let a = (i as f64) * 2.0 * std::f64::consts::PI / (n as f64);
+                    NVector::from_lat_long_degrees(radius * a.sin(), radius * a.cos())
+                })
+                .collect();
+            Loop::new(&vs)
+        };
+        let shell = circle(10.0, 1000);
+        let hole = circle(5.0, 1000);
+        // bridged without scanning all pairs of vertices.
+        let chain: Vec<NVector> = shell.iter_vertices().copied().collect();
+        let mut hvs: Vec<NVector> = hole.iter_vertices().copied().collect();
+        hvs.reverse();
+        assert!(visible_vertex(&chain, &hvs, &[]).is_some());
+        let p = Polygon::new(shell, vec![hole]);
+        assert_triangulation(&p, 2000);
+    }
+
+    // visible_vertex
+
+    #[test]
+    fn visible_vertex_is_bridge() {
+        let p = polygon();
+        let chain: Vec<NVector> = shell().iter_vertices().copied().collect();
+        for (i, h) in p.iter_holes().enumerate() {
+            let mut hole: Vec<NVector> = h.iter_vertices().copied().collect();
+            hole.reverse();
+            let (ci, hi) = visible_vertex(&chain, &hole, &[]).unwrap();
+            assert!(p.is_bridge(chain[ci], hole[hi], &[]), "hole {}", i);
+        }
+    }
+
+    #[test]
+    fn visible_vertex_inside_triangle() {
+        // the ray from (5, 4) heads south and hits the shell at (1.6, 4) between (2, 5) and (0, 0): the reflex vertex
+        // (2, 2) of the shell is inside the triangle formed with (0, 0).
+        let shell: Vec<NVector> = vec![
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(1.0, 0.0),
+            NVector::from_lat_long_degrees(2.0, 2.0),
+            NVector::from_lat_long_degrees(3.0, 0.0),
+            NVector::from_lat_long_degrees(10.0, 0.0),
+            NVector::from_lat_long_degrees(10.0, 10.0),
+            NVector::from_lat_long_degrees(0.0, 10.0),
+            NVector::from_lat_long_degrees(2.0, 5.0),
+        ];
+        let hole: Vec<NVector> = vec![
+            NVector::from_lat_long_degrees(5.0, 4.0),
+            NVector::from_lat_long_degrees(8.0, 3.9),
+            NVector::from_lat_long_degrees(8.0, 4.1),
+        ];
+        assert_eq!(Some((2, 0)), visible_vertex(&shell, &hole, &[]));
+    }
+
+    #[test]
+    fn visible_vertex_blocked_by_remaining_hole() {
+        let chain: Vec<NVector> = shell().iter_vertices().copied().collect();
+        let hole: Vec<NVector> = vec![
+            NVector::from_lat_long_degrees(5.0, 4.0),
+            NVector::from_lat_long_degrees(8.0, 3.9),
+            NVector::from_lat_long_degrees(8.0, 4.1),
+        ];
+        let blocking = Loop::new(&[
+            NVector::from_lat_long_degrees(2.0, 3.0),
+            NVector::from_lat_long_degrees(3.0, 3.0),
+            NVector::from_lat_long_degrees(3.0, 5.0),
+            NVector::from_lat_long_degrees(2.0, 5.0),
+        ]);
+        assert!(visible_vertex(&chain, &hole, &[]).is_some());
+        assert_eq!(
+            None,
+            visible_vertex(&chain, &hole, std::slice::from_ref(&blocking))
+        );
+        // falls back to scanning all candidates.
+        let p = Polygon::new(shell(), vec![Loop::new(&hole), blocking]);
+        assert_triangulation(&p, 4 + 3 + 4 + 2);
+    }
+
+    fn assert_triangulation(p: &Polygon, expected_len: usize) {
+        let ts = p.triangulate();
+        assert_eq!(expected_len, ts.len());
+        // triangles cover the polygon: same spherical excess.
+        let excess = ts
+            .iter()
+            .map(|t| Loop::new(&[t.0, t.1, t.2]).spherical_excess())
+            .fold(Angle::ZERO, |acc, e| acc + e);
+        assert_eq!(p.spherical_excess().round_d7(), excess.round_d7());
+        // no triangle inside a hole.
+        for t in ts {
+            let l = Loop::new(&[t.0, t.1, t.2]);
+            for h in p.iter_holes() {
+                for v in h.iter_vertices() {
+                    assert!(!l.contains_position(*v));
+                }
+            }
+        }
+    }
+}
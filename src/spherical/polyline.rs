@@ -0,0 +1,1024 @@
+use crate::{Angle, Length, NVector};
+
+use super::{
+    buffer::{buffer_radius, chain_buffer},
+    simplification::{crossing_candidates, douglas_peucker, visvalingam_whyatt},
+    ChordLength, MinorArc, Polygon, Rectangle, Sphere,
+};
+
+/// An open chain of vertices connected by [minor arcs](crate::spherical::MinorArc) - e.g. a route or a track.
+///
+/// Unlike a [Loop](crate::spherical::Loop), the last vertex is not implicitly connected to the first vertex.
+///
+/// Polylines are either:
+/// - made of at least 2 vertices, where consecutive vertices are distinct and not the antipode of one another -
+///   the latter is not enforced at runtime, therefore operations are undefined on such polylines
+/// - or, [empty](crate::spherical::Polyline::is_empty).
+///
+/// # Examples
+///
+/// ```
+/// use jord::{LatLong, Length, NVector};
+/// use jord::spherical::{Polyline, Sphere};
+///
+/// let route = Polyline::new(&[
+///     NVector::from_lat_long_degrees(55.605, 13.0038),
+///     NVector::from_lat_long_degrees(55.4295, 13.82),
+///     NVector::from_lat_long_degrees(56.0294, 14.1567),
+/// ]);
+///
+/// assert_eq!(Length::from_kilometres(124.921), route.length(&Sphere::EARTH).round_m());
+///
+/// let halfway = route.position_at_fraction(0.5).unwrap();
+/// assert_eq!(
+///     LatLong::from_degrees(55.493833, 13.8555913),
+///     LatLong::from_nvector(halfway).round_d7()
+/// );
+/// ```
+#[derive(PartialEq, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))] // codecov:ignore:this
+pub struct Polyline {
+    vertices: Vec<NVector>,
+    edges: Vec<MinorArc>,
+}
+
+impl Polyline {
+    /// an empty [Polyline]: 0 vertex and edge.
+    pub const EMPTY: Self = Self {
+        vertices: Vec::new(),
+        edges: Vec::new(),
+    };
+
+    /// Creates a new polyline from the given vertices. Consecutive equal vertices are collapsed into a single vertex.
+    ///
+    /// An [empty](crate::spherical::Polyline::EMPTY) polyline is returned if less than 2 distinct vertices are
+    /// given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::NVector;
+    /// use jord::spherical::Polyline;
+    ///
+    /// let p = NVector::from_lat_long_degrees(0.0, 0.0);
+    /// assert!(Polyline::new(&[p, p]).is_empty());
+    ///
+    /// let pl = Polyline::new(&[p, p, NVector::from_lat_long_degrees(0.0, 1.0)]);
+    /// assert_eq!(2, pl.num_vertices());
+    /// ```
+    pub fn new(vs: &[NVector]) -> Self {
+        let mut vertices = vs.to_vec();
+        vertices.dedup();
+        if vertices.len() < 2 {
+            Self::EMPTY
+        } else {
+            let edges = vertices
+                .windows(2)
+                .map(|w| MinorArc::new(w[0], w[1]))
+                .collect();
+            Self { vertices, edges }
+        }
+    }
+
+    /// Determines whether this polyline is empty: i.e. less than 2 distinct vertices were supplied at construction.
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+
+    /// Returns the number of vertices of this polyline.
+    pub fn num_vertices(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Returns the vertex at the given index. Panics if the index is out of bounds.
+    pub fn vertex(&self, i: usize) -> NVector {
+        self.vertices[i]
+    }
+
+    /// Returns a iterator over the vertices of this polyline, from the first to the last.
+    pub fn iter_vertices(&self) -> impl Iterator<Item = &NVector> {
+        self.vertices.iter()
+    }
+
+    /// Returns a iterator over the edges of this polyline, from the first to the last.
+    pub fn iter_edges(&self) -> impl Iterator<Item = &MinorArc> {
+        self.edges.iter()
+    }
+
+    /// Computes the length of this polyline on the given sphere: sum of the lengths of all edges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Length, NVector};
+    /// use jord::spherical::{Polyline, Sphere};
+    ///
+    /// let pl = Polyline::new(&[
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(0.0, 90.0),
+    ///     NVector::from_lat_long_degrees(0.0, 180.0),
+    ///     NVector::from_lat_long_degrees(0.0, -90.0),
+    /// ]);
+    ///
+    /// // 3/4 of the circumference of the sphere.
+    /// let s = Sphere::new(Length::from_metres(1.0));
+    /// assert_eq!(Length::from_metres(1.5 * std::f64::consts::PI), pl.length(&s));
+    /// ```
+    pub fn length(&self, sphere: &Sphere) -> Length {
+        self.angle() * sphere.radius()
+    }
+
+    /// Computes the position at the given distance along this polyline from its first vertex on the given sphere.
+    /// Returns `None` if this polyline is empty or if the given distance is negative or greater than the
+    /// [length](crate::spherical::Polyline::length) of this polyline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, Length, NVector};
+    /// use jord::spherical::{Polyline, Sphere};
+    ///
+    /// let pl = Polyline::new(&[
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(0.0, 1.0),
+    ///     NVector::from_lat_long_degrees(1.0, 1.0),
+    /// ]);
+    ///
+    /// let d = Sphere::EARTH.distance(pl.vertex(0), pl.vertex(1)) * 1.5;
+    /// let p = pl.position_at_distance(d, &Sphere::EARTH).unwrap();
+    /// assert_eq!(LatLong::from_degrees(0.5, 1.0), LatLong::from_nvector(p).round_d7());
+    ///
+    /// assert!(pl.position_at_distance(Length::from_metres(-1.0), &Sphere::EARTH).is_none());
+    /// ```
+    pub fn position_at_distance(&self, distance: Length, sphere: &Sphere) -> Option<NVector> {
+        self.position_at_angle(self.angle_at(distance, sphere))
+    }
+
+    /// Computes the position at the given fraction of the [length](crate::spherical::Polyline::length) of this
+    /// polyline from its first vertex. Returns `None` if this polyline is empty or if the given fraction is `< 0` or
+    /// `> 1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, NVector};
+    /// use jord::spherical::Polyline;
+    ///
+    /// let pl = Polyline::new(&[
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(0.0, 1.0),
+    ///     NVector::from_lat_long_degrees(0.0, 3.0),
+    /// ]);
+    ///
+    /// let p = pl.position_at_fraction(0.25).unwrap();
+    /// assert_eq!(LatLong::from_degrees(0.0, 0.75), LatLong::from_nvector(p).round_d7());
+    /// assert_eq!(Some(pl.vertex(2)), pl.position_at_fraction(1.0));
+    /// assert!(pl.position_at_fraction(1.1).is_none());
+    /// ```
+    pub fn position_at_fraction(&self, f: f64) -> Option<NVector> {
+        if !(0.0..=1.0).contains(&f) {
+            None
+        } else {
+            self.position_at_angle(f * self.angle())
+        }
+    }
+
+    /// Computes the position on this polyline which is the closest to the given position. Returns `None` if this
+    /// polyline is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, NVector};
+    /// use jord::spherical::Polyline;
+    ///
+    /// let pl = Polyline::new(&[
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(0.0, 10.0),
+    ///     NVector::from_lat_long_degrees(10.0, 10.0),
+    /// ]);
+    ///
+    /// let c = pl.closest_position(NVector::from_lat_long_degrees(-1.0, 5.0)).unwrap();
+    /// assert_eq!(LatLong::from_degrees(0.0, 5.0), LatLong::from_nvector(c).round_d7());
+    ///
+    /// let c = pl.closest_position(NVector::from_lat_long_degrees(11.0, 11.0)).unwrap();
+    /// assert_eq!(LatLong::from_degrees(10.0, 10.0), LatLong::from_nvector(c).round_d7());
+    /// ```
+    pub fn closest_position(&self, p: NVector) -> Option<NVector> {
+        self.closest(p).map(|(_, c)| c)
+    }
+
+    /// Computes how far the given position is along this polyline on the given sphere: i.e. the distance along this
+    /// polyline from its first vertex to the [closest position](crate::spherical::Polyline::closest_position) of the
+    /// given position. Returns `None` if this polyline is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Length, NVector};
+    /// use jord::spherical::{Polyline, Sphere};
+    ///
+    /// let pl = Polyline::new(&[
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(0.0, 10.0),
+    ///     NVector::from_lat_long_degrees(10.0, 10.0),
+    /// ]);
+    ///
+    /// let p = NVector::from_lat_long_degrees(5.0, 11.0);
+    /// let expected = Sphere::EARTH.distance(pl.vertex(0), pl.vertex(1))
+    ///     + Sphere::EARTH.distance(pl.vertex(1), pl.closest_position(p).unwrap());
+    /// assert_eq!(
+    ///     expected.round_mm(),
+    ///     pl.along_track_distance(p, &Sphere::EARTH).unwrap().round_mm()
+    /// );
+    /// ```
+    pub fn along_track_distance(&self, p: NVector, sphere: &Sphere) -> Option<Length> {
+        self.closest(p).map(|(i, c)| {
+            let before = self.edges[..i]
+                .iter()
+                .fold(Angle::ZERO, |acc, e| acc + edge_angle(e));
+            (before + Sphere::angle(self.vertices[i], c)) * sphere.radius()
+        })
+    }
+
+    /// Splits this polyline at the given distance along this polyline on the given sphere: the first returned
+    /// polyline goes from the first vertex to the position at the given distance and the second returned polyline
+    /// goes from the position at the given distance to the last vertex. The given distance is clamped to
+    /// [0, [length](crate::spherical::Polyline::length)], therefore either returned polyline may be
+    /// [empty](crate::spherical::Polyline::EMPTY).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, Length, NVector};
+    /// use jord::spherical::{Polyline, Sphere};
+    ///
+    /// let pl = Polyline::new(&[
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(0.0, 1.0),
+    ///     NVector::from_lat_long_degrees(1.0, 1.0),
+    /// ]);
+    ///
+    /// let d = Sphere::EARTH.distance(pl.vertex(0), pl.vertex(1)) * 0.5;
+    /// let (first, second) = pl.split_at_distance(d, &Sphere::EARTH);
+    /// assert_eq!(2, first.num_vertices());
+    /// assert_eq!(3, second.num_vertices());
+    /// assert_eq!(
+    ///     LatLong::from_degrees(0.0, 0.5),
+    ///     LatLong::from_nvector(second.vertex(0)).round_d7()
+    /// );
+    ///
+    /// let (first, second) = pl.split_at_distance(Length::ZERO, &Sphere::EARTH);
+    /// assert!(first.is_empty());
+    /// assert_eq!(pl, second);
+    /// ```
+    pub fn split_at_distance(&self, distance: Length, sphere: &Sphere) -> (Self, Self) {
+        self.split_at_angle(self.angle_at(distance, sphere))
+    }
+
+    /// Returns the part of this polyline between the 2 given distances along this polyline on the given sphere.
+    /// Both distances are clamped to [0, [length](crate::spherical::Polyline::length)]; an
+    /// [empty](crate::spherical::Polyline::EMPTY) polyline is returned if `from >= to`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, Length, NVector};
+    /// use jord::spherical::{Polyline, Sphere};
+    ///
+    /// let pl = Polyline::new(&[
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(0.0, 1.0),
+    ///     NVector::from_lat_long_degrees(0.0, 2.0),
+    /// ]);
+    ///
+    /// let one_degree = Sphere::EARTH.distance(pl.vertex(0), pl.vertex(1));
+    /// let sub = pl.sub_polyline(one_degree * 0.5, one_degree * 1.5, &Sphere::EARTH);
+    /// assert_eq!(
+    ///     vec![
+    ///         LatLong::from_degrees(0.0, 0.5),
+    ///         LatLong::from_degrees(0.0, 1.0),
+    ///         LatLong::from_degrees(0.0, 1.5),
+    ///     ],
+    ///     sub.iter_vertices()
+    ///         .map(|v| LatLong::from_nvector(*v).round_d7())
+    ///         .collect::<Vec<_>>()
+    /// );
+    /// ```
+    pub fn sub_polyline(&self, from: Length, to: Length, sphere: &Sphere) -> Self {
+        let from = self.angle_at(from, sphere);
+        let to = self.angle_at(to, sphere);
+        if from >= to {
+            return Self::EMPTY;
+        }
+        let (head, _) = self.split_at_angle(to);
+        let (_, res) = head.split_at_angle(from);
+        res
+    }
+
+    /// Returns the polyline made of the vertices of this polyline in reverse order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::NVector;
+    /// use jord::spherical::Polyline;
+    ///
+    /// let a = NVector::from_lat_long_degrees(0.0, 0.0);
+    /// let b = NVector::from_lat_long_degrees(0.0, 1.0);
+    ///
+    /// assert_eq!(Polyline::new(&[b, a]), Polyline::new(&[a, b]).reverse());
+    /// ```
+    pub fn reverse(&self) -> Self {
+        let mut vertices = self.vertices.clone();
+        vertices.reverse();
+        Self::new(&vertices)
+    }
+
+    /// Simplifies this polyline using the [Douglas-Peucker](https://en.wikipedia.org/wiki/Ramer%E2%80%93Douglas%E2%80%93Peucker_algorithm)
+    /// algorithm: the returned polyline retains the first and last vertices and a subset of the other vertices such
+    /// that every removed vertex is within the given tolerance of the returned polyline.
+    ///
+    /// The tolerance is the central angle between a vertex and the closest position on the simplified polyline: a
+    /// distance on a sphere can be converted using [Sphere::distance_to_angle](crate::spherical::Sphere::distance_to_angle).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Length, NVector};
+    /// use jord::spherical::{Polyline, Sphere};
+    ///
+    /// let pl = Polyline::new(&[
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(0.0001, 0.5),
+    ///     NVector::from_lat_long_degrees(0.0, 1.0),
+    ///     NVector::from_lat_long_degrees(0.5, 1.5),
+    ///     NVector::from_lat_long_degrees(0.0, 2.0),
+    /// ]);
+    ///
+    /// // 100 metres on Earth.
+    /// let tolerance = Sphere::EARTH.distance_to_angle(Length::from_metres(100.0));
+    /// let s = pl.simplify_douglas_peucker(tolerance);
+    /// assert_eq!(4, s.num_vertices());
+    /// assert_eq!(pl.vertex(0), s.vertex(0));
+    /// assert_eq!(pl.vertex(3), s.vertex(2));
+    /// ```
+    pub fn simplify_douglas_peucker(&self, tolerance: Angle) -> Self {
+        Self::new(&douglas_peucker(&self.vertices, tolerance))
+    }
+
+    /// Simplifies this polyline using the Douglas-Peucker algorithm with a tolerance expressed as a distance on the
+    /// given sphere - see [simplify_douglas_peucker](crate::spherical::Polyline::simplify_douglas_peucker).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Length, NVector};
+    /// use jord::spherical::{Polyline, Sphere};
+    ///
+    /// let pl = Polyline::new(&[
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(0.0001, 0.5),
+    ///     NVector::from_lat_long_degrees(0.0, 1.0),
+    ///     NVector::from_lat_long_degrees(0.5, 1.5),
+    ///     NVector::from_lat_long_degrees(0.0, 2.0),
+    /// ]);
+    ///
+    /// let tolerance = Length::from_metres(100.0);
+    /// assert_eq!(
+    ///     pl.simplify_douglas_peucker(Sphere::EARTH.distance_to_angle(tolerance)),
+    ///     pl.simplify_douglas_peucker_by_distance(tolerance, &Sphere::EARTH)
+    /// );
+    /// assert_eq!(4, pl.simplify_douglas_peucker_by_distance(tolerance, &Sphere::EARTH).num_vertices());
+    /// ```
+    pub fn simplify_douglas_peucker_by_distance(&self, tolerance: Length, sphere: &Sphere) -> Self {
+        self.simplify_douglas_peucker(sphere.distance_to_angle(tolerance))
+    }
+
+    /// Simplifies this polyline using the [Visvalingam-Whyatt](https://en.wikipedia.org/wiki/Visvalingam%E2%80%93Whyatt_algorithm)
+    /// algorithm: vertices are removed in increasing order of effective area - the
+    /// [spherical excess](crate::spherical::Loop::spherical_excess) of the triangle formed by the vertex and its 2
+    /// neighbours - until all the remaining vertices have an effective area of at least the given minimum area. The
+    /// first and last vertices are always retained.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, NVector};
+    /// use jord::spherical::Polyline;
+    ///
+    /// let pl = Polyline::new(&[
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(0.0001, 0.5),
+    ///     NVector::from_lat_long_degrees(0.0, 1.0),
+    ///     NVector::from_lat_long_degrees(0.5, 1.5),
+    ///     NVector::from_lat_long_degrees(0.0, 2.0),
+    /// ]);
+    ///
+    /// let s = pl.simplify_visvalingam_whyatt(Angle::from_radians(1e-6));
+    /// assert_eq!(4, s.num_vertices());
+    /// assert_eq!(pl.vertex(3), s.vertex(2));
+    /// ```
+    pub fn simplify_visvalingam_whyatt(&self, min_area: Angle) -> Self {
+        Self::new(&visvalingam_whyatt(&self.vertices, min_area, false))
+    }
+
+    /// Simplifies this polyline using the Visvalingam-Whyatt algorithm with a tolerance expressed as a distance on the
+    /// given sphere: the minimum effective area is the area of a square whose side is the given distance - see
+    /// [simplify_visvalingam_whyatt](crate::spherical::Polyline::simplify_visvalingam_whyatt).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, Length, NVector};
+    /// use jord::spherical::{Polyline, Sphere};
+    ///
+    /// let pl = Polyline::new(&[
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(0.0001, 0.5),
+    ///     NVector::from_lat_long_degrees(0.0, 1.0),
+    ///     NVector::from_lat_long_degrees(0.5, 1.5),
+    ///     NVector::from_lat_long_degrees(0.0, 2.0),
+    /// ]);
+    ///
+    /// // 1 kilometre on Earth: the minimum area is 1 square kilometre.
+    /// let tolerance = Length::from_kilometres(1.0);
+    /// let side = Sphere::EARTH.distance_to_angle(tolerance).as_radians();
+    /// assert_eq!(
+    ///     pl.simplify_visvalingam_whyatt(Angle::from_radians(side * side)),
+    ///     pl.simplify_visvalingam_whyatt_by_distance(tolerance, &Sphere::EARTH)
+    /// );
+    /// assert_eq!(4, pl.simplify_visvalingam_whyatt_by_distance(tolerance, &Sphere::EARTH).num_vertices());
+    /// ```
+    pub fn simplify_visvalingam_whyatt_by_distance(
+        &self,
+        tolerance: Length,
+        sphere: &Sphere,
+    ) -> Self {
+        let side = sphere.distance_to_angle(tolerance).as_radians();
+        self.simplify_visvalingam_whyatt(Angle::from_radians(side * side))
+    }
+
+    /// Returns all the positions where this polyline intersects itself: i.e. the intersections of all pairs of
+    /// non-consecutive edges, and the vertices at which this polyline turns back onto its previous edge. A polyline
+    /// whose last vertex is its first vertex (a closed chain) intersects itself at that vertex.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, NVector};
+    /// use jord::spherical::Polyline;
+    ///
+    /// let pl = Polyline::new(&[
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(0.0, 10.0),
+    ///     NVector::from_lat_long_degrees(5.0, 5.0),
+    ///     NVector::from_lat_long_degrees(-5.0, 5.0),
+    /// ]);
+    ///
+    /// let xs = pl.self_intersections();
+    /// assert_eq!(1, xs.len());
+    /// assert_eq!(LatLong::from_degrees(0.0, 5.0), LatLong::from_nvector(xs[0]).round_d7());
+    /// ```
+    pub fn self_intersections(&self) -> Vec<NVector> {
+        let mut res = Vec::new();
+        // only the edges whose bounding boxes overlap are tested.
+        let candidates = crossing_candidates(&self.vertices);
+        for (i, cs) in candidates.iter().enumerate() {
+            let e1 = self.edges[i];
+            if let Some(e2) = self.edges.get(i + 1) {
+                // consecutive edges share a vertex: they only intersect if the second edge turns back.
+                if e1.contains_position(e2.end()) || e2.contains_position(e1.start()) {
+                    res.push(e1.end());
+                }
+            }
+            for j in cs {
+                if let Some(p) = e1.intersection(self.edges[*j]) {
+                    res.push(p);
+                }
+            }
+        }
+        res
+    }
+
+    /// Determines whether this polyline does not intersect itself - see
+    /// [self_intersections](crate::spherical::Polyline::self_intersections).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::NVector;
+    /// use jord::spherical::Polyline;
+    ///
+    /// let pl = Polyline::new(&[
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(0.0, 10.0),
+    ///     NVector::from_lat_long_degrees(5.0, 5.0),
+    /// ]);
+    /// assert!(pl.is_simple());
+    /// ```
+    pub fn is_simple(&self) -> bool {
+        self.self_intersections().is_empty()
+    }
+
+    /// Calculates the [minimum bounding rectangle](crate::spherical::Rectangle) of this polyline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, NVector};
+    /// use jord::spherical::Polyline;
+    ///
+    /// let vs = vec![
+    ///     NVector::from_lat_long_degrees(55.605, 13.0038),
+    ///     NVector::from_lat_long_degrees(55.4295, 13.82),
+    ///     NVector::from_lat_long_degrees(56.0294, 14.1567),
+    /// ];
+    ///
+    /// let b = Polyline::new(&vs).bound();
+    /// for v in vs.iter() {
+    ///     assert!(b.contains_position(LatLong::from_nvector(*v)));
+    /// }
+    /// ```
+    pub fn bound(&self) -> Rectangle {
+        let all: Vec<Rectangle> = self
+            .edges
+            .iter()
+            .map(|e| Rectangle::from_minor_arc(*e))
+            .collect();
+        // expand by 1e-7 degrees to absorb the floating-point error introduced when converting NVector <-> LatLong.
+        Rectangle::from_union(&all)
+            .expand(Angle::from_degrees(1.0e-7))
+            .polar_closure()
+    }
+
+    /// Computes the buffer of this polyline on the given sphere: the [Polygon] enclosing all positions within the
+    /// given distance of this polyline, i.e. the union of the [buffers](crate::spherical::MinorArc::buffer) of its
+    /// edges. Joins between edges are therefore round and approximated by vertices spaced by at most a quarter circle
+    /// divided by the given number of segments per quadrant (at least 1).
+    ///
+    /// The returned polygon has holes if the polyline loops back onto itself further than twice the given distance
+    /// away. An [empty](crate::spherical::Polygon::EMPTY) polygon is returned if this polyline is empty, if the given
+    /// distance is not positive or if it is greater or equal to a quarter of the circumference of the sphere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Length, NVector};
+    /// use jord::spherical::{Polyline, Sphere};
+    ///
+    /// let pl = Polyline::new(&[
+    ///     NVector::from_lat_long_degrees(55.605, 13.0038),
+    ///     NVector::from_lat_long_degrees(55.4295, 13.82),
+    ///     NVector::from_lat_long_degrees(56.0294, 14.1567),
+    /// ]);
+    ///
+    /// // 10 nautical miles around the route.
+    /// let b = pl.buffer(Length::from_metres(18_520.0), &Sphere::EARTH, 8);
+    /// assert_eq!(0, b.num_holes());
+    /// assert!(b.contains_position(NVector::from_lat_long_degrees(55.5, 13.8)));
+    /// assert!(b.contains_position(NVector::from_lat_long_degrees(55.8, 14.0)));
+    /// assert!(!b.contains_position(NVector::from_lat_long_degrees(55.8, 13.5)));
+    /// ```
+    pub fn buffer(&self, distance: Length, sphere: &Sphere, quadrant_segments: usize) -> Polygon {
+        match buffer_radius(distance, sphere) {
+            Some(r) if r > 0.0 => chain_buffer(&self.vertices, r, quadrant_segments),
+            _ => Polygon::EMPTY,
+        }
+    }
+
+    /// Returns the length of this polyline on the unit sphere.
+    fn angle(&self) -> Angle {
+        self.edges
+            .iter()
+            .fold(Angle::ZERO, |acc, e| acc + edge_angle(e))
+    }
+
+    /// Returns the angle along this polyline on the unit sphere corresponding to the given distance along this
+    /// polyline on the given sphere. The distance is converted as a fraction of the length of this polyline - rather
+    /// than by [Sphere::distance_to_angle] which wraps distances beyond half the circumference - so that the length
+    /// of this polyline maps exactly to its angle.
+    fn angle_at(&self, distance: Length, sphere: &Sphere) -> Angle {
+        let length = self.length(sphere);
+        if length == Length::ZERO {
+            Angle::ZERO
+        } else {
+            (distance / length) * self.angle()
+        }
+    }
+
+    /// Returns the index of the edge containing the position at the given angle along this polyline and that
+    /// position. An angle exceeding the length of this polyline by no more than a few ulps - e.g. due to rounding
+    /// errors when converting a distance - is clamped to the last vertex.
+    fn locate(&self, a: Angle) -> Option<(usize, NVector)> {
+        if self.is_empty() || a < Angle::ZERO {
+            return None;
+        }
+        let mut acc = Angle::ZERO;
+        for (i, e) in self.edges.iter().enumerate() {
+            let len = edge_angle(e);
+            if a <= acc + len {
+                let p = if a == acc {
+                    e.start()
+                } else if a == acc + len {
+                    e.end()
+                } else {
+                    Sphere::position_on_great_circle(e.start(), e.end(), a - acc)
+                };
+                return Some((i, p));
+            }
+            acc = acc + len;
+        }
+        if a - acc <= acc * (4.0 * f64::EPSILON) {
+            let last = self.edges.len() - 1;
+            Some((last, self.edges[last].end()))
+        } else {
+            None
+        }
+    }
+
+    fn position_at_angle(&self, a: Angle) -> Option<NVector> {
+        self.locate(a).map(|(_, p)| p)
+    }
+
+    fn split_at_angle(&self, a: Angle) -> (Self, Self) {
+        if self.is_empty() {
+            return (Self::EMPTY, Self::EMPTY);
+        }
+        if a <= Angle::ZERO {
+            return (Self::EMPTY, self.clone());
+        }
+        match self.locate(a) {
+            Some((i, p)) => {
+                let mut first = self.vertices[..=i].to_vec();
+                first.push(p);
+                let mut second = vec![p];
+                second.extend_from_slice(&self.vertices[i + 1..]);
+                (Self::new(&first), Self::new(&second))
+            }
+            None => (self.clone(), Self::EMPTY),
+        }
+    }
+
+    /// Returns the index of the edge which is the closest to the given position and the closest position on
+    /// that edge.
+    fn closest(&self, p: NVector) -> Option<(usize, NVector)> {
+        let mut best: Option<(usize, ChordLength)> = None;
+        for (i, e) in self.edges.iter().enumerate() {
+            let d = e.distance_to(p);
+            if best.map_or(true, |(_, b)| d < b) {
+                best = Some((i, d));
+            }
+        }
+        best.map(|(i, _)| {
+            let e = self.edges[i];
+            let c = e.projection(p).unwrap_or_else(|| {
+                if ChordLength::new(p, e.start()) <= ChordLength::new(p, e.end()) {
+                    e.start()
+                } else {
+                    e.end()
+                }
+            });
+            (i, c)
+        })
+    }
+}
+
+/// Returns the length of the given edge on the unit sphere.
+fn edge_angle(e: &MinorArc) -> Angle {
+    Sphere::angle(e.start(), e.end())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        spherical::{Polyline, Sphere},
+        Angle, LatLong, Length, NVector,
+    };
+
+    fn unit() -> Sphere {
+        Sphere::new(Length::from_metres(1.0))
+    }
+
+    fn degrees(d: f64) -> Length {
+        Angle::from_degrees(d) * unit().radius()
+    }
+
+    fn lls(pl: &Polyline) -> Vec<LatLong> {
+        pl.iter_vertices()
+            .map(|v| LatLong::from_nvector(*v).round_d7())
+            .collect()
+    }
+
+    fn equator() -> Polyline {
+        Polyline::new(&[
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(0.0, 10.0),
+            NVector::from_lat_long_degrees(0.0, 30.0),
+        ])
+    }
+
+    #[test]
+    fn new() {
+        assert!(Polyline::new(&[]).is_empty());
+        assert!(Polyline::new(&[NVector::from_lat_long_degrees(0.0, 0.0)]).is_empty());
+        assert_eq!(Polyline::EMPTY, Polyline::new(&[]));
+
+        let a = NVector::from_lat_long_degrees(0.0, 0.0);
+        let b = NVector::from_lat_long_degrees(0.0, 1.0);
+        let pl = Polyline::new(&[a, a, b, b, a]);
+        assert_eq!(3, pl.num_vertices());
+        assert_eq!(2, pl.iter_edges().count());
+        assert_eq!(a, pl.vertex(2));
+    }
+
+    #[test]
+    fn length() {
+        assert_eq!(Length::ZERO, Polyline::EMPTY.length(&unit()));
+        assert_eq!(
+            degrees(30.0).round_mm(),
+            equator().length(&unit()).round_mm()
+        );
+    }
+
+    #[test]
+    fn position_at_distance() {
+        let pl = equator();
+        let s = unit();
+        assert_eq!(
+            Some(pl.vertex(0)),
+            pl.position_at_distance(Length::ZERO, &s)
+        );
+        assert_eq!(
+            LatLong::from_degrees(0.0, 20.0),
+            LatLong::from_nvector(pl.position_at_distance(degrees(20.0), &s).unwrap()).round_d7()
+        );
+        assert_eq!(
+            LatLong::from_degrees(0.0, 10.0),
+            LatLong::from_nvector(pl.position_at_distance(degrees(10.0), &s).unwrap()).round_d7()
+        );
+        assert!(pl.position_at_distance(degrees(31.0), &s).is_none());
+        assert!(Polyline::EMPTY
+            .position_at_distance(Length::ZERO, &s)
+            .is_none());
+    }
+
+    #[test]
+    fn position_at_distance_end() {
+        let s = Sphere::EARTH;
+        for i in 0..2000 {
+            let f = i as f64;
+            let pl = Polyline::new(&[
+                NVector::from_lat_long_degrees(80.0 * (f * 0.37).sin(), 170.0 * (f * 1.3).cos()),
+                NVector::from_lat_long_degrees(80.0 * (f * 2.1).cos(), 170.0 * (f * 0.71).sin()),
+                NVector::from_lat_long_degrees(80.0 * (f * 1.7).sin(), 170.0 * (f * 0.53).cos()),
+            ]);
+            let last = pl.vertex(pl.num_vertices() - 1);
+            let length = pl.length(&s);
+            assert_eq!(Some(last), pl.position_at_distance(length, &s));
+            assert_eq!(
+                Some(last),
+                pl.position_at_distance(length * (1.0 + f64::EPSILON), &s)
+            );
+            assert!(pl.position_at_distance(length * 1.000001, &s).is_none());
+        }
+    }
+
+    #[test]
+    fn position_at_distance_longer_than_half_circumference() {
+        let pl = Polyline::new(&[
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(0.0, 90.0),
+            NVector::from_lat_long_degrees(0.0, 180.0),
+            NVector::from_lat_long_degrees(0.0, -90.0),
+        ]);
+        let s = unit();
+        assert_eq!(
+            LatLong::from_degrees(0.0, -160.0),
+            LatLong::from_nvector(pl.position_at_distance(degrees(200.0), &s).unwrap()).round_d7()
+        );
+        assert_eq!(
+            Some(pl.vertex(3)),
+            pl.position_at_distance(pl.length(&s), &s)
+        );
+    }
+
+    #[test]
+    fn position_at_fraction() {
+        let pl = equator();
+        assert_eq!(Some(pl.vertex(0)), pl.position_at_fraction(0.0));
+        assert_eq!(Some(pl.vertex(2)), pl.position_at_fraction(1.0));
+        assert_eq!(
+            LatLong::from_degrees(0.0, 15.0),
+            LatLong::from_nvector(pl.position_at_fraction(0.5).unwrap()).round_d7()
+        );
+        assert!(pl.position_at_fraction(-0.1).is_none());
+        assert!(Polyline::EMPTY.position_at_fraction(0.5).is_none());
+    }
+
+    #[test]
+    fn closest_position() {
+        let pl = equator();
+        assert_eq!(
+            LatLong::from_degrees(0.0, 25.0),
+            LatLong::from_nvector(
+                pl.closest_position(NVector::from_lat_long_degrees(5.0, 25.0))
+                    .unwrap()
+            )
+            .round_d7()
+        );
+        // beyond the last vertex.
+        assert_eq!(
+            Some(pl.vertex(2)),
+            pl.closest_position(NVector::from_lat_long_degrees(1.0, 40.0))
+        );
+        // before the first vertex.
+        assert_eq!(
+            Some(pl.vertex(0)),
+            pl.closest_position(NVector::from_lat_long_degrees(-1.0, -5.0))
+        );
+        assert!(Polyline::EMPTY
+            .closest_position(NVector::from_lat_long_degrees(0.0, 0.0))
+            .is_none());
+    }
+
+    #[test]
+    fn along_track_distance() {
+        let pl = equator();
+        let s = unit();
+        assert_eq!(
+            degrees(25.0).round_mm(),
+            pl.along_track_distance(NVector::from_lat_long_degrees(5.0, 25.0), &s)
+                .unwrap()
+                .round_mm()
+        );
+        assert_eq!(
+            Length::ZERO,
+            pl.along_track_distance(NVector::from_lat_long_degrees(-1.0, -5.0), &s)
+                .unwrap()
+        );
+        assert_eq!(
+            degrees(30.0).round_mm(),
+            pl.along_track_distance(NVector::from_lat_long_degrees(1.0, 40.0), &s)
+                .unwrap()
+                .round_mm()
+        );
+        assert!(Polyline::EMPTY
+            .along_track_distance(NVector::from_lat_long_degrees(0.0, 0.0), &s)
+            .is_none());
+    }
+
+    #[test]
+    fn split_at_distance() {
+        let pl = equator();
+        let s = unit();
+
+        let (first, second) = pl.split_at_distance(degrees(20.0), &s);
+        assert_eq!(
+            vec![
+                LatLong::from_degrees(0.0, 0.0),
+                LatLong::from_degrees(0.0, 10.0),
+                LatLong::from_degrees(0.0, 20.0)
+            ],
+            lls(&first)
+        );
+        assert_eq!(
+            vec![
+                LatLong::from_degrees(0.0, 20.0),
+                LatLong::from_degrees(0.0, 30.0)
+            ],
+            lls(&second)
+        );
+
+        // at a vertex.
+        let (first, second) = pl.split_at_distance(pl.length(&s) / 3.0, &s);
+        assert_eq!(2, first.num_vertices());
+        assert_eq!(2, second.num_vertices());
+
+        // clamped.
+        let (first, second) = pl.split_at_distance(degrees(-1.0), &s);
+        assert!(first.is_empty());
+        assert_eq!(pl, second);
+        let (first, second) = pl.split_at_distance(degrees(31.0), &s);
+        assert_eq!(pl, first);
+        assert!(second.is_empty());
+        let (first, second) = pl.split_at_distance(pl.length(&s), &s);
+        assert_eq!(pl, first);
+        assert!(second.is_empty());
+    }
+
+    #[test]
+    fn sub_polyline() {
+        let pl = equator();
+        let s = unit();
+        assert_eq!(
+            vec![
+                LatLong::from_degrees(0.0, 5.0),
+                LatLong::from_degrees(0.0, 10.0),
+                LatLong::from_degrees(0.0, 25.0)
+            ],
+            lls(&pl.sub_polyline(degrees(5.0), degrees(25.0), &s))
+        );
+        assert_eq!(
+            vec![
+                LatLong::from_degrees(0.0, 12.0),
+                LatLong::from_degrees(0.0, 18.0)
+            ],
+            lls(&pl.sub_polyline(degrees(12.0), degrees(18.0), &s))
+        );
+        assert_eq!(pl, pl.sub_polyline(degrees(-10.0), degrees(50.0), &s));
+        assert!(pl.sub_polyline(degrees(20.0), degrees(20.0), &s).is_empty());
+        assert!(pl.sub_polyline(degrees(20.0), degrees(10.0), &s).is_empty());
+    }
+
+    #[test]
+    fn reverse() {
+        let pl = equator();
+        let r = pl.reverse();
+        assert_eq!(pl.vertex(0), r.vertex(2));
+        assert_eq!(pl.vertex(2), r.vertex(0));
+        assert_eq!(pl, r.reverse());
+        assert!(Polyline::EMPTY.reverse().is_empty());
+    }
+
+    #[test]
+    fn self_intersections() {
+        assert!(equator().is_simple());
+        assert!(Polyline::EMPTY.is_simple());
+
+        // crossing.
+        let pl = Polyline::new(&[
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(0.0, 10.0),
+            NVector::from_lat_long_degrees(5.0, 5.0),
+            NVector::from_lat_long_degrees(-5.0, 5.0),
+        ]);
+        assert!(!pl.is_simple());
+
+        // turning back.
+        let pl = Polyline::new(&[
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(0.0, 10.0),
+            NVector::from_lat_long_degrees(0.0, 5.0),
+        ]);
+        assert_eq!(
+            vec![LatLong::from_degrees(0.0, 10.0)],
+            pl.self_intersections()
+                .iter()
+                .map(|p| LatLong::from_nvector(*p).round_d7())
+                .collect::<Vec<_>>()
+        );
+
+        // closed.
+        let pl = Polyline::new(&[
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(0.0, 10.0),
+            NVector::from_lat_long_degrees(10.0, 5.0),
+            NVector::from_lat_long_degrees(0.0, 0.0),
+        ]);
+        assert!(!pl.is_simple());
+    }
+
+    #[test]
+    fn self_intersections_many_vertices() {
+        // a 20 degrees long meandering track, then back across it at 10 degrees of longitude.
+        let mut vs: Vec<NVector> = (0..20_000)
+            .map(|i| {
+                let i = i as f64;
+                NVector::from_lat_long_degrees((i / 10.0).sin() / 100.0, i / 1_000.0)
+            })
+            .collect();
+        vs.push(NVector::from_lat_long_degrees(1.0, 19.999));
+        vs.push(NVector::from_lat_long_degrees(1.0, 10.0005));
+        vs.push(NVector::from_lat_long_degrees(-1.0, 10.0005));
+        let pl = Polyline::new(&vs);
+        let xs = pl.self_intersections();
+        assert_eq!(1, xs.len());
+        assert_eq!(
+            10.0005,
+            LatLong::from_nvector(xs[0])
+                .longitude()
+                .round_d7()
+                .as_degrees()
+        );
+    }
+
+    #[test]
+    fn bound() {
+        assert!(Polyline::EMPTY.bound().is_empty());
+        let pl = Polyline::new(&[
+            NVector::from_lat_long_degrees(0.0, 170.0),
+            NVector::from_lat_long_degrees(5.0, -175.0),
+            NVector::from_lat_long_degrees(-5.0, -170.0),
+        ]);
+        let b = pl.bound();
+        assert_eq!(
+            LatLong::from_degrees(5.0, -170.0),
+            b.north_east().round_d5()
+        );
+        assert_eq!(
+            LatLong::from_degrees(-5.0, 170.0),
+            b.south_west().round_d5()
+        );
+    }
+}
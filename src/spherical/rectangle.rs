@@ -2,10 +2,10 @@ use std::{cmp::Ordering, f64::consts::PI};
 
 use crate::{
     numbers::{eq_zero, gte, lte},
-    Angle, LatLong, Vec3,
+    Angle, LatLong, NVector, Vec3,
 };
 
-use super::MinorArc;
+use super::{ChordLength, Loop, MinorArc};
 
 /// A closed rectangle defined by 2 parallels and 2 meridians (inclusive).
 ///
@@ -18,7 +18,6 @@ pub struct Rectangle {
 }
 
 // TODO(CL): Examples
-// TODO(CL): (Interior)Intersection
 impl Rectangle {
     /// Empty rectangle: contains no position.
     pub const EMPTY: Rectangle = Self {
@@ -181,6 +180,282 @@ impl Rectangle {
         self.lat.interior_contains_int(r.lat) && self.lng.interior_contains_int(r.lng)
     }
 
+    /// Determines whether this rectangle and the given rectangle have any position in common (boundaries included).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::Angle;
+    /// use jord::spherical::Rectangle;
+    ///
+    /// let a = Rectangle::from_nesw(
+    ///     Angle::from_degrees(30.0),
+    ///     Angle::from_degrees(30.0),
+    ///     Angle::ZERO,
+    ///     Angle::ZERO
+    /// );
+    ///
+    /// // shared eastern/western meridian.
+    /// let b = Rectangle::from_nesw(
+    ///     Angle::from_degrees(30.0),
+    ///     Angle::from_degrees(40.0),
+    ///     Angle::ZERO,
+    ///     Angle::from_degrees(30.0)
+    /// );
+    ///
+    /// assert!(a.intersects(b));
+    /// assert!(!a.interior_intersects(b));
+    /// ```
+    pub fn intersects(&self, r: Rectangle) -> bool {
+        self.lat.intersects(r.lat) && self.lng.intersects(r.lng)
+    }
+
+    /// Determines whether the interior of this rectangle intersects the given rectangle (including its boundary).
+    pub fn interior_intersects(&self, r: Rectangle) -> bool {
+        self.lat.interior_intersects(r.lat) && self.lng.interior_intersects(r.lng)
+    }
+
+    /// Returns the smallest rectangle containing the intersection of this rectangle and the given rectangle. Note
+    /// that the intersection of 2 rectangles is not always a rectangle: when both longitude intervals overlap at
+    /// both ends, the shortest longitude interval is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong};
+    /// use jord::spherical::Rectangle;
+    ///
+    /// let a = Rectangle::from_nesw(
+    ///     Angle::from_degrees(30.0),
+    ///     Angle::from_degrees(30.0),
+    ///     Angle::ZERO,
+    ///     Angle::ZERO
+    /// );
+    /// let b = Rectangle::from_nesw(
+    ///     Angle::from_degrees(40.0),
+    ///     Angle::from_degrees(40.0),
+    ///     Angle::from_degrees(10.0),
+    ///     Angle::from_degrees(20.0)
+    /// );
+    ///
+    /// let i = a.intersection(b);
+    /// assert_eq!(LatLong::from_degrees(30.0, 30.0), i.north_east());
+    /// assert_eq!(LatLong::from_degrees(10.0, 20.0), i.south_west());
+    /// ```
+    pub fn intersection(&self, r: Rectangle) -> Self {
+        let lat = self.lat.intersection(r.lat);
+        let lng = self.lng.intersection(r.lng);
+        if lat.is_empty() || lng.is_empty() {
+            Self::EMPTY
+        } else {
+            Self { lat, lng }
+        }
+    }
+
+    /// Returns the centre of this rectangle: the position at the mid latitude and mid longitude of this rectangle.
+    /// The centre of an [empty](crate::spherical::Rectangle::is_empty) rectangle is undefined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong};
+    /// use jord::spherical::Rectangle;
+    ///
+    /// let a = Rectangle::from_nesw(
+    ///     Angle::from_degrees(30.0),
+    ///     Angle::from_degrees(-170.0),
+    ///     Angle::from_degrees(10.0),
+    ///     Angle::from_degrees(170.0)
+    /// );
+    ///
+    /// assert_eq!(LatLong::from_degrees(20.0, 180.0), a.centre());
+    /// ```
+    pub fn centre(&self) -> LatLong {
+        LatLong::new(0.5 * (self.lat.lo + self.lat.hi), self.lng.centre())
+    }
+
+    /// Calculates the spherical excess of this rectangle - i.e. its area on the unit sphere. Unlike
+    /// [Loop::spherical_excess](crate::spherical::Loop::spherical_excess), this is not the area of the loop joining
+    /// the 4 vertices of this rectangle, since parallels are not great circles.
+    ///
+    /// The area of this rectangle can be obtained by multiplying the spherical excess by the sphere radius squared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64::consts::PI;
+    ///
+    /// use jord::Angle;
+    /// use jord::spherical::Rectangle;
+    ///
+    /// // northern hemisphere.
+    /// let a = Rectangle::from_nesw(
+    ///     Angle::QUARTER_CIRCLE,
+    ///     Angle::HALF_CIRCLE,
+    ///     Angle::ZERO,
+    ///     Angle::NEG_HALF_CIRCLE
+    /// );
+    ///
+    /// assert_eq!(Angle::from_radians(2.0 * PI), a.spherical_excess());
+    /// assert_eq!(Angle::ZERO, Rectangle::EMPTY.spherical_excess());
+    /// ```
+    pub fn spherical_excess(&self) -> Angle {
+        if self.is_empty() {
+            Angle::ZERO
+        } else {
+            let lat_span = self.lat.hi.as_radians().sin() - self.lat.lo.as_radians().sin();
+            Angle::from_radians(self.lng.len().as_radians() * lat_span)
+        }
+    }
+
+    /// Returns the [Loop] whose vertices are the 4 vertices of this rectangle - or 3 vertices if this rectangle
+    /// includes a pole. The edges of the loop are minor arcs of great circle, therefore the loop only approximates this
+    /// rectangle: parallels (except the equator) are not great circles.
+    ///
+    /// An [empty](crate::spherical::Loop::EMPTY) loop is returned if this rectangle is empty or spans 180 degrees
+    /// or more of longitude.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong};
+    /// use jord::spherical::Rectangle;
+    ///
+    /// let a = Rectangle::from_nesw(
+    ///     Angle::from_degrees(10.0),
+    ///     Angle::from_degrees(10.0),
+    ///     Angle::ZERO,
+    ///     Angle::ZERO
+    /// );
+    ///
+    /// let l = a.to_loop();
+    /// assert_eq!(4, l.num_vertices());
+    /// assert!(l.contains_position(a.centre().to_nvector()));
+    /// ```
+    pub fn to_loop(&self) -> Loop {
+        if self.is_empty() || self.lng.len() >= Angle::HALF_CIRCLE {
+            return Loop::EMPTY;
+        }
+        let mut vs = vec![
+            LatLong::new(self.lat.lo, self.lng.lo).to_nvector(),
+            LatLong::new(self.lat.lo, self.lng.hi).to_nvector(),
+            LatLong::new(self.lat.hi, self.lng.hi).to_nvector(),
+            LatLong::new(self.lat.hi, self.lng.lo).to_nvector(),
+        ];
+        // vertices at a pole are all equal.
+        vs.dedup();
+        Loop::new(&vs)
+    }
+
+    /// Computes the [chord length](crate::spherical::ChordLength) between the given position and the closest
+    /// position of this (non-empty) rectangle: zero if this rectangle contains the given position.
+    pub(crate) fn distance_to(&self, p: NVector) -> ChordLength {
+        let ll = LatLong::from_nvector(p);
+        if self.lng.contains_lng(ll.longitude()) {
+            let d = (ll.latitude() - self.lat.hi)
+                .as_radians()
+                .max((self.lat.lo - ll.latitude()).as_radians())
+                .max(0.0);
+            return ChordLength::from_angle(Angle::from_radians(d));
+        }
+        // the closest position is on the meridian of the closest longitude bound.
+        let lng = if LongitudeInterval::new(self.lng.hi, self.lng.complement_centre())
+            .contains_lng(ll.longitude())
+        {
+            self.lng.hi
+        } else {
+            self.lng.lo
+        };
+        self.meridian_edges(lng)
+            .iter()
+            .map(|e| e.distance_to(p))
+            .min()
+            .unwrap_or(ChordLength::MAX)
+    }
+
+    /// Determines whether the given minor arc has any position in common with this rectangle.
+    pub(crate) fn intersects_minor_arc(&self, ma: MinorArc) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+        if self.contains_position(LatLong::from_nvector(ma.start()))
+            || self.contains_position(LatLong::from_nvector(ma.end()))
+        {
+            return true;
+        }
+        // expand the bound of the minor arc to absorb floating-point errors.
+        if !self.intersects(Rectangle::from_minor_arc(ma).expand(Angle::from_degrees(1.0e-7))) {
+            return false;
+        }
+        // both ends are outside of this rectangle, so the minor arc intersects it only if it crosses its boundary.
+        if !self.lng.is_full()
+            && [self.lng.lo, self.lng.hi]
+                .iter()
+                .flat_map(|lng| self.meridian_edges(*lng))
+                .any(|e| ma.intersection(e).is_some())
+        {
+            return true;
+        }
+        (self.lat.hi < Angle::QUARTER_CIRCLE && self.intersects_parallel(ma, self.lat.hi))
+            || (self.lat.lo > -Angle::QUARTER_CIRCLE && self.intersects_parallel(ma, self.lat.lo))
+    }
+
+    /// The 2 minor arcs along the given meridian between the south and north latitudes of this rectangle: the
+    /// meridian is split at the mid latitude since it may span 180 degrees.
+    fn meridian_edges(&self, lng: Angle) -> [MinorArc; 2] {
+        let mid = 0.5 * (self.lat.lo + self.lat.hi);
+        let lo = LatLong::new(self.lat.lo, lng).to_nvector();
+        let m = LatLong::new(mid, lng).to_nvector();
+        let hi = LatLong::new(self.lat.hi, lng).to_nvector();
+        [MinorArc::new(lo, m), MinorArc::new(m, hi)]
+    }
+
+    /// Determines whether the given minor arc intersects the parallel at the given latitude within the longitude
+    /// interval of this rectangle. A parallel is not a great circle, so it may intersect the minor arc at 0, 1 or
+    /// 2 positions.
+    fn intersects_parallel(&self, ma: MinorArc, lat: Angle) -> bool {
+        // frame (x, y, z) where z is the normal to the minor arc pointing north and x is the direction of the
+        // maximum latitude of the great circle of the minor arc.
+        let mut z = ma.normal();
+        if z == Vec3::ZERO {
+            return false;
+        }
+        if z.z() < 0.0 {
+            z = -z;
+        }
+        let y = z.stable_cross_prod_unit(Vec3::UNIT_Z);
+        if y == Vec3::ZERO {
+            // the minor arc is along the equator.
+            return eq_zero(lat.as_radians()) && self.lng.intersects(Self::from_minor_arc(ma).lng);
+        }
+        let x = y.cross_prod(z);
+        let sin_lat = lat.as_radians().sin();
+        if sin_lat.abs() >= x.z() {
+            // the great circle does not reach the given latitude.
+            return false;
+        }
+        let cos_theta = sin_lat / x.z();
+        let sin_theta = (1.0 - cos_theta * cos_theta).sqrt();
+        let theta = Angle::from_radians(sin_theta.atan2(cos_theta));
+        let angle_in_frame = |v: NVector| {
+            Angle::from_radians(v.as_vec3().dot_prod(y).atan2(v.as_vec3().dot_prod(x)))
+        };
+        let ma_theta = LongitudeInterval::from_point_pair(
+            angle_in_frame(ma.start()),
+            angle_in_frame(ma.end()),
+        );
+        [(theta, sin_theta), (-theta, -sin_theta)]
+            .iter()
+            .any(|(t, sin_t)| {
+                if !ma_theta.contains_lng(*t) {
+                    return false;
+                }
+                let i = x * cos_theta + y * *sin_t;
+                self.lng
+                    .contains_lng(Angle::from_radians(i.y().atan2(i.x())))
+            })
+    }
+
     /// Determines whether this rectangle is [full](crate::spherical::Rectangle::FULL).
     pub fn is_full(&self) -> bool {
         self.is_latitude_full() && self.is_longitude_full()
@@ -396,6 +671,16 @@ impl LatitudeInterval {
         }
     }
 
+    /// Returns true if and only if this latitude interval and the given latitude interval have any latitude in
+    /// common.
+    fn intersects(&self, o: Self) -> bool {
+        if self.lo <= o.lo {
+            o.lo <= self.hi && o.lo <= o.hi
+        } else {
+            self.lo <= o.hi && self.lo <= self.hi
+        }
+    }
+
     /// Returns an interval that has been expanded/shrinked on each side by the given amount.
     fn expand(&self, amount: Angle) -> Self {
         if self.is_empty() {
@@ -418,6 +703,11 @@ impl LatitudeInterval {
         self.lo == -Angle::QUARTER_CIRCLE && self.hi == Angle::QUARTER_CIRCLE
     }
 
+    /// Returns true if and only if the interior of this latitude interval intersects the given latitude interval.
+    fn interior_intersects(&self, o: Self) -> bool {
+        o.lo < self.hi && self.lo < o.hi && self.lo < self.hi && o.lo <= o.hi
+    }
+
     /// Return the intersection of this interval with the given interval.
     /// Empty intervals do not need to be special-cased.
     fn intersection(&self, o: Self) -> Self {
@@ -474,8 +764,13 @@ impl LongitudeInterval {
     }
 
     fn from_minor_arc(lls: LatLong, lle: LatLong) -> Self {
-        let start = Self::normalised_longitude(lls.longitude());
-        let end = Self::normalised_longitude(lle.longitude());
+        Self::from_point_pair(lls.longitude(), lle.longitude())
+    }
+
+    /// Returns the shortest interval containing the 2 given longitudes.
+    fn from_point_pair(a: Angle, b: Angle) -> Self {
+        let start = Self::normalised_longitude(a);
+        let end = Self::normalised_longitude(b);
         if Self::positive_distance(start, end) <= Angle::HALF_CIRCLE {
             Self::new(start, end)
         } else {
@@ -584,6 +879,83 @@ impl LongitudeInterval {
         (o.lo > self.lo && o.hi < self.hi) || self.is_full()
     }
 
+    /// Returns true if and only if the interior of this longitude interval intersects the given longitude interval.
+    fn interior_intersects(&self, o: Self) -> bool {
+        if self.is_empty() || o.is_empty() || self.lo == self.hi {
+            false
+        } else if self.is_inverted() {
+            o.is_inverted() || o.lo < self.hi || o.hi > self.lo
+        } else if o.is_inverted() {
+            o.lo < self.hi || o.hi > self.lo
+        } else {
+            (o.lo < self.hi && o.hi > self.lo) || self.is_full()
+        }
+    }
+
+    /// Returns the intersection of this interval with the given interval.
+    fn intersection(&self, o: Self) -> Self {
+        if o.is_empty() {
+            Self::EMPTY
+        } else if self.contains_lng(o.lo) {
+            if self.contains_lng(o.hi) {
+                // either this interval contains o, or the intersection consists of 2 disjoint intervals: in both
+                // cases, return the shortest interval.
+                if o.len() < self.len() {
+                    o
+                } else {
+                    *self
+                }
+            } else {
+                Self::new(o.lo, self.hi)
+            }
+        } else if self.contains_lng(o.hi) {
+            Self::new(self.lo, o.hi)
+        } else if o.contains_lng(self.lo) {
+            // this interval contains neither end of o: o contains all of this interval or both are disjoint.
+            *self
+        } else {
+            Self::EMPTY
+        }
+    }
+
+    /// Returns the centre of this interval.
+    fn centre(&self) -> Angle {
+        let centre = 0.5 * (self.lo + self.hi);
+        if !self.is_inverted() {
+            centre
+        } else if centre <= Angle::ZERO {
+            centre + Angle::HALF_CIRCLE
+        } else {
+            centre - Angle::HALF_CIRCLE
+        }
+    }
+
+    /// Returns the centre of the complement of this interval.
+    fn complement_centre(&self) -> Angle {
+        if self.lo != self.hi {
+            // the complement of [lo, hi] is [hi, lo].
+            Self::new(self.hi, self.lo).centre()
+        } else if self.hi <= Angle::ZERO {
+            self.hi + Angle::HALF_CIRCLE
+        } else {
+            self.hi - Angle::HALF_CIRCLE
+        }
+    }
+
+    /// Returns true if and only if this longitude interval and the given longitude interval have any longitude in
+    /// common.
+    fn intersects(&self, o: Self) -> bool {
+        if self.is_empty() || o.is_empty() {
+            false
+        } else if self.is_inverted() {
+            o.is_inverted() || o.lo <= self.hi || o.hi >= self.lo
+        } else if o.is_inverted() {
+            o.lo <= self.hi || o.hi >= self.lo
+        } else {
+            o.lo <= self.hi && o.hi >= self.lo
+        }
+    }
+
     /// Returns true if this longitude interval is full.
     fn is_full(&self) -> bool {
         self.lo == Angle::NEG_HALF_CIRCLE && self.hi == Angle::HALF_CIRCLE
@@ -659,9 +1031,12 @@ impl LongitudeInterval {
 
 #[cfg(test)]
 mod tests {
-    use std::cmp::Ordering;
+    use std::{cmp::Ordering, f64::consts::PI};
 
-    use crate::{spherical::MinorArc, Angle, LatLong, NVector};
+    use crate::{
+        spherical::{ChordLength, MinorArc},
+        Angle, LatLong, NVector,
+    };
 
     use super::Rectangle;
 
@@ -1219,7 +1594,7 @@ mod tests {
         for lat in -900..900 {
             let lat_f = lat as f64;
             let p = LatLong::from_degrees(lat_f / 10.0, 0.0);
-            if lat >= 0 && lat <= 100 {
+            if (0..=100).contains(&lat) {
                 assert!(actual.contains_position(p));
             } else {
                 assert!(!actual.contains_position(p));
@@ -1744,6 +2119,152 @@ mod tests {
         assert_eq!(e, expanded);
     }
 
+    fn rect(n: f64, e: f64, s: f64, w: f64) -> Rectangle {
+        Rectangle::from_nesw(
+            Angle::from_degrees(n),
+            Angle::from_degrees(e),
+            Angle::from_degrees(s),
+            Angle::from_degrees(w),
+        )
+    }
+
+    #[test]
+    fn intersection_nominal() {
+        let i = rect(30.0, 30.0, 0.0, 0.0).intersection(rect(40.0, 40.0, 10.0, 20.0));
+        assert_eq!(rect(30.0, 30.0, 10.0, 20.0), i);
+    }
+
+    #[test]
+    fn intersection_date_line() {
+        let i = rect(30.0, -170.0, 0.0, 170.0).intersection(rect(40.0, -160.0, 10.0, 175.0));
+        assert_eq!(rect(30.0, -170.0, 10.0, 175.0), i);
+    }
+
+    #[test]
+    fn intersection_disjoint() {
+        let a = rect(30.0, 30.0, 0.0, 0.0);
+        assert!(a.intersection(rect(30.0, 50.0, 0.0, 40.0)).is_empty());
+        assert!(a.intersection(rect(50.0, 30.0, 40.0, 0.0)).is_empty());
+        assert!(a.intersection(Rectangle::EMPTY).is_empty());
+        assert_eq!(a, a.intersection(Rectangle::FULL));
+    }
+
+    #[test]
+    fn intersection_longitudes_overlapping_at_both_ends() {
+        // longitude intervals overlap on [-170, -160] and [160, 170]: the shortest interval is returned.
+        let a = rect(30.0, 170.0, 0.0, -170.0);
+        let b = rect(30.0, -160.0, 0.0, 160.0);
+        assert_eq!(b, a.intersection(b));
+        assert_eq!(b, b.intersection(a));
+    }
+
+    #[test]
+    fn intersects() {
+        let a = rect(30.0, 30.0, 0.0, 0.0);
+        assert!(a.intersects(a));
+        assert!(a.intersects(rect(40.0, 40.0, 10.0, 20.0)));
+        // shared vertex.
+        assert!(a.intersects(rect(40.0, 40.0, 30.0, 30.0)));
+        assert!(!a.interior_intersects(rect(40.0, 40.0, 30.0, 30.0)));
+        assert!(!a.intersects(rect(30.0, 50.0, 0.0, 40.0)));
+        assert!(!a.intersects(Rectangle::EMPTY));
+        assert!(a.intersects(Rectangle::FULL));
+        assert!(a.interior_intersects(Rectangle::FULL));
+        assert!(rect(30.0, -170.0, 0.0, 170.0).intersects(rect(10.0, 180.0, 5.0, 175.0)));
+    }
+
+    #[test]
+    fn centre() {
+        assert_eq!(ll(15, 20), rect(30.0, 30.0, 0.0, 10.0).centre());
+        assert_eq!(ll(0, 0), Rectangle::FULL.centre());
+        assert_eq!(ll(-15, -175), rect(0.0, -160.0, -30.0, 170.0).centre());
+    }
+
+    #[test]
+    fn spherical_excess() {
+        assert_eq!(
+            Angle::from_radians(4.0 * PI),
+            Rectangle::FULL.spherical_excess()
+        );
+        // 1 degree square at the equator.
+        let se = rect(1.0, 1.0, 0.0, 0.0).spherical_excess().as_radians();
+        let e = (1.0f64.to_radians()).sin() * 1.0f64.to_radians();
+        assert!((e - se).abs() < 1e-15);
+        // the loop joining the vertices is below the northern parallel.
+        let r = rect(60.0, 40.0, 50.0, 0.0);
+        assert!(r.spherical_excess() > r.to_loop().spherical_excess());
+    }
+
+    #[test]
+    fn to_loop() {
+        let l = rect(10.0, 10.0, 0.0, 0.0).to_loop();
+        assert_eq!(4, l.num_vertices());
+        assert!(l.contains_position(NVector::from_lat_long_degrees(5.0, 5.0)));
+        assert!(!l.contains_position(NVector::from_lat_long_degrees(5.0, 11.0)));
+
+        // pole: triangle.
+        let l = rect(90.0, 10.0, 80.0, 0.0).to_loop();
+        assert_eq!(3, l.num_vertices());
+
+        assert!(rect(10.0, 90.0, 0.0, -90.0).to_loop().is_empty());
+        assert!(Rectangle::EMPTY.to_loop().is_empty());
+        assert!(Rectangle::FULL.to_loop().is_empty());
+    }
+
+    #[test]
+    fn distance_to() {
+        let r = rect(10.0, 10.0, 0.0, 0.0);
+        assert_eq!(
+            ChordLength::ZERO,
+            r.distance_to(NVector::from_lat_long_degrees(5.0, 5.0))
+        );
+        assert_eq!(
+            Angle::from_degrees(2.0),
+            r.distance_to(NVector::from_lat_long_degrees(12.0, 5.0))
+                .to_angle()
+                .round_d7()
+        );
+        assert_eq!(
+            Angle::from_degrees(3.0),
+            r.distance_to(NVector::from_lat_long_degrees(0.0, -3.0))
+                .to_angle()
+                .round_d7()
+        );
+        // closest to the eastern meridian across the date line.
+        assert_eq!(
+            Angle::from_degrees(1.0),
+            rect(10.0, -179.0, 0.0, 170.0)
+                .distance_to(NVector::from_lat_long_degrees(0.0, -178.0))
+                .to_angle()
+                .round_d7()
+        );
+    }
+
+    #[test]
+    fn intersects_minor_arc() {
+        let r = rect(20.0, 35.0, 15.0, -35.0);
+        // crosses the southern parallel twice.
+        assert!(r.intersects_minor_arc(MinorArc::new(
+            NVector::from_lat_long_degrees(9.0, -60.0),
+            NVector::from_lat_long_degrees(9.0, 60.0)
+        )));
+        // crosses the western meridian.
+        assert!(r.intersects_minor_arc(MinorArc::new(
+            NVector::from_lat_long_degrees(17.0, -40.0),
+            NVector::from_lat_long_degrees(17.0, -30.0)
+        )));
+        // inside.
+        assert!(r.intersects_minor_arc(MinorArc::new(
+            NVector::from_lat_long_degrees(17.0, -10.0),
+            NVector::from_lat_long_degrees(17.0, 10.0)
+        )));
+        // below.
+        assert!(!r.intersects_minor_arc(MinorArc::new(
+            NVector::from_lat_long_degrees(5.0, -60.0),
+            NVector::from_lat_long_degrees(5.0, 60.0)
+        )));
+    }
+
     fn ll(lat: i64, lng: i64) -> LatLong {
         LatLong::from_degrees(lat as f64, lng as f64)
     }
@@ -0,0 +1,749 @@
+use crate::{Angle, LatLong, NVector, Vec3};
+
+use super::{Cap, CellId, ChordLength, Loop, Rectangle};
+
+/// A region of the unit sphere: [Cap], [Rectangle] or [Loop].
+///
+/// This trait allows generic code to accept any shape, e.g. to test whether a position is within a geofence or
+/// whether two geofences overlap, and to approximate any shape by [cells](crate::spherical::CellId) - see
+/// [RegionCoverer](crate::spherical::RegionCoverer).
+///
+/// This trait is very much based on [S2Region](https://github.com/google/s2geometry/blob/master/src/s2/s2region.h).
+///
+/// # Examples
+///
+/// ```
+/// use jord::{Angle, NVector};
+/// use jord::spherical::{Cap, Loop, Rectangle, Region};
+///
+/// let geofences: Vec<Box<dyn Region>> = vec![
+///     Box::new(Cap::from_centre_and_radius(
+///         NVector::from_lat_long_degrees(55.605, 13.0038),
+///         Angle::from_degrees(0.1),
+///     )),
+///     Box::new(Rectangle::from_nesw(
+///         Angle::from_degrees(56.1),
+///         Angle::from_degrees(13.3),
+///         Angle::from_degrees(55.9),
+///         Angle::from_degrees(12.6),
+///     )),
+///     Box::new(Loop::new(&vec![
+///         NVector::from_lat_long_degrees(55.605, 13.0038),
+///         NVector::from_lat_long_degrees(55.4295, 13.82),
+///         NVector::from_lat_long_degrees(56.0294, 14.1567),
+///     ])),
+/// ];
+///
+/// let p = NVector::from_lat_long_degrees(56.0, 13.0);
+/// assert!(geofences.iter().any(|g| g.contains_position(p)));
+///
+/// // the cap intersects the loop (the centre of the cap is a vertex of the loop), but not the rectangle.
+/// assert!(geofences[0].intersects_region(geofences[2].as_ref()));
+/// assert!(!geofences[0].intersects_region(geofences[1].as_ref()));
+///
+/// // the rectangle bound of any region contains the region.
+/// assert!(geofences.iter().all(|g| g.rect_bound().contains_region(g.as_ref())));
+/// ```
+pub trait Region: Sealed {
+    /// Returns a [Cap] that contains this region.
+    fn cap_bound(&self) -> Cap;
+
+    /// Returns a [Rectangle] that contains this region.
+    fn rect_bound(&self) -> Rectangle;
+
+    /// Determines whether this region contains the given position. Whether positions on the boundary of the region
+    /// are contained depends on the region: see [Cap::contains_position], [Rectangle::contains_position] and
+    /// [Loop::contains_position].
+    fn contains_position(&self, p: NVector) -> bool;
+
+    /// Determines whether this region contains the given region. Any region contains an empty region.
+    fn contains_region(&self, o: &dyn Region) -> bool {
+        contains(self.shape(), o.shape())
+    }
+
+    /// Determines whether this region and the given region have any position in common.
+    fn intersects_region(&self, o: &dyn Region) -> bool {
+        intersects(self.shape(), o.shape())
+    }
+
+    /// Determines whether this region contains the given cell. This method is allowed to be conservative: it
+    /// may return false even if the cell is contained.
+    fn contains_cell(&self, cell: CellId) -> bool {
+        self.contains_region(&cell.to_loop())
+    }
+
+    /// Determines whether this region may intersect the given cell. This method is allowed to be conservative: it
+    /// may return true even if the cell does not intersect this region, but must return true if it does.
+    fn may_intersect_cell(&self, cell: CellId) -> bool {
+        self.intersects_region(&cell.to_loop())
+    }
+}
+
+/// The shape of a region. Regions are restricted to the shapes of this crate since the relations between 2 regions
+/// are computed according to their respective shapes.
+#[derive(Clone, Copy, Debug)]
+pub enum Shape<'a> {
+    /// A [Cap].
+    Cap(&'a Cap),
+    /// A [Rectangle].
+    Rectangle(&'a Rectangle),
+    /// A [Loop].
+    Loop(&'a Loop),
+}
+
+/// Prevents [Region] from being implemented outside of this crate.
+pub trait Sealed {
+    /// Returns the shape of this region.
+    fn shape(&self) -> Shape<'_>;
+}
+
+impl Sealed for Cap {
+    fn shape(&self) -> Shape<'_> {
+        Shape::Cap(self)
+    }
+}
+
+impl Region for Cap {
+    fn cap_bound(&self) -> Cap {
+        *self
+    }
+
+    fn rect_bound(&self) -> Rectangle {
+        self.bound()
+    }
+
+    fn contains_position(&self, p: NVector) -> bool {
+        Cap::contains_position(self, p)
+    }
+}
+
+impl Sealed for Rectangle {
+    fn shape(&self) -> Shape<'_> {
+        Shape::Rectangle(self)
+    }
+}
+
+impl Region for Rectangle {
+    fn cap_bound(&self) -> Cap {
+        rectangle_cap_bound(self)
+    }
+
+    fn rect_bound(&self) -> Rectangle {
+        *self
+    }
+
+    fn contains_position(&self, p: NVector) -> bool {
+        Rectangle::contains_position(self, LatLong::from_nvector(p))
+    }
+}
+
+impl Sealed for Loop {
+    fn shape(&self) -> Shape<'_> {
+        Shape::Loop(self)
+    }
+}
+
+impl Region for Loop {
+    fn cap_bound(&self) -> Cap {
+        Loop::cap_bound(self)
+    }
+
+    fn rect_bound(&self) -> Rectangle {
+        self.bound()
+    }
+
+    fn contains_position(&self, p: NVector) -> bool {
+        Loop::contains_position(self, p)
+    }
+}
+
+fn is_empty(s: Shape<'_>) -> bool {
+    match s {
+        Shape::Cap(c) => c.is_empty(),
+        Shape::Rectangle(r) => r.is_empty(),
+        Shape::Loop(l) => l.is_empty(),
+    }
+}
+
+/// Determines whether the first given shape contains the second given shape.
+fn contains(a: Shape<'_>, b: Shape<'_>) -> bool {
+    if is_empty(b) {
+        return true;
+    }
+    match (a, b) {
+        (Shape::Cap(a), Shape::Cap(b)) => a.contains_cap(*b),
+        (Shape::Cap(c), Shape::Rectangle(r)) => {
+            c.is_full() || !cap_intersects_rectangle(&c.complement(), r, true)
+        }
+        (Shape::Cap(c), Shape::Loop(l)) => cap_contains_loop(c, l),
+        (Shape::Rectangle(r), Shape::Cap(c)) => r.contains_rectangle(c.bound()),
+        (Shape::Rectangle(a), Shape::Rectangle(b)) => a.contains_rectangle(*b),
+        (Shape::Rectangle(r), Shape::Loop(l)) => rectangle_contains_loop(r, l),
+        (Shape::Loop(l), Shape::Cap(c)) => loop_contains_cap(l, c),
+        (Shape::Loop(l), Shape::Rectangle(r)) => loop_contains_rectangle(l, r),
+        (Shape::Loop(a), Shape::Loop(b)) => a.contains_loop(b),
+    }
+}
+
+/// Determines whether the 2 given shapes have any position in common.
+fn intersects(a: Shape<'_>, b: Shape<'_>) -> bool {
+    match (a, b) {
+        (Shape::Cap(a), Shape::Cap(b)) => a.intersects(*b),
+        (Shape::Cap(c), Shape::Rectangle(r)) | (Shape::Rectangle(r), Shape::Cap(c)) => {
+            cap_intersects_rectangle(c, r, false)
+        }
+        (Shape::Cap(c), Shape::Loop(l)) | (Shape::Loop(l), Shape::Cap(c)) => c.intersects_loop(l),
+        (Shape::Rectangle(a), Shape::Rectangle(b)) => a.intersects(*b),
+        (Shape::Rectangle(r), Shape::Loop(l)) | (Shape::Loop(l), Shape::Rectangle(r)) => {
+            rectangle_intersects_loop(r, l)
+        }
+        (Shape::Loop(a), Shape::Loop(b)) => a.intersects(b),
+    }
+}
+
+/// Determines whether the given cap (or its interior) intersects the given rectangle.
+fn cap_intersects_rectangle(c: &Cap, r: &Rectangle, interior: bool) -> bool {
+    if c.is_empty() || r.is_empty() {
+        return false;
+    }
+    if c.is_full() {
+        return true;
+    }
+    let d = r.distance_to(c.centre());
+    let radius = ChordLength::from_angle(c.radius());
+    d < radius || (!interior && d == radius)
+}
+
+fn cap_contains_loop(c: &Cap, l: &Loop) -> bool {
+    if c.is_empty() || !l.iter_vertices().all(|v| c.contains_position(*v)) {
+        return false;
+    }
+    // caps of radius not exceeding 90 degrees are convex: the edges of the loop are contained since all its
+    // vertices are. Larger caps must additionally not intersect the loop through their complement.
+    c.radius() <= Angle::QUARTER_CIRCLE || !c.complement().interior_intersects_loop(l)
+}
+
+fn loop_contains_cap(l: &Loop, c: &Cap) -> bool {
+    if c.is_full() {
+        return false;
+    }
+    let centre = c.centre();
+    let radius = ChordLength::from_angle(c.radius());
+    l.contains_position(centre) && l.iter_edges().all(|e| e.distance_to(centre) > radius)
+}
+
+fn rectangle_intersects_loop(r: &Rectangle, l: &Loop) -> bool {
+    if r.is_empty() || l.is_empty() {
+        return false;
+    }
+    if l.iter_edges().any(|e| r.intersects_minor_arc(*e)) {
+        return true;
+    }
+    // the boundary of the loop is outside of the rectangle: either the rectangle is within the loop or both are
+    // disjoint.
+    l.contains_position(r.south_west().to_nvector())
+}
+
+fn rectangle_contains_loop(r: &Rectangle, l: &Loop) -> bool {
+    if r.is_empty() {
+        return false;
+    }
+    if r.is_full() {
+        return true;
+    }
+    if !l
+        .iter_edges()
+        .all(|e| r.contains_rectangle(Rectangle::from_minor_arc(*e)))
+    {
+        return false;
+    }
+    // the boundary of the loop is inside the rectangle: each connected component of the complement of the rectangle
+    // is either entirely inside or entirely outside of the loop.
+    let mut outside = Vec::new();
+    let np = LatLong::new(Angle::QUARTER_CIRCLE, Angle::ZERO);
+    let sp = LatLong::new(-Angle::QUARTER_CIRCLE, Angle::ZERO);
+    if !r.contains_position(np) {
+        outside.push(np);
+    }
+    if !r.contains_position(sp) {
+        outside.push(sp);
+    }
+    if outside.is_empty() {
+        // both poles are inside: the complement of the rectangle is a lune.
+        let ne = r.north_east().longitude();
+        let sw = r.south_west().longitude();
+        let mut span = sw - ne;
+        if span < Angle::ZERO {
+            span = span + Angle::FULL_CIRCLE;
+        }
+        outside.push(LatLong::new(Angle::ZERO, ne + 0.5 * span));
+    }
+    outside.iter().all(|p| !l.contains_position(p.to_nvector()))
+}
+
+fn loop_contains_rectangle(l: &Loop, r: &Rectangle) -> bool {
+    if r.is_full() || l.iter_edges().any(|e| r.intersects_minor_arc(*e)) {
+        return false;
+    }
+    l.contains_position(r.south_west().to_nvector())
+}
+
+/// Returns a cap containing the given rectangle: either the cap centred at the closest pole or the cap centred at
+/// the centre of the rectangle, whichever is smaller.
+fn rectangle_cap_bound(r: &Rectangle) -> Cap {
+    if r.is_empty() {
+        return Cap::EMPTY;
+    }
+    let ne = r.north_east();
+    let sw = r.south_west();
+    let (lat_lo, lat_hi) = (sw.latitude(), ne.latitude());
+    // expand by 1e-7 degrees to absorb the floating-point error introduced when converting LatLong <-> NVector.
+    let margin = Angle::from_degrees(1.0e-7);
+    let (pole, pole_radius) = if (lat_lo + lat_hi) < Angle::ZERO {
+        (Vec3::NEG_UNIT_Z, Angle::QUARTER_CIRCLE + lat_hi + margin)
+    } else {
+        (Vec3::UNIT_Z, Angle::QUARTER_CIRCLE - lat_lo + margin)
+    };
+    let pole_cap = if pole_radius >= Angle::HALF_CIRCLE {
+        Cap::FULL
+    } else {
+        Cap::from_centre_and_radius(NVector::new(pole), pole_radius)
+    };
+
+    let mut lng_span = ne.longitude() - sw.longitude();
+    if lng_span < Angle::ZERO {
+        lng_span = lng_span + Angle::FULL_CIRCLE;
+    }
+    // the farthest position of the rectangle from its centre is one of its vertices only if the rectangle spans at
+    // most half of the longitudes.
+    if r.is_longitude_full() || lng_span > Angle::HALF_CIRCLE {
+        return pole_cap;
+    }
+    let centre = r.centre().to_nvector();
+    let vertices = [
+        sw,
+        LatLong::new(lat_lo, ne.longitude()),
+        ne,
+        LatLong::new(lat_hi, sw.longitude()),
+    ];
+    let radius = vertices
+        .iter()
+        .map(|v| ChordLength::new(centre, v.to_nvector()))
+        .max()
+        .unwrap_or(ChordLength::ZERO)
+        .to_angle();
+    let mid_cap = Cap::from_centre_and_radius(centre, radius + margin);
+    if mid_cap.radius() < pole_cap.radius() {
+        mid_cap
+    } else {
+        pole_cap
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        spherical::{Cap, CellId, Loop, Rectangle},
+        Angle, LatLong, NVector,
+    };
+
+    use super::Region;
+
+    fn grid(lat0: f64, lon0: f64, lat1: f64, lon1: f64) -> Vec<NVector> {
+        let mut ps = Vec::new();
+        let n = 20;
+        for i in 0..=n {
+            for j in 0..=n {
+                let lat = lat0 + (lat1 - lat0) * (i as f64) / (n as f64);
+                let lon = lon0 + (lon1 - lon0) * (j as f64) / (n as f64);
+                ps.push(NVector::from_lat_long_degrees(lat, lon));
+            }
+        }
+        ps
+    }
+
+    #[test]
+    fn cap_cell() {
+        let cap = Cap::from_centre_and_radius(
+            NVector::from_lat_long_degrees(45.0, 45.0),
+            Angle::from_degrees(10.0),
+        );
+        let inside = CellId::from_nvector_at_level(cap.centre(), 8);
+        assert!(cap.contains_cell(inside));
+        assert!(cap.may_intersect_cell(inside));
+
+        let face = CellId::from_nvector_at_level(cap.centre(), 0);
+        assert!(!cap.contains_cell(face));
+        assert!(cap.may_intersect_cell(face));
+
+        let far = CellId::from_nvector_at_level(NVector::from_lat_long_degrees(-45.0, -135.0), 5);
+        assert!(!cap.contains_cell(far));
+        assert!(!cap.may_intersect_cell(far));
+
+        assert!(!Cap::EMPTY.may_intersect_cell(face));
+        assert!(Cap::FULL.contains_cell(face));
+    }
+
+    #[test]
+    fn large_cap_cell() {
+        // all the vertices of the face are inside the cap but the cap does not contain the face centre.
+        let face = CellId::from_face(0);
+        let cap = Cap::from_centre_and_radius(
+            NVector::from_lat_long_degrees(0.0, 180.0),
+            Angle::from_degrees(170.0),
+        );
+        assert!(cap.may_intersect_cell(face));
+        assert!(!cap.contains_cell(face));
+        assert!(cap.complement().may_intersect_cell(face));
+    }
+
+    #[test]
+    fn cap_intersects_cell_edge() {
+        // a small cap crossing the edge of a cell but containing none of its vertices.
+        let cell = CellId::from_nvector_at_level(NVector::from_lat_long_degrees(0.1, 0.1), 4);
+        let vs = cell.vertices();
+        let mid = NVector::new((vs[0].as_vec3() + vs[1].as_vec3()).unit());
+        let cap = Cap::from_centre_and_radius(mid, Angle::from_degrees(0.01));
+        assert!(!vs.iter().any(|v| cap.contains_position(*v)));
+        assert!(cap.may_intersect_cell(cell));
+        assert!(!cap.contains_cell(cell));
+    }
+
+    #[test]
+    fn rectangle_cell() {
+        let r = Rectangle::from_nesw(
+            Angle::from_degrees(60.0),
+            Angle::from_degrees(30.0),
+            Angle::from_degrees(10.0),
+            Angle::from_degrees(-20.0),
+        );
+        let inside = CellId::from_nvector_at_level(NVector::from_lat_long_degrees(30.0, 0.0), 6);
+        assert!(r.contains_cell(inside));
+        assert!(r.may_intersect_cell(inside));
+
+        let far = CellId::from_nvector_at_level(NVector::from_lat_long_degrees(-40.0, 0.0), 6);
+        assert!(!r.contains_cell(far));
+        assert!(!r.may_intersect_cell(far));
+    }
+
+    #[test]
+    fn rectangle_cap_bound() {
+        let rs = [
+            Rectangle::from_nesw(
+                Angle::from_degrees(60.0),
+                Angle::from_degrees(30.0),
+                Angle::from_degrees(10.0),
+                Angle::from_degrees(-20.0),
+            ),
+            // crosses the antimeridian.
+            Rectangle::from_nesw(
+                Angle::from_degrees(-10.0),
+                Angle::from_degrees(-170.0),
+                Angle::from_degrees(-30.0),
+                Angle::from_degrees(160.0),
+            ),
+            // wide.
+            Rectangle::from_nesw(
+                Angle::from_degrees(80.0),
+                Angle::from_degrees(170.0),
+                Angle::from_degrees(70.0),
+                Angle::from_degrees(-170.0),
+            ),
+        ];
+        for r in rs {
+            let cap = r.cap_bound();
+            let ne = r.north_east();
+            let sw = r.south_west();
+            let mut lng_span = ne.longitude() - sw.longitude();
+            if lng_span < Angle::ZERO {
+                lng_span = lng_span + Angle::FULL_CIRCLE;
+            }
+            for i in 0..=10 {
+                for j in 0..=10 {
+                    let lat = sw.latitude() + (ne.latitude() - sw.latitude()) * (i as f64 / 10.0);
+                    let lng = sw.longitude() + lng_span * (j as f64 / 10.0);
+                    let p = LatLong::new(lat, lng).to_nvector();
+                    assert!(cap.contains_position(p), "{:?} {:?}", r, p);
+                }
+            }
+        }
+        assert!(Rectangle::EMPTY.cap_bound().is_empty());
+        assert!(Rectangle::FULL.cap_bound().is_full());
+    }
+
+    #[test]
+    fn loop_cell() {
+        let l = Loop::new(&[
+            NVector::from_lat_long_degrees(10.0, 10.0),
+            NVector::from_lat_long_degrees(10.0, 30.0),
+            NVector::from_lat_long_degrees(30.0, 30.0),
+            NVector::from_lat_long_degrees(30.0, 10.0),
+        ]);
+        let inside = CellId::from_nvector_at_level(NVector::from_lat_long_degrees(20.0, 20.0), 6);
+        assert!(l.contains_cell(inside));
+        assert!(l.may_intersect_cell(inside));
+
+        // the loop is inside the face.
+        let face = CellId::from_nvector_at_level(NVector::from_lat_long_degrees(20.0, 20.0), 0);
+        assert!(!l.contains_cell(face));
+        assert!(l.may_intersect_cell(face));
+
+        // crosses the boundary of the loop.
+        let crossing = CellId::from_nvector_at_level(NVector::from_lat_long_degrees(10.0, 20.0), 6);
+        assert!(!l.contains_cell(crossing));
+        assert!(l.may_intersect_cell(crossing));
+
+        let far = CellId::from_nvector_at_level(NVector::from_lat_long_degrees(-20.0, -20.0), 6);
+        assert!(!l.contains_cell(far));
+        assert!(!l.may_intersect_cell(far));
+
+        assert!(!Loop::EMPTY.may_intersect_cell(face));
+    }
+
+    #[test]
+    fn loop_cap_bound() {
+        let l = Loop::new(&[
+            NVector::from_lat_long_degrees(10.0, 10.0),
+            NVector::from_lat_long_degrees(10.0, 30.0),
+            NVector::from_lat_long_degrees(30.0, 30.0),
+            NVector::from_lat_long_degrees(30.0, 10.0),
+        ]);
+        let cap = l.cap_bound();
+        for p in grid(10.0, 10.0, 30.0, 30.0) {
+            assert!(cap.contains_position(p));
+        }
+        assert!(cap.radius() < Angle::from_degrees(20.0));
+    }
+
+    fn rect(n: f64, e: f64, s: f64, w: f64) -> Rectangle {
+        Rectangle::from_nesw(
+            Angle::from_degrees(n),
+            Angle::from_degrees(e),
+            Angle::from_degrees(s),
+            Angle::from_degrees(w),
+        )
+    }
+
+    fn cap(lat: f64, lng: f64, radius: f64) -> Cap {
+        Cap::from_centre_and_radius(
+            NVector::from_lat_long_degrees(lat, lng),
+            Angle::from_degrees(radius),
+        )
+    }
+
+    fn lp(vs: &[(f64, f64)]) -> Loop {
+        let vs: Vec<NVector> = vs
+            .iter()
+            .map(|(lat, lng)| NVector::from_lat_long_degrees(*lat, *lng))
+            .collect();
+        Loop::new(&vs)
+    }
+
+    #[test]
+    fn contains_position() {
+        let p = NVector::from_lat_long_degrees(5.0, 5.0);
+        let q = NVector::from_lat_long_degrees(-5.0, 5.0);
+        let regions: [Box<dyn Region>; 3] = [
+            Box::new(cap(0.0, 0.0, 10.0)),
+            Box::new(rect(10.0, 10.0, 0.0, 0.0)),
+            Box::new(lp(&[(0.0, 0.0), (0.0, 10.0), (10.0, 10.0), (10.0, 0.0)])),
+        ];
+        for r in regions.iter() {
+            assert!(r.contains_position(p));
+        }
+        assert!(regions[0].contains_position(q));
+        assert!(!regions[1].contains_position(q));
+        assert!(!regions[2].contains_position(q));
+    }
+
+    #[test]
+    fn bounds_contain_region() {
+        let regions: [Box<dyn Region>; 6] = [
+            Box::new(cap(55.0, 13.0, 2.0)),
+            Box::new(cap(85.0, 13.0, 10.0)),
+            Box::new(cap(0.0, 179.0, 5.0)),
+            Box::new(rect(10.0, -170.0, -10.0, 170.0)),
+            Box::new(lp(&[(0.0, 0.0), (0.0, 10.0), (10.0, 10.0), (10.0, 0.0)])),
+            Box::new(lp(&[
+                (80.0, 0.0),
+                (80.0, 90.0),
+                (80.0, 180.0),
+                (80.0, -90.0),
+            ])),
+        ];
+        for r in regions.iter() {
+            assert!(
+                r.rect_bound().contains_region(r.as_ref()),
+                "{:?}",
+                r.shape()
+            );
+            assert!(r.cap_bound().contains_region(r.as_ref()), "{:?}", r.shape());
+        }
+    }
+
+    #[test]
+    fn cap_rect_bound() {
+        let r = cap(0.0, 0.0, 10.0).rect_bound();
+        assert_eq!(LatLong::from_degrees(10.0, 10.0), r.north_east().round_d5());
+        assert_eq!(
+            LatLong::from_degrees(-10.0, -10.0),
+            r.south_west().round_d5()
+        );
+
+        // crosses the antimeridian.
+        let r = cap(0.0, 180.0, 10.0).rect_bound();
+        assert_eq!(
+            LatLong::from_degrees(10.0, -170.0),
+            r.north_east().round_d5()
+        );
+        assert_eq!(
+            LatLong::from_degrees(-10.0, 170.0),
+            r.south_west().round_d5()
+        );
+
+        // includes the north pole.
+        let r = cap(85.0, 0.0, 10.0).rect_bound();
+        assert!(r.is_longitude_full());
+        assert_eq!(
+            Angle::from_degrees(75.0),
+            r.south_west().latitude().round_d5()
+        );
+
+        assert!(Cap::EMPTY.rect_bound().is_empty());
+        assert!(Cap::FULL.rect_bound().is_full());
+    }
+
+    #[test]
+    fn empty_regions() {
+        let c = cap(0.0, 0.0, 10.0);
+        let empties: [&dyn Region; 3] = [&Cap::EMPTY, &Rectangle::EMPTY, &Loop::EMPTY];
+        for e in empties {
+            assert!(c.contains_region(e));
+            assert!(!c.intersects_region(e));
+            assert!(!e.intersects_region(&c));
+            assert!(!e.contains_region(&c));
+        }
+    }
+
+    #[test]
+    fn cap_cap() {
+        let a = cap(0.0, 0.0, 10.0);
+        assert!(a.intersects_region(&cap(0.0, 19.0, 10.0)));
+        assert!(!a.intersects_region(&cap(0.0, 21.0, 10.0)));
+        assert!(a.contains_region(&cap(0.0, 5.0, 4.0)));
+        assert!(!a.contains_region(&cap(0.0, 5.0, 6.0)));
+        assert!(Cap::FULL.contains_region(&a));
+    }
+
+    #[test]
+    fn cap_rectangle() {
+        let r = rect(10.0, 20.0, 0.0, 0.0);
+
+        // the cap intersects the northern parallel but contains no vertex of the rectangle.
+        let c = cap(10.5, 10.0, 1.0);
+        assert!(c.intersects_region(&r));
+        assert!(r.intersects_region(&c));
+        assert!(!c.contains_region(&r));
+        assert!(!r.contains_region(&c));
+
+        // outside of the longitude interval.
+        let c = cap(5.0, 22.0, 1.0);
+        assert!(!c.intersects_region(&r));
+        assert!(!r.intersects_region(&c));
+
+        // the northern parallel bulges north of the minor arc between the 2 northern vertices.
+        let c = cap(5.0, 10.0, 25.0);
+        assert!(c.contains_region(&r));
+        assert!(!r.contains_region(&c));
+        assert!(!cap(0.0, 10.0, 14.0).contains_region(&r));
+
+        let c = cap(5.0, 10.0, 2.0);
+        assert!(r.contains_region(&c));
+        assert!(!c.contains_region(&r));
+    }
+
+    #[test]
+    fn cap_loop() {
+        let l = lp(&[(0.0, 0.0), (0.0, 20.0), (10.0, 20.0), (10.0, 0.0)]);
+
+        // the cap intersects an edge but contains no vertex.
+        let c = cap(-0.5, 10.0, 1.0);
+        assert!(c.intersects_region(&l));
+        assert!(l.intersects_region(&c));
+        assert!(!c.contains_region(&l));
+        assert!(!l.contains_region(&c));
+
+        let c = cap(5.0, 10.0, 2.0);
+        assert!(l.contains_region(&c));
+        assert!(!c.contains_region(&l));
+
+        let c = cap(5.0, 10.0, 20.0);
+        assert!(c.contains_region(&l));
+        assert!(!l.contains_region(&c));
+
+        assert!(!cap(-5.0, 10.0, 1.0).intersects_region(&l));
+
+        // large cap containing the vertices of the loop but not its interior.
+        let c = cap(-5.0, -170.0, 179.0);
+        assert!(l.iter_vertices().all(|v| c.contains_position(*v)));
+        assert!(!c.contains_region(&l));
+    }
+
+    #[test]
+    fn rectangle_rectangle() {
+        let a = rect(10.0, 20.0, 0.0, 0.0);
+        assert!(a.intersects_region(&rect(20.0, 30.0, 10.0, 20.0)));
+        assert!(!a.intersects_region(&rect(20.0, 30.0, 11.0, 20.0)));
+        assert!(a.contains_region(&rect(5.0, 10.0, 1.0, 1.0)));
+        assert!(!a.contains_region(&rect(15.0, 10.0, 1.0, 1.0)));
+    }
+
+    #[test]
+    fn rectangle_loop() {
+        let r = rect(20.0, 35.0, 15.0, -35.0);
+        // the northern edge of the loop crosses the southern parallel of the rectangle twice.
+        let l = lp(&[(9.0, -60.0), (9.0, 60.0), (-10.0, 0.0)]);
+        assert!(r.intersects_region(&l));
+        assert!(l.intersects_region(&r));
+        assert!(!r.contains_region(&l));
+        assert!(!l.contains_region(&r));
+
+        let l = lp(&[(16.0, -10.0), (16.0, 10.0), (19.0, 0.0)]);
+        assert!(r.contains_region(&l));
+        assert!(!l.contains_region(&r));
+
+        let l = lp(&[(0.0, -40.0), (0.0, 40.0), (30.0, 40.0), (30.0, -40.0)]);
+        assert!(l.contains_region(&r));
+        assert!(!r.contains_region(&l));
+
+        let l = lp(&[(0.0, 40.0), (0.0, 50.0), (10.0, 50.0)]);
+        assert!(!l.intersects_region(&r));
+        assert!(!r.intersects_region(&l));
+
+        // loop around the north pole inside a polar rectangle.
+        let polar = rect(90.0, 180.0, 70.0, -180.0);
+        let l = lp(&[(80.0, 0.0), (80.0, 90.0), (80.0, 180.0), (80.0, -90.0)]);
+        assert!(polar.contains_region(&l));
+        assert!(!rect(90.0, 180.0, 85.0, -180.0).contains_region(&l));
+        assert!(rect(90.0, 180.0, 85.0, -180.0).intersects_region(&l));
+    }
+
+    #[test]
+    fn loop_loop() {
+        let a = lp(&[(0.0, 0.0), (0.0, 20.0), (20.0, 20.0), (20.0, 0.0)]);
+        let b = lp(&[(5.0, 5.0), (5.0, 10.0), (10.0, 10.0), (10.0, 5.0)]);
+        let c = lp(&[(10.0, 10.0), (10.0, 30.0), (30.0, 30.0), (30.0, 10.0)]);
+        let d = lp(&[(-10.0, -10.0), (-10.0, -5.0), (-5.0, -5.0)]);
+
+        assert!(a.contains_region(&b));
+        assert!(!b.contains_region(&a));
+        assert!(a.intersects_region(&b));
+        assert!(b.intersects_region(&a));
+
+        assert!(a.intersects_region(&c));
+        assert!(!a.contains_region(&c));
+
+        assert!(!a.intersects_region(&d));
+        assert!(!d.intersects_region(&a));
+    }
+}
@@ -0,0 +1,528 @@
+use std::{cmp::Ordering, collections::BinaryHeap, f64::consts::SQRT_2};
+
+use super::{CellId, CellUnion, Region};
+
+/// Approximates a [Region] by a [CellUnion]: either a covering - the union of the cells contains the region, or an
+/// interior covering - the region contains the union of the cells.
+///
+/// The approximation is constrained by:
+/// - a minimum level: no cell larger than this level is used,
+/// - a maximum level: no cell smaller than this level is used,
+/// - a maximum number of cells: a best effort limit which may be exceeded if the minimum level is too high for the
+///   region, or if the region intersects more face cells than this limit.
+///
+/// Coverings are typically used to pre-filter large datasets: positions (or cells) that are not in the covering of
+/// a region cannot be in the region, whereas positions in the interior covering are certainly in the region.
+///
+/// This struct and implementation is very much based on [S2RegionCoverer](https://github.com/google/s2geometry/blob/master/src/s2/s2region_coverer.h).
+///
+/// # Examples
+///
+/// ```
+/// use jord::NVector;
+/// use jord::spherical::{Loop, RegionCoverer};
+///
+/// let l = Loop::new(&vec![
+///     NVector::from_lat_long_degrees(55.605, 13.0038),
+///     NVector::from_lat_long_degrees(55.4295, 13.82),
+///     NVector::from_lat_long_degrees(56.0294, 14.1567),
+///     NVector::from_lat_long_degrees(56.0465, 12.6945),
+/// ]);
+///
+/// let coverer = RegionCoverer::new(0, 20, 16);
+/// let covering = coverer.covering(&l);
+/// assert!(covering.len() <= 16);
+///
+/// let p = NVector::from_lat_long_degrees(55.8, 13.5);
+/// assert!(l.contains_position(p));
+/// assert!(covering.contains_position(p));
+///
+/// // positions outside of the covering are outside of the loop.
+/// let q = NVector::from_lat_long_degrees(40.0, 13.5);
+/// assert!(!covering.contains_position(q));
+/// ```
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))] // codecov:ignore:this
+pub struct RegionCoverer {
+    min_level: u8,
+    max_level: u8,
+    max_cells: usize,
+}
+
+impl Default for RegionCoverer {
+    /// Returns a coverer using all levels and at most 8 cells.
+    fn default() -> Self {
+        Self {
+            min_level: 0,
+            max_level: CellId::MAX_LEVEL,
+            max_cells: 8,
+        }
+    }
+}
+
+impl RegionCoverer {
+    /// Creates a new region coverer with the given minimum level, maximum level and maximum number of cells. The
+    /// maximum level is set to the minimum level if lesser and both are capped to [MAX_LEVEL](crate::spherical::CellId::MAX_LEVEL).
+    pub fn new(min_level: u8, max_level: u8, max_cells: usize) -> Self {
+        let min_level = min_level.min(CellId::MAX_LEVEL);
+        Self {
+            min_level,
+            max_level: max_level.clamp(min_level, CellId::MAX_LEVEL),
+            max_cells,
+        }
+    }
+
+    /// Returns the minimum level of the cells used by this coverer.
+    pub fn min_level(&self) -> u8 {
+        self.min_level
+    }
+
+    /// Returns the maximum level of the cells used by this coverer.
+    pub fn max_level(&self) -> u8 {
+        self.max_level
+    }
+
+    /// Returns the maximum desired number of cells in the approximations computed by this coverer.
+    pub fn max_cells(&self) -> usize {
+        self.max_cells
+    }
+
+    /// Returns a [CellUnion] covering the given region: all the positions of the region are in the union.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, NVector};
+    /// use jord::spherical::{Cap, RegionCoverer};
+    ///
+    /// let cap = Cap::from_centre_and_radius(
+    ///     NVector::from_lat_long_degrees(55.605, 13.0038),
+    ///     Angle::from_degrees(1.0)
+    /// );
+    ///
+    /// let covering = RegionCoverer::new(4, 12, 10).covering(&cap);
+    /// assert!(covering.len() <= 10);
+    /// assert!(covering.iter().all(|c| c.level() >= 4 && c.level() <= 12));
+    /// assert!(cap.boundary(36).into_iter().all(|p| covering.contains_position(p)));
+    /// ```
+    pub fn covering<R: Region + ?Sized>(&self, region: &R) -> CellUnion {
+        Coverer::new(*self, region, false).covering()
+    }
+
+    /// Returns a [CellUnion] contained by the given region: all the positions of the union are in the region.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, NVector};
+    /// use jord::spherical::{Cap, RegionCoverer};
+    ///
+    /// let cap = Cap::from_centre_and_radius(
+    ///     NVector::from_lat_long_degrees(55.605, 13.0038),
+    ///     Angle::from_degrees(1.0)
+    /// );
+    ///
+    /// let interior = RegionCoverer::new(4, 12, 10).interior_covering(&cap);
+    /// assert!(!interior.is_empty());
+    /// assert!(interior.iter().all(|c| c.vertices().iter().all(|v| cap.contains_position(*v))));
+    /// ```
+    pub fn interior_covering<R: Region + ?Sized>(&self, region: &R) -> CellUnion {
+        Coverer::new(*self, region, true).covering()
+    }
+}
+
+/// a cell which intersects the region being covered.
+struct Candidate {
+    cell: CellId,
+    /// whether the cell is part of the result without further subdivision.
+    is_terminal: bool,
+    /// children intersecting the region.
+    children: Vec<Candidate>,
+}
+
+/// a non-terminal candidate with its priority: candidates with the lowest level, the fewest children and the
+/// fewest terminal children are subdivided first.
+struct Entry {
+    priority: i64,
+    candidate: Candidate,
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl Eq for Entry {}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Entry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority.cmp(&other.priority)
+    }
+}
+
+/// state of a single covering computation.
+struct Coverer<'a, R: Region + ?Sized> {
+    options: RegionCoverer,
+    region: &'a R,
+    interior: bool,
+    result: Vec<CellId>,
+    queue: BinaryHeap<Entry>,
+}
+
+impl<'a, R: Region + ?Sized> Coverer<'a, R> {
+    fn new(options: RegionCoverer, region: &'a R, interior: bool) -> Self {
+        Self {
+            options,
+            region,
+            interior,
+            result: Vec::new(),
+            queue: BinaryHeap::new(),
+        }
+    }
+
+    fn covering(mut self) -> CellUnion {
+        self.initial_candidates();
+        while let Some(Entry { mut candidate, .. }) = self.queue.pop() {
+            if self.interior && self.result.len() >= self.options.max_cells {
+                break;
+            }
+            let level = candidate.cell.level();
+            let num_children = candidate.children.len();
+            if level < self.options.min_level
+                || num_children == 1
+                || self.result.len() + self.queue.len() + num_children <= self.options.max_cells
+            {
+                for child in candidate.children {
+                    if !self.interior || self.result.len() < self.options.max_cells {
+                        self.add_candidate(child);
+                    }
+                }
+            } else if !self.interior {
+                candidate.is_terminal = true;
+                self.add_candidate(candidate);
+            }
+        }
+        self.merge_siblings();
+        CellUnion::new(&self.result)
+    }
+
+    /// adds the cells around the centre of the cap bound of the region if the region is small enough, or the 6
+    /// faces otherwise.
+    fn initial_candidates(&mut self) {
+        let cap = self.region.cap_bound();
+        if cap.is_empty() {
+            return;
+        }
+        if self.options.max_cells >= 4 {
+            let level = level_for_min_width(2.0 * cap.radius().as_radians())
+                .min(self.options.max_level)
+                .min(CellId::MAX_LEVEL - 1);
+            if level > 0 {
+                // the cap contains at most one vertex at that level, so it is covered by the 4 (or 3) cells
+                // sharing the vertex closest to its centre.
+                for cell in CellId::from_nvector(cap.centre()).vertex_neighbours(level) {
+                    if let Some(c) = self.new_candidate(cell) {
+                        self.add_candidate(c);
+                    }
+                }
+                return;
+            }
+        }
+        for face in 0..6 {
+            if let Some(c) = self.new_candidate(CellId::from_face(face)) {
+                self.add_candidate(c);
+            }
+        }
+    }
+
+    /// returns a candidate for the given cell if it intersects the region.
+    fn new_candidate(&self, cell: CellId) -> Option<Candidate> {
+        if !self.region.may_intersect_cell(cell) {
+            return None;
+        }
+        let mut is_terminal = false;
+        let level = cell.level();
+        if level >= self.options.min_level {
+            if self.interior {
+                if self.region.contains_cell(cell) {
+                    is_terminal = true;
+                } else if level + 1 > self.options.max_level {
+                    return None;
+                }
+            } else if level + 1 > self.options.max_level || self.region.contains_cell(cell) {
+                is_terminal = true;
+            }
+        }
+        Some(Candidate {
+            cell,
+            is_terminal,
+            children: Vec::new(),
+        })
+    }
+
+    /// adds the given candidate to the result if terminal, or to the priority queue after expanding its children.
+    fn add_candidate(&mut self, mut candidate: Candidate) {
+        if candidate.is_terminal {
+            self.result.push(candidate.cell);
+            return;
+        }
+        let mut num_terminals = 0;
+        if let Some(children) = candidate.cell.children() {
+            for child in children {
+                if let Some(c) = self.new_candidate(child) {
+                    if c.is_terminal {
+                        num_terminals += 1;
+                    }
+                    candidate.children.push(c);
+                }
+            }
+        }
+        let level = candidate.cell.level();
+        if candidate.children.is_empty() {
+            // the cell does not intersect the region after all.
+        } else if !self.interior && num_terminals == 4 && level >= self.options.min_level {
+            // all children are terminal: use this cell instead.
+            self.result.push(candidate.cell);
+        } else {
+            let priority =
+                -((((level as i64) << 2) + candidate.children.len() as i64) << 2) - num_terminals;
+            self.queue.push(Entry {
+                priority,
+                candidate,
+            });
+        }
+    }
+
+    /// replaces any 4 sibling cells of the result by their parent if the parent level is not less than the minimum
+    /// level.
+    fn merge_siblings(&mut self) {
+        let mut cells = CellUnion::new(&self.result).cell_ids().to_vec();
+        let mut merged: Vec<CellId> = Vec::with_capacity(cells.len());
+        loop {
+            let mut changed = false;
+            for c in cells {
+                merged.push(c);
+                // each merge may complete a set of siblings one level up, handled by the next pass.
+                let n = merged.len();
+                if n >= 4 {
+                    let last4 = &merged[n - 4..];
+                    if let Some(parent) = last4[0].parent() {
+                        if parent.level() >= self.options.min_level
+                            && last4.iter().enumerate().all(|(i, s)| {
+                                s.parent() == Some(parent) && *s == parent.child(i as u8)
+                            })
+                        {
+                            merged.truncate(n - 4);
+                            merged.push(parent);
+                            changed = true;
+                        }
+                    }
+                }
+            }
+            if !changed {
+                break;
+            }
+            cells = std::mem::take(&mut merged);
+        }
+        self.result = merged;
+    }
+}
+
+/// maximum level such that the minimum width of all cells at that level is at least the given width (radians).
+fn level_for_min_width(width: f64) -> u8 {
+    // minimum width of face cells with the quadratic projection.
+    let min_width_deriv = 2.0 * SQRT_2 / 3.0;
+    if width <= 0.0 {
+        return CellId::MAX_LEVEL;
+    }
+    let level = (min_width_deriv / width).log2().floor();
+    if level <= 0.0 {
+        0
+    } else {
+        (level as u8).min(CellId::MAX_LEVEL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        spherical::{Cap, CellId, Loop, Rectangle, Region, RegionCoverer},
+        Angle, LatLong, NVector,
+    };
+
+    fn caps() -> Vec<Cap> {
+        vec![
+            Cap::from_centre_and_radius(
+                NVector::from_lat_long_degrees(55.605, 13.0038),
+                Angle::from_degrees(0.5),
+            ),
+            Cap::from_centre_and_radius(
+                NVector::from_lat_long_degrees(90.0, 0.0),
+                Angle::from_degrees(5.0),
+            ),
+            Cap::from_centre_and_radius(
+                NVector::from_lat_long_degrees(-33.8678, 151.2153),
+                Angle::from_degrees(30.0),
+            ),
+            Cap::from_centre_and_radius(
+                NVector::from_lat_long_degrees(0.0, 180.0),
+                Angle::from_degrees(120.0),
+            ),
+        ]
+    }
+
+    fn samples() -> Vec<NVector> {
+        let mut ps = Vec::new();
+        for lat in (-900..=900).step_by(25) {
+            for lon in (-1800..1800).step_by(25) {
+                ps.push(NVector::from_lat_long_degrees(
+                    lat as f64 / 10.0,
+                    lon as f64 / 10.0,
+                ));
+            }
+        }
+        ps
+    }
+
+    fn assert_covering<R: Region>(region: &R, contains: impl Fn(NVector) -> bool) {
+        let coverers = [
+            RegionCoverer::default(),
+            RegionCoverer::new(0, 10, 4),
+            RegionCoverer::new(3, 8, 20),
+            RegionCoverer::new(5, 5, 1),
+        ];
+        let ps = samples();
+        for coverer in coverers {
+            let covering = coverer.covering(region);
+            assert!(!covering.is_empty());
+            for c in covering.iter() {
+                assert!(c.level() >= coverer.min_level() && c.level() <= coverer.max_level());
+            }
+            if coverer.min_level() == 0 {
+                // large regions may intersect up to 6 faces.
+                assert!(covering.len() <= coverer.max_cells().max(6));
+            }
+            for p in ps.iter() {
+                if contains(*p) {
+                    assert!(covering.contains_position(*p), "{:?}", p);
+                }
+            }
+
+            let interior = coverer.interior_covering(region);
+            assert!(interior.len() <= coverer.max_cells());
+            for c in interior.iter() {
+                assert!(c.level() >= coverer.min_level() && c.level() <= coverer.max_level());
+                assert!(region.contains_cell(*c));
+            }
+            for p in ps.iter() {
+                if interior.contains_position(*p) {
+                    assert!(contains(*p), "{:?}", p);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn new() {
+        let c = RegionCoverer::new(10, 5, 8);
+        assert_eq!(10, c.min_level());
+        assert_eq!(10, c.max_level());
+        assert_eq!(8, c.max_cells());
+
+        let c = RegionCoverer::new(40, 50, 8);
+        assert_eq!(30, c.min_level());
+        assert_eq!(30, c.max_level());
+
+        let d = RegionCoverer::default();
+        assert_eq!(0, d.min_level());
+        assert_eq!(30, d.max_level());
+        assert_eq!(8, d.max_cells());
+    }
+
+    #[test]
+    fn cap_covering() {
+        for cap in caps() {
+            assert_covering(&cap, |p| cap.contains_position(p));
+        }
+    }
+
+    #[test]
+    fn rectangle_covering() {
+        let r = Rectangle::from_nesw(
+            Angle::from_degrees(60.0),
+            Angle::from_degrees(-170.0),
+            Angle::from_degrees(10.0),
+            Angle::from_degrees(170.0),
+        );
+        assert_covering(&r, |p| r.contains_position(LatLong::from_nvector(p)));
+    }
+
+    #[test]
+    fn loop_covering() {
+        let l = Loop::new(&[
+            NVector::from_lat_long_degrees(-10.0, -10.0),
+            NVector::from_lat_long_degrees(-10.0, 20.0),
+            NVector::from_lat_long_degrees(15.0, 20.0),
+            NVector::from_lat_long_degrees(5.0, 5.0),
+            NVector::from_lat_long_degrees(15.0, -10.0),
+        ]);
+        assert_covering(&l, |p| l.contains_position(p));
+    }
+
+    #[test]
+    fn loop_around_pole() {
+        let l = Loop::new(&[
+            NVector::from_lat_long_degrees(80.0, 0.0),
+            NVector::from_lat_long_degrees(80.0, 90.0),
+            NVector::from_lat_long_degrees(80.0, 180.0),
+            NVector::from_lat_long_degrees(80.0, -90.0),
+        ]);
+        assert_covering(&l, |p| l.contains_position(p));
+    }
+
+    #[test]
+    fn full_and_empty() {
+        let covering = RegionCoverer::default().covering(&Cap::FULL);
+        assert_eq!(6, covering.len());
+        assert!(covering.iter().all(|c| c.is_face()));
+        assert_eq!(
+            covering,
+            RegionCoverer::default().interior_covering(&Cap::FULL)
+        );
+
+        assert!(RegionCoverer::default().covering(&Cap::EMPTY).is_empty());
+        assert!(RegionCoverer::default().covering(&Loop::EMPTY).is_empty());
+        assert!(RegionCoverer::default()
+            .interior_covering(&Rectangle::EMPTY)
+            .is_empty());
+    }
+
+    #[test]
+    fn min_level_forces_subdivision() {
+        let coverer = RegionCoverer::new(6, 6, 1);
+        let cap = Cap::from_centre_and_radius(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            Angle::from_degrees(10.0),
+        );
+        let covering = coverer.covering(&cap);
+        assert!(covering.len() > 1);
+        assert!(covering.iter().all(|c| c.level() == 6));
+    }
+
+    #[test]
+    fn covering_of_cell() {
+        // a cell is exactly covered by itself.
+        let cell = CellId::from_nvector_at_level(NVector::from_lat_long_degrees(40.0, 40.0), 8);
+        let l = cell.to_loop();
+        let covering = RegionCoverer::new(0, 12, 8).covering(&l);
+        assert!(covering.contains_cell(cell));
+        assert!(covering.len() <= 8);
+    }
+}
@@ -0,0 +1,665 @@
+// Simplification of chains of vertices using the Douglas-Peucker and the Visvalingam-Whyatt algorithms adapted to
+// minor arcs.
+//
+// Douglas-Peucker: the distance between a vertex and the minor arc joining the retained vertices surrounding it is
+// the cross-track distance clamped to the minor arc - see MinorArc::distance_to.
+//
+// Visvalingam-Whyatt: the effective area of a vertex is the spherical excess of the triangle formed by the vertex and
+// its 2 neighbours; vertices are removed in increasing order of effective area.
+//
+// When simplifying closed chains (loops), both algorithms preserve the topology: a vertex is retained (or re-inserted
+// in the case of Douglas-Peucker) whenever its removal would make an edge intersect another non-adjacent edge. In the
+// case of Visvalingam-Whyatt, the candidate edge is only tested against the edges whose bounding box overlaps its own
+// bounding box, found using a uniform grid over the 3D space containing the unit sphere. The same grid is used to find
+// the self-intersections of open chains (polylines).
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap},
+};
+
+use crate::{Angle, NVector};
+
+use super::{ChordLength, MinorArc};
+
+/// Simplifies the given open chain using the Douglas-Peucker algorithm: the first and last vertices are always
+/// retained.
+pub(crate) fn douglas_peucker(vs: &[NVector], tolerance: Angle) -> Vec<NVector> {
+    let len = vs.len();
+    if len < 3 {
+        return vs.to_vec();
+    }
+    let mut keep = vec![false; len];
+    keep[0] = true;
+    keep[len - 1] = true;
+    mark(
+        vs,
+        0,
+        len - 1,
+        ChordLength::from_angle(tolerance),
+        &mut keep,
+    );
+    retained(vs, &keep)
+}
+
+/// Simplifies the given closed chain - first vertex implicitly connected to the last - using the Douglas-Peucker
+/// algorithm. At least 3 vertices are retained and retained edges do not intersect unless the given chain
+/// intersects itself.
+pub(crate) fn douglas_peucker_closed(vs: &[NVector], tolerance: Angle) -> Vec<NVector> {
+    let len = vs.len();
+    if len <= 3 {
+        return vs.to_vec();
+    }
+    // the chain is opened at the first vertex and split at the vertex which is the farthest from it.
+    let mut closed = vs.to_vec();
+    closed.push(vs[0]);
+    let split = (1..len)
+        .max_by(|a, b| ChordLength::new(vs[0], vs[*a]).cmp(&ChordLength::new(vs[0], vs[*b])))
+        .unwrap_or(1);
+
+    let mut keep = vec![false; len + 1];
+    keep[0] = true;
+    keep[split] = true;
+    keep[len] = true;
+    let tolerance = ChordLength::from_angle(tolerance);
+    mark(&closed, 0, split, tolerance, &mut keep);
+    mark(&closed, split, len, tolerance, &mut keep);
+
+    loop {
+        let indices: Vec<usize> = (0..=len).filter(|i| keep[*i]).collect();
+        // number of retained edges.
+        let m = indices.len() - 1;
+        let span = |t: usize| (indices[t], indices[t + 1]);
+        let mut insert: Option<usize> = None;
+        if m < 3 {
+            insert = (0..m)
+                .filter_map(|t| farthest(&closed, span(t).0, span(t).1))
+                .max_by(|a, b| a.1.cmp(&b.1))
+                .map(|(i, _)| i);
+        } else {
+            let chain: Vec<NVector> = indices[..m].iter().map(|i| closed[*i]).collect();
+            if let Some((t, u)) = first_crossing(&chain) {
+                let ((a, b), (c, d)) = (span(t), span(u));
+                // re-insert a vertex in the edge which simplified the most vertices.
+                let o = if b - a >= d - c { (a, b) } else { (c, d) };
+                // an edge crossing another non-adjacent edge spans at least 1 removed vertex.
+                insert = farthest(&closed, o.0, o.1)
+                    .or_else(|| farthest(&closed, a, b))
+                    .or_else(|| farthest(&closed, c, d))
+                    .map(|(i, _)| i);
+            }
+        }
+        match insert {
+            Some(i) => keep[i] = true,
+            None => break,
+        }
+    }
+    keep.pop();
+    retained(vs, &keep)
+}
+
+/// Simplifies the given chain using the Visvalingam-Whyatt algorithm: vertices whose effective area is less than the
+/// given minimum area are removed. If the chain is open, the first and last vertices are always retained; if it is
+/// closed at least 3 vertices are retained and no vertex is removed if that would make 2 non-adjacent edges intersect.
+pub(crate) fn visvalingam_whyatt(vs: &[NVector], min_area: Angle, closed: bool) -> Vec<NVector> {
+    let len = vs.len();
+    let min_len = if closed { 3 } else { 2 };
+    if len <= min_len {
+        return vs.to_vec();
+    }
+    let min_area = min_area.as_radians();
+    let mut prev: Vec<usize> = (0..len).map(|i| (i + len - 1) % len).collect();
+    let mut next: Vec<usize> = (0..len).map(|i| (i + 1) % len).collect();
+    let mut removed = vec![false; len];
+    let mut areas = vec![f64::INFINITY; len];
+    let mut heap = BinaryHeap::with_capacity(len);
+
+    let candidates = if closed { 0..len } else { 1..(len - 1) };
+    for i in candidates {
+        areas[i] = triangle_area(vs[prev[i]], vs[i], vs[next[i]]);
+        heap.push(Entry {
+            area: areas[i],
+            index: i,
+        });
+    }
+
+    let mut remaining = len;
+    let mut grid = if closed {
+        Some(EdgeGrid::new(vs, &next, &removed, remaining))
+    } else {
+        None
+    };
+    while let Some(Entry { area, index: i }) = heap.pop() {
+        if removed[i] || area != areas[i] {
+            // stale entry.
+            continue;
+        }
+        if area >= min_area || remaining == min_len {
+            break;
+        }
+        let (p, n) = (prev[i], next[i]);
+        if let Some(g) = grid.as_mut() {
+            if g.crossing(vs, &next, &prev, &removed, p, n).is_some() {
+                // the vertex is re-considered once any of its neighbours is removed.
+                areas[i] = f64::INFINITY;
+                continue;
+            }
+        }
+        removed[i] = true;
+        remaining -= 1;
+        next[p] = n;
+        prev[n] = p;
+        if let Some(g) = grid.as_mut() {
+            if 2 * remaining <= g.nb_edges {
+                // edges get longer as vertices are removed: cells are resized to the new mean edge length.
+                *g = EdgeGrid::new(vs, &next, &removed, remaining);
+            } else {
+                g.insert(p, vs[p], vs[n]);
+            }
+        }
+        for j in [p, n] {
+            if closed || (j != 0 && j != len - 1) {
+                // the effective area of a neighbour cannot be less than the area of the removed vertex.
+                areas[j] = triangle_area(vs[prev[j]], vs[j], vs[next[j]]).max(area);
+                heap.push(Entry {
+                    area: areas[j],
+                    index: j,
+                });
+            }
+        }
+    }
+    vs.iter()
+        .zip(removed.iter())
+        .filter(|(_, r)| !**r)
+        .map(|(v, _)| *v)
+        .collect()
+}
+
+/// Determines whether no pair of non-adjacent edges of the given closed chain intersect.
+pub(crate) fn no_crossing_edges(vs: &[NVector]) -> bool {
+    first_crossing(vs).is_none()
+}
+
+/// Returns the indices of the start vertices of 2 non-adjacent edges of the given closed chain that intersect, if
+/// any.
+fn first_crossing(vs: &[NVector]) -> Option<(usize, usize)> {
+    let len = vs.len();
+    if len <= 3 {
+        return None;
+    }
+    let prev: Vec<usize> = (0..len).map(|i| (i + len - 1) % len).collect();
+    let next: Vec<usize> = (0..len).map(|i| (i + 1) % len).collect();
+    let removed = vec![false; len];
+    let mut grid = EdgeGrid::new(vs, &next, &removed, len);
+    // the edges adjacent to the edge from i to next[i] are excluded.
+    (0..len).find_map(|i| {
+        grid.crossing(vs, &next, &prev, &removed, i, next[i])
+            .map(|j| (i.min(j), i.max(j)))
+    })
+}
+
+/// Returns, for each edge of the given open chain - identified by the index of its start vertex -, the indices of the
+/// following non-consecutive edges whose bounding box overlaps its own bounding box, in ascending order: only these
+/// edges may intersect it.
+pub(crate) fn crossing_candidates(vs: &[NVector]) -> Vec<Vec<usize>> {
+    let len = vs.len();
+    if len < 2 {
+        return Vec::new();
+    }
+    let next: Vec<usize> = (0..len).map(|i| (i + 1) % len).collect();
+    // the last vertex does not start an edge.
+    let removed: Vec<bool> = (0..len).map(|i| i == len - 1).collect();
+    let mut grid = EdgeGrid::new(vs, &next, &removed, len - 1);
+    (0..len - 1)
+        .map(|i| {
+            let mut cs = Vec::new();
+            grid.find(vs[i], vs[i + 1], |j| {
+                if j > i + 1 {
+                    cs.push(j);
+                }
+                false
+            });
+            cs.sort_unstable();
+            cs
+        })
+        .collect()
+}
+
+/// Marks the vertices between `from` and `to` that must be retained by the Douglas-Peucker algorithm.
+fn mark(vs: &[NVector], from: usize, to: usize, tolerance: ChordLength, keep: &mut [bool]) {
+    let mut stack = vec![(from, to)];
+    while let Some((a, b)) = stack.pop() {
+        if let Some((i, d)) = farthest(vs, a, b) {
+            if d > tolerance {
+                keep[i] = true;
+                stack.push((a, i));
+                stack.push((i, b));
+            }
+        }
+    }
+}
+
+/// Returns the index of the vertex strictly between `from` and `to` which is the farthest from the minor arc joining
+/// the vertices at `from` and `to`, and its distance to that minor arc.
+fn farthest(vs: &[NVector], from: usize, to: usize) -> Option<(usize, ChordLength)> {
+    let ma = MinorArc::new(vs[from], vs[to]);
+    ((from + 1)..to)
+        .map(|i| (i, ma.distance_to(vs[i])))
+        .max_by(|a, b| a.1.cmp(&b.1))
+}
+
+/// Returns the retained vertices.
+fn retained(vs: &[NVector], keep: &[bool]) -> Vec<NVector> {
+    vs.iter()
+        .zip(keep.iter())
+        .filter(|(_, k)| **k)
+        .map(|(v, _)| *v)
+        .collect()
+}
+
+/// Maximum number of cells in which an edge is registered: longer edges are tested by every query.
+const MAX_CELLS: f64 = 64.0;
+
+/// A uniform grid over the 3D space containing the unit sphere: each edge of a chain - identified by the index of its
+/// start vertex - is registered in every cell overlapped by its bounding box. Entries of edges that have since
+/// been replaced are not removed, they only yield false candidates which are discarded by the intersection test.
+struct EdgeGrid {
+    cell_size: f64,
+    nb_edges: usize,
+    cells: HashMap<(i64, i64, i64), Vec<usize>>,
+    // edges overlapping more than MAX_CELLS cells, tested by every query.
+    large: Vec<usize>,
+    // index of the last query for which each edge has been tested, to test each edge at most once per query.
+    visited: Vec<usize>,
+    query: usize,
+}
+
+impl EdgeGrid {
+    /// Creates the grid of the given remaining edges of the closed chain described by the given links: the size of
+    /// the cells is twice the mean length of the edges.
+    fn new(vs: &[NVector], next: &[usize], removed: &[bool], remaining: usize) -> Self {
+        let edges = || (0..vs.len()).filter(|i| !removed[*i]);
+        let total: f64 = edges()
+            .map(|i| (vs[next[i]].as_vec3() - vs[i].as_vec3()).norm())
+            .sum();
+        let cell_size = (2.0 * total / remaining as f64).max(1e-9);
+        let mut grid = Self {
+            cell_size,
+            nb_edges: remaining,
+            cells: HashMap::with_capacity(2 * remaining),
+            large: Vec::new(),
+            visited: vec![0; vs.len()],
+            query: 0,
+        };
+        for i in edges() {
+            grid.insert(i, vs[i], vs[next[i]]);
+        }
+        grid
+    }
+
+    /// Registers the edge starting at the given index from `a` to `b`.
+    fn insert(&mut self, index: usize, a: NVector, b: NVector) {
+        let (lo, hi) = self.cell_range(a, b);
+        if cell_count(lo, hi) > MAX_CELLS {
+            self.large.push(index);
+            return;
+        }
+        for x in lo.0..=hi.0 {
+            for y in lo.1..=hi.1 {
+                for z in lo.2..=hi.2 {
+                    self.cells.entry((x, y, z)).or_default().push(index);
+                }
+            }
+        }
+    }
+
+    /// Returns the index of the start vertex of an edge of the closed chain described by the given links which
+    /// intersects the minor arc from `vs[p]` to `vs[n]`, excluding the edges adjacent to `p` and `n`.
+    fn crossing(
+        &mut self,
+        vs: &[NVector],
+        next: &[usize],
+        prev: &[usize],
+        removed: &[bool],
+        p: usize,
+        n: usize,
+    ) -> Option<usize> {
+        let ma = MinorArc::new(vs[p], vs[n]);
+        self.find(vs[p], vs[n], |j| {
+            !removed[j]
+                && j != prev[p]
+                && j != p
+                && j != next[p]
+                && j != n
+                && ma.intersection(MinorArc::new(vs[j], vs[next[j]])).is_some()
+        })
+    }
+
+    /// Returns the index of the first registered edge whose bounding box may overlap the bounding box of the minor arc
+    /// from `a` to `b` and which satisfies the given predicate; each edge is tested at most once.
+    fn find<F>(&mut self, a: NVector, b: NVector, mut f: F) -> Option<usize>
+    where
+        F: FnMut(usize) -> bool,
+    {
+        self.query += 1;
+        let query = self.query;
+        let (lo, hi) = self.cell_range(a, b);
+        let visited = &mut self.visited;
+        let mut test = |j: usize| {
+            if visited[j] == query {
+                false
+            } else {
+                visited[j] = query;
+                f(j)
+            }
+        };
+        if let Some(j) = self.large.iter().copied().find(|j| test(*j)) {
+            return Some(j);
+        }
+        if cell_count(lo, hi) > self.cells.len() as f64 {
+            // fewer cells are occupied than overlapped by the bounding box: the edges of all the occupied cells
+            // within the bounding box are tested in ascending order of index.
+            let mut es: Vec<usize> = self
+                .cells
+                .iter()
+                .filter(|(c, _)| {
+                    (lo.0..=hi.0).contains(&c.0)
+                        && (lo.1..=hi.1).contains(&c.1)
+                        && (lo.2..=hi.2).contains(&c.2)
+                })
+                .flat_map(|(_, es)| es.iter().copied())
+                .collect();
+            es.sort_unstable();
+            return es.into_iter().find(|j| test(*j));
+        }
+        for x in lo.0..=hi.0 {
+            for y in lo.1..=hi.1 {
+                for z in lo.2..=hi.2 {
+                    let Some(es) = self.cells.get(&(x, y, z)) else {
+                        continue;
+                    };
+                    if let Some(j) = es.iter().copied().find(|j| test(*j)) {
+                        return Some(j);
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns the lowest and highest cells overlapped by the bounding box of the minor arc from `a` to `b`.
+    fn cell_range(&self, a: NVector, b: NVector) -> ((i64, i64, i64), (i64, i64, i64)) {
+        let (va, vb) = (a.as_vec3(), b.as_vec3());
+        // the minor arc is within the sagitta of its chord.
+        let chord = (vb - va).norm();
+        let sagitta = 1.0 - (1.0 - 0.25 * chord * chord).max(0.0).sqrt() + 1e-12;
+        let cell = |c: f64| (c / self.cell_size).floor() as i64;
+        let lo = |c1: f64, c2: f64| cell(c1.min(c2) - sagitta);
+        let hi = |c1: f64, c2: f64| cell(c1.max(c2) + sagitta);
+        (
+            (lo(va.x(), vb.x()), lo(va.y(), vb.y()), lo(va.z(), vb.z())),
+            (hi(va.x(), vb.x()), hi(va.y(), vb.y()), hi(va.z(), vb.z())),
+        )
+    }
+}
+
+/// Returns the number of cells between the given lowest and highest cells.
+fn cell_count(lo: (i64, i64, i64), hi: (i64, i64, i64)) -> f64 {
+    ((hi.0 - lo.0 + 1) as f64) * ((hi.1 - lo.1 + 1) as f64) * ((hi.2 - lo.2 + 1) as f64)
+}
+
+/// Returns the spherical excess of the triangle formed by the 3 given positions (in radians).
+fn triangle_area(a: NVector, b: NVector, c: NVector) -> f64 {
+    // tan(E/2) = |a . (b x c)| / (1 + a . b + b . c + c . a).
+    let (a, b, c) = (a.as_vec3(), b.as_vec3(), c.as_vec3());
+    let triple = a.dot_prod(b.cross_prod(c)).abs();
+    let denom = 1.0 + a.dot_prod(b) + b.dot_prod(c) + c.dot_prod(a);
+    2.0 * triple.atan2(denom)
+}
+
+/// A vertex and its effective area, ordered so that the [BinaryHeap] pops the smallest area first.
+#[derive(Clone, Copy, Debug)]
+struct Entry {
+    area: f64,
+    index: usize,
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Entry {}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Entry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .area
+            .total_cmp(&self.area)
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        spherical::{ChordLength, Loop, MinorArc, Polyline},
+        Angle, NVector,
+    };
+
+    use super::{
+        crossing_candidates, douglas_peucker, douglas_peucker_closed, no_crossing_edges,
+        triangle_area, visvalingam_whyatt,
+    };
+
+    fn zigzag() -> Vec<NVector> {
+        (0..100)
+            .map(|i| {
+                let lat = if i % 2 == 0 {
+                    0.0
+                } else {
+                    0.001 * (1 + i % 7) as f64
+                };
+                NVector::from_lat_long_degrees(lat, i as f64 * 0.01)
+            })
+            .collect()
+    }
+
+    // double walled spiral: naive simplification makes edges cross the walls.
+    fn spiral() -> Vec<NVector> {
+        let outer: Vec<(f64, f64)> = (0..200)
+            .map(|i| (i as f64 * 0.3, 1.0 + 0.05 * i as f64))
+            .collect();
+        let mut vs: Vec<NVector> = outer
+            .iter()
+            .map(|(a, r)| NVector::from_lat_long_degrees(r * a.sin(), r * a.cos()))
+            .collect();
+        for (a, r) in outer.iter().rev() {
+            let r = r - 0.02;
+            vs.push(NVector::from_lat_long_degrees(r * a.sin(), r * a.cos()));
+        }
+        vs
+    }
+
+    // wiggly loop of n vertices around (0, 0).
+    fn wiggly(n: usize) -> Vec<NVector> {
+        (0..n)
+            .map(|i| {
+                let t = i as f64 / n as f64 * std::f64::consts::TAU;
+                let r = 1.0 + 0.05 * (t * 97.0).sin() + 0.02 * (t * 1013.0).cos();
+                NVector::from_lat_long_degrees(r * t.sin(), r * t.cos())
+            })
+            .collect()
+    }
+
+    fn assert_within_tolerance(original: &[NVector], simplified: &[NVector], tolerance: Angle) {
+        let pl = Polyline::new(simplified);
+        let t = ChordLength::from_angle(tolerance);
+        for v in original {
+            let d = pl.iter_edges().map(|e| e.distance_to(*v)).min().unwrap();
+            assert!(d <= t);
+        }
+    }
+
+    #[test]
+    fn douglas_peucker_open() {
+        let vs = zigzag();
+        assert_eq!(vs, douglas_peucker(&vs, Angle::ZERO));
+        assert_eq!(
+            vec![vs[0], vs[99]],
+            douglas_peucker(&vs, Angle::from_degrees(1.0))
+        );
+        let tolerance = Angle::from_degrees(0.002);
+        let s = douglas_peucker(&vs, tolerance);
+        assert!(s.len() < vs.len());
+        assert_eq!(vs[0], s[0]);
+        assert_eq!(vs[99], s[s.len() - 1]);
+        assert_within_tolerance(&vs, &s, tolerance);
+        assert_eq!(vec![vs[0]], douglas_peucker(&vs[0..1], tolerance));
+    }
+
+    #[test]
+    fn douglas_peucker_closed_preserves_topology() {
+        let vs = spiral();
+        assert!(Loop::new(&vs).is_simple());
+        for d in [0.1, 0.5, 1.0, 5.0] {
+            let s = douglas_peucker_closed(&vs, Angle::from_degrees(d));
+            assert!(s.len() >= 3);
+            assert!(s.len() < vs.len());
+            assert!(Loop::new(&s).is_simple(), "tolerance {}", d);
+        }
+    }
+
+    #[test]
+    fn douglas_peucker_closed_retains_triangle() {
+        let vs = vec![
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(0.0, 5.0),
+            NVector::from_lat_long_degrees(0.0, 10.0),
+            NVector::from_lat_long_degrees(10.0, 10.0),
+            NVector::from_lat_long_degrees(10.0, 0.0),
+        ];
+        let s = douglas_peucker_closed(&vs, Angle::from_degrees(45.0));
+        assert_eq!(3, s.len());
+        assert!(!Loop::new(&s).is_empty());
+        assert_eq!(
+            vec![vs[0], vs[2], vs[3], vs[4]],
+            douglas_peucker_closed(&vs, Angle::from_degrees(0.1))
+        );
+    }
+
+    #[test]
+    fn visvalingam_whyatt_open() {
+        let vs = zigzag();
+        assert_eq!(vs, visvalingam_whyatt(&vs, Angle::ZERO, false));
+        assert_eq!(
+            vec![vs[0], vs[99]],
+            visvalingam_whyatt(&vs, Angle::from_radians(1.0), false)
+        );
+        let s = visvalingam_whyatt(&vs, Angle::from_radians(1e-8), false);
+        assert!(s.len() > 2 && s.len() < vs.len());
+        assert_eq!(vs[0], s[0]);
+        assert_eq!(vs[99], s[s.len() - 1]);
+    }
+
+    #[test]
+    fn visvalingam_whyatt_closed_preserves_topology() {
+        let vs = spiral();
+        for a in [1e-6, 1e-5, 1e-2, 1.0] {
+            let s = visvalingam_whyatt(&vs, Angle::from_radians(a), true);
+            assert!(s.len() >= 3);
+            assert!(s.len() < vs.len());
+            assert!(Loop::new(&s).is_simple(), "min area {}", a);
+        }
+    }
+
+    #[test]
+    fn closed_many_vertices() {
+        let vs = wiggly(50_000);
+        assert!(no_crossing_edges(&vs));
+        let s = douglas_peucker_closed(&vs, Angle::from_radians(1e-4));
+        assert!(s.len() < 2_000);
+        assert!(Loop::new(&s).is_simple());
+        let s = visvalingam_whyatt(&vs, Angle::from_radians(1e-7), true);
+        assert!(s.len() < 2_000);
+        assert!(Loop::new(&s).is_simple());
+    }
+
+    #[test]
+    fn no_crossing_edges_is_simple() {
+        let vs = spiral();
+        assert!(no_crossing_edges(&vs));
+        let mut vs = wiggly(1_000);
+        assert!(no_crossing_edges(&vs));
+        // swapping 2 vertices makes the edges around them cross.
+        vs.swap(100, 101);
+        assert!(!Loop::new(&vs).is_simple());
+        assert!(!no_crossing_edges(&vs));
+        let square = [
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(0.0, 1.0),
+            NVector::from_lat_long_degrees(1.0, 0.0),
+            NVector::from_lat_long_degrees(1.0, 1.0),
+        ];
+        assert!(!no_crossing_edges(&square));
+    }
+
+    #[test]
+    fn no_crossing_edges_long_edges() {
+        // a meandering track closed by 3 edges much longer than the others.
+        let mut vs: Vec<NVector> = (0..20_000)
+            .map(|i| {
+                let i = i as f64;
+                NVector::from_lat_long_degrees((i / 10.0).sin() / 100.0, i / 1_000.0)
+            })
+            .collect();
+        vs.push(NVector::from_lat_long_degrees(5.0, 19.999));
+        vs.push(NVector::from_lat_long_degrees(5.0, 0.0));
+        assert!(no_crossing_edges(&vs));
+        let len = vs.len();
+        vs.swap(len - 2, len - 1);
+        assert!(!no_crossing_edges(&vs));
+    }
+
+    #[test]
+    fn crossing_candidates_include_crossings() {
+        // a star: every edge crosses several others.
+        let vs: Vec<NVector> = (0..50)
+            .map(|i| {
+                let a = (i as f64 * 137.5).to_radians();
+                NVector::from_lat_long_degrees(a.sin() * (1.0 + i as f64 / 50.0), a.cos())
+            })
+            .collect();
+        let candidates = crossing_candidates(&vs);
+        assert_eq!(vs.len() - 1, candidates.len());
+        let mut crossings = 0;
+        for i in 0..vs.len() - 1 {
+            let e1 = MinorArc::new(vs[i], vs[i + 1]);
+            for j in (i + 2)..vs.len() - 1 {
+                if e1.intersection(MinorArc::new(vs[j], vs[j + 1])).is_some() {
+                    crossings += 1;
+                    assert!(candidates[i].contains(&j));
+                }
+            }
+        }
+        assert!(crossings > 100);
+    }
+
+    #[test]
+    fn triangle_area_is_spherical_excess() {
+        let a = NVector::from_lat_long_degrees(0.0, 0.0);
+        let b = NVector::from_lat_long_degrees(0.0, 10.0);
+        let c = NVector::from_lat_long_degrees(10.0, 5.0);
+        let expected = Loop::new(&[a, b, c]).spherical_excess().as_radians();
+        assert!((expected - triangle_area(a, b, c)).abs() < 1e-12);
+        assert!((expected - triangle_area(c, b, a)).abs() < 1e-12);
+        assert_eq!(0.0, triangle_area(a, b, b));
+    }
+}
@@ -1,8 +1,18 @@
 use std::{cmp::Ordering, f64::consts::PI};
 
-use crate::{numbers::eq, numbers::eq_zero, Angle, NVector, Vec3};
+use crate::{numbers::eq, numbers::eq_zero, numbers::gte, Angle, Length, NVector, Vec3};
 
-use super::{base::angle_radians_between, ChordLength, MinorArc, Rectangle, Sphere};
+use super::{
+    base::angle_radians_between,
+    buffer::{buffer_radius, loop_buffer},
+    clipping::{clip, Operation},
+    simplification::{douglas_peucker_closed, no_crossing_edges, visvalingam_whyatt},
+    Cap, ChordLength, MinorArc, Polygon, Rectangle, Region, Sphere,
+};
+
+/// distance (sine of the angle) below which a vertex is considered on the great circle of its adjacent vertices when
+/// searching for positions inside a loop.
+const COLLINEAR_EPS: f64 = 1e-12;
 
 /// A single chain of vertices where the first vertex is implicitly connected to the last.
 ///
@@ -104,6 +114,95 @@ impl Loop {
         }
     }
 
+    /// Computes the convex hull of the given positions: i.e. the smallest convex loop containing all the given
+    /// positions. The vertices of the returned loop are a subset of the given positions; positions that lie on an
+    /// edge of the hull are not vertices of the hull.
+    ///
+    /// The convex hull is only well defined if the given positions fit in an open hemisphere: an
+    /// [empty](crate::spherical::Loop::EMPTY) loop is returned otherwise, as well as when less than 3 distinct
+    /// positions are given or when all the given positions lie on a single great circle.
+    ///
+    /// The positions are projected onto the plane tangent to the sphere at the centre of their
+    /// [minimal enclosing cap](crate::spherical::Cap::minimal_enclosing) using the gnomonic projection - which maps
+    /// great circles to straight lines - and the hull is computed using Andrew's monotone chain algorithm.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, NVector};
+    /// use jord::spherical::Loop;
+    ///
+    /// let ps = vec![
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(1.0, 1.0),
+    ///     NVector::from_lat_long_degrees(0.0, 2.0),
+    ///     NVector::from_lat_long_degrees(2.0, 2.0),
+    ///     NVector::from_lat_long_degrees(1.5, 0.5),
+    ///     NVector::from_lat_long_degrees(2.0, 0.0),
+    /// ];
+    ///
+    /// let hull = Loop::convex_hull(&ps);
+    /// assert!(hull.is_convex());
+    ///
+    /// // vertices in clockwise order.
+    /// assert_eq!(
+    ///     vec![
+    ///         LatLong::from_degrees(0.0, 0.0),
+    ///         LatLong::from_degrees(2.0, 0.0),
+    ///         LatLong::from_degrees(2.0, 2.0),
+    ///         LatLong::from_degrees(0.0, 2.0),
+    ///     ],
+    ///     hull.iter_vertices()
+    ///         .map(|v| LatLong::from_nvector(*v).round_d7())
+    ///         .collect::<Vec<_>>()
+    /// );
+    /// ```
+    pub fn convex_hull(ps: &[NVector]) -> Self {
+        let centre = match Cap::minimal_enclosing(ps) {
+            Ok(c) if !c.is_empty() => c.centre().as_vec3(),
+            _ => return Self::EMPTY,
+        };
+        let u = centre.orthogonal().unit();
+        let v = centre.cross_prod(u);
+        // gnomonic projection: all positions are within 90 degrees of the centre, so p . centre > 0.
+        let mut projected: Vec<(f64, f64, NVector)> = ps
+            .iter()
+            .map(|p| {
+                let pv = p.as_vec3();
+                let d = pv.dot_prod(centre);
+                (pv.dot_prod(u) / d, pv.dot_prod(v) / d, *p)
+            })
+            .collect();
+        projected.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+
+        // lower and upper chains: collinear positions (side = 0) are discarded.
+        let mut hull: Vec<NVector> = Vec::with_capacity(projected.len() + 1);
+        for (_, _, p) in projected.iter() {
+            while hull.len() >= 2
+                && Sphere::side(hull[hull.len() - 2], hull[hull.len() - 1], *p) <= 0
+            {
+                hull.pop();
+            }
+            hull.push(*p);
+        }
+        let lower_len = hull.len() + 1;
+        for (_, _, p) in projected.iter().rev().skip(1) {
+            while hull.len() >= lower_len
+                && Sphere::side(hull[hull.len() - 2], hull[hull.len() - 1], *p) <= 0
+            {
+                hull.pop();
+            }
+            hull.push(*p);
+        }
+        // the last position is the first position.
+        hull.pop();
+        if hull.len() < 3 {
+            Self::EMPTY
+        } else {
+            Self::new(&hull)
+        }
+    }
+
     /// Determines whether this loop is convex.
     ///
     /// This function always returns false for [empty](crate::spherical::Loop::is_empty) loops, undefined for [non simple](crate::spherical::Loop::is_simple) loops.
@@ -202,11 +301,8 @@ impl Loop {
     /// assert!(l4.is_simple());
     /// ```
     pub fn is_simple(&self) -> bool {
-        let v_len = self.vertices.len();
-        for i in 0..v_len {
-            if !Sphere::is_great_circle(self.vertices[i].0, self.vertices[(i + 1) % v_len].0) {
-                return false;
-            }
+        if !self.has_valid_edges() {
+            return false;
         }
         let es_len = self.edges.len();
         if es_len <= 3 {
@@ -226,6 +322,14 @@ impl Loop {
         }
     }
 
+    /// Determines whether all edges of this loop are valid minor arcs: consecutive vertices are neither
+    /// coincidental nor the antipode of one another.
+    fn has_valid_edges(&self) -> bool {
+        let v_len = self.vertices.len();
+        (0..v_len)
+            .all(|i| Sphere::is_great_circle(self.vertices[i].0, self.vertices[(i + 1) % v_len].0))
+    }
+
     /// Determines whether this loop is empty. An loop is empty if less than 3 non-collinear vertices were supplied at construction.
     ///
     /// # Examples
@@ -306,6 +410,40 @@ impl Loop {
         self.edges.iter()
     }
 
+    /// Calculates a [cap](crate::spherical::Cap) containing this loop. If the vertices of this loop fit in an open
+    /// hemisphere the returned cap is the [minimal enclosing cap](crate::spherical::Cap::minimal_enclosing) of the
+    /// vertices, otherwise the returned cap is derived from the [bound](crate::spherical::Loop::bound) of this loop.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong, NVector};
+    /// use jord::spherical::Loop;
+    ///
+    /// let l = Loop::new(&vec![
+    ///     NVector::from_lat_long_degrees(0.0, -10.0),
+    ///     NVector::from_lat_long_degrees(-5.0, 0.0),
+    ///     NVector::from_lat_long_degrees(0.0, 10.0),
+    ///     NVector::from_lat_long_degrees(5.0, 0.0),
+    /// ]);
+    ///
+    /// let c = l.cap_bound();
+    /// assert_eq!(LatLong::from_degrees(0.0, 0.0), LatLong::from_nvector(c.centre()).round_d7());
+    /// assert_eq!(Angle::from_degrees(10.0), c.radius().round_d7());
+    /// ```
+    pub fn cap_bound(&self) -> Cap {
+        if self.is_empty() {
+            return Cap::EMPTY;
+        }
+        let vs: Vec<NVector> = self.iter_vertices().copied().collect();
+        match Cap::minimal_enclosing(&vs) {
+            // the cap is convex and contains all vertices, therefore it contains all edges; it contains the loop
+            // unless the interior of the loop is the complement of the region enclosed by the edges.
+            Ok(c) if !self.contains_position(c.centre().antipode()) => c,
+            _ => Region::cap_bound(&self.bound()),
+        }
+    }
+
     /// Calculates the [minimum bounding rectangle](crate::spherical::Rectangle) of this loop. The returned bound is
     /// conservative in that if this loop [contains](crate::spherical::Loop::contains_position) the position `P`,
     /// then the bound also [contains](crate::spherical::Rectangle::contains_position) `P`.
@@ -394,43 +532,32 @@ impl Loop {
                     return true;
                 }
                 let i = if a.is_antipode_of(p) { b } else { a };
-                let ma = MinorArc::new(i, p);
+                let n = MinorArc::new(i, p).normal();
+                let iv = i.as_vec3();
+                let pv = p.as_vec3();
+                // count the crossings of the boundary with the minor arc from i to p: vertices on the great circle of
+                // the minor arc are considered left of it, so that the boundary crossing the minor arc at a vertex is
+                // counted once, and the boundary touching the minor arc at a vertex is counted twice or never.
                 let mut count_i: usize = 0;
-                // if ma intersect e on either start or end, then the same
-                // intersection will be detected with next edge:
-                // assuming the following edges: [e1, e2, e3, e4]
-                // - intersection with e2 could be the same as with e1
-                // - intersection with e3 could be the same as with e2
-                // - intersection with e4 could be the same as with e3 or e1
-                let mut first_i_vec3 = Vec3::ZERO;
-                let mut prev_i_vec3 = Vec3::ZERO;
-                let n = self.edges.len();
-                for (i, e) in self.edges.iter().enumerate() {
-                    if let Some(iv) = ma.intersection(*e) {
-                        if i == 0 {
-                            count_i += 1;
-                            first_i_vec3 = iv.as_vec3();
-                        } else if i == n - 1 {
-                            let iv_vec3 = iv.as_vec3();
-                            // last edge, check diff with first and prev.
-                            if vec3_eq(first_i_vec3, iv_vec3) || vec3_eq(prev_i_vec3, iv_vec3) {
-                                // skip this intersection (already found on previous or first edge).
-                            } else {
-                                count_i += 1;
-                            }
-                        } else {
-                            let iv_vec3 = iv.as_vec3();
-                            // check diff with prev.
-                            if vec3_eq(prev_i_vec3, iv_vec3) {
-                                // skip this intersection (already found on previous or first edge).
-                            } else {
-                                count_i += 1;
-                            }
-                            prev_i_vec3 = iv_vec3;
-                        }
-                    } else {
-                        // no intersection reset prev_i_vec3
-                        prev_i_vec3 = Vec3::ZERO;
+                for e in self.edges.iter() {
+                    if e.start() == p {
+                        return false;
+                    }
+                    let s = e.start().as_vec3();
+                    let t = e.end().as_vec3();
+                    let sn = s.dot_prod(n);
+                    let tn = t.dot_prod(n);
+                    if (sn >= 0.0) == (tn >= 0.0) {
+                        continue;
+                    }
+                    // the edge crosses the great circle at x (interpolated between start and end, which remains
+                    // accurate if the edge is nearly along the great circle): is x within the minor arc?
+                    let x = (s * tn - t * sn).unit();
+                    let x = if sn < 0.0 { x } else { -x };
+                    if gte(iv.cross_prod(x).dot_prod(n), 0.0)
+                        && gte(x.cross_prod(pv).dot_prod(n), 0.0)
+                    {
+                        count_i += 1;
                     }
                 }
 
@@ -472,6 +599,376 @@ impl Loop {
         }
     }
 
+    /// Determines whether this loop and the given loop have any position in common: i.e. whether the boundary of one
+    /// loop touches or crosses the other loop, or whether one loop is within the other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::NVector;
+    /// use jord::spherical::Loop;
+    ///
+    /// let a = Loop::new(&vec![
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(0.0, 10.0),
+    ///     NVector::from_lat_long_degrees(10.0, 10.0),
+    ///     NVector::from_lat_long_degrees(10.0, 0.0),
+    /// ]);
+    /// let b = Loop::new(&vec![
+    ///     NVector::from_lat_long_degrees(5.0, 5.0),
+    ///     NVector::from_lat_long_degrees(5.0, 15.0),
+    ///     NVector::from_lat_long_degrees(15.0, 15.0),
+    ///     NVector::from_lat_long_degrees(15.0, 5.0),
+    /// ]);
+    /// let c = Loop::new(&vec![
+    ///     NVector::from_lat_long_degrees(-5.0, -5.0),
+    ///     NVector::from_lat_long_degrees(-5.0, -1.0),
+    ///     NVector::from_lat_long_degrees(-1.0, -1.0),
+    /// ]);
+    ///
+    /// assert!(a.intersects(&b));
+    /// assert!(!a.intersects(&c));
+    /// ```
+    pub fn intersects(&self, o: &Loop) -> bool {
+        if self.is_empty() || o.is_empty() {
+            return false;
+        }
+        o.iter_vertices()
+            .any(|v| self.contains_position(*v) || self.any_edge_contains_position(*v))
+            || self
+                .iter_vertices()
+                .any(|v| o.contains_position(*v) || o.any_edge_contains_position(*v))
+            || self.edges_intersect(o)
+    }
+
+    /// Determines whether this loop contains the given loop: i.e. all the vertices of the given loop are inside this
+    /// loop and no edge of this loop crosses or touches an edge of the given loop. Any loop contains an empty loop.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::NVector;
+    /// use jord::spherical::Loop;
+    ///
+    /// let a = Loop::new(&vec![
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(0.0, 10.0),
+    ///     NVector::from_lat_long_degrees(10.0, 10.0),
+    ///     NVector::from_lat_long_degrees(10.0, 0.0),
+    /// ]);
+    /// let b = Loop::new(&vec![
+    ///     NVector::from_lat_long_degrees(2.0, 2.0),
+    ///     NVector::from_lat_long_degrees(2.0, 8.0),
+    ///     NVector::from_lat_long_degrees(8.0, 8.0),
+    /// ]);
+    ///
+    /// assert!(a.contains_loop(&b));
+    /// assert!(!b.contains_loop(&a));
+    /// ```
+    pub fn contains_loop(&self, o: &Loop) -> bool {
+        if o.is_empty() {
+            return true;
+        }
+        // all the vertices of o are inside this loop, and the boundary of this loop neither crosses the edges of o
+        // nor lies inside o.
+        o.iter_vertices().all(|v| self.contains_position(*v))
+            && !self.iter_vertices().any(|v| o.contains_position(*v))
+            && !self.edges_intersect(o)
+    }
+
+    /// Returns all the positions where the given minor arc intersects the boundary of this loop, ordered by distance
+    /// from the start of the minor arc. A position where the minor arc goes through a vertex of this loop is returned
+    /// only once. Positions where the minor arc only touches the boundary of this loop are included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong, NVector};
+    /// use jord::spherical::{Loop, MinorArc};
+    ///
+    /// let l = Loop::new(&vec![
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(0.0, 10.0),
+    ///     NVector::from_lat_long_degrees(10.0, 10.0),
+    ///     NVector::from_lat_long_degrees(10.0, 0.0),
+    /// ]);
+    ///
+    /// let ma = MinorArc::new(
+    ///     NVector::from_lat_long_degrees(5.0, 15.0),
+    ///     NVector::from_lat_long_degrees(5.0, -5.0),
+    /// );
+    ///
+    /// // enters through the eastern edge and exits through the western edge.
+    /// let cs = l.crossings(ma);
+    /// assert_eq!(2, cs.len());
+    /// assert_eq!(Angle::from_degrees(10.0), LatLong::from_nvector(cs[0]).longitude().round_d7());
+    /// assert_eq!(Angle::from_degrees(0.0), LatLong::from_nvector(cs[1]).longitude().round_d7());
+    /// ```
+    pub fn crossings(&self, ma: MinorArc) -> Vec<NVector> {
+        let start = ma.start();
+        let mut res: Vec<(ChordLength, NVector)> = self
+            .edges
+            .iter()
+            .filter_map(|e| ma.intersection(*e))
+            .map(|p| (ChordLength::new(start, p), p))
+            .collect();
+        res.sort_by_key(|a| a.0);
+        // an intersection at a vertex is found on both edges sharing that vertex.
+        res.dedup_by(|a, b| vec3_eq(a.1.as_vec3(), b.1.as_vec3()));
+        res.into_iter().map(|(_, p)| p).collect()
+    }
+
+    /// Clips the given minor arc to this loop: returns the portions of the given minor arc that are inside this loop,
+    /// ordered from the start of the given minor arc. Portions of the minor arc that lie along an edge of this loop
+    /// are not inside this loop - see [contains_position](crate::spherical::Loop::contains_position).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong, NVector};
+    /// use jord::spherical::{Loop, MinorArc};
+    ///
+    /// // U-shaped loop.
+    /// let l = Loop::new(&vec![
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(0.0, 10.0),
+    ///     NVector::from_lat_long_degrees(10.0, 10.0),
+    ///     NVector::from_lat_long_degrees(10.0, 7.0),
+    ///     NVector::from_lat_long_degrees(2.0, 7.0),
+    ///     NVector::from_lat_long_degrees(2.0, 3.0),
+    ///     NVector::from_lat_long_degrees(10.0, 3.0),
+    ///     NVector::from_lat_long_degrees(10.0, 0.0),
+    /// ]);
+    ///
+    /// let ma = MinorArc::new(
+    ///     NVector::from_lat_long_degrees(5.0, -5.0),
+    ///     NVector::from_lat_long_degrees(5.0, 15.0),
+    /// );
+    ///
+    /// // crosses both arms of the U.
+    /// let clipped = l.clip(ma);
+    /// assert_eq!(2, clipped.len());
+    /// assert_eq!(Angle::from_degrees(3.0), LatLong::from_nvector(clipped[0].end()).longitude().round_d7());
+    /// assert_eq!(Angle::from_degrees(7.0), LatLong::from_nvector(clipped[1].start()).longitude().round_d7());
+    /// ```
+    pub fn clip(&self, ma: MinorArc) -> Vec<MinorArc> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+        let mut ps = vec![ma.start()];
+        ps.extend(self.crossings(ma));
+        ps.push(ma.end());
+        let mut res: Vec<MinorArc> = Vec::new();
+        // whether the last retained portion ends where the current one starts.
+        let mut extend = false;
+        for w in ps.windows(2) {
+            let (a, b) = (w[0], w[1]);
+            if vec3_eq(a.as_vec3(), b.as_vec3()) {
+                continue;
+            }
+            let mid = NVector::new((a.as_vec3() + b.as_vec3()).unit());
+            if self.contains_position(mid) {
+                match res.last_mut() {
+                    // the minor arc touches the boundary without exiting this loop.
+                    Some(last) if extend => *last = MinorArc::new(last.start(), b),
+                    _ => res.push(MinorArc::new(a, b)),
+                }
+                extend = true;
+            } else {
+                extend = false;
+            }
+        }
+        res
+    }
+
+    /// Simplifies this loop using the [Douglas-Peucker](https://en.wikipedia.org/wiki/Ramer%E2%80%93Douglas%E2%80%93Peucker_algorithm)
+    /// algorithm: the returned loop retains a subset of the vertices of this loop such that every removed vertex is
+    /// within the given tolerance of the returned loop.
+    ///
+    /// The returned loop is guaranteed to be [simple](crate::spherical::Loop::is_simple) if this loop is simple:
+    /// vertices are re-inserted as needed to prevent edges from intersecting. At least 3 vertices are retained; this
+    /// loop is returned unmodified if it cannot be simplified into a simple loop.
+    ///
+    /// The tolerance is the central angle between a vertex and the closest position on the simplified loop: a
+    /// distance on a sphere can be converted using [Sphere::distance_to_angle](crate::spherical::Sphere::distance_to_angle).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, Length, NVector};
+    /// use jord::spherical::{Cap, Loop, Sphere};
+    ///
+    /// let l = Loop::new(&Cap::from_centre_and_radius(
+    ///     NVector::from_lat_long_degrees(55.605, 13.0038),
+    ///     Angle::from_degrees(0.1)
+    /// ).boundary(1000));
+    ///
+    /// // 10 metres on Earth.
+    /// let tolerance = Sphere::EARTH.distance_to_angle(Length::from_metres(10.0));
+    /// let s = l.simplify_douglas_peucker(tolerance);
+    /// assert!(s.num_vertices() < 200);
+    /// assert!(s.is_simple());
+    /// ```
+    pub fn simplify_douglas_peucker(&self, tolerance: Angle) -> Self {
+        let vs: Vec<NVector> = self.iter_vertices().copied().collect();
+        self.simplified(&douglas_peucker_closed(&vs, tolerance))
+    }
+
+    /// Simplifies this loop using the Douglas-Peucker algorithm with a tolerance expressed as a distance on the
+    /// given sphere - see [simplify_douglas_peucker](crate::spherical::Loop::simplify_douglas_peucker).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Length, NVector};
+    /// use jord::spherical::{Loop, Sphere};
+    ///
+    /// let l = Loop::new(&[
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(0.0001, 0.5),
+    ///     NVector::from_lat_long_degrees(0.0, 1.0),
+    ///     NVector::from_lat_long_degrees(1.0, 1.0),
+    ///     NVector::from_lat_long_degrees(1.0, 0.0),
+    /// ]);
+    ///
+    /// let tolerance = Length::from_metres(100.0);
+    /// assert_eq!(
+    ///     l.simplify_douglas_peucker(Sphere::EARTH.distance_to_angle(tolerance)),
+    ///     l.simplify_douglas_peucker_by_distance(tolerance, &Sphere::EARTH)
+    /// );
+    /// assert_eq!(4, l.simplify_douglas_peucker_by_distance(tolerance, &Sphere::EARTH).num_vertices());
+    /// ```
+    pub fn simplify_douglas_peucker_by_distance(&self, tolerance: Length, sphere: &Sphere) -> Self {
+        self.simplify_douglas_peucker(sphere.distance_to_angle(tolerance))
+    }
+
+    /// Simplifies this loop using the [Visvalingam-Whyatt](https://en.wikipedia.org/wiki/Visvalingam%E2%80%93Whyatt_algorithm)
+    /// algorithm: vertices are removed in increasing order of effective area - the
+    /// [spherical excess](crate::spherical::Loop::spherical_excess) of the triangle formed by the vertex and its 2
+    /// neighbours - until all the remaining vertices have an effective area of at least the given minimum area.
+    ///
+    /// The returned loop is guaranteed to be [simple](crate::spherical::Loop::is_simple) if this loop is simple:
+    /// a vertex is not removed if that would make edges intersect. At least 3 vertices are retained; this loop is
+    /// returned unmodified if it cannot be simplified into a simple loop.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, NVector};
+    /// use jord::spherical::{Cap, Loop};
+    ///
+    /// let l = Loop::new(&Cap::from_centre_and_radius(
+    ///     NVector::from_lat_long_degrees(55.605, 13.0038),
+    ///     Angle::from_degrees(0.1)
+    /// ).boundary(1000));
+    ///
+    /// let s = l.simplify_visvalingam_whyatt(Angle::from_radians(1e-10));
+    /// assert!(s.num_vertices() < 200);
+    /// assert!(s.is_simple());
+    /// ```
+    pub fn simplify_visvalingam_whyatt(&self, min_area: Angle) -> Self {
+        let vs: Vec<NVector> = self.iter_vertices().copied().collect();
+        self.simplified(&visvalingam_whyatt(&vs, min_area, true))
+    }
+
+    /// Simplifies this loop using the Visvalingam-Whyatt algorithm with a tolerance expressed as a distance on the
+    /// given sphere: the minimum effective area is the area of a square whose side is the given distance - see
+    /// [simplify_visvalingam_whyatt](crate::spherical::Loop::simplify_visvalingam_whyatt).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, Length, NVector};
+    /// use jord::spherical::{Loop, Sphere};
+    ///
+    /// let l = Loop::new(&[
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(0.0001, 0.5),
+    ///     NVector::from_lat_long_degrees(0.0, 1.0),
+    ///     NVector::from_lat_long_degrees(1.0, 1.0),
+    ///     NVector::from_lat_long_degrees(1.0, 0.0),
+    /// ]);
+    ///
+    /// // 1 kilometre on Earth: the minimum area is 1 square kilometre.
+    /// let tolerance = Length::from_kilometres(1.0);
+    /// let side = Sphere::EARTH.distance_to_angle(tolerance).as_radians();
+    /// assert_eq!(
+    ///     l.simplify_visvalingam_whyatt(Angle::from_radians(side * side)),
+    ///     l.simplify_visvalingam_whyatt_by_distance(tolerance, &Sphere::EARTH)
+    /// );
+    /// assert_eq!(4, l.simplify_visvalingam_whyatt_by_distance(tolerance, &Sphere::EARTH).num_vertices());
+    /// ```
+    pub fn simplify_visvalingam_whyatt_by_distance(
+        &self,
+        tolerance: Length,
+        sphere: &Sphere,
+    ) -> Self {
+        let side = sphere.distance_to_angle(tolerance).as_radians();
+        self.simplify_visvalingam_whyatt(Angle::from_radians(side * side))
+    }
+
+    /// Computes the buffer of this loop on the given sphere: outward if the given distance is positive - the region
+    /// within the given distance of this loop - or inward if negative - the region of this loop further than the
+    /// absolute value of the given distance from its boundary. Corners are rounded by vertices spaced by at most a
+    /// quarter circle divided by the given number of segments per quadrant (at least 1).
+    ///
+    /// The result is returned as a list of [Polygon]s: an outward buffer is a single polygon - which has holes if
+    /// concavities of this loop get closed - whereas an inward buffer may consist of several disjoint polygons or
+    /// none at all. An empty list is returned if this loop is empty or if the absolute value of the given distance is
+    /// greater or equal to a quarter of the circumference of the sphere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Length, NVector};
+    /// use jord::spherical::{Loop, Sphere};
+    ///
+    /// let l = Loop::new(&vec![
+    ///     NVector::from_lat_long_degrees(55.605, 13.0038),
+    ///     NVector::from_lat_long_degrees(55.4295, 13.82),
+    ///     NVector::from_lat_long_degrees(56.0294, 14.1567),
+    /// ]);
+    ///
+    /// // 5 kilometres outside the border.
+    /// let outward = l.buffer(Length::from_kilometres(5.0), &Sphere::EARTH, 8);
+    /// assert_eq!(1, outward.len());
+    /// assert!(outward[0].contains_position(NVector::from_lat_long_degrees(55.58, 13.0)));
+    ///
+    /// // 5 kilometres inside the border.
+    /// let inward = l.buffer(Length::from_kilometres(-5.0), &Sphere::EARTH, 8);
+    /// assert_eq!(1, inward.len());
+    /// assert!(l.contains_position(NVector::from_lat_long_degrees(55.6, 13.1)));
+    /// assert!(!inward[0].contains_position(NVector::from_lat_long_degrees(55.6, 13.1)));
+    /// ```
+    pub fn buffer(
+        &self,
+        distance: Length,
+        sphere: &Sphere,
+        quadrant_segments: usize,
+    ) -> Vec<Polygon> {
+        buffer_radius(distance, sphere)
+            .map_or(Vec::new(), |r| loop_buffer(self, r, quadrant_segments))
+    }
+
+    /// Determines whether any edge of this loop intersects any edge of the given loop.
+    fn edges_intersect(&self, o: &Loop) -> bool {
+        self.edges
+            .iter()
+            .any(|e1| o.edges.iter().any(|e2| e1.intersection(*e2).is_some()))
+    }
+
+    /// Returns the loop made of the given vertices if it is simple, otherwise this loop.
+    fn simplified(&self, vs: &[NVector]) -> Self {
+        let l = Self::new(vs);
+        let lvs: Vec<NVector> = l.iter_vertices().copied().collect();
+        // equivalent to is_simple, but in sub-quadratic time.
+        if !l.is_empty() && l.has_valid_edges() && no_crossing_edges(&lvs) {
+            l
+        } else {
+            self.clone()
+        }
+    }
+
     /// Computes the distance from the given position to the boundary of this polygon.
     /// Note: if the given position is inside this polygon a non-zero length is returned. If this is not desirable,
     /// use [contains_position](crate::spherical::Loop::contains_position) beforehand.
@@ -583,13 +1080,13 @@ impl Loop {
 
             // sum interior angles; depending on whether polygon is cw or ccw, angle between edges is PI - a or PI
             // + a, where a is angle between great-circle vectors; so sum a, then take n * PI - abs(sum(a)) (cannot
-            // use sum(PI - abs(a)) as concave polygons would fail); use vector to 1st point as plane normal for
-            // sign of a.
-            let n1 = Some(self.vertices[0].0.as_vec3());
+            // use sum(PI - abs(a)) as concave polygons would fail); use vector to the vertex shared by both edges as
+            // plane normal for sign of a.
             let mut interior = 0.0;
             let len = ns.len();
             for i in 0..len {
-                interior += angle_radians_between(ns[i], ns[(i + 1) % len], n1);
+                let v = Some(self.edges[i].end().as_vec3());
+                interior += angle_radians_between(ns[i], ns[(i + 1) % len], v);
             }
 
             let n = len as f64;
@@ -599,6 +1096,114 @@ impl Loop {
             Angle::from_radians(sum - (n - 2.0) * PI)
         }
     }
+
+    /// Computes the intersection of this loop and the given loop: the region contained in both loops.
+    ///
+    /// The result is returned as a list of [Polygon]s; the intersection of 2 loops never has holes, but may consist
+    /// of several disjoint loops. The edges of both loops are split where the boundaries meet - including vertices of
+    /// one loop on the boundary of the other loop and shared edges - and the sub-edges bounding the result are linked
+    /// into loops: the vertices of the result are vertices of either loop or positions where the boundaries cross.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::NVector;
+    /// use jord::spherical::Loop;
+    ///
+    /// let a = Loop::new(&vec![
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(10.0, 0.0),
+    ///     NVector::from_lat_long_degrees(10.0, 10.0),
+    ///     NVector::from_lat_long_degrees(0.0, 10.0),
+    /// ]);
+    /// let b = Loop::new(&vec![
+    ///     NVector::from_lat_long_degrees(5.0, 5.0),
+    ///     NVector::from_lat_long_degrees(15.0, 5.0),
+    ///     NVector::from_lat_long_degrees(15.0, 15.0),
+    ///     NVector::from_lat_long_degrees(5.0, 15.0),
+    /// ]);
+    ///
+    /// let i = a.intersection(&b);
+    /// assert_eq!(1, i.len());
+    /// assert_eq!(4, i[0].shell().num_vertices());
+    /// assert_eq!(0, i[0].num_holes());
+    /// assert!(i[0].contains_position(NVector::from_lat_long_degrees(7.0, 7.0)));
+    /// assert!(!i[0].contains_position(NVector::from_lat_long_degrees(2.0, 2.0)));
+    /// ```
+    pub fn intersection(&self, o: &Loop) -> Vec<Polygon> {
+        clip(self, o, Operation::Intersection)
+    }
+
+    /// Computes the union of this loop and the given loop: the region contained in either loop.
+    ///
+    /// The result is returned as a list of [Polygon]s, since the union of 2 loops may have holes or consist of
+    /// 2 disjoint loops. See [intersection](crate::spherical::Loop::intersection) for details about the algorithm.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::NVector;
+    /// use jord::spherical::Loop;
+    ///
+    /// let a = Loop::new(&vec![
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(10.0, 0.0),
+    ///     NVector::from_lat_long_degrees(10.0, 10.0),
+    ///     NVector::from_lat_long_degrees(0.0, 10.0),
+    /// ]);
+    /// let b = Loop::new(&vec![
+    ///     NVector::from_lat_long_degrees(5.0, 5.0),
+    ///     NVector::from_lat_long_degrees(15.0, 5.0),
+    ///     NVector::from_lat_long_degrees(15.0, 15.0),
+    ///     NVector::from_lat_long_degrees(5.0, 15.0),
+    /// ]);
+    ///
+    /// let u = a.union(&b);
+    /// assert_eq!(1, u.len());
+    /// assert_eq!(8, u[0].shell().num_vertices());
+    /// assert!(u[0].contains_position(NVector::from_lat_long_degrees(2.0, 2.0)));
+    /// assert!(u[0].contains_position(NVector::from_lat_long_degrees(12.0, 12.0)));
+    /// assert!(!u[0].contains_position(NVector::from_lat_long_degrees(12.0, 2.0)));
+    /// ```
+    pub fn union(&self, o: &Loop) -> Vec<Polygon> {
+        clip(self, o, Operation::Union)
+    }
+
+    /// Computes the difference between this loop and the given loop: the region contained in this loop but not in
+    /// the given loop.
+    ///
+    /// The result is returned as a list of [Polygon]s, since the difference may have a hole (given loop inside this
+    /// loop) or consist of several disjoint loops. See [intersection](crate::spherical::Loop::intersection) for
+    /// details about the algorithm.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::NVector;
+    /// use jord::spherical::Loop;
+    ///
+    /// let a = Loop::new(&vec![
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(10.0, 0.0),
+    ///     NVector::from_lat_long_degrees(10.0, 10.0),
+    ///     NVector::from_lat_long_degrees(0.0, 10.0),
+    /// ]);
+    /// let b = Loop::new(&vec![
+    ///     NVector::from_lat_long_degrees(5.0, 5.0),
+    ///     NVector::from_lat_long_degrees(15.0, 5.0),
+    ///     NVector::from_lat_long_degrees(15.0, 15.0),
+    ///     NVector::from_lat_long_degrees(5.0, 15.0),
+    /// ]);
+    ///
+    /// let d = a.difference(&b);
+    /// assert_eq!(1, d.len());
+    /// assert_eq!(6, d[0].shell().num_vertices());
+    /// assert!(d[0].contains_position(NVector::from_lat_long_degrees(2.0, 2.0)));
+    /// assert!(!d[0].contains_position(NVector::from_lat_long_degrees(7.0, 7.0)));
+    /// ```
+    pub fn difference(&self, o: &Loop) -> Vec<Polygon> {
+        clip(self, o, Operation::Difference)
+    }
 }
 
 /// Determines whether the given vertices are given in clockwise order.
@@ -647,6 +1252,20 @@ pub fn is_loop_clockwise(vs: &[NVector]) -> bool {
     }
 }
 
+/// Triangulates the chain defined by the given vertices - given in clockwise order - using ear-clipping method.
+///
+/// Unlike a [Loop] the chain may contain repeated vertices (e.g. the bridges between the shell and holes of
+/// a [polygon](crate::spherical::Polygon)).
+pub(crate) fn triangulate_chain(vs: &[NVector]) -> Vec<(NVector, NVector, NVector)> {
+    if vs.len() < 3 {
+        Vec::new()
+    } else {
+        let (edges, _) = to_edges(vs);
+        let vertices = clockwise_edges_to_vertices(&edges);
+        ear_clipping(&vertices)
+    }
+}
+
 #[derive(PartialEq, Clone, Copy, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))] // codecov:ignore:this
 enum Classification {
@@ -755,8 +1374,7 @@ fn find_insides(vs: &[Vertex]) -> Option<(NVector, NVector)> {
 
     loop {
         if remaining.len() == 3 {
-            let inside =
-                Sphere::triangle_mean_position(remaining[0].0, remaining[1].0, remaining[2].0);
+            let inside = ear_inside(remaining[0].0, remaining[1].0, remaining[2].0);
             if let Some(p) = inside {
                 res.push(p);
             }
@@ -764,7 +1382,7 @@ fn find_insides(vs: &[Vertex]) -> Option<(NVector, NVector)> {
         }
 
         if let Some(ear) = next_ear(&mut remaining) {
-            let inside = Sphere::triangle_mean_position(ear.0, ear.1, ear.2);
+            let inside = ear_inside(ear.0, ear.1, ear.2);
             if let Some(p) = inside {
                 res.push(p);
                 if res.len() == 2 {
@@ -777,10 +1395,22 @@ fn find_insides(vs: &[Vertex]) -> Option<(NVector, NVector)> {
             break;
         }
     }
-    if res.len() == 2 {
-        Some((res[0], res[1]))
-    } else {
+    match res.len() {
+        2 => Some((res[0], res[1])),
+        // all other ears were degenerate.
+        1 => Some((res[0], res[0])),
+        _ => None,
+    }
+}
+
+/// Returns the mean position of the given ear, or None if the ear is degenerate: i.e. its vertices are (nearly) on
+/// the same great circle, in which case the mean position could be on the boundary of the loop.
+fn ear_inside(prev: NVector, cur: NVector, next: NVector) -> Option<NVector> {
+    let n = prev.as_vec3().cross_prod(next.as_vec3()).unit();
+    if n.dot_prod(cur.as_vec3()).abs() < COLLINEAR_EPS {
         None
+    } else {
+        Sphere::triangle_mean_position(prev, cur, next)
     }
 }
 
@@ -927,7 +1557,7 @@ fn vec3_eq(a: Vec3, b: Vec3) -> bool {
 #[cfg(test)]
 mod tests {
     use crate::{
-        spherical::{is_loop_clockwise, ChordLength, Loop, Sphere},
+        spherical::{is_loop_clockwise, ChordLength, Loop, MinorArc, Sphere},
         Angle, LatLong, Length, NVector, Vec3,
     };
 
@@ -1001,7 +1631,7 @@ mod tests {
 
     #[test]
     fn new_triangle() {
-        assert_loop_invariants(&vec![
+        assert_loop_invariants(&[
             NVector::from_lat_long_degrees(20.0, 20.0),
             NVector::from_lat_long_degrees(10.0, 30.0),
             NVector::from_lat_long_degrees(40.0, 40.0),
@@ -1010,7 +1640,7 @@ mod tests {
 
     #[test]
     fn new_loop() {
-        assert_loop_invariants(&vec![
+        assert_loop_invariants(&[
             NVector::from_lat_long_degrees(-85.0, 10.0),
             NVector::from_lat_long_degrees(-85.0, 170.0),
             NVector::from_lat_long_degrees(-85.0, -170.0),
@@ -1063,7 +1693,7 @@ mod tests {
         assert_eq!(opened.len(), l2.num_vertices());
         assert_eq!(opened.len(), l3.num_vertices());
 
-        let e_it = if is_loop_clockwise(&opened) {
+        let e_it = if is_loop_clockwise(opened) {
             opened.iter()
         } else {
             rvs.iter()
@@ -1078,19 +1708,19 @@ mod tests {
 
     #[test]
     fn is_convex_triangle() {
-        assert_convex(true, &vec![ystad(), hoor(), helsingborg()]);
+        assert_convex(true, &[ystad(), hoor(), helsingborg()]);
     }
 
     #[test]
     fn is_convex_concave() {
-        assert_convex(false, &vec![ystad(), hoor(), helsingborg(), kristianstad()]);
+        assert_convex(false, &[ystad(), hoor(), helsingborg(), kristianstad()]);
     }
 
     #[test]
     fn is_convex_concave_collinear_vertices() {
         assert_convex(
             false,
-            &vec![
+            &[
                 NVector::from_lat_long_degrees(10.0, 10.0),
                 NVector::from_lat_long_degrees(11.0, 10.0),
                 NVector::from_lat_long_degrees(12.0, 10.0),
@@ -1103,7 +1733,7 @@ mod tests {
 
     #[test]
     fn is_convex() {
-        assert_convex(true, &vec![ystad(), malmo(), helsingborg(), kristianstad()]);
+        assert_convex(true, &[ystad(), malmo(), helsingborg(), kristianstad()]);
     }
 
     fn assert_convex(e: bool, vs: &[NVector]) {
@@ -1154,15 +1784,15 @@ mod tests {
 
     #[test]
     fn is_loop_clockwise_less_than_3_vertices() {
-        assert!(!is_loop_clockwise(&vec![]));
-        assert!(!is_loop_clockwise(&vec![NVector::from_lat_long_degrees(
+        assert!(!is_loop_clockwise(&[]));
+        assert!(!is_loop_clockwise(&[NVector::from_lat_long_degrees(
             1.0, 1.0
         )]));
-        assert!(!is_loop_clockwise(&vec![
+        assert!(!is_loop_clockwise(&[
             NVector::from_lat_long_degrees(1.0, 1.0),
             NVector::from_lat_long_degrees(2.0, 1.0)
         ]));
-        assert!(!is_loop_clockwise(&vec![
+        assert!(!is_loop_clockwise(&[
             NVector::from_lat_long_degrees(1.0, 1.0),
             NVector::from_lat_long_degrees(2.0, 1.0),
             NVector::from_lat_long_degrees(1.0, 1.0)
@@ -1217,7 +1847,7 @@ mod tests {
 
     #[test]
     fn is_simple_consectutive_coincidental_vertices() {
-        let l = Loop::new(&vec![
+        let l = Loop::new(&[
             NVector::from_lat_long_degrees(-2.0, -2.0),
             NVector::from_lat_long_degrees(-2.0, -2.0),
             NVector::from_lat_long_degrees(3.0, 0.0),
@@ -1226,7 +1856,7 @@ mod tests {
     }
     #[test]
     fn is_simple_consectutive_antipodal_vertices() {
-        let l = Loop::new(&vec![
+        let l = Loop::new(&[
             NVector::from_lat_long_degrees(-2.0, -2.0),
             NVector::from_lat_long_degrees(-2.0, -2.0).antipode(),
             NVector::from_lat_long_degrees(3.0, 0.0),
@@ -1236,7 +1866,7 @@ mod tests {
 
     #[test]
     fn is_simple_self_intersecting() {
-        let l = Loop::new(&vec![
+        let l = Loop::new(&[
             NVector::from_lat_long_degrees(-2.0, -2.0),
             NVector::from_lat_long_degrees(2.0, -2.0),
             NVector::from_lat_long_degrees(3.0, 0.0),
@@ -1248,7 +1878,7 @@ mod tests {
 
     #[test]
     fn is_simple() {
-        let l = Loop::new(&vec![
+        let l = Loop::new(&[
             NVector::from_lat_long_degrees(-2.0, -2.0),
             NVector::from_lat_long_degrees(2.0, -2.0),
             NVector::from_lat_long_degrees(3.0, 0.0),
@@ -1258,6 +1888,111 @@ mod tests {
         assert!(l.is_simple());
     }
 
+    // convex_hull
+
+    #[test]
+    fn convex_hull_degenerate() {
+        assert!(Loop::convex_hull(&[]).is_empty());
+        let p = NVector::from_lat_long_degrees(10.0, 10.0);
+        assert!(Loop::convex_hull(&[p, p, p]).is_empty());
+        assert!(Loop::convex_hull(&[p, NVector::from_lat_long_degrees(20.0, 20.0)]).is_empty());
+        // all positions on the equator.
+        assert!(Loop::convex_hull(&[
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(0.0, 10.0),
+            NVector::from_lat_long_degrees(0.0, 5.0),
+            NVector::from_lat_long_degrees(0.0, 20.0),
+        ])
+        .is_empty());
+    }
+
+    #[test]
+    fn convex_hull_not_within_hemisphere() {
+        assert!(Loop::convex_hull(&[
+            NVector::from_lat_long_degrees(90.0, 0.0),
+            NVector::from_lat_long_degrees(-30.0, 0.0),
+            NVector::from_lat_long_degrees(-30.0, 120.0),
+            NVector::from_lat_long_degrees(-30.0, -120.0),
+        ])
+        .is_empty());
+    }
+
+    #[test]
+    fn convex_hull_discards_collinear_and_duplicates() {
+        let ps = vec![
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(0.0, 5.0),
+            NVector::from_lat_long_degrees(0.0, 10.0),
+            NVector::from_lat_long_degrees(0.0, 10.0),
+            NVector::from_lat_long_degrees(10.0, 5.0),
+            NVector::from_lat_long_degrees(3.0, 5.0),
+        ];
+        let hull = Loop::convex_hull(&ps);
+        assert_eq!(
+            vec![
+                LatLong::from_degrees(0.0, 0.0),
+                LatLong::from_degrees(10.0, 5.0),
+                LatLong::from_degrees(0.0, 10.0),
+            ],
+            hull.iter_vertices()
+                .map(|v| LatLong::from_nvector(*v).round_d7())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn convex_hull_contains_all_positions() {
+        let mut ps = Vec::new();
+        for i in 0..50 {
+            let f = i as f64;
+            // pseudo-random scattered positions around the antimeridian.
+            let lat = 20.0 * (f * 1.7).sin();
+            let lng = 180.0 + 15.0 * (f * 2.3).cos();
+            ps.push(NVector::from_lat_long_degrees(lat, lng));
+        }
+        let hull = Loop::convex_hull(&ps);
+        assert!(!hull.is_empty());
+        assert!(hull.is_convex());
+        assert!(hull.is_simple());
+        for p in ps.iter() {
+            assert!(hull.contains_position(*p) || hull.any_edge_contains_position(*p));
+        }
+        assert!(hull.iter_vertices().all(|v| ps.contains(v)));
+    }
+
+    #[test]
+    fn convex_hull_ordered_positions() {
+        // detections ordered along a track.
+        let n = 20_000;
+        let ps: Vec<NVector> = (0..n)
+            .map(|i| {
+                let t = i as f64 / n as f64;
+                NVector::from_lat_long_degrees(50.0 + (t * 200.0).sin(), 10.0 * t)
+            })
+            .collect();
+        let hull = Loop::convex_hull(&ps);
+        assert!(!hull.is_empty());
+        assert!(hull.is_convex());
+        for p in ps.iter().step_by(97) {
+            assert!(hull.contains_position(*p) || hull.any_edge_contains_position(*p));
+        }
+    }
+
+    #[test]
+    fn convex_hull_around_pole() {
+        let ps = vec![
+            NVector::from_lat_long_degrees(80.0, 0.0),
+            NVector::from_lat_long_degrees(80.0, 90.0),
+            NVector::from_lat_long_degrees(85.0, 45.0),
+            NVector::from_lat_long_degrees(80.0, 180.0),
+            NVector::from_lat_long_degrees(80.0, -90.0),
+            NVector::from_lat_long_degrees(90.0, 0.0),
+        ];
+        let hull = Loop::convex_hull(&ps);
+        assert_eq!(4, hull.num_vertices());
+        assert!(hull.contains_position(NVector::from_lat_long_degrees(90.0, 0.0)));
+    }
+
     // bound
 
     #[test]
@@ -1298,6 +2033,41 @@ mod tests {
         assert_bound(&Loop::new(&vs), -84.9999999, 180.0, -90.0, -180.0);
     }
 
+    #[test]
+    fn cap_bound_empty() {
+        assert!(Loop::EMPTY.cap_bound().is_empty());
+    }
+
+    #[test]
+    fn cap_bound_north_pole() {
+        let l = Loop::new(&[
+            NVector::from_lat_long_degrees(85.0, 10.0),
+            NVector::from_lat_long_degrees(85.0, 100.0),
+            NVector::from_lat_long_degrees(85.0, -170.0),
+            NVector::from_lat_long_degrees(85.0, -80.0),
+        ]);
+        let c = l.cap_bound();
+        assert_eq!(
+            Angle::QUARTER_CIRCLE,
+            LatLong::from_nvector(c.centre()).latitude().round_d7()
+        );
+        assert_eq!(Angle::from_degrees(5.0), c.radius().round_d7());
+        assert!(l.iter_vertices().all(|v| c.contains_position(*v)));
+    }
+
+    #[test]
+    fn cap_bound_not_within_hemisphere() {
+        let l = Loop::new(&[
+            NVector::from_lat_long_degrees(30.0, 0.0),
+            NVector::from_lat_long_degrees(-10.0, 90.0),
+            NVector::from_lat_long_degrees(30.0, 180.0),
+            NVector::from_lat_long_degrees(-10.0, -90.0),
+        ]);
+        let c = l.cap_bound();
+        assert!(c.radius() > Angle::QUARTER_CIRCLE);
+        assert!(l.iter_vertices().all(|v| c.contains_position(*v)));
+    }
+
     fn assert_bound(l: &Loop, north: f64, east: f64, south: f64, west: f64) {
         let b = l.bound();
         let ne: LatLong = b.north_east();
@@ -1323,7 +2093,7 @@ mod tests {
         let v1 = NVector::from_lat_long_degrees(20.0, 20.0);
         let v2 = NVector::from_lat_long_degrees(10.0, 30.0);
         let v3 = NVector::from_lat_long_degrees(40.0, 40.0);
-        let l = Loop::new(&vec![v1, v2, v3]);
+        let l = Loop::new(&[v1, v2, v3]);
         assert!(l.contains_position(inside));
         assert!(!l.contains_position(antipode));
     }
@@ -1334,7 +2104,7 @@ mod tests {
         let v1 = NVector::from_lat_long_degrees(10.0, 179.0);
         let v2 = NVector::from_lat_long_degrees(10.0, -150.0);
         let v3 = NVector::from_lat_long_degrees(-85.0, -150.0);
-        let l = Loop::new(&vec![v1, v2, v3]);
+        let l = Loop::new(&[v1, v2, v3]);
         assert!(!l.contains_position(position));
     }
 
@@ -1397,6 +2167,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn contains_position_aligned_with_vertex() {
+        let l = Loop::new(&[
+            NVector::from_lat_long_degrees(1.0, -1.0),
+            NVector::from_lat_long_degrees(0.0, 1.0),
+            NVector::from_lat_long_degrees(-2.0, 4.0),
+            NVector::from_lat_long_degrees(-1.0, -1.0),
+        ]);
+        // on the equator, as vertex (0, 1).
+        assert!(l.contains_position(NVector::from_lat_long_degrees(0.0, 0.0)));
+        assert!(!l.contains_position(NVector::from_lat_long_degrees(0.0, 2.0)));
+    }
+
+    #[test]
+    fn contains_position_collinear_vertices() {
+        // (-5, -9) and (9, -9) are on the meridian edge from (-6, -9) to (10, -9).
+        let l = Loop::new(&[
+            NVector::from_lat_long_degrees(9.0, -9.0),
+            NVector::from_lat_long_degrees(10.0, -9.0),
+            NVector::from_lat_long_degrees(-2.0, -2.0),
+            NVector::from_lat_long_degrees(-6.0, -9.0),
+            NVector::from_lat_long_degrees(-5.0, -9.0),
+        ]);
+        assert!(l.contains_position(NVector::from_lat_long_degrees(0.0, -5.0)));
+        assert!(!l.contains_position(NVector::from_lat_long_degrees(0.0, 0.0)));
+        assert!(!l.contains_position(NVector::from_lat_long_degrees(5.0, 5.0)));
+        assert!(!l.contains_position(NVector::from_lat_long_degrees(-8.0, -5.0)));
+    }
+
     #[test]
     fn does_not_contain_position_on_edge() {
         let vertices = vec![
@@ -1424,7 +2223,7 @@ mod tests {
         // p is one arc microsecond east of v2: detected on both (v1, v2) and (v2, v3).
         let p = NVector::from_lat_long_degrees(0.0, one_mas);
 
-        let l = Loop::new(&vec![v1, v2, v3]);
+        let l = Loop::new(&[v1, v2, v3]);
 
         assert!(!l.contains_position(p));
         assert!(l.any_edge_contains_position(p));
@@ -1477,11 +2276,228 @@ mod tests {
         assert!(!l.contains_position(p));
     }
 
+    // intersects & contains_loop
+
+    fn square(lat: f64, lon: f64, size: f64) -> Loop {
+        Loop::new(&[
+            NVector::from_lat_long_degrees(lat, lon),
+            NVector::from_lat_long_degrees(lat, lon + size),
+            NVector::from_lat_long_degrees(lat + size, lon + size),
+            NVector::from_lat_long_degrees(lat + size, lon),
+        ])
+    }
+
+    #[test]
+    fn intersects_and_contains_loop_empty() {
+        let a = square(0.0, 0.0, 10.0);
+        assert!(!a.intersects(&Loop::EMPTY));
+        assert!(!Loop::EMPTY.intersects(&a));
+        assert!(a.contains_loop(&Loop::EMPTY));
+        assert!(!Loop::EMPTY.contains_loop(&a));
+    }
+
+    #[test]
+    fn intersects_and_contains_loop_nested() {
+        let a = square(0.0, 0.0, 10.0);
+        let b = square(2.0, 2.0, 5.0);
+        assert!(a.intersects(&b));
+        assert!(b.intersects(&a));
+        assert!(a.contains_loop(&b));
+        assert!(!b.contains_loop(&a));
+    }
+
+    #[test]
+    fn intersects_and_contains_loop_overlapping() {
+        let a = square(0.0, 0.0, 10.0);
+        let b = square(5.0, 5.0, 10.0);
+        assert!(a.intersects(&b));
+        assert!(b.intersects(&a));
+        assert!(!a.contains_loop(&b));
+        assert!(!b.contains_loop(&a));
+    }
+
+    #[test]
+    fn intersects_and_contains_loop_crossing_without_vertex_inside() {
+        // cross shape: no vertex of either loop is inside the other loop.
+        let a = Loop::new(&[
+            NVector::from_lat_long_degrees(-1.0, -10.0),
+            NVector::from_lat_long_degrees(-1.0, 10.0),
+            NVector::from_lat_long_degrees(1.0, 10.0),
+            NVector::from_lat_long_degrees(1.0, -10.0),
+        ]);
+        let b = Loop::new(&[
+            NVector::from_lat_long_degrees(-10.0, -1.0),
+            NVector::from_lat_long_degrees(-10.0, 1.0),
+            NVector::from_lat_long_degrees(10.0, 1.0),
+            NVector::from_lat_long_degrees(10.0, -1.0),
+        ]);
+        assert!(a.intersects(&b));
+        assert!(!a.contains_loop(&b));
+        assert!(!b.contains_loop(&a));
+    }
+
+    #[test]
+    fn intersects_and_contains_loop_touching() {
+        let a = square(0.0, 0.0, 10.0);
+        let b = square(0.0, 10.0, 10.0);
+        assert!(a.intersects(&b));
+        assert!(!a.contains_loop(&b));
+        // shared edge.
+        let c = square(2.0, 0.0, 5.0);
+        assert!(!a.contains_loop(&c));
+    }
+
+    #[test]
+    fn intersects_and_contains_loop_disjoint() {
+        let a = square(0.0, 0.0, 10.0);
+        let b = square(20.0, 20.0, 10.0);
+        assert!(!a.intersects(&b));
+        assert!(!a.contains_loop(&b));
+        assert!(!b.contains_loop(&a));
+    }
+
+    // crossings
+
+    #[test]
+    fn crossings_none() {
+        let l = square(0.0, 0.0, 10.0);
+        let inside = MinorArc::new(
+            NVector::from_lat_long_degrees(2.0, 2.0),
+            NVector::from_lat_long_degrees(8.0, 8.0),
+        );
+        assert!(l.crossings(inside).is_empty());
+        let outside = MinorArc::new(
+            NVector::from_lat_long_degrees(20.0, 2.0),
+            NVector::from_lat_long_degrees(20.0, 8.0),
+        );
+        assert!(l.crossings(outside).is_empty());
+        assert!(Loop::EMPTY.crossings(inside).is_empty());
+    }
+
+    #[test]
+    fn crossings_ordered_along_arc() {
+        let l = square(-5.0, 0.0, 10.0);
+        let eastward = MinorArc::new(
+            NVector::from_lat_long_degrees(0.0, -5.0),
+            NVector::from_lat_long_degrees(0.0, 15.0),
+        );
+        let cs: Vec<LatLong> = l
+            .crossings(eastward)
+            .iter()
+            .map(|p| LatLong::from_nvector(*p).round_d7())
+            .collect();
+        assert_eq!(
+            vec![
+                LatLong::from_degrees(0.0, 0.0),
+                LatLong::from_degrees(0.0, 10.0)
+            ],
+            cs
+        );
+        let mut reversed = l.crossings(eastward.opposite());
+        reversed.reverse();
+        assert_eq!(
+            cs,
+            reversed
+                .iter()
+                .map(|p| LatLong::from_nvector(*p).round_d7())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn crossings_through_vertex() {
+        let l = Loop::new(&[
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(5.0, 5.0),
+            NVector::from_lat_long_degrees(0.0, 10.0),
+            NVector::from_lat_long_degrees(-5.0, 5.0),
+        ]);
+        // goes through the northern and southern vertices.
+        let ma = MinorArc::new(
+            NVector::from_lat_long_degrees(10.0, 5.0),
+            NVector::from_lat_long_degrees(-10.0, 5.0),
+        );
+        let cs = l.crossings(ma);
+        assert_eq!(2, cs.len());
+        assert_eq!(
+            LatLong::from_degrees(5.0, 5.0),
+            LatLong::from_nvector(cs[0]).round_d7()
+        );
+        assert_eq!(
+            LatLong::from_degrees(-5.0, 5.0),
+            LatLong::from_nvector(cs[1]).round_d7()
+        );
+    }
+
+    // clip
+
+    #[test]
+    fn clip_inside_and_outside() {
+        let l = square(0.0, 0.0, 10.0);
+        let inside = MinorArc::new(
+            NVector::from_lat_long_degrees(2.0, 2.0),
+            NVector::from_lat_long_degrees(8.0, 8.0),
+        );
+        assert_eq!(vec![inside], l.clip(inside));
+        let outside = MinorArc::new(
+            NVector::from_lat_long_degrees(20.0, 2.0),
+            NVector::from_lat_long_degrees(20.0, 8.0),
+        );
+        assert!(l.clip(outside).is_empty());
+        assert!(Loop::EMPTY.clip(inside).is_empty());
+    }
+
+    #[test]
+    fn clip_enters_and_exits() {
+        let l = square(-5.0, 0.0, 10.0);
+        let ma = MinorArc::new(
+            NVector::from_lat_long_degrees(0.0, 5.0),
+            NVector::from_lat_long_degrees(0.0, 15.0),
+        );
+        let c = l.clip(ma);
+        assert_eq!(1, c.len());
+        assert_eq!(ma.start(), c[0].start());
+        assert_eq!(
+            LatLong::from_degrees(0.0, 10.0),
+            LatLong::from_nvector(c[0].end()).round_d7()
+        );
+    }
+
+    #[test]
+    fn clip_touching_vertex_from_inside() {
+        // concave loop whose reflex vertex touches the arc from outside: the arc remains inside on both sides.
+        let l = Loop::new(&[
+            NVector::from_lat_long_degrees(-5.0, 0.0),
+            NVector::from_lat_long_degrees(5.0, 0.0),
+            NVector::from_lat_long_degrees(5.0, 10.0),
+            NVector::from_lat_long_degrees(-5.0, 10.0),
+            NVector::from_lat_long_degrees(-5.0, 6.0),
+            NVector::from_lat_long_degrees(0.0, 5.0),
+            NVector::from_lat_long_degrees(-5.0, 4.0),
+        ]);
+        let ma = MinorArc::new(
+            NVector::from_lat_long_degrees(0.0, 2.0),
+            NVector::from_lat_long_degrees(0.0, 8.0),
+        );
+        assert_eq!(1, l.crossings(ma).len());
+        assert_eq!(vec![ma], l.clip(ma));
+    }
+
+    #[test]
+    fn clip_along_edge() {
+        let l = square(0.0, 0.0, 10.0);
+        let ma = MinorArc::new(
+            NVector::from_lat_long_degrees(0.0, 2.0),
+            NVector::from_lat_long_degrees(0.0, 8.0),
+        );
+        assert!(l.clip(ma).is_empty());
+    }
+
     // distance_to_boundary
 
     #[test]
     fn distance_to_boundary_edge() {
-        let l = Loop::new(&vec![
+        let l = Loop::new(&[
             NVector::from_lat_long_degrees(0.0, 0.0),
             NVector::from_lat_long_degrees(0.0, 10.0),
             NVector::from_lat_long_degrees(10.0, 10.0),
@@ -1495,20 +2511,18 @@ mod tests {
             Angle::from_degrees(90.0),
         ];
 
-        let mut i = 0;
-        for e in l.iter_edges() {
-            let m = Sphere::mean_position(&vec![e.start(), e.end()]).unwrap();
+        for (i, e) in l.iter_edges().enumerate() {
+            let m = Sphere::mean_position(&[e.start(), e.end()]).unwrap();
             let p = Sphere::EARTH.destination_position(m, bearings[i], Length::from_metres(10.0));
             let expected = ChordLength::new(m, p).to_angle().round_d7();
             assert_eq!(expected, l.distance_to_boundary(p).to_angle().round_d7());
-            i += 1;
         }
     }
 
     #[test]
     fn distance_to_boundary_vertex() {
         // define loop in clockwise order.
-        let l = Loop::new(&vec![
+        let l = Loop::new(&[
             NVector::from_lat_long_degrees(0.0, 0.0),
             NVector::from_lat_long_degrees(10.0, 0.0),
             NVector::from_lat_long_degrees(10.0, 10.0),
@@ -1522,12 +2536,10 @@ mod tests {
             Angle::from_degrees(135.0),
         ];
 
-        let mut i = 0;
-        for v in l.iter_vertices() {
+        for (i, v) in l.iter_vertices().enumerate() {
             let p = Sphere::EARTH.destination_position(*v, bearings[i], Length::from_metres(10.0));
             let expected = ChordLength::new(*v, p);
             assert_eq!(expected, l.distance_to_boundary(p));
-            i += 1;
         }
     }
 
@@ -1542,7 +2554,7 @@ mod tests {
         let v4 = NVector::from_lat_long_degrees(20.0, 20.0);
 
         let expected = vec![(v0, v1, v2), (v4, v0, v2), (v2, v3, v4)];
-        assert_loop_triangulation(&expected, &vec![v0, v1, v2, v3, v4]);
+        assert_loop_triangulation(&expected, &[v0, v1, v2, v3, v4]);
     }
 
     #[test]
@@ -1562,12 +2574,12 @@ mod tests {
             (v6, v0, v4),
             (v4, v5, v6),
         ];
-        assert_loop_triangulation(&expected, &vec![v0, v1, v2, v3, v4, v5, v6]);
+        assert_loop_triangulation(&expected, &[v0, v1, v2, v3, v4, v5, v6]);
     }
 
     #[test]
     fn triangulate_convex_6() {
-        let vs = &vec![
+        let vs = &[
             bangui(),
             juba(),
             narobi(),
@@ -1582,7 +2594,7 @@ mod tests {
             (kinshasa(), narobi(), dar_es_salaam()),
             (dar_es_salaam(), harare(), kinshasa()),
         ];
-        assert_loop_triangulation(&expected, &vs);
+        assert_loop_triangulation(&expected, vs);
     }
 
     #[test]
@@ -1631,7 +2643,7 @@ mod tests {
             (v1, v7, v8),
             (v1, v8, v9),
         ];
-        assert_loop_triangulation(&expected, &vec![v0, v1, v2, v3, v4, v5, v6, v7, v8, v9]);
+        assert_loop_triangulation(&expected, &[v0, v1, v2, v3, v4, v5, v6, v7, v8, v9]);
     }
 
     #[test]
@@ -1657,12 +2669,12 @@ mod tests {
             (v9, v5, v7),
             (v7, v8, v9),
         ];
-        assert_loop_triangulation(&expected, &vec![v0, v1, v2, v3, v4, v5, v6, v7, v8, v9]);
+        assert_loop_triangulation(&expected, &[v0, v1, v2, v3, v4, v5, v6, v7, v8, v9]);
     }
 
     #[test]
     fn triangulate_self_intersecting() {
-        let l = Loop::new(&vec![
+        let l = Loop::new(&[
             NVector::from_lat_long_degrees(-2.0, -2.0),
             NVector::from_lat_long_degrees(2.0, -2.0),
             NVector::from_lat_long_degrees(3.0, 0.0),
@@ -1691,8 +2703,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn spherical_excess_large_concave_loop() {
+        let l = Loop::new(&[
+            NVector::from_lat_long_degrees(30.0, -40.0),
+            NVector::from_lat_long_degrees(10.0, 0.0),
+            NVector::from_lat_long_degrees(30.0, 30.0),
+            NVector::from_lat_long_degrees(0.0, 20.0),
+            NVector::from_lat_long_degrees(-20.0, 40.0),
+            NVector::from_lat_long_degrees(-20.0, 20.0),
+            NVector::from_lat_long_degrees(-40.0, 0.0),
+            NVector::from_lat_long_degrees(-40.0, -10.0),
+        ]);
+        let triangles = l
+            .triangulate()
+            .iter()
+            .map(|t| Loop::new(&[t.0, t.1, t.2]).spherical_excess())
+            .fold(Angle::ZERO, |acc, e| acc + e);
+        assert_eq!(triangles.round_d7(), l.spherical_excess().round_d7());
+    }
+
     fn assert_loop_triangulation(e: &[(NVector, NVector, NVector)], vs: &[NVector]) {
-        assert_triangulation(e, &Loop::new(&vs));
+        assert_triangulation(e, &Loop::new(vs));
         let mut rvs = vs.to_vec();
         rvs.reverse();
         assert_triangulation(e, &Loop::new(&rvs));
@@ -1,8 +1,11 @@
 use std::{f64::consts::PI, time::Duration};
 
 use crate::{
-    surface::Surface, Angle, Cartesian3DVector, GeocentricPosition, GeodeticPosition, LatLong,
-    Length, Mat33, NVector, Speed, Vec3, Vehicle,
+    kinematics::{cpa, intercept, offset_vehicle, Cpa, Intercept, Kinematics},
+    rhumb::Rhumb,
+    surface::Surface,
+    Angle, Cartesian3DVector, GeocentricPosition, GeodeticPosition, LatLong, Length, Mat33,
+    NVector, Speed, Vec3, Vehicle,
 };
 
 use super::{
@@ -413,11 +416,134 @@ impl Sphere {
         Angle::from_radians(angle_radians_between(n1, n2, Some(b.as_vec3())))
     }
 
+    // rhumb lines
+
+    /// Computes the constant bearing (compass angle) of the [rhumb line](https://en.wikipedia.org/wiki/Rhumb_line)
+    /// (loxodrome) from `p1` to `p2`: unlike great circles, rhumb lines cross all meridians at the same angle.
+    /// The rhumb line never crosses the antimeridian the long way round.
+    /// Returns 0 if both positions are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, NVector};
+    /// use jord::spherical::Sphere;
+    ///
+    /// assert_eq!(
+    ///   Angle::from_degrees(260.1271),
+    ///   Sphere::rhumb_bearing(
+    ///     NVector::from_lat_long_degrees(50.36389, -4.15694),
+    ///     NVector::from_lat_long_degrees(42.35111, -71.04083)
+    ///   ).round_d5()
+    /// );
+    /// ```
+    pub fn rhumb_bearing(p1: NVector, p2: NVector) -> Angle {
+        Angle::from_radians(Rhumb::new(1.0, 0.0).inverse(p1, p2).bearing).normalised()
+    }
+
+    /// Computes the position reached from the given position having travelled the given distance along the rhumb
+    /// line of given bearing (compass angle). Since rhumb lines spiral towards the poles, the pole is returned if
+    /// the given distance exceeds the distance to the pole.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong, NVector};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let p = Sphere::EARTH.rhumb_destination_position(
+    ///     NVector::from_lat_long_degrees(0.0, 179.0),
+    ///     Angle::from_degrees(90.0),
+    ///     Sphere::EARTH.radius() * 2.0f64.to_radians()
+    /// );
+    /// assert_eq!(LatLong::from_degrees(0.0, -179.0), LatLong::from_nvector(p).round_d7());
+    /// ```
+    pub fn rhumb_destination_position(
+        &self,
+        p0: NVector,
+        bearing: Angle,
+        distance: Length,
+    ) -> NVector {
+        Rhumb::new(self.radius.as_metres(), 0.0).direct(
+            p0,
+            bearing.as_radians(),
+            distance.as_metres(),
+        )
+    }
+
+    /// Computes the surface distance along the rhumb line between the two given positions; this is never shorter
+    /// than the great circle [distance](crate::spherical::Sphere::distance).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::NVector;
+    /// use jord::spherical::Sphere;
+    ///
+    /// let d = Sphere::EARTH.rhumb_distance(
+    ///     NVector::from_lat_long_degrees(50.36389, -4.15694),
+    ///     NVector::from_lat_long_degrees(42.35111, -71.04083)
+    /// );
+    /// assert_eq!(5196.0, d.as_kilometres().round());
+    /// ```
+    pub fn rhumb_distance(&self, p1: NVector, p2: NVector) -> Length {
+        Length::from_metres(
+            Rhumb::new(self.radius.as_metres(), 0.0)
+                .inverse(p1, p2)
+                .distance,
+        )
+    }
+
+    /// Computes the position at given fraction along the rhumb line between the given positions: e.g. the
+    /// midpoint is obtained with a fraction of 0.5.
+    /// Returns `None` if the given fraction is `< 0` or `> 1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, NVector};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let m = Sphere::rhumb_interpolated_position(
+    ///     NVector::from_lat_long_degrees(50.36389, -4.15694),
+    ///     NVector::from_lat_long_degrees(42.35111, -71.04083),
+    ///     0.5
+    /// );
+    /// assert_eq!(
+    ///     Some(LatLong::from_degrees(46.3575, -38.82746)),
+    ///     m.map(|p| LatLong::from_nvector(p).round_d5())
+    /// );
+    /// ```
+    pub fn rhumb_interpolated_position(p1: NVector, p2: NVector, f: f64) -> Option<NVector> {
+        Rhumb::new(1.0, 0.0).interpolated(p1, p2, f)
+    }
+
     // kinematics
 
     /// Calculates the position that the given vehicle will reach after the given time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use jord::{Angle, LatLong, NVector, Speed, Vehicle};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let rover = Vehicle::new(
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     Angle::ZERO,
+    ///     Speed::from_kilometres_per_hour(10.0),
+    /// );
+    ///
+    /// let p = Sphere::MOON.position_after(rover, Duration::from_secs(3600));
+    /// assert_eq!(
+    ///     LatLong::from_degrees(0.32978, 0.0),
+    ///     LatLong::from_nvector(p).round_d5()
+    /// );
+    /// ```
     pub fn position_after(&self, vehicle: Vehicle, duration: Duration) -> NVector {
-        Sphere::EARTH.destination_position(
+        self.destination_position(
             vehicle.position(),
             vehicle.bearing(),
             vehicle.speed() * duration,
@@ -606,6 +732,115 @@ impl Sphere {
             Some(Duration::from_secs_f64(t_intercept_secs))
         }
     }
+
+    /// Returns the vehicle located at the given offset distance behind the given vehicle along its great circle.
+    fn offset_vehicle(&self, vehicle: Vehicle, offset: Length) -> Vehicle {
+        offset_vehicle(
+            vehicle,
+            offset,
+            |p, b, d| self.destination_position(p, b, d),
+            Sphere::initial_bearing,
+        )
+    }
+}
+
+impl Kinematics for Sphere {
+    fn distance(&self, p1: NVector, p2: NVector) -> Length {
+        Sphere::distance(self, p1, p2)
+    }
+
+    fn position_after(&self, vehicle: Vehicle, duration: Duration) -> NVector {
+        Sphere::position_after(self, vehicle, duration)
+    }
+
+    fn state_after(&self, vehicle: Vehicle, duration: Duration) -> Vehicle {
+        let distance = vehicle.speed() * duration;
+        if distance == Length::ZERO {
+            return vehicle;
+        }
+        let p0 = vehicle.position().as_vec3();
+        let ed = easting(p0);
+        let nd = p0.cross_prod(ed);
+        // central angle
+        let ta = distance.as_metres() / self.radius.as_metres();
+        let bearing_radians = vehicle.bearing().as_radians();
+        let dir = nd * bearing_radians.cos() + ed * bearing_radians.sin();
+        let p1 = (p0 * ta.cos() + dir * ta.sin()).unit();
+        // direction of travel at p1
+        let dir1 = dir * ta.cos() - p0 * ta.sin();
+        let ed1 = easting(p1);
+        let nd1 = p1.cross_prod(ed1);
+        let bearing = Angle::from_radians(dir1.dot_prod(ed1).atan2(dir1.dot_prod(nd1)));
+        Vehicle::new(NVector::new(p1), bearing.normalised(), vehicle.speed())
+    }
+
+    fn time_to_cpa(&self, ownship: Vehicle, intruder: Vehicle) -> Option<Duration> {
+        Sphere::time_to_cpa(self, ownship, intruder)
+    }
+
+    fn cpa(&self, ownship: Vehicle, intruder: Vehicle) -> Cpa {
+        cpa(
+            self,
+            ownship,
+            intruder,
+            |p1, p2| self.distance(p1, p2),
+            Sphere::initial_bearing,
+        )
+    }
+
+    fn max_time_to_intercept(
+        &self,
+        interceptor_pos: NVector,
+        intruder: Vehicle,
+    ) -> Option<Duration> {
+        Sphere::max_time_to_intercept(self, interceptor_pos, intruder)
+    }
+
+    fn time_to_intercept(
+        &self,
+        interceptor_pos: NVector,
+        interceptor_speed: Speed,
+        intruder: Vehicle,
+    ) -> Option<Duration> {
+        Sphere::time_to_intercept(self, interceptor_pos, interceptor_speed, intruder)
+    }
+
+    fn intercept(
+        &self,
+        interceptor_pos: NVector,
+        interceptor_speed: Speed,
+        intruder: Vehicle,
+        offset: Length,
+    ) -> Option<Intercept> {
+        let target = self.offset_vehicle(intruder, offset);
+        let time = Sphere::time_to_intercept(self, interceptor_pos, interceptor_speed, target)?;
+        Some(intercept(
+            self,
+            interceptor_pos,
+            Some(interceptor_speed),
+            target,
+            time,
+            Sphere::initial_bearing,
+        ))
+    }
+
+    fn min_speed_intercept(
+        &self,
+        interceptor_pos: NVector,
+        intruder: Vehicle,
+        offset: Length,
+    ) -> Option<Intercept> {
+        let target = self.offset_vehicle(intruder, offset);
+        let time = Sphere::max_time_to_intercept(self, interceptor_pos, target)?;
+        Some(intercept(
+            self,
+            interceptor_pos,
+            None,
+            target,
+            time,
+            Sphere::initial_bearing,
+        ))
+    }
 }
 
 impl Surface for Sphere {
@@ -1255,7 +1490,7 @@ mod tests {
     #[test]
     fn mean_antipodal() {
         let p = NVector::from_lat_long_degrees(0.0, 0.0);
-        assert!(Sphere::mean_position(&vec!(p, p.antipode())).is_none());
+        assert!(Sphere::mean_position(&[p, p.antipode()]).is_none());
     }
 
     #[test]
@@ -1283,10 +1518,177 @@ mod tests {
     fn mean_one() {
         assert_eq!(
             Some(NVector::from_lat_long_degrees(0.0, 0.0)),
-            Sphere::mean_position(&vec!(NVector::from_lat_long_degrees(0.0, 0.0)))
+            Sphere::mean_position(&[NVector::from_lat_long_degrees(0.0, 0.0)])
+        );
+    }
+
+    // rhumb lines
+
+    #[test]
+    fn rhumb_bearing_across_date_line() {
+        let p1 = NVector::from_lat_long_degrees(0.0, 179.0);
+        let p2 = NVector::from_lat_long_degrees(0.0, -179.0);
+        assert_eq!(
+            Angle::from_degrees(90.0),
+            Sphere::rhumb_bearing(p1, p2).round_d7()
+        );
+        assert_eq!(
+            Angle::from_degrees(270.0),
+            Sphere::rhumb_bearing(p2, p1).round_d7()
         );
     }
 
+    #[test]
+    fn rhumb_bearing_coincidental() {
+        let p = NVector::from_lat_long_degrees(50.0, 5.0);
+        assert_eq!(Angle::ZERO, Sphere::rhumb_bearing(p, p));
+    }
+
+    #[test]
+    fn rhumb_bearing_poles() {
+        let p = NVector::from_lat_long_degrees(50.0, 5.0);
+        let np = NVector::from_lat_long_degrees(90.0, 0.0);
+        let sp = NVector::from_lat_long_degrees(-90.0, 0.0);
+        assert_eq!(Angle::ZERO, Sphere::rhumb_bearing(p, np).round_d7());
+        assert_eq!(Angle::HALF_CIRCLE, Sphere::rhumb_bearing(p, sp).round_d7());
+        assert_eq!(Angle::HALF_CIRCLE, Sphere::rhumb_bearing(np, p).round_d7());
+        assert_eq!(Angle::ZERO, Sphere::rhumb_bearing(sp, p).round_d7());
+    }
+
+    #[test]
+    fn rhumb_destination_position_across_date_line() {
+        let p = NVector::from_lat_long_degrees(60.0, 179.0);
+        let d = Sphere::EARTH.rhumb_distance(p, NVector::from_lat_long_degrees(60.0, -179.0));
+        assert_nv_eq_d7(
+            NVector::from_lat_long_degrees(60.0, -179.0),
+            Sphere::EARTH.rhumb_destination_position(p, Angle::from_degrees(90.0), d),
+        );
+    }
+
+    #[test]
+    fn rhumb_destination_position_beyond_pole() {
+        let p = NVector::from_lat_long_degrees(80.0, 0.0);
+        assert_nv_eq_d7(
+            NVector::from_lat_long_degrees(90.0, 0.0),
+            Sphere::EARTH.rhumb_destination_position(
+                p,
+                Angle::from_degrees(45.0),
+                Length::from_kilometres(10_000.0),
+            ),
+        );
+    }
+
+    #[test]
+    fn rhumb_destination_position_from_pole() {
+        let p = NVector::from_lat_long_degrees(90.0, 0.0);
+        let d = Sphere::EARTH.radius() * (PI / 4.0);
+        assert_eq!(
+            45.0,
+            LatLong::from_nvector(Sphere::EARTH.rhumb_destination_position(
+                p,
+                Angle::HALF_CIRCLE,
+                d
+            ))
+            .round_d7()
+            .latitude()
+            .as_degrees()
+        );
+    }
+
+    #[test]
+    fn rhumb_destination_position_round_trip() {
+        let p1 = NVector::from_lat_long_degrees(-33.8688, 151.2093);
+        let p2 = NVector::from_lat_long_degrees(37.7749, -122.4194);
+        let b = Sphere::rhumb_bearing(p1, p2);
+        let d = Sphere::EARTH.rhumb_distance(p1, p2);
+        assert_nv_eq_d7(p2, Sphere::EARTH.rhumb_destination_position(p1, b, d));
+    }
+
+    #[test]
+    fn rhumb_destination_position_zero_distance() {
+        let p = NVector::from_lat_long_degrees(55.0, 55.0);
+        assert_eq!(
+            p,
+            Sphere::EARTH.rhumb_destination_position(p, Angle::from_degrees(10.0), Length::ZERO)
+        );
+    }
+
+    #[test]
+    fn rhumb_distance_along_meridian() {
+        let p1 = NVector::from_lat_long_degrees(10.0, 20.0);
+        let p2 = NVector::from_lat_long_degrees(-40.0, 20.0);
+        assert_eq!(
+            Sphere::EARTH.distance(p1, p2).round_mm(),
+            Sphere::EARTH.rhumb_distance(p1, p2).round_mm()
+        );
+    }
+
+    #[test]
+    fn rhumb_distance_along_parallel() {
+        let p1 = NVector::from_lat_long_degrees(60.0, 0.0);
+        let p2 = NVector::from_lat_long_degrees(60.0, 10.0);
+        let expected = Sphere::EARTH.radius() * (60.0f64.to_radians().cos() * 10.0f64.to_radians());
+        assert_eq!(
+            expected.round_mm(),
+            Sphere::EARTH.rhumb_distance(p1, p2).round_mm()
+        );
+        assert!(Sphere::EARTH.rhumb_distance(p1, p2) > Sphere::EARTH.distance(p1, p2));
+    }
+
+    #[test]
+    fn rhumb_distance_pole() {
+        let p1 = NVector::from_lat_long_degrees(0.0, 20.0);
+        let p2 = NVector::from_lat_long_degrees(90.0, 0.0);
+        assert_eq!(
+            (Sphere::EARTH.radius() * (PI / 2.0)).round_mm(),
+            Sphere::EARTH.rhumb_distance(p1, p2).round_mm()
+        );
+    }
+
+    #[test]
+    fn rhumb_distance_zero() {
+        let p = NVector::from_lat_long_degrees(50.0, 5.0);
+        assert_eq!(Length::ZERO, Sphere::EARTH.rhumb_distance(p, p));
+    }
+
+    #[test]
+    fn rhumb_interpolated_position_across_date_line() {
+        assert_opt_nv_eq_d7(
+            NVector::from_lat_long_degrees(0.0, 180.0),
+            Sphere::rhumb_interpolated_position(
+                NVector::from_lat_long_degrees(0.0, 179.0),
+                NVector::from_lat_long_degrees(0.0, -179.0),
+                0.5,
+            ),
+        );
+    }
+
+    #[test]
+    fn rhumb_interpolated_position_f0_f1() {
+        let p1 = NVector::from_lat_long_degrees(10.0, 20.0);
+        let p2 = NVector::from_lat_long_degrees(20.0, 30.0);
+        assert_eq!(Some(p1), Sphere::rhumb_interpolated_position(p1, p2, 0.0));
+        assert_eq!(Some(p2), Sphere::rhumb_interpolated_position(p1, p2, 1.0));
+    }
+
+    #[test]
+    fn rhumb_interpolated_position_invalid_f() {
+        let p1 = NVector::from_lat_long_degrees(10.0, 20.0);
+        let p2 = NVector::from_lat_long_degrees(20.0, 30.0);
+        assert!(Sphere::rhumb_interpolated_position(p1, p2, -0.1).is_none());
+        assert!(Sphere::rhumb_interpolated_position(p1, p2, 1.1).is_none());
+    }
+
+    #[test]
+    fn rhumb_interpolated_position_constant_bearing() {
+        let p1 = NVector::from_lat_long_degrees(10.0, 20.0);
+        let p2 = NVector::from_lat_long_degrees(50.0, 80.0);
+        let b = Sphere::rhumb_bearing(p1, p2);
+        let i = Sphere::rhumb_interpolated_position(p1, p2, 0.3).unwrap();
+        assert_eq!(b.round_d7(), Sphere::rhumb_bearing(p1, i).round_d7());
+        assert_eq!(b.round_d7(), Sphere::rhumb_bearing(i, p2).round_d7());
+    }
+
     // side
 
     #[test]
@@ -1437,6 +1839,28 @@ mod tests {
         assert_eq!(Some(PI), r);
     }
 
+    // position_after
+
+    #[test]
+    fn position_after_uses_radius() {
+        let v = Vehicle::new(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            Angle::ZERO,
+            Speed::from_metres_per_second(100.0),
+        );
+        let d = Duration::from_secs(3600);
+        for s in [Sphere::EARTH, Sphere::MOON] {
+            assert_eq!(
+                s.destination_position(v.position(), v.bearing(), Length::from_metres(360_000.0)),
+                s.position_after(v, d)
+            );
+        }
+        assert_ne!(
+            Sphere::EARTH.position_after(v, d),
+            Sphere::MOON.position_after(v, d)
+        );
+    }
+
     // time to CPA
 
     #[test]
@@ -1538,7 +1962,7 @@ mod tests {
         );
 
         assert_time_to_cpa(
-            Duration::from_millis(113_961_40),
+            Duration::from_millis(11_396_140),
             Sphere::EARTH.time_to_cpa(ownship, intruder),
         );
     }
@@ -0,0 +1,643 @@
+use std::{fmt, str::FromStr};
+
+use crate::{Angle, LatLong};
+
+/// Textual representation of a [LatLong].
+///
+/// The precision given alongside the format is the number of decimal places of the last component: degrees,
+/// minutes or seconds.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub enum LatLongFormat {
+    /// Decimal degrees with hemisphere letters, e.g. `55.60583°N, 13.00194°E`.
+    #[default]
+    DecimalDegrees,
+    /// Degrees and decimal minutes with hemisphere letters, e.g. `55°36.350'N, 13°00.117'E`.
+    DegreesMinutes,
+    /// Degrees, minutes and decimal seconds with hemisphere letters, e.g. `55°36'21.0"N, 13°00'07.0"E`.
+    DegreesMinutesSeconds,
+    /// [ISO 6709](https://en.wikipedia.org/wiki/ISO_6709) signed decimal degrees, e.g. `+55.60583+013.00194/`.
+    Iso6709,
+}
+
+/// Error returned when parsing a malformed [Angle] or [LatLong].
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum ParseError {
+    /// The string is empty.
+    Empty,
+    /// The string does not follow any of the supported formats.
+    InvalidFormat(String),
+    /// A component is not a valid number.
+    InvalidNumber(String),
+    /// A hemisphere letter is not valid for the component: N or S for latitudes, E or W for longitudes.
+    InvalidHemisphere(char),
+    /// A component is outside of its valid range: minutes and seconds in [0, 60), latitude in [-90, 90] and
+    /// longitude in [-180, 180].
+    OutOfRange(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "empty string"),
+            ParseError::InvalidFormat(s) => write!(f, "unsupported format: '{}'", s),
+            ParseError::InvalidNumber(s) => write!(f, "invalid number: '{}'", s),
+            ParseError::InvalidHemisphere(c) => write!(f, "invalid hemisphere: '{}'", c),
+            ParseError::OutOfRange(s) => write!(f, "value out of range: '{}'", s),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl fmt::Display for Angle {
+    /// Formats this angle in decimal degrees followed by the degree sign; the precision of the formatter, if any,
+    /// is the number of decimal places.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(p) => write!(f, "{:.p$}°", rounded_zero(self.as_degrees(), 1.0, p), p = p),
+            // adding 0 turns -0 into 0.
+            None => write!(f, "{}°", self.as_degrees() + 0.0),
+        }
+    }
+}
+
+impl FromStr for Angle {
+    type Err = ParseError;
+
+    /// Parses an angle given in signed decimal degrees, degrees and decimal minutes or degrees, minutes and
+    /// decimal seconds, optionally prefixed or suffixed by a hemisphere letter (S and W denote negative angles).
+    ///
+    /// Components may be separated by whitespace, `:` or the degree (`°`, `º`), minute (`'`, `′`) and
+    /// second (`"`, `″`) symbols.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::Angle;
+    ///
+    /// assert_eq!(Ok(Angle::from_degrees(-55.5)), "-55.5".parse::<Angle>());
+    /// assert_eq!(Ok(Angle::from_degrees(-55.5)), "55°30'S".parse::<Angle>());
+    /// assert_eq!(Ok(Angle::from_degrees(55.5)), "N 55 30 0".parse::<Angle>());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_angle(s).map(|(degrees, _)| Angle::from_degrees(degrees))
+    }
+}
+
+impl fmt::Display for LatLong {
+    /// Formats this position in decimal degrees with hemisphere letters, e.g. `55.60583°N, 13.00194°E`; the
+    /// precision of the formatter is the number of decimal places (5 if not specified).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let p = f.precision().unwrap_or(5);
+        f.write_str(&format_lat_long(*self, LatLongFormat::DecimalDegrees, p))
+    }
+}
+
+impl FromStr for LatLong {
+    type Err = ParseError;
+
+    /// Parses a latitude and longitude given either:
+    /// - as an [ISO 6709](https://en.wikipedia.org/wiki/ISO_6709) string, e.g. `+55.6058+013.0019/` or
+    ///   `+553621+0130007/`; the height and the CRS are ignored,
+    /// - as 2 components separated by a comma, by whitespace or delimited by their hemisphere letters, each
+    ///   component being in any of the formats accepted by [Angle::from_str]; when hemisphere letters are given,
+    ///   the longitude may come first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::LatLong;
+    ///
+    /// let expected = LatLong::from_degrees(55.5, -13.25);
+    /// assert_eq!(Ok(expected), "55.5, -13.25".parse::<LatLong>());
+    /// assert_eq!(Ok(expected), "55°30'N 13°15'W".parse::<LatLong>());
+    /// assert_eq!(Ok(expected), "W13 15 N55 30".parse::<LatLong>());
+    /// assert_eq!(Ok(expected), "+5530-01315/".parse::<LatLong>());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_lat_long(s)
+    }
+}
+
+/// Formats the given latitude and longitude (degrees) using the given format and precision.
+pub(crate) fn format_lat_long(ll: LatLong, format: LatLongFormat, precision: usize) -> String {
+    // components that round to zero have no sign.
+    let units_per_degree = match format {
+        LatLongFormat::DegreesMinutes => 60.0,
+        LatLongFormat::DegreesMinutesSeconds => 3600.0,
+        _ => 1.0,
+    };
+    let lat = rounded_zero(ll.latitude().as_degrees(), units_per_degree, precision);
+    let lon = rounded_zero(ll.longitude().as_degrees(), units_per_degree, precision);
+    let ns = if lat < 0.0 { 'S' } else { 'N' };
+    let ew = if lon < 0.0 { 'W' } else { 'E' };
+    match format {
+        LatLongFormat::DecimalDegrees => format!(
+            "{:.p$}°{}, {:.p$}°{}",
+            lat.abs(),
+            ns,
+            lon.abs(),
+            ew,
+            p = precision
+        ),
+        LatLongFormat::DegreesMinutes => {
+            format!(
+                "{}{}, {}{}",
+                format_dm(lat.abs(), precision),
+                ns,
+                format_dm(lon.abs(), precision),
+                ew
+            )
+        }
+        LatLongFormat::DegreesMinutesSeconds => {
+            format!(
+                "{}{}, {}{}",
+                format_dms(lat.abs(), precision),
+                ns,
+                format_dms(lon.abs(), precision),
+                ew
+            )
+        }
+        LatLongFormat::Iso6709 => {
+            let w = if precision == 0 { 0 } else { precision + 1 };
+            format!(
+                "{:+0lat_w$.p$}{:+0lon_w$.p$}/",
+                lat,
+                lon,
+                lat_w = 3 + w,
+                lon_w = 4 + w,
+                p = precision
+            )
+        }
+    }
+}
+
+/// Returns 0 if the given angle in degrees rounds to zero in the unit given by its number per degree with the given
+/// number of decimal places, otherwise the given angle.
+fn rounded_zero(degrees: f64, units_per_degree: f64, precision: usize) -> f64 {
+    let scale = units_per_degree * 10f64.powi(precision as i32);
+    if (degrees * scale).round() == 0.0 {
+        0.0
+    } else {
+        degrees
+    }
+}
+
+/// degrees and decimal minutes of the given positive angle in degrees.
+fn format_dm(degrees: f64, precision: usize) -> String {
+    let scale = 10f64.powi(precision as i32);
+    // round once in the smallest unit so that e.g. 59.9999' is carried to the degrees.
+    let total = (degrees * 60.0 * scale).round();
+    let per_degree = 60.0 * scale;
+    let d = (total / per_degree).floor();
+    let m = (total - d * per_degree) / scale;
+    let w = if precision == 0 { 2 } else { precision + 3 };
+    format!("{}°{:0w$.p$}'", d, m, w = w, p = precision)
+}
+
+/// degrees, minutes and decimal seconds of the given positive angle in degrees.
+fn format_dms(degrees: f64, precision: usize) -> String {
+    let scale = 10f64.powi(precision as i32);
+    let total = (degrees * 3600.0 * scale).round();
+    let per_degree = 3600.0 * scale;
+    let per_minute = 60.0 * scale;
+    let d = (total / per_degree).floor();
+    let rem = total - d * per_degree;
+    let m = (rem / per_minute).floor();
+    let s = (rem - m * per_minute) / scale;
+    let w = if precision == 0 { 2 } else { precision + 3 };
+    format!("{}°{:02}'{:0w$.p$}\"", d, m, s, w = w, p = precision)
+}
+
+/// Parses an angle in degrees: signed decimal degrees or degrees, minutes and seconds with an optional hemisphere
+/// letter; returns the angle in degrees and the hemisphere letter if any.
+fn parse_angle(s: &str) -> Result<(f64, Option<char>), ParseError> {
+    let t = s.trim();
+    if t.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    let (t, hemisphere) = strip_hemisphere(t)?;
+    let (t, negative) = match t.strip_prefix('-') {
+        Some(r) => (r, true),
+        None => (t.strip_prefix('+').unwrap_or(t), false),
+    };
+    if negative && hemisphere.is_some() {
+        return Err(ParseError::InvalidFormat(s.to_string()));
+    }
+    let parts: Vec<&str> = t
+        .split(|c: char| c.is_whitespace() || "°º'′\"″:".contains(c))
+        .filter(|p| !p.is_empty())
+        .collect();
+    if parts.is_empty() || parts.len() > 3 {
+        return Err(ParseError::InvalidFormat(s.to_string()));
+    }
+    let mut values = Vec::with_capacity(3);
+    for p in &parts {
+        // digits with an optional decimal point only: no sign, exponent, infinity or NaN.
+        let v = Some(p)
+            .filter(|p| {
+                p.contains(|c: char| c.is_ascii_digit())
+                    && p.chars().all(|c| c.is_ascii_digit() || c == '.')
+                    && p.matches('.').count() <= 1
+            })
+            .and_then(|p| p.parse::<f64>().ok())
+            .ok_or_else(|| ParseError::InvalidNumber(p.to_string()))?;
+        values.push(v);
+    }
+    // only the last component may have decimals; minutes and seconds must be less than 60.
+    for (i, v) in values.iter().enumerate() {
+        if i + 1 < values.len() && v.fract() != 0.0 {
+            return Err(ParseError::InvalidFormat(s.to_string()));
+        }
+        if i > 0 && *v >= 60.0 {
+            return Err(ParseError::OutOfRange(parts[i].to_string()));
+        }
+    }
+    let degrees = values
+        .iter()
+        .zip([1.0, 60.0, 3600.0])
+        .fold(0.0, |acc, (v, d)| acc + v / d);
+    let negative = negative || matches!(hemisphere, Some('S') | Some('W'));
+    Ok((if negative { -degrees } else { degrees }, hemisphere))
+}
+
+/// Parses a latitude and longitude: see [LatLong::from_str].
+fn parse_lat_long(s: &str) -> Result<LatLong, ParseError> {
+    let t = s.trim();
+    if t.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    let (lat, lon) = if is_iso6709(t) {
+        parse_iso6709(t)?
+    } else {
+        let (a, b) = split_lat_long(t)?;
+        let (va, ha) = parse_angle(a)?;
+        let (vb, hb) = parse_angle(b)?;
+        match (ha, hb) {
+            (Some('E') | Some('W'), Some('N') | Some('S')) => (vb, va),
+            (Some('E') | Some('W'), _) => {
+                return Err(ParseError::InvalidHemisphere(ha.unwrap_or('E')))
+            }
+            (_, Some('N') | Some('S')) => {
+                return Err(ParseError::InvalidHemisphere(hb.unwrap_or('N')))
+            }
+            _ => (va, vb),
+        }
+    };
+    if lat.abs() > 90.0 {
+        return Err(ParseError::OutOfRange(format!("latitude {}", lat)));
+    }
+    if lon.abs() > 180.0 {
+        return Err(ParseError::OutOfRange(format!("longitude {}", lon)));
+    }
+    Ok(LatLong::new(
+        Angle::from_degrees(lat),
+        Angle::from_degrees(lon),
+    ))
+}
+
+/// removes the leading or trailing hemisphere letter of the given trimmed component.
+fn strip_hemisphere(t: &str) -> Result<(&str, Option<char>), ParseError> {
+    let is_letter = |c: char| c.is_ascii_alphabetic();
+    let first = t.chars().next().filter(|c| is_letter(*c));
+    let last = t.chars().last().filter(|c| is_letter(*c));
+    let (rest, letter) = match (first, last) {
+        (Some(c), Some(_)) if t.len() == 1 => ("", Some(c)),
+        (Some(_), Some(_)) => return Err(ParseError::InvalidFormat(t.to_string())),
+        (Some(c), None) => (&t[1..], Some(c)),
+        (None, Some(c)) => (&t[..t.len() - 1], Some(c)),
+        (None, None) => (t, None),
+    };
+    match letter.map(|c| c.to_ascii_uppercase()) {
+        Some(c) if "NSEW".contains(c) => Ok((rest.trim(), Some(c))),
+        Some(c) => Err(ParseError::InvalidHemisphere(c)),
+        None => Ok((rest, None)),
+    }
+}
+
+/// splits the given string into the latitude and longitude components.
+fn split_lat_long(t: &str) -> Result<(&str, &str), ParseError> {
+    let commas = t.matches(',').count();
+    if commas == 1 {
+        return t
+            .split_once(',')
+            .ok_or_else(|| ParseError::InvalidFormat(t.to_string()));
+    }
+    if commas > 1 {
+        return Err(ParseError::InvalidFormat(t.to_string()));
+    }
+    let letters: Vec<usize> = t
+        .char_indices()
+        .filter(|(_, c)| c.is_ascii_alphabetic())
+        .map(|(i, _)| i)
+        .collect();
+    if letters.len() == 2 {
+        // hemisphere letters: either both leading (N55 E13) or both trailing (55N 13E).
+        let i = if letters[0] == 0 {
+            letters[1]
+        } else {
+            letters[0] + 1
+        };
+        return Ok(t.split_at(i));
+    }
+    if !letters.is_empty() {
+        return Err(ParseError::InvalidFormat(t.to_string()));
+    }
+    // whitespace separated components: the first half is the latitude.
+    let tokens: Vec<&str> = t.split_whitespace().collect();
+    if tokens.len() % 2 != 0 || tokens.len() > 6 {
+        return Err(ParseError::InvalidFormat(t.to_string()));
+    }
+    let n = tokens.len() / 2;
+    let mut in_token = false;
+    let mut count = 0;
+    for (i, c) in t.char_indices() {
+        if !c.is_whitespace() {
+            in_token = true;
+        } else if in_token {
+            in_token = false;
+            count += 1;
+            if count == n {
+                return Ok(t.split_at(i));
+            }
+        }
+    }
+    Err(ParseError::InvalidFormat(t.to_string()))
+}
+
+/// whether the given trimmed string is an ISO 6709 string: signed components without separators.
+fn is_iso6709(t: &str) -> bool {
+    t.starts_with(['+', '-'])
+        && !t.contains(|c: char| c.is_whitespace() || c == ',')
+        && t[1..].contains(['+', '-'])
+}
+
+/// parses an ISO 6709 string: ±DD[.D]±DDD[.D], ±DDMM[.M]±DDDMM[.M] or ±DDMMSS[.S]±DDDMMSS[.S], followed by an
+/// optional height, an optional CRS identifier and an optional '/'.
+fn parse_iso6709(t: &str) -> Result<(f64, f64), ParseError> {
+    let body = t.strip_suffix('/').unwrap_or(t);
+    let body = match body.find("CRS") {
+        Some(i) => &body[..i],
+        None => body,
+    };
+    let starts: Vec<usize> = body
+        .char_indices()
+        .filter(|(_, c)| *c == '+' || *c == '-')
+        .map(|(i, _)| i)
+        .collect();
+    // latitude, longitude and optional height.
+    if starts.len() < 2 || starts.len() > 3 {
+        return Err(ParseError::InvalidFormat(t.to_string()));
+    }
+    let lat = &body[starts[0]..starts[1]];
+    let lon = match starts.get(2) {
+        Some(e) => &body[starts[1]..*e],
+        None => &body[starts[1]..],
+    };
+    Ok((iso_component(lat, 2)?, iso_component(lon, 3)?))
+}
+
+/// parses a signed ISO 6709 component with the given number of degree digits.
+fn iso_component(c: &str, degree_digits: usize) -> Result<f64, ParseError> {
+    let negative = c.starts_with('-');
+    let digits = &c[1..];
+    let int_len = digits.find('.').unwrap_or(digits.len());
+    if !digits.chars().all(|ch| ch.is_ascii_digit() || ch == '.') || digits.matches('.').count() > 1
+    {
+        return Err(ParseError::InvalidNumber(c.to_string()));
+    }
+    let number = |s: &str| {
+        s.parse::<f64>()
+            .map_err(|_| ParseError::InvalidNumber(c.to_string()))
+    };
+    let (d, m, s) = if int_len == degree_digits {
+        (number(digits)?, 0.0, 0.0)
+    } else if int_len == degree_digits + 2 {
+        (
+            number(&digits[..degree_digits])?,
+            number(&digits[degree_digits..])?,
+            0.0,
+        )
+    } else if int_len == degree_digits + 4 {
+        (
+            number(&digits[..degree_digits])?,
+            number(&digits[degree_digits..degree_digits + 2])?,
+            number(&digits[degree_digits + 2..])?,
+        )
+    } else {
+        return Err(ParseError::InvalidFormat(c.to_string()));
+    };
+    if m >= 60.0 || s >= 60.0 {
+        return Err(ParseError::OutOfRange(c.to_string()));
+    }
+    let v = d + m / 60.0 + s / 3600.0;
+    Ok(if negative { -v } else { v })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Angle, LatLong, LatLongFormat, ParseError};
+
+    #[test]
+    fn display_angle() {
+        assert_eq!("45.5°", Angle::from_degrees(45.5).to_string());
+        assert_eq!("-45.50°", format!("{:.2}", Angle::from_degrees(-45.5)));
+        assert_eq!("0.00°", format!("{:.2}", Angle::from_degrees(-1e-9)));
+        assert_eq!("0°", Angle::from_degrees(-0.0).to_string());
+    }
+
+    #[test]
+    fn display_lat_long() {
+        let p = LatLong::from_degrees(-33.856784, 151.215297);
+        assert_eq!("33.85678°S, 151.21530°E", p.to_string());
+        assert_eq!("33.9°S, 151.2°E", format!("{:.1}", p));
+        let p = LatLong::from_degrees(-1e-9, -1e-9);
+        assert_eq!("0.00°N, 0.00°E", format!("{:.2}", p));
+        assert_eq!(
+            "0°00.00'N, 0°00.00'E",
+            p.format(LatLongFormat::DegreesMinutes, 2)
+        );
+        assert_eq!(
+            "0°00'00.0\"N, 0°00'00.0\"E",
+            p.format(LatLongFormat::DegreesMinutesSeconds, 1)
+        );
+        assert_eq!("+00.00+000.00/", p.format(LatLongFormat::Iso6709, 2));
+    }
+
+    #[test]
+    fn format_carries() {
+        // 59.9999' and 59.9999" must be carried over to the next component.
+        let p = LatLong::from_degrees(10.0 - 1e-7, -(20.0 - 1e-7));
+        assert_eq!(
+            "10°00.00'N, 20°00.00'W",
+            p.format(LatLongFormat::DegreesMinutes, 2)
+        );
+        assert_eq!(
+            "10°00'00.0\"N, 20°00'00.0\"W",
+            p.format(LatLongFormat::DegreesMinutesSeconds, 1)
+        );
+    }
+
+    #[test]
+    fn format_iso6709() {
+        assert_eq!(
+            "+00.00+000.00/",
+            LatLong::from_degrees(0.0, 0.0).format(LatLongFormat::Iso6709, 2)
+        );
+        assert_eq!(
+            "-05-005/",
+            LatLong::from_degrees(-5.0, -5.0).format(LatLongFormat::Iso6709, 0)
+        );
+        assert_eq!(
+            "+90.0-180.0/",
+            LatLong::from_degrees(90.0, -180.0).format(LatLongFormat::Iso6709, 1)
+        );
+    }
+
+    #[test]
+    fn parse_angle() {
+        let expected = Ok(Angle::from_degrees(12.5125));
+        assert_eq!(expected, "12.5125".parse::<Angle>());
+        assert_eq!(expected, "+12.5125°".parse::<Angle>());
+        assert_eq!(expected, "12°30.75'".parse::<Angle>());
+        assert_eq!(expected, "12°30′45″".parse::<Angle>());
+        assert_eq!(expected, "12:30:45".parse::<Angle>());
+        assert_eq!(expected, " 12 30 45 E ".parse::<Angle>());
+        assert_eq!(expected, "n12º30'45\"".parse::<Angle>());
+        assert_eq!(
+            Ok(Angle::from_degrees(-12.5125)),
+            "W 12 30 45".parse::<Angle>()
+        );
+    }
+
+    #[test]
+    fn parse_angle_errors() {
+        assert_eq!(Err(ParseError::Empty), "  ".parse::<Angle>());
+        assert_eq!(
+            Err(ParseError::InvalidNumber("1x".to_string())),
+            "1x 30".parse::<Angle>()
+        );
+        assert_eq!(
+            Err(ParseError::InvalidHemisphere('Q')),
+            "12Q".parse::<Angle>()
+        );
+        assert_eq!(
+            Err(ParseError::OutOfRange("60".to_string())),
+            "12 60".parse::<Angle>()
+        );
+        assert_eq!(
+            Err(ParseError::OutOfRange("60.5".to_string())),
+            "12 30 60.5".parse::<Angle>()
+        );
+        // decimals only on the last component, sign and hemisphere are exclusive.
+        assert!(matches!(
+            "12.5 30".parse::<Angle>(),
+            Err(ParseError::InvalidFormat(_))
+        ));
+        assert!(matches!(
+            "-12S".parse::<Angle>(),
+            Err(ParseError::InvalidFormat(_))
+        ));
+        assert!(matches!(
+            "1 2 3 4".parse::<Angle>(),
+            Err(ParseError::InvalidFormat(_))
+        ));
+        // only digits and a decimal point.
+        for s in [
+            "1e1", "1E1", "1e1N", "10 1e1", "inf", "N inf", "NaN", "1.2.3", ".",
+        ] {
+            assert!(s.parse::<Angle>().is_err(), "{}", s);
+        }
+        assert_eq!(
+            Err(ParseError::InvalidNumber("1e1".to_string())),
+            "1e1N".parse::<Angle>()
+        );
+    }
+
+    #[test]
+    fn parse_lat_long() {
+        let expected = Ok(LatLong::from_degrees(-33.5, 151.25));
+        assert_eq!(expected, "-33.5, 151.25".parse::<LatLong>());
+        assert_eq!(expected, "-33.5 151.25".parse::<LatLong>());
+        assert_eq!(expected, "-33 30 151 15".parse::<LatLong>());
+        assert_eq!(expected, "33°30'S 151°15'E".parse::<LatLong>());
+        assert_eq!(expected, "33°30.0'S,151°15.0'E".parse::<LatLong>());
+        assert_eq!(expected, "S33 30 00 E151 15 00".parse::<LatLong>());
+        assert_eq!(expected, "151.25E 33.5S".parse::<LatLong>());
+    }
+
+    #[test]
+    fn parse_iso6709() {
+        let expected = Ok(LatLong::from_degrees(-33.5, 151.25));
+        assert_eq!(expected, "-33.5+151.25/".parse::<LatLong>());
+        assert_eq!(expected, "-3330+15115".parse::<LatLong>());
+        assert_eq!(expected, "-333000+1511500/".parse::<LatLong>());
+        assert_eq!(
+            expected,
+            "-333000.0+1511500.0+58CRSWGS_84/".parse::<LatLong>()
+        );
+        assert_eq!(
+            Ok(LatLong::from_degrees(40.20361, -75.00417)),
+            "+40.20361-075.00417/".parse::<LatLong>()
+        );
+        assert!(matches!(
+            "+4+075/".parse::<LatLong>(),
+            Err(ParseError::InvalidFormat(_))
+        ));
+        assert!(matches!(
+            "+4060-07500/".parse::<LatLong>(),
+            Err(ParseError::OutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn parse_lat_long_errors() {
+        assert_eq!(Err(ParseError::Empty), "".parse::<LatLong>());
+        assert_eq!(
+            Err(ParseError::InvalidHemisphere('E')),
+            "10E, 20E".parse::<LatLong>()
+        );
+        assert_eq!(
+            Err(ParseError::InvalidHemisphere('N')),
+            "10N 20N".parse::<LatLong>()
+        );
+        assert!(matches!(
+            "91, 0".parse::<LatLong>(),
+            Err(ParseError::OutOfRange(_))
+        ));
+        assert!(matches!(
+            "0, -180.5".parse::<LatLong>(),
+            Err(ParseError::OutOfRange(_))
+        ));
+        assert!(matches!(
+            "1, 2, 3".parse::<LatLong>(),
+            Err(ParseError::InvalidFormat(_))
+        ));
+        assert!(matches!(
+            "1 2 3".parse::<LatLong>(),
+            Err(ParseError::InvalidFormat(_))
+        ));
+    }
+
+    #[test]
+    fn round_trip() {
+        let p = LatLong::from_degrees(-33.856784, 151.215297);
+        for (format, precision) in [
+            (LatLongFormat::DecimalDegrees, 6),
+            (LatLongFormat::DegreesMinutes, 4),
+            (LatLongFormat::DegreesMinutesSeconds, 2),
+            (LatLongFormat::Iso6709, 6),
+        ] {
+            let actual: LatLong = p.format(format, precision).parse().unwrap();
+            assert_eq!(p.round_d5(), actual.round_d5(), "{:?}", format);
+        }
+    }
+
+    #[test]
+    fn display_error() {
+        assert_eq!(
+            "invalid hemisphere: 'Q'",
+            ParseError::InvalidHemisphere('Q').to_string()
+        );
+    }
+}